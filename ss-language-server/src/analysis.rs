@@ -0,0 +1,514 @@
+//! Everything the language server knows about a given `.ss` document, derived
+//! from the same parser and command declarations the compiler uses.
+use std::path::{Path, PathBuf};
+use std::panic::AssertUnwindSafe;
+use either::Either::Left;
+use lsp_types::{
+    CompletionItem,
+    CompletionItemKind,
+    CompletionTextEdit,
+    Diagnostic,
+    DiagnosticSeverity,
+    DocumentSymbol,
+    Documentation,
+    MarkupContent,
+    MarkupKind,
+    Position,
+    Range,
+    SymbolKind,
+    TextEdit,
+};
+use subscript_compiler::ss::{
+    Ann,
+    CharRange,
+    CmdCall,
+    ContentMode,
+    Ident,
+    LayoutMode,
+    Node,
+    ResourceEnv,
+    SemanticScope,
+};
+use subscript_compiler::ss::completion::{self, CompletionKind};
+use subscript_compiler::ss::diagnostics::{self as compiler_diagnostics, Severity};
+use subscript_compiler::ss::cmd_decl::{
+    cmd_invocation::{ArgumentDeclMap, CmdPayload, Internal, Metadata},
+    ArgumentsDeclInstance,
+    AttributeValueType,
+    CmdDeclaration,
+    Override,
+    VariableArguments,
+};
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LINE INDEX
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Maps byte offsets into the source to LSP positions and back. LSP columns
+/// are counted in UTF-16 code units.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(ix, _)| ix + 1))
+            .collect();
+        LineIndex{line_starts}
+    }
+    pub fn position(&self, source: &str, byte_index: usize) -> Position {
+        let byte_index = byte_index.min(source.len());
+        let line = match self.line_starts.binary_search(&byte_index) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let line_start = self.line_starts[line];
+        let character = source
+            .get(line_start..byte_index)
+            .map(|x| x.encode_utf16().count())
+            .unwrap_or(0);
+        Position::new(line as u32, character as u32)
+    }
+    pub fn range(&self, source: &str, range: CharRange) -> Range {
+        Range::new(
+            self.position(source, range.start.byte_index),
+            self.position(source, range.end.byte_index),
+        )
+    }
+    pub fn byte_index(&self, source: &str, position: Position) -> usize {
+        let line_start = match self.line_starts.get(position.line as usize) {
+            Some(line_start) => *line_start,
+            None => return source.len(),
+        };
+        let mut utf16_count = 0;
+        for (ix, ch) in source[line_start..].char_indices() {
+            if ch == '\n' || utf16_count >= position.character as usize {
+                return line_start + ix;
+            }
+            utf16_count += ch.len_utf16();
+        }
+        source.len()
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// DOCUMENT ANALYSIS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    pub source: String,
+    pub line_index: LineIndex,
+    pub scope: SemanticScope,
+    /// `None` if the parser panicked.
+    pub ast: Option<Node>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Analysis {
+    pub fn new(file_path: Option<&Path>, source: String) -> Self {
+        let line_index = LineIndex::new(&source);
+        let scope = init_scope(file_path);
+        let env = ResourceEnv::default();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let ast = subscript_compiler::ss::parser::parse_source(&env, &scope, &source)
+                .apply_commands(&env, &scope);
+            subscript_compiler::ss::validation::validate(&env, &scope, &ast);
+            ast
+        }));
+        let mut analysis = Analysis {
+            source,
+            line_index,
+            scope,
+            ast: None,
+            diagnostics: Vec::new(),
+        };
+        match result {
+            Ok(ast) => {
+                // E.g. a macro argument that's used twice in its template is
+                // validated twice.
+                analysis.diagnostics = compiler_diagnostics::normalize(env.diagnostics())
+                    .into_iter()
+                    .filter(|x| x.file_path.is_none() || x.file_path == analysis.scope.file_path)
+                    .map(|x| analysis.to_lsp_diagnostic(x))
+                    .collect();
+                analysis.ast = Some(ast);
+            }
+            Err(payload) => {
+                let reason = payload
+                    .downcast_ref::<&str>()
+                    .map(|x| x.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| String::from("unknown error"));
+                analysis.diagnostics.push(Diagnostic {
+                    range: Range::default(),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    source: Some(String::from("subscript")),
+                    message: format!("failed to parse this file: {reason}"),
+                    ..Default::default()
+                });
+            }
+        }
+        analysis
+    }
+    pub fn to_range(&self, range: CharRange) -> Range {
+        self.line_index.range(&self.source, range)
+    }
+    fn to_lsp_diagnostic(&self, diagnostic: compiler_diagnostics::Diagnostic) -> Diagnostic {
+        Diagnostic {
+            range: diagnostic.range.map(|x| self.to_range(x)).unwrap_or_default(),
+            severity: Some(match diagnostic.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            }),
+            source: Some(String::from("subscript")),
+            message: diagnostic.message,
+            ..Default::default()
+        }
+    }
+    pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+        let mut headings = Vec::new();
+        if let Some(ast) = self.ast.as_ref() {
+            collect_headings(ast, &mut headings);
+        }
+        let mut roots: Vec<(u8, DocumentSymbol)> = Vec::new();
+        let mut stack: Vec<(u8, DocumentSymbol)> = Vec::new();
+        for (level, cmd) in headings {
+            let range = match Node::Cmd(cmd.clone()).range() {
+                Some(range) => self.to_range(range),
+                None => continue,
+            };
+            let selection_range = cmd.identifier.range
+                .map(|x| self.to_range(x))
+                .unwrap_or(range);
+            let name = cmd.arguments
+                .iter()
+                .map(to_plain_text)
+                .collect::<String>();
+            let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+            #[allow(deprecated)]
+            let symbol = DocumentSymbol {
+                name: if name.is_empty() {cmd.identifier.value.as_str().to_owned()} else {name},
+                detail: Some(cmd.identifier.value.unwrap_remove_slash().to_owned()),
+                kind: SymbolKind::STRING,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range,
+                children: None,
+            };
+            while stack.last().map(|(x, _)| *x >= level).unwrap_or(false) {
+                pop_symbol(&mut stack, &mut roots);
+            }
+            stack.push((level, symbol));
+        }
+        while !stack.is_empty() {
+            pop_symbol(&mut stack, &mut roots);
+        }
+        roots.into_iter().map(|(_, x)| x).collect()
+    }
+    /// Markdown formatted hover info for the command under the given offset.
+    pub fn hover(&self, byte_index: usize) -> Option<(String, Range)> {
+        let ident = find_ident_at(self.ast.as_ref()?, byte_index)?;
+        let cmd_decls = self.scope.cmd_decls.get(&ident.value)?;
+        let contents = cmd_decls
+            .iter()
+            .map(hover_contents)
+            .collect::<Vec<_>>()
+            .join("\n\n---\n\n");
+        let range = self.to_range(ident.range?);
+        Some((contents, range))
+    }
+    /// Commands (or attributes) that are valid at the given offset. Uses the
+    /// last successfully parsed tree, so while typing e.g. an unbalanced
+    /// bracket we may only know about the enclosing top-level scope.
+    pub fn completions(&self, byte_index: usize) -> Vec<CompletionItem> {
+        let empty = Node::Fragment(Vec::new());
+        let ast = self.ast.as_ref().unwrap_or(&empty);
+        let env = ResourceEnv::default();
+        let position = self.line_index.position(&self.source, byte_index);
+        completion::completions(&env, &self.scope, &self.source, ast, byte_index)
+            .into_iter()
+            .map(|item| {
+                let start = self.line_index.position(&self.source, item.replace_start);
+                let (kind, detail) = match item.kind {
+                    CompletionKind::Command => {
+                        (CompletionItemKind::FUNCTION, Some(item.signatures.join("\n")))
+                    }
+                    CompletionKind::Attribute{required, value_ty} => {
+                        let value_ty = match value_ty {
+                            Some(AttributeValueType::FilePath) => String::from("file path"),
+                            Some(AttributeValueType::String) => String::from("string"),
+                            Some(AttributeValueType::Int) => String::from("integer"),
+                            Some(AttributeValueType::Bool) => String::from("boolean"),
+                            Some(AttributeValueType::Number) => String::from("number"),
+                            Some(AttributeValueType::OneOf(values)) => format!("one of {}", values.join(", ")),
+                            None => String::from("flag"),
+                        };
+                        let detail = if required {
+                            format!("{value_ty} (required)")
+                        } else {
+                            value_ty
+                        };
+                        (CompletionItemKind::PROPERTY, Some(detail))
+                    }
+                };
+                CompletionItem {
+                    label: item.label.clone(),
+                    kind: Some(kind),
+                    detail,
+                    documentation: item.documentation.map(|value| {
+                        Documentation::MarkupContent(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value,
+                        })
+                    }),
+                    filter_text: Some(item.label.clone()),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: Range::new(start, position),
+                        new_text: item.label,
+                    })),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+fn pop_symbol(
+    stack: &mut Vec<(u8, DocumentSymbol)>,
+    roots: &mut Vec<(u8, DocumentSymbol)>,
+) {
+    let (level, symbol) = stack.pop().unwrap();
+    if let Some((_, parent)) = stack.last_mut() {
+        // Extend the parent section so it covers all its subsections.
+        if parent.range.end < symbol.range.end {
+            parent.range.end = symbol.range.end;
+        }
+        parent.children.get_or_insert_with(Vec::new).push(symbol);
+        return;
+    }
+    roots.push((level, symbol));
+}
+
+/// Same as the compiler’s scope for a given file, except `\include` isn’t
+/// expanded, since the included nodes would point into other files.
+fn init_scope(file_path: Option<&Path>) -> SemanticScope {
+    let commands = subscript_compiler::ss_v1_std::all_commands_list()
+        .into_iter()
+        .map(|mut cmd| {
+            if cmd.identifier == "\\include" {
+                cmd.arguments = VariableArguments(vec![ArgumentsDeclInstance {
+                    ty: Left(Override::NoArguments),
+                    apply: ArgumentDeclMap(keep_cmd_call),
+                }]);
+            }
+            cmd
+        })
+        .collect::<Vec<_>>();
+    match file_path {
+        Some(file_path) => {
+            let base_path = find_project_dir(file_path)
+                .or_else(|| file_path.parent().map(Path::to_path_buf))
+                .unwrap_or_else(|| PathBuf::from("."));
+            SemanticScope::new(base_path, file_path, commands)
+        }
+        None => SemanticScope::test_mode_with_cmds(commands),
+    }
+}
+
+fn keep_cmd_call(_: &mut Internal, _: Metadata, cmd_payload: CmdPayload) -> Option<Node> {
+    Some(Node::Cmd(CmdCall {
+        identifier: cmd_payload.identifier,
+        attributes: cmd_payload.attributes.unwrap_or_default(),
+        arguments: Vec::new(),
+    }))
+}
+
+/// The nearest ancestor directory with a `Subscript.toml` manifest.
+fn find_project_dir(file_path: &Path) -> Option<PathBuf> {
+    file_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| {
+            dir.join("Subscript.toml").exists() || dir.join("subscript.toml").exists()
+        })
+        .map(Path::to_path_buf)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// AST HELPERS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+fn collect_headings(node: &Node, headings: &mut Vec<(u8, CmdCall)>) {
+    match node {
+        Node::Cmd(cmd) if cmd.is_heading_node() => {
+            let level = cmd.identifier.value.unwrap_remove_slash()[1..]
+                .parse::<u8>()
+                .unwrap_or(1);
+            headings.push((level, cmd.clone()));
+        }
+        Node::Cmd(cmd) => {
+            cmd.arguments.iter().for_each(|x| collect_headings(x, headings));
+        }
+        Node::Bracket(Ann{value, ..}) => {
+            value.children.iter().for_each(|x| collect_headings(x, headings));
+        }
+        Node::Quotation(Ann{value, ..}) => {
+            value.children.iter().for_each(|x| collect_headings(x, headings));
+        }
+        Node::Fragment(xs) => {
+            xs.iter().for_each(|x| collect_headings(x, headings));
+        }
+        _ => (),
+    }
+}
+
+fn to_plain_text(node: &Node) -> String {
+    match node {
+        Node::Cmd(cmd) => cmd.arguments.iter().map(to_plain_text).collect(),
+        Node::Bracket(Ann{value, ..}) => value.children.iter().map(to_plain_text).collect(),
+        Node::Quotation(Ann{value, ..}) => value.children.iter().map(to_plain_text).collect(),
+        Node::Fragment(xs) => xs.iter().map(to_plain_text).collect(),
+        Node::Ident(x) => x.value.as_str().to_owned(),
+        Node::Text(x) => x.value.clone(),
+        Node::Symbol(x) => x.value.clone(),
+        Node::InvalidToken(_) | Node::Drawing(_) => String::new(),
+    }
+}
+
+/// Finds the command identifier (of either a matched command call or a lone
+/// identifier) that spans the given offset.
+fn find_ident_at(node: &Node, byte_index: usize) -> Option<&Ann<Ident>> {
+    fn contains(range: Option<CharRange>, byte_index: usize) -> bool {
+        range
+            .map(|x| x.start.byte_index <= byte_index && byte_index <= x.end.byte_index)
+            .unwrap_or(false)
+    }
+    match node {
+        Node::Cmd(cmd) if contains(cmd.identifier.range, byte_index) => Some(&cmd.identifier),
+        Node::Cmd(cmd) => cmd.arguments.iter().find_map(|x| find_ident_at(x, byte_index)),
+        Node::Ident(ident) if contains(ident.range, byte_index) => Some(ident),
+        Node::Bracket(Ann{value, ..}) => {
+            value.children.iter().find_map(|x| find_ident_at(x, byte_index))
+        }
+        Node::Quotation(Ann{value, ..}) => {
+            value.children.iter().find_map(|x| find_ident_at(x, byte_index))
+        }
+        Node::Fragment(xs) => xs.iter().find_map(|x| find_ident_at(x, byte_index)),
+        _ => None,
+    }
+}
+
+fn hover_contents(cmd_decl: &CmdDeclaration) -> String {
+    let signatures = cmd_decl.signatures().join("\n");
+    let mut availability = Vec::new();
+    if let Some(parent) = cmd_decl.parent_env.parent.as_ref() {
+        availability.push(format!("within `{}`", parent.as_str()));
+    }
+    availability.push(match cmd_decl.parent_env.layout_mode {
+        LayoutMode::Block => String::from("block layout"),
+        LayoutMode::Inline => String::from("inline layout"),
+        LayoutMode::Both => String::from("any layout"),
+    });
+    availability.push(match cmd_decl.parent_env.content_mode {
+        ContentMode::Text => String::from("text mode"),
+        ContentMode::Symbolic(_) => String::from("symbolic mode"),
+    });
+    let mut contents = format!("```subscript\n{signatures}\n```");
+    if let Some(docs) = cmd_decl.documentation.as_ref() {
+        contents.push_str("\n\n");
+        contents.push_str(docs);
+    }
+    contents.push_str(&format!("\n\n*Available in: {}*", availability.join(", ")));
+    contents
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TESTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analysis(source: &str) -> Analysis {
+        Analysis::new(None, source.to_owned())
+    }
+
+    #[test]
+    fn positions_are_counted_in_utf16() {
+        let source = "ab\n𝑥y\n";
+        let line_index = LineIndex::new(source);
+        // `𝑥` is four bytes, but two UTF-16 code units.
+        assert_eq!(line_index.position(source, 7), Position::new(1, 2));
+        assert_eq!(line_index.byte_index(source, Position::new(1, 2)), 7);
+        assert_eq!(line_index.byte_index(source, Position::new(9, 0)), source.len());
+    }
+
+    #[test]
+    fn diagnostics_point_at_their_source() {
+        let analysis = analysis("\\h1{Title}\n\\p{Text \\unknownCmd}");
+        assert!(analysis.ast.is_some());
+        assert_eq!(analysis.diagnostics.len(), 1, "{:#?}", analysis.diagnostics);
+        let diagnostic = &analysis.diagnostics[0];
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diagnostic.message, "unknown command `\\unknownCmd`");
+        assert_eq!(diagnostic.range, Range::new(Position::new(1, 8), Position::new(1, 19)));
+        assert!(self::analysis("\\h1{Title}\n\\p{Text}").diagnostics.is_empty());
+    }
+
+    #[test]
+    fn diagnostics_are_reported_once() {
+        let source = "\\define[name=\"twice\", params=\"x\"]{\\p{\\x}\\p{\\x}}\n\\twice{a]}";
+        let diagnostics = analysis(source).diagnostics;
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:#?}");
+        assert_eq!(diagnostics[0].message, "unmatched `]`");
+        assert_eq!(diagnostics[0].range.start, Position::new(1, 8));
+    }
+
+    #[test]
+    fn headings_are_nested_symbols() {
+        let symbols = analysis("\\h1{One}\n\\h2{Two}\n\\h2{Three}\n\\h1{Four}").document_symbols();
+        let names = |xs: &[DocumentSymbol]| xs.iter().map(|x| x.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&symbols), ["One", "Four"]);
+        assert_eq!(names(symbols[0].children.as_deref().unwrap()), ["Two", "Three"]);
+        // Sections span their subsections.
+        assert_eq!(symbols[0].range.end, Position::new(2, 10));
+    }
+
+    #[test]
+    fn hover_shows_the_declaration() {
+        let analysis = analysis("\\h1{Title}\n\\p{Text}");
+        let (contents, range) = analysis.hover(13).unwrap();
+        assert!(contents.starts_with("```subscript\n\\p"), "{contents}");
+        assert!(contents.contains("*Available in:"), "{contents}");
+        assert_eq!(range, Range::new(Position::new(1, 0), Position::new(1, 2)));
+        // Plain text has nothing to show.
+        assert!(analysis.hover(16).is_none());
+    }
+
+    #[test]
+    fn completions_replace_the_typed_prefix() {
+        let source = "\\p{Text}\n\\h";
+        let items = analysis(source).completions(source.len());
+        let item = items.iter().find(|x| x.label == "\\h1").unwrap();
+        assert_eq!(item.kind, Some(CompletionItemKind::FUNCTION));
+        let Some(CompletionTextEdit::Edit(edit)) = item.text_edit.as_ref() else {
+            panic!("{item:?}")
+        };
+        assert_eq!(edit.range, Range::new(Position::new(1, 0), Position::new(1, 2)));
+        assert!(items.iter().all(|x| x.label.starts_with("\\h")), "{items:?}");
+    }
+
+    #[test]
+    fn attribute_completions_describe_their_values() {
+        let source = "\\include[";
+        let items = analysis(source).completions(source.len());
+        let src = items.iter().find(|x| x.label == "src").unwrap();
+        assert_eq!(src.kind, Some(CompletionItemKind::PROPERTY));
+        assert_eq!(src.detail.as_deref(), Some("file path (required)"));
+    }
+}
@@ -1,3 +1,290 @@
-fn main() {
-    println!("Hello, world!");
+//! A language server for SubScript (`.ss`) files over stdio.
+//!
+//! Documents are fully re-parsed on every change (the same way the compiler
+//! would), which is plenty fast for the sizes of files we deal with.
+use std::collections::HashMap;
+use std::error::Error;
+use lsp_server::{Connection, ExtractError, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument,
+    DidCloseTextDocument,
+    DidOpenTextDocument,
+    Notification as _,
+    PublishDiagnostics,
+};
+use lsp_types::request::{Completion, DocumentSymbolRequest, HoverRequest};
+use lsp_types::{
+    CompletionOptions,
+    CompletionResponse,
+    DocumentSymbolResponse,
+    Hover,
+    HoverContents,
+    HoverProviderCapability,
+    MarkupContent,
+    MarkupKind,
+    OneOf,
+    PublishDiagnosticsParams,
+    ServerCapabilities,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+};
+
+mod analysis;
+
+use analysis::Analysis;
+
+type ServerResult<T> = Result<T, Box<dyn Error + Sync + Send>>;
+
+fn main() -> ServerResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = serde_json::to_value(ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![String::from("\\"), String::from("[")]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })?;
+    connection.initialize(capabilities)?;
+    let server = Server {connection, documents: HashMap::default()};
+    // The connection must be dropped before joining, or the writer thread
+    // never finishes.
+    server.run()?;
+    io_threads.join()?;
+    Ok(())
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Analysis>,
+}
+
+impl Server {
+    fn run(mut self) -> ServerResult<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.on_request(request)?;
+                }
+                Message::Notification(notification) => {
+                    self.on_notification(notification)?;
+                }
+                Message::Response(_) => (),
+            }
+        }
+        Ok(())
+    }
+    fn on_request(&mut self, request: Request) -> ServerResult<()> {
+        let request = match cast_request::<HoverRequest>(request) {
+            Cast::Matched(id, params) => {
+                let uri = params.text_document_position_params.text_document.uri;
+                let position = params.text_document_position_params.position;
+                let result = self.documents.get(&uri).and_then(|document| {
+                    let byte_index = document.line_index.byte_index(&document.source, position);
+                    document.hover(byte_index)
+                });
+                let result = result.map(|(value, range)| Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value,
+                    }),
+                    range: Some(range),
+                });
+                return self.respond(id, result);
+            }
+            Cast::Invalid(id, message) => return self.respond_invalid_params(id, message),
+            Cast::Other(request) => request,
+        };
+        let request = match cast_request::<DocumentSymbolRequest>(request) {
+            Cast::Matched(id, params) => {
+                let result = self.documents
+                    .get(&params.text_document.uri)
+                    .map(|document| DocumentSymbolResponse::Nested(document.document_symbols()));
+                return self.respond(id, result);
+            }
+            Cast::Invalid(id, message) => return self.respond_invalid_params(id, message),
+            Cast::Other(request) => request,
+        };
+        let request = match cast_request::<Completion>(request) {
+            Cast::Matched(id, params) => {
+                let uri = params.text_document_position.text_document.uri;
+                let position = params.text_document_position.position;
+                let result = self.documents.get(&uri).map(|document| {
+                    let byte_index = document.line_index.byte_index(&document.source, position);
+                    CompletionResponse::Array(document.completions(byte_index))
+                });
+                return self.respond(id, result);
+            }
+            Cast::Invalid(id, message) => return self.respond_invalid_params(id, message),
+            Cast::Other(request) => request,
+        };
+        let response = Response::new_err(
+            request.id,
+            lsp_server::ErrorCode::MethodNotFound as i32,
+            format!("unsupported request: {}", request.method),
+        );
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+    fn on_notification(&mut self, notification: Notification) -> ServerResult<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Some(params) = cast_notification::<DidOpenTextDocument>(notification) {
+                    let document = params.text_document;
+                    self.update_document(document.uri, document.text, Some(document.version))?;
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Some(params) = cast_notification::<DidChangeTextDocument>(notification) {
+                    // We only advertise full document syncing, so the last
+                    // change is the whole document.
+                    if let Some(change) = params.content_changes.into_iter().last() {
+                        let document = params.text_document;
+                        self.update_document(document.uri, change.text, Some(document.version))?;
+                    }
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Some(params) = cast_notification::<DidCloseTextDocument>(notification) {
+                    let uri = params.text_document.uri;
+                    self.documents.remove(&uri);
+                    self.publish_diagnostics(uri, Vec::new(), None)?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+    fn update_document(&mut self, uri: Url, text: String, version: Option<i32>) -> ServerResult<()> {
+        let file_path = uri.to_file_path().ok();
+        let analysis = Analysis::new(file_path.as_deref(), text);
+        let diagnostics = analysis.diagnostics.clone();
+        self.documents.insert(uri.clone(), analysis);
+        self.publish_diagnostics(uri, diagnostics, version)
+    }
+    fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+        version: Option<i32>,
+    ) -> ServerResult<()> {
+        let params = PublishDiagnosticsParams {uri, diagnostics, version};
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_owned(), params);
+        self.connection.sender.send(Message::Notification(notification))?;
+        Ok(())
+    }
+    fn respond<T: serde::Serialize>(&self, id: RequestId, result: T) -> ServerResult<()> {
+        let response = Response::new_ok(id, result);
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+    fn respond_invalid_params(&self, id: RequestId, message: String) -> ServerResult<()> {
+        let response = Response::new_err(id, lsp_server::ErrorCode::InvalidParams as i32, message);
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+}
+
+enum Cast<P> {
+    Matched(RequestId, P),
+    /// Params that don’t deserialize, which are answered with an error
+    /// rather than taking down the server.
+    Invalid(RequestId, String),
+    Other(Request),
+}
+
+/// Notifications can’t be answered, so params that don’t deserialize are
+/// logged (to stderr, since stdout is the connection) and ignored.
+fn cast_notification<N>(notification: Notification) -> Option<N::Params>
+where
+    N: lsp_types::notification::Notification,
+    N::Params: serde::de::DeserializeOwned,
+{
+    match notification.extract(N::METHOD) {
+        Ok(params) => Some(params),
+        Err(ExtractError::MethodMismatch(_)) => None,
+        Err(ExtractError::JsonError{method, error}) => {
+            eprintln!("ignoring invalid params for {method}: {error}");
+            None
+        }
+    }
+}
+
+fn cast_request<R>(request: Request) -> Cast<R::Params>
+where
+    R: lsp_types::request::Request,
+    R::Params: serde::de::DeserializeOwned,
+{
+    let id = request.id.clone();
+    match request.extract(R::METHOD) {
+        Ok((id, params)) => Cast::Matched(id, params),
+        Err(ExtractError::MethodMismatch(request)) => Cast::Other(request),
+        Err(ExtractError::JsonError{method, error}) => {
+            Cast::Invalid(id, format!("invalid params for {method}: {error}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use lsp_types::notification::Exit;
+    use lsp_types::request::Shutdown;
+    use serde_json::json;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn notification<N: lsp_types::notification::Notification>(params: serde_json::Value) -> Message {
+        Message::Notification(Notification::new(N::METHOD.to_owned(), params))
+    }
+
+    fn request<R: lsp_types::request::Request>(id: i32, params: serde_json::Value) -> Message {
+        Message::Request(Request::new(RequestId::from(id), R::METHOD.to_owned(), params))
+    }
+
+    #[test]
+    fn malformed_params_dont_end_the_server() {
+        let (connection, client) = Connection::memory();
+        let server = std::thread::spawn(move || {
+            Server {connection, documents: HashMap::default()}.run().unwrap();
+        });
+        let uri = "file:///notes/index.ss";
+        client.sender.send(notification::<DidOpenTextDocument>(json!({"textDocument": 42}))).unwrap();
+        client.sender.send(notification::<DidChangeTextDocument>(json!({}))).unwrap();
+        client.sender.send(request::<HoverRequest>(1, json!({"position": "nowhere"}))).unwrap();
+        match client.receiver.recv_timeout(TIMEOUT).unwrap() {
+            Message::Response(response) => {
+                assert_eq!(response.id, RequestId::from(1));
+                let error = response.error.unwrap();
+                assert_eq!(error.code, lsp_server::ErrorCode::InvalidParams as i32);
+                assert!(error.message.starts_with("invalid params for textDocument/hover"), "{}", error.message);
+            }
+            message => panic!("{message:?}"),
+        }
+        // Still serving.
+        client.sender.send(notification::<DidOpenTextDocument>(json!({
+            "textDocument": {"uri": uri, "languageId": "subscript", "version": 1, "text": "\\p{\\unknownCmd}"},
+        }))).unwrap();
+        match client.receiver.recv_timeout(TIMEOUT).unwrap() {
+            Message::Notification(notification) => {
+                assert_eq!(notification.method, PublishDiagnostics::METHOD);
+                let params: PublishDiagnosticsParams = serde_json::from_value(notification.params).unwrap();
+                assert_eq!(params.uri.as_str(), uri);
+                assert_eq!(params.version, Some(1));
+                assert_eq!(params.diagnostics.len(), 1, "{:?}", params.diagnostics);
+            }
+            message => panic!("{message:?}"),
+        }
+        client.sender.send(request::<Shutdown>(2, serde_json::Value::Null)).unwrap();
+        assert!(matches!(client.receiver.recv_timeout(TIMEOUT).unwrap(), Message::Response(_)));
+        client.sender.send(notification::<Exit>(serde_json::Value::Null)).unwrap();
+        server.join().unwrap();
+    }
 }
@@ -39,13 +39,194 @@ function setForceSingleColumnToOn() {
     updateColumns();
 }
 
-function expandDiv(id) {
-    const div = document.getElementById(id);
+// Called from the buttons of hidden drawings, which have no IDs so that the
+// output is reproducible.
+function expandDiv(button) {
+    const div = button.closest(".toggle-visibility");
     div.setAttribute("data-visible", "true");
 }
 
-function collapseDiv(id) {
-    const div = document.getElementById(id);
+function collapseDiv(button) {
+    const div = button.closest(".toggle-visibility");
     div.setAttribute("data-visible", "false");
 }
 
+
+// SITE SEARCH (SEE `crate::html::search`)
+
+function searchTerms(query) {
+    return query.toLowerCase().split(/\s+/).filter((term) => term.length > 0);
+}
+
+function searchPages(terms) {
+    const matches = [];
+    for (const page of window.SUBSCRIPT_SEARCH_INDEX.pages) {
+        for (const section of page.sections) {
+            const heading = section.heading.toLowerCase();
+            const text = section.text.toLowerCase();
+            if (!terms.every((term) => heading.includes(term) || text.includes(term))) {
+                continue;
+            }
+            const score = terms.filter((term) => heading.includes(term)).length;
+            matches.push({page, section, score});
+        }
+    }
+    // Sorting is stable, so matches are otherwise in reading order.
+    return matches.sort((a, b) => b.score - a.score).slice(0, 20);
+}
+
+function searchSnippet(text, terms) {
+    const lower = text.toLowerCase();
+    // Terms may only match the heading.
+    const found = terms.map((term) => lower.indexOf(term)).filter((ix) => ix >= 0);
+    const start = found.length > 0 ? Math.max(0, Math.min(...found) - 40) : 0;
+    const snippet = text.slice(start, start + 160);
+    return (start > 0 ? "…" : "") + snippet + (start + 160 < text.length ? "…" : "");
+}
+
+function updateSearchResults(query) {
+    const results = document.getElementById('site-search-results');
+    const terms = searchTerms(query);
+    results.replaceChildren();
+    if (terms.length == 0) {
+        return;
+    }
+    for (const {page, section} of searchPages(terms)) {
+        const link = document.createElement('a');
+        link.href = section.id.length > 0 ? `${page.href}#${section.id}` : page.href;
+        const title = document.createElement('span');
+        title.setAttribute('data-title', '');
+        title.textContent = section.heading.length > 0 && section.heading != page.title
+            ? `${page.title} › ${section.heading}`
+            : page.title;
+        const snippet = document.createElement('span');
+        snippet.setAttribute('data-snippet', '');
+        snippet.textContent = searchSnippet(section.text, terms);
+        link.append(title, snippet);
+        const item = document.createElement('li');
+        item.append(link);
+        results.append(item);
+    }
+}
+
+window.addEventListener('load', () => {
+    const input = document.getElementById('site-search-input');
+    if (input == null || window.SUBSCRIPT_SEARCH_INDEX === undefined) {
+        return;
+    }
+    input.addEventListener('input', () => updateSearchResults(input.value));
+});
+
+
+// DESMOS & GEOGEBRA EMBEDS (SEE `crate::ss_v1_std::embeds`)
+
+// Pages may set `window.SUBSCRIPT_DESMOS_API_KEY` (e.g. via `head.html`), the
+// default is the demo key from the Desmos API documentation.
+const EMBED_LIBRARIES = {
+    desmos: () => {
+        const key = window.SUBSCRIPT_DESMOS_API_KEY || 'dcb31709b452b1cf9dc26972add0fda6';
+        return `https://www.desmos.com/api/v1.9/calculator.js?apiKey=${key}`;
+    },
+    geogebra: () => 'https://www.geogebra.org/apps/deployggb.js',
+};
+
+function loadEmbedLibrary(kind) {
+    return new Promise((resolve, reject) => {
+        const script = document.createElement('script');
+        script.src = EMBED_LIBRARIES[kind]();
+        script.async = true;
+        script.addEventListener('load', resolve);
+        script.addEventListener('error', reject);
+        document.head.append(script);
+    });
+}
+
+function embedTarget(embed, config) {
+    const target = document.createElement('div');
+    target.setAttribute('data-embed-target', '');
+    target.style.height = `${config.height}px`;
+    embed.append(target);
+    return target;
+}
+
+function initDesmosEmbed(embed, config) {
+    const calculator = Desmos.GraphingCalculator(embedTarget(embed, config), config.options);
+    config.expressions.forEach((expression, ix) => {
+        calculator.setExpression({id: `expr-${ix + 1}`, ...expression});
+    });
+    if (config.bounds !== undefined) {
+        calculator.setMathBounds(config.bounds);
+    }
+}
+
+function initGeoGebraEmbed(embed, config, ix) {
+    const target = embedTarget(embed, config);
+    target.id = `ss-geogebra-${ix}`;
+    const params = {
+        ...config.params,
+        width: target.clientWidth,
+        height: config.height,
+        appletOnLoad: (api) => {
+            if (config.grid !== undefined) {
+                api.setGridVisible(config.grid);
+            }
+            if (config.axes !== undefined) {
+                api.setAxesVisible(config.axes, config.axes);
+            }
+            if (config.coords !== undefined) {
+                api.setCoordSystem(...config.coords);
+            }
+            for (const expression of config.expressions) {
+                const labels = api.evalCommandGetLabels(expression.command) || '';
+                for (const label of labels.split(',').filter((x) => x.length > 0)) {
+                    if (expression.color !== undefined) {
+                        api.setColor(label, ...expression.color);
+                    }
+                    if (expression.lineStyle !== undefined) {
+                        api.setLineStyle(label, expression.lineStyle);
+                    }
+                    if (expression.lineThickness !== undefined) {
+                        api.setLineThickness(label, expression.lineThickness);
+                    }
+                    if (expression.hidden) {
+                        api.setVisible(label, false);
+                    }
+                    if (expression.label !== undefined) {
+                        api.setCaption(label, expression.label);
+                        api.setLabelStyle(label, 3);
+                        api.setLabelVisible(label, true);
+                    }
+                }
+            }
+        },
+    };
+    new GGBApplet(params, true).inject(target.id);
+}
+
+const EMBED_INITIALIZERS = {desmos: initDesmosEmbed, geogebra: initGeoGebraEmbed};
+
+// Embeds keep their fallback notice if the library can’t be loaded (e.g.
+// offline).
+window.addEventListener('load', () => {
+    const embeds = Array.from(document.querySelectorAll('[data-embed]'));
+    for (const kind of Object.keys(EMBED_INITIALIZERS)) {
+        const group = embeds.filter((embed) => embed.getAttribute('data-embed') == kind);
+        if (group.length == 0) {
+            continue;
+        }
+        loadEmbedLibrary(kind)
+            .then(() => group.forEach((embed, ix) => {
+                try {
+                    const config = JSON.parse(embed.querySelector('[data-embed-config]').textContent);
+                    EMBED_INITIALIZERS[kind](embed, config, ix);
+                    embed.querySelector('[data-embed-fallback]').remove();
+                    embed.setAttribute('data-embed-state', 'ready');
+                } catch (error) {
+                    console.error(error);
+                    embed.querySelector('[data-embed-target]')?.remove();
+                    embed.setAttribute('data-embed-state', 'unavailable');
+                }
+            }))
+            .catch(() => group.forEach((embed) => embed.setAttribute('data-embed-state', 'unavailable')));
+    }
+});
@@ -1,7 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 use crate::project::manifest::ProjectSettings;
 use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
 
 #[derive(StructOpt, Debug, Clone)]
 #[structopt(name = "The Subscript Compiler CLI")]
@@ -21,11 +22,43 @@ pub enum SubscriptCompilerCommand {
         /// Overrides the default output dir. 
         #[structopt(long)]
         output_dir: Option<PathBuf>,
-        /// By default Subscript uses symlinks in the HTML output folder,
-        /// this option will override that behavior and instead copy images
-        /// to such. Especially useful for publishing to Github Pages. 
+        /// No longer has any effect, images are always copied to the output
+        /// folder (under content-addressed names). Kept for existing scripts.
         #[structopt(long)]
         copy_images: bool,
+        /// Treat warnings (e.g. unknown commands) as errors.
+        #[structopt(long)]
+        deny_warnings: bool,
+        /// Recompile every page, instead of only those whose sources (or
+        /// any file such includes) changed since the last build.
+        #[structopt(long)]
+        force: bool,
+        /// Treat broken links (to missing files or heading anchors) as
+        /// errors, e.g. to fail the build in CI.
+        #[structopt(long)]
+        deny_broken_links: bool,
+        /// Point each block element back at the command it came from VIA a
+        /// `data-ss-src="file:line:column"` attribute, e.g. for editors.
+        #[structopt(long)]
+        source_map: bool,
+    },
+    /// Builds the project (see `build`) and serves the output directory on
+    /// localhost, pages are rebuilt and reloaded whenever sources change.
+    Serve {
+        #[structopt(long, default_value = ".")]
+        project_dir: PathBuf,
+        #[structopt(long, default_value = "8080")]
+        port: u16,
+        /// Pages are served under this prefix, as on GitHub Pages.
+        #[structopt(long)]
+        route_prefix: Option<String>,
+        /// Overrides the default output dir.
+        #[structopt(long)]
+        output_dir: Option<PathBuf>,
+        /// Point each block element back at the command it came from VIA a
+        /// `data-ss-src="file:line:column"` attribute, e.g. for editors.
+        #[structopt(long)]
+        source_map: bool,
     },
     CompileFile {
         #[structopt(long)]
@@ -36,6 +69,48 @@ pub enum SubscriptCompilerCommand {
         watch: bool,
         #[structopt(long)]
         debug_print_ast: bool,
+        /// Treat warnings (e.g. unknown commands) as errors.
+        #[structopt(long)]
+        deny_warnings: bool,
+        /// Point each block element back at the command it came from VIA a
+        /// `data-ss-src="file:line:column"` attribute, e.g. for editors.
+        #[structopt(long)]
+        source_map: bool,
+    },
+    /// Compiles a single page to a standalone LaTeX document, images and
+    /// drawings are written next to the `--output` file.
+    CompileLatex {
+        #[structopt(long)]
+        source: PathBuf,
+        #[structopt(long)]
+        output: PathBuf,
+        /// Treat warnings (e.g. unknown commands) as errors.
+        #[structopt(long)]
+        deny_warnings: bool,
+    },
+    /// Converts an HTML file (or every HTML file of a directory) to `.ss`
+    /// source, e.g. to migrate notes written with other tools. Tags without
+    /// a Subscript equivalent are reported.
+    ImportHtml {
+        #[structopt(long)]
+        source: PathBuf,
+        /// The `.ss` file, or for a `--source` directory, the directory the
+        /// files are written to (under the same relative paths).
+        #[structopt(long)]
+        output: PathBuf,
+        /// Overwrite existing `.ss` files.
+        #[structopt(long)]
+        force: bool,
+    },
+    /// Formats `.ss` files in place, see `crate::ss::format`.
+    Fmt {
+        /// Files, or directories whose `.ss` files are formatted.
+        #[structopt(default_value = ".")]
+        paths: Vec<PathBuf>,
+        /// Don’t write anything, but fail if a file isn’t formatted, e.g.
+        /// for CI.
+        #[structopt(long)]
+        check: bool,
     }
 }
 
@@ -46,31 +121,12 @@ impl SubscriptCompilerCommand {
     }
     pub fn execute_cmd(self) {
         match self {
-            SubscriptCompilerCommand::Build { project_dir, filter, watch, route_prefix, output_dir, copy_images } => {
-                let mut project_settings = ProjectSettings::parse_subscript_toml_file(&project_dir)
-                    .expect("Should be a valid Subscript.toml file");
-                if let Some(output_dir) = output_dir {
-                    project_settings.manifest.project.locations.output = output_dir;
-                }
-                let compiler = project_settings
-                    .init_compiler()
-                    .with_output_dir(&project_settings.manifest.project.locations.output)
-                    .with_project_dir(&project_settings.project_dir)
-                    .sort_files();
-                let compiler = match project_settings.manifest.project.title.as_ref() {
-                    Some(title) => compiler.with_project_info(crate::compiler::ProjectInfo{
-                        title: Some(title.clone())
-                    }),
-                    None => compiler,
-                };
-                let compiler = match copy_images {
-                    true => compiler.copy_images(true),
-                    _ => compiler,
-                };
-                let compiler = match route_prefix {
-                    Some(prefix) => compiler.with_route_prefix(prefix),
-                    None => compiler,
-                };
+            SubscriptCompilerCommand::Build { project_dir, filter, watch, route_prefix, output_dir, copy_images: _, deny_warnings, force, deny_broken_links, source_map } => {
+                let (project_settings, compiler) = project_compiler(&project_dir, output_dir, route_prefix);
+                let compiler = compiler
+                    .incremental(!force)
+                    .deny_broken_links(deny_broken_links)
+                    .source_map(source_map);
                 // println!("filter: {filter:?}");
                 let compiler = match filter {
                     Some(pattern) => compiler.filter_matching_files(
@@ -82,14 +138,31 @@ impl SubscriptCompilerCommand {
                 if watch {
                     compiler.compile_html_watch_sources();
                 } else {
-                    compiler.compile_pages_to_html();
+                    exit_with_diagnostics(compiler.compile_pages_to_html(), deny_warnings);
                 }
             }
-            SubscriptCompilerCommand::CompileFile { source, output, watch, debug_print_ast } => {
+            SubscriptCompilerCommand::Serve { project_dir, port, route_prefix, output_dir, source_map } => {
+                let (project_settings, compiler) = project_compiler(&project_dir, output_dir, route_prefix.clone());
+                let output_dir = project_settings.manifest.project.locations.output;
+                let dev_server = crate::compiler::serve::DevServer::new(&output_dir, route_prefix.clone());
+                if let Err(error) = dev_server.start(format!("127.0.0.1:{port}")) {
+                    eprintln!("error: failed to listen on port {port}: {error}");
+                    std::process::exit(1);
+                }
+                let route = route_prefix.map(|x| format!("{x}/")).unwrap_or_default();
+                println!("Serving {output_dir:?} at http://localhost:{port}/{route}");
+                compiler
+                    .incremental(true)
+                    .source_map(source_map)
+                    .with_dev_server(dev_server)
+                    .compile_html_watch_sources();
+            }
+            SubscriptCompilerCommand::CompileFile { source, output, watch, debug_print_ast, deny_warnings, source_map } => {
                 let compiler = crate::compiler::Compiler::new()
                     .add_file(&source, &output)
                     .with_output_dir(&output.parent().unwrap())
                     .with_project_dir(source.parent().unwrap())
+                    .source_map(source_map)
                     .sort_files();
                 let compiler = {
                     if debug_print_ast {
@@ -104,11 +177,176 @@ impl SubscriptCompilerCommand {
                 if watch {
                     compiler.compile_html_watch_sources();
                 } else {
-                    compiler.compile_pages_to_html();
+                    exit_with_diagnostics(compiler.compile_pages_to_html(), deny_warnings);
                 }
             }
+            SubscriptCompilerCommand::CompileLatex { source, output, deny_warnings } => {
+                let project_dir = source
+                    .parent()
+                    .filter(|x| !x.as_os_str().is_empty())
+                    .unwrap_or(Path::new("."));
+                let compiler = crate::compiler::Compiler::new()
+                    .add_file(&source, &output)
+                    .with_output_dir(output.parent().unwrap())
+                    .with_project_dir(project_dir);
+                exit_with_diagnostics(compiler.compile_pages_to_latex(), deny_warnings);
+            }
+            SubscriptCompilerCommand::ImportHtml { source, output, force } => {
+                let files = if source.is_dir() {
+                    wax::Glob::new("**/*.{html,htm}")
+                        .unwrap()
+                        .walk(&source)
+                        .flatten()
+                        .map(|x| x.into_path())
+                        .map(|src| {
+                            let out = output
+                                .join(src.strip_prefix(&source).unwrap())
+                                .with_extension("ss");
+                            (src, out)
+                        })
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![(source, output)]
+                };
+                let diagnostics = files
+                    .into_iter()
+                    .flat_map(|(src, out)| import_html_file(&src, &out, force))
+                    .collect::<Vec<_>>();
+                exit_with_diagnostics(diagnostics, false);
+            }
+            SubscriptCompilerCommand::Fmt { paths, check } => {
+                let files = paths
+                    .into_iter()
+                    .flat_map(|path| {
+                        if path.is_dir() {
+                            wax::Glob::new("**/*.ss")
+                                .unwrap()
+                                .walk(&path)
+                                .flatten()
+                                .map(|x| x.into_path())
+                                .collect::<Vec<_>>()
+                        } else {
+                            vec![path]
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let diagnostics = files
+                    .iter()
+                    .flat_map(|file| format_file(file, check))
+                    .collect::<Vec<_>>();
+                exit_with_diagnostics(diagnostics, false);
+            }
         }
     }
 }
 
+/// See `crate::ss::format`.
+fn format_file(file_path: &Path, check: bool) -> Vec<Diagnostic> {
+    use crate::ss::format::FormatError;
+    let formatted = match crate::ss::format::format_file(file_path) {
+        Ok(formatted) => formatted,
+        Err(FormatError::Syntax(diagnostics)) => return diagnostics,
+        Err(FormatError::ChangedMeaning) => {
+            let message = format!(
+                "{}: formatting would change the meaning of this file, so it was left as is",
+                file_path.display(),
+            );
+            return vec![Diagnostic::error(message)]
+        }
+    };
+    if std::fs::read_to_string(file_path).ok().as_deref() == Some(formatted.as_str()) {
+        return Vec::new()
+    }
+    if check {
+        return vec![Diagnostic::error(format!("{} isn’t formatted", file_path.display()))]
+    }
+    if let Err(error) = std::fs::write(file_path, formatted) {
+        return vec![Diagnostic::error(format!("failed to write {}: {error}", file_path.display()))]
+    }
+    println!("formatted {}", file_path.display());
+    Vec::new()
+}
 
+/// See `crate::html::import`.
+fn import_html_file(source: &Path, output: &Path, force: bool) -> Vec<Diagnostic> {
+    if output.exists() && !force {
+        let message = format!("{} already exists, use `--force` to overwrite it", output.display());
+        return vec![Diagnostic::error(message)]
+    }
+    let html = match std::fs::read_to_string(source) {
+        Ok(html) => html,
+        Err(error) => {
+            let message = format!("failed to read {}: {error}", source.display());
+            return vec![Diagnostic::error(message)]
+        }
+    };
+    let result = crate::html::import::import_html_str(&html);
+    let written = match output.parent() {
+        Some(parent) => std::fs::create_dir_all(parent).and_then(|()| std::fs::write(output, &result.source)),
+        None => std::fs::write(output, &result.source),
+    };
+    if let Err(error) = written {
+        return vec![Diagnostic::error(format!("failed to write {}: {error}", output.display()))]
+    }
+    println!("{} -> {}", source.display(), output.display());
+    let mut diagnostics = crate::html::import::check_syntax(output, &result.source);
+    if !result.unmapped_tags.is_empty() {
+        let tags = result.unmapped_tags
+            .iter()
+            .map(|(tag, count)| format!("<{tag}> ({count}×)"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!("{}: no Subscript equivalent for {tags}", source.display());
+        diagnostics.push(Diagnostic::warning(message));
+    }
+    diagnostics
+}
+
+/// The `Subscript.toml` based setup shared by `build` and `serve`.
+fn project_compiler(
+    project_dir: &Path,
+    output_dir: Option<PathBuf>,
+    route_prefix: Option<String>,
+) -> (ProjectSettings, crate::compiler::Compiler) {
+    let mut project_settings = match ProjectSettings::parse_subscript_toml_file(project_dir) {
+        Ok(project_settings) => project_settings,
+        Err(error) => {
+            let message = format!("{}: {error} (expected a valid Subscript.toml)", project_dir.display());
+            crate::ss::diagnostics::print_diagnostics(&[Diagnostic::error(message)]);
+            std::process::exit(1);
+        }
+    };
+    if let Some(output_dir) = output_dir {
+        project_settings.manifest.project.locations.output = output_dir;
+    }
+    let compiler = project_settings
+        .init_compiler()
+        .with_output_dir(&project_settings.manifest.project.locations.output)
+        .with_project_dir(&project_settings.project_dir)
+        .sort_files();
+    let compiler = match project_settings.manifest.project.title.as_ref() {
+        Some(title) => compiler.with_project_info(crate::compiler::ProjectInfo{
+            title: Some(title.clone())
+        }),
+        None => compiler,
+    };
+    let compiler = match route_prefix {
+        Some(prefix) => compiler.with_route_prefix(prefix),
+        None => compiler,
+    };
+    let assets = &project_settings.manifest.project.assets;
+    let compiler = compiler.with_asset_options(crate::compiler::assets::AssetOptions {
+        max_image_width: assets.max_image_width,
+        jpeg_quality: assets.jpeg_quality,
+    });
+    (project_settings, compiler)
+}
+
+/// Prints the given diagnostics, and exits with a non-zero status code if
+/// there are any errors (or any warnings with `deny_warnings`).
+fn exit_with_diagnostics(diagnostics: Vec<Diagnostic>, deny_warnings: bool) {
+    crate::ss::diagnostics::print_diagnostics(&diagnostics);
+    if diagnostics.iter().any(|x| deny_warnings || x.is_error()) {
+        std::process::exit(1);
+    }
+}
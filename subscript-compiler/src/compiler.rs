@@ -1,14 +1,27 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
-use std::{fmt::Display, path::{PathBuf, Path}, collections::HashMap};
+use std::{fmt::Display, path::{PathBuf, Path}, collections::{HashMap, HashSet}};
 use itertools::Itertools;
 use rayon::prelude::*;
 use ss_freeform_format::PageEntryType;
 pub mod watch;
+pub mod incremental;
+pub mod serve;
+pub mod links;
+pub mod assets;
 use crate::html::toc::TocPageEntry;
 use crate::html::template::TemplateFile;
-use crate::ss::{SemanticScope, HtmlCodegenEnv, ResourceEnv};
+use crate::html::navigation::{NavPageEntry, SiteNavigation};
+use crate::html::search::{SearchIndex, SearchPage, SearchSection};
+use crate::ss::{SemanticScope, HtmlCodegenEnv, LatexCodegenEnv, ResourceEnv};
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::env::canonical_path;
+use crate::ss::labels::{Label, LabelIndex};
+use crate::ss::bibliography::Bibliography;
+use crate::ss::env::LinkSource;
+use crate::data::Store;
+use incremental::PageDependencies;
 
 
 pub mod low_level_api {
@@ -18,6 +31,8 @@ pub mod low_level_api {
     pub enum CompilerError {
         NoFilePath,
         FileNotFound {file_path: PathBuf},
+        /// E.g. a file that isn't readable or valid UTF-8.
+        FailedToRead {file_path: PathBuf, error: String},
     }
     impl Display for CompilerError {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -25,6 +40,9 @@ pub mod low_level_api {
                 CompilerError::FileNotFound { file_path } => {
                     write!(f, "File not found: {:?}", file_path)
                 }
+                CompilerError::FailedToRead { file_path, error } => {
+                    write!(f, "Failed to read {:?}: {error}", file_path)
+                }
                 CompilerError::NoFilePath => {
                     write!(f, "You didn't define a file path and tried to use a compiler feature that expected such.")
                 }
@@ -32,20 +50,32 @@ pub mod low_level_api {
         }
     }
     pub fn parse_source(
+        env: &ResourceEnv,
         scope: &SemanticScope,
         source: impl AsRef<str>
     ) -> Result<crate::ss::Node, CompilerError> {
-        let node = crate::ss::parser::parse_source(scope, source.as_ref()).defragment_node_tree();
+        let node = crate::ss::parser::parse_source(env, scope, source.as_ref()).defragment_node_tree();
         Ok(node)
     }
+    /// I.e. `.ss` and Markdown files, as opposed to e.g. drawings.
+    pub fn is_source_file(path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        path.extension().map(|x| x == "ss").unwrap_or(false) || crate::ss::markdown::is_markdown_file(path)
+    }
     /// Make sure that `Scope::file_path` is set to the file you want to parse.
-    pub fn parse_file(scope: &SemanticScope) -> Result<crate::ss::Node, CompilerError> {
+    /// Markdown files are parsed with `crate::ss::markdown`.
+    pub fn parse_file(env: &ResourceEnv, scope: &SemanticScope) -> Result<crate::ss::Node, CompilerError> {
         if let Some(file_path) = scope.file_path.clone() {
             if !file_path.exists() {
                 return Err(CompilerError::FileNotFound { file_path: file_path.to_owned() });
             }
-            let source = std::fs::read_to_string(file_path).unwrap();
-            let node = parse_source(scope, source)?;
+            let source = std::fs::read_to_string(&file_path).map_err(|error| {
+                CompilerError::FailedToRead { file_path: file_path.to_owned(), error: error.to_string() }
+            })?;
+            if crate::ss::markdown::is_markdown_file(&file_path) {
+                return Ok(crate::ss::markdown::parse_source(env, scope, source))
+            }
+            let node = parse_source(env, scope, source)?;
             return Ok(node)
         }
         Err(CompilerError::NoFilePath)
@@ -66,9 +96,10 @@ pub mod low_level_api {
         env: &ResourceEnv,
         scope: &SemanticScope
     ) -> Result<crate::ss::Node, CompilerError> {
-        let nodes = parse_file(&scope)?;
+        let nodes = parse_file(env, &scope)?;
         // let start = std::time::Instant::now();
         let nodes = process_commands(env, scope, nodes);
+        crate::ss::validation::validate(env, scope, &nodes);
         // scope.file_path.as_ref().map(|file| {
         //     let elapsed = start.elapsed();
         //     println!("Elapsed Time [{:?}]: {:.2?}", file, elapsed);
@@ -83,23 +114,60 @@ pub mod low_level_api {
         // let start = std::time::Instant::now();
         let ss_ast = parse_process(env, scope)?;
         if debug_settings.map(|x| x.print_ast).unwrap_or(false) {
-            if let Some(path) = scope.file_path.as_ref() {
-                println!("[{:?}]: {ss_ast:#?}", path);
-            } else {
-                println!("{ss_ast:#?}");
-            }
+            print_ast(scope, &ss_ast);
         }
         // scope.file_path.as_ref().map(|file| {
         //     let elapsed = start.elapsed();
         //     println!("Elapsed Time [{:?}]: {:.2?}", file, elapsed);
         // });
-        let mut html_cg_env = crate::ss::HtmlCodegenEnv::from_scope(scope);
+        Ok(ast_to_html(env, scope, &LabelIndex::default(), &Bibliography::default(), ss_ast))
+    }
+    pub fn print_ast(scope: &SemanticScope, ss_ast: &crate::ss::Node) {
+        if let Some(path) = scope.file_path.as_ref() {
+            println!("[{:?}]: {ss_ast:#?}", path);
+        } else {
+            println!("{ss_ast:#?}");
+        }
+    }
+    /// Code-gen for an already processed AST, where `labels` are those of
+    /// every page (see `crate::ss::labels`), and `bibliography` is that of
+    /// the page (see `crate::ss::bibliography`).
+    pub fn ast_to_html(
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        labels: &LabelIndex,
+        bibliography: &Bibliography,
+        ss_ast: crate::ss::Node,
+    ) -> (HtmlCodegenEnv, crate::html::Node) {
+        let mut html_cg_env = crate::ss::HtmlCodegenEnv::from_scope(scope)
+            .with_resource_env(env)
+            .with_labels(labels)
+            .with_bibliography(bibliography);
         let html_ast = ss_ast.to_html(&mut html_cg_env, scope);
-        // scope.file_path.as_ref().map(|file| {
-        //     let elapsed = start.elapsed();
-        //     println!("Elapsed Time [{:?}]: {:.2?}", file, elapsed);
-        // });
-        Ok((html_cg_env, html_ast))
+        (html_cg_env, html_ast)
+    }
+    /// Returns the document body, see `crate::ss::codegen::latex_document`.
+    pub fn compile_to_latex(
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+    ) -> Result<(LatexCodegenEnv, String), CompilerError> {
+        let ss_ast = parse_process(env, scope)?;
+        Ok(ast_to_latex(env, scope, &LabelIndex::default(), &Bibliography::default(), ss_ast))
+    }
+    /// See `ast_to_html`.
+    pub fn ast_to_latex(
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        labels: &LabelIndex,
+        bibliography: &Bibliography,
+        ss_ast: crate::ss::Node,
+    ) -> (LatexCodegenEnv, String) {
+        let latex_cg_env = LatexCodegenEnv::from_scope(scope)
+            .with_resource_env(env)
+            .with_labels(labels)
+            .with_bibliography(bibliography);
+        let latex = ss_ast.to_latex(&latex_cg_env, scope);
+        (latex_cg_env, latex)
     }
 }
 
@@ -158,13 +226,98 @@ pub struct HtmlMetadata {
 pub struct Compiler {
     pub project_info: Option<ProjectInfo>,
     pub project_dir: Option<PathBuf>,
-    pub copy_images: Option<bool>,
+    /// See `crate::compiler::assets`.
+    pub asset_options: Option<assets::AssetOptions>,
     pub output_dir: Option<PathBuf>,
     pub files: Vec<FileIOEntry>,
     pub html_metadata: Option<HtmlMetadata>,
     pub template_file: Option<TemplateFile>,
     pub route_prefix: Option<String>,
     pub debug_settings: Option<DebugSettings>,
+    /// See `crate::compiler::incremental`. With `Some(false)` every page is
+    /// recompiled, but the build cache is still updated.
+    pub incremental: Option<bool>,
+    /// So that new pages can be picked up in watch mode.
+    pub source_globs: Vec<SourceGlob>,
+    pub filter: Option<FileFilter>,
+    /// Pages are reloaded after each recompile, see `Compiler::recompile`.
+    pub dev_server: Option<serve::DevServer>,
+    /// The labels of each page as of the last (re)compile, keyed by the
+    /// canonical page path, see `crate::ss::labels`.
+    pub page_labels: Store<HashMap<PathBuf, Vec<Label>>>,
+    /// The title of each page (if it has a heading) as of the last
+    /// (re)compile, keyed by the canonical page path, see
+    /// `crate::html::navigation`.
+    pub page_titles: Store<HashMap<PathBuf, Option<String>>>,
+    /// Likewise for the search index, see `crate::html::search`.
+    pub page_search: Store<HashMap<PathBuf, Vec<SearchSection>>>,
+    /// Likewise for the source locations of links, see
+    /// `crate::compiler::links`.
+    pub page_links: Store<HashMap<PathBuf, Vec<LinkSource>>>,
+    /// Report broken links as errors rather than warnings, see
+    /// `crate::compiler::links`.
+    pub deny_broken_links: Option<bool>,
+    /// Point elements back at their source, see `crate::ss::source_map`.
+    pub source_map: Option<bool>,
+}
+
+/// See `Compiler::add_files_via_glob`.
+#[derive(Debug, Clone)]
+pub struct SourceGlob {
+    pub src_base_dir: PathBuf,
+    pub glob: String,
+    pub out_dir: PathBuf,
+    pub out_ext: String,
+}
+
+impl SourceGlob {
+    pub fn is_match(&self, src_file_path: impl AsRef<Path>) -> bool {
+        use wax::Pattern;
+        let base_dir = crate::ss::env::canonical_path(&self.src_base_dir);
+        let src_file_path = crate::ss::env::canonical_path(src_file_path);
+        match src_file_path.strip_prefix(&base_dir) {
+            Ok(rel_path) => wax::Glob::new(&self.glob).unwrap().is_match(rel_path),
+            Err(_) => false,
+        }
+    }
+}
+
+/// See `Compiler::filter_matching_files`.
+#[derive(Debug, Clone)]
+pub struct FileFilter {
+    pub pattern: String,
+    pub base_dir: PathBuf,
+}
+
+impl FileFilter {
+    pub fn matching_files(&self) -> Vec<PathBuf> {
+        wax::Glob::new(&self.pattern)
+            .unwrap()
+            .walk(&self.base_dir)
+            .flatten()
+            .map(|x| x.into_path())
+            .collect_vec()
+    }
+}
+
+/// A page after processing, i.e. before code-gen, see
+/// `Compiler::process_page`.
+struct ProcessedPage<'a> {
+    file_io_entry: &'a FileIOEntry,
+    scope: SemanticScope,
+    ss_ast: crate::ss::Node,
+    labels: Vec<Label>,
+    bibliography: Bibliography,
+}
+
+/// A page after code-gen, i.e. before the site navigation is known, see
+/// `Compiler::render_page_to_html`.
+struct RenderedPage<'a> {
+    file_io_entry: &'a FileIOEntry,
+    toc: TocPageEntry,
+    search: Vec<SearchSection>,
+    links: Vec<LinkSource>,
+    html: crate::html::Node,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -194,8 +347,24 @@ impl Compiler {
         self.route_prefix = Some(prefix);
         self
     }
-    pub fn copy_images(mut self, toggle: bool) -> Self {
-        self.copy_images = Some(toggle);
+    pub fn with_asset_options(mut self, options: assets::AssetOptions) -> Self {
+        self.asset_options = Some(options);
+        self
+    }
+    pub fn with_dev_server(mut self, dev_server: serve::DevServer) -> Self {
+        self.dev_server = Some(dev_server);
+        self
+    }
+    pub fn deny_broken_links(mut self, toggle: bool) -> Self {
+        self.deny_broken_links = Some(toggle);
+        self
+    }
+    pub fn source_map(mut self, toggle: bool) -> Self {
+        self.source_map = Some(toggle);
+        self
+    }
+    pub fn incremental(mut self, toggle: bool) -> Self {
+        self.incremental = Some(toggle);
         self
     }
     pub fn add_file<I, O>(
@@ -229,7 +398,7 @@ impl Compiler {
             .as_ref()
             .strip_prefix(src_base_dir.as_ref())
             .ok()
-            .filter(|page| page.as_os_str() == "index.ss")
+            .filter(|page| page.as_os_str() == "index.ss" || page.as_os_str() == "index.md")
             .is_some();
         let file_io_entry = FileIOEntry{
             src_file: src_file_path.as_ref().to_path_buf(),
@@ -239,6 +408,17 @@ impl Compiler {
                 is_root_index_page
             }),
         };
+        // E.g. a directory with both an `index.ss` and an `index.md` page,
+        // where the former wins.
+        let existing = self.files
+            .iter_mut()
+            .find(|x| x.out_file == file_io_entry.out_file);
+        if let Some(existing) = existing {
+            if crate::ss::markdown::is_markdown_file(&existing.src_file) {
+                *existing = file_io_entry;
+            }
+            return self
+        }
         self.files.push(file_io_entry);
         self
     }
@@ -249,6 +429,12 @@ impl Compiler {
         out_dir: impl AsRef<Path>,
         out_ext: impl AsRef<str>,
     ) -> Self {
+        self.source_globs.push(SourceGlob {
+            src_base_dir: source_base_dir.as_ref().to_path_buf(),
+            glob: source_glob.as_ref().to_owned(),
+            out_dir: out_dir.as_ref().to_path_buf(),
+            out_ext: out_ext.as_ref().to_owned(),
+        });
         wax::Glob::new(source_glob.as_ref())
             .unwrap()
             .walk(source_base_dir.as_ref())
@@ -268,12 +454,12 @@ impl Compiler {
         pattern: impl AsRef<str>,
         base_dir: impl AsRef<Path>,
     ) -> Self {
-        let ref filtered = wax::Glob::new(pattern.as_ref())
-            .unwrap()
-            .walk(base_dir.as_ref())
-            .flatten()
-            .map(|x| x.into_path())
-            .collect_vec();
+        let filter = FileFilter {
+            pattern: pattern.as_ref().to_owned(),
+            base_dir: base_dir.as_ref().to_path_buf(),
+        };
+        let filtered = &filter.matching_files();
+        self.filter = Some(filter);
         self.files = self.files
             .into_iter()
             .filter(|entry| {
@@ -318,12 +504,17 @@ impl Compiler {
             .unwrap_or_else(|| {
                 crate::html::template::TemplateFile::pre_compile_default()
             });
+        let template_file = match self.dev_server.as_ref() {
+            Some(_) => template_file.with_live_reload(serve::LIVE_RELOAD_PATH),
+            None => template_file,
+        };
         self.template_file = Some(template_file);
         self
     }
     /// Files with longer paths tend to be dependencies of files with shorter
-    /// paths, so until dependency tracking is implemented, this heuristic is
-    /// a quick and dirty alternative. 
+    /// paths, so compiling such first makes better use of the include cache.
+    /// (Dependencies are only known after a build, see
+    /// `crate::compiler::incremental`.)
     pub fn sort_files(mut self) -> Self {
         self.files = self.files
             .into_iter()
@@ -337,67 +528,355 @@ impl Compiler {
             .collect_vec();
         self
     }
-    pub fn compile_pages_to_html(&self) {
-        let resource_env = ResourceEnv::default();
-        let mut nav_entries: Vec<TocPageEntry> = Default::default();
-        let ref root_path = PathBuf::from("/");
-        let system_start = std::time::Instant::now();
-        let (tocs, envs): (Vec<TocPageEntry>, Vec<ResourceEnv>) = self.files
-            .par_iter()
-            .map(|file_io_entry| {
-                let mut env = resource_env.clone();
-                let html = self.compile_page_to_html(&mut env, file_io_entry);
-                (html, env)
+    /// Returns everything reported while compiling, see
+    /// `crate::ss::diagnostics::print_diagnostics`.
+    pub fn compile_pages_to_html(&self) -> Vec<Diagnostic> {
+        self.compile_pages_to_html_with_dependencies().0
+    }
+    /// Also returns the dependency closure of each page, see
+    /// `crate::compiler::incremental`.
+    pub fn compile_pages_to_html_with_dependencies(&self) -> (Vec<Diagnostic>, PageDependencies) {
+        let resource_env = self.resource_env();
+        let build_cache = self.load_build_cache();
+        let mut content_hashes = incremental::ContentHashes::default();
+        let files = self.files
+            .iter()
+            .filter(|entry| {
+                build_cache
+                    .as_ref()
+                    .map(|x| !x.try_reuse(entry, &mut content_hashes, &resource_env))
+                    .unwrap_or(true)
             })
-            .unzip();
-        let result = self.output_dir
+            .collect_vec();
+        let mut compiled = files
+            .iter()
+            .map(|x| canonical_path(&x.src_file))
+            .collect::<HashSet<_>>();
+        // Skipped pages keep the labels (and titles, search sections and link
+        // sources) of the build that compiled them.
+        let cached_pages = build_cache
+            .iter()
+            .flat_map(|x| x.pages.iter())
+            .filter(|(src_file, _)| !compiled.contains(*src_file))
+            .collect_vec();
+        let cached_labels = cached_pages
+            .iter()
+            .map(|(src_file, record)| ((*src_file).clone(), record.labels.clone()))
+            .collect();
+        let cached_titles = cached_pages
+            .iter()
+            .map(|(src_file, record)| ((*src_file).clone(), record.title.clone()))
+            .collect();
+        let cached_search = cached_pages
+            .iter()
+            .map(|(src_file, record)| ((*src_file).clone(), record.search.clone()))
+            .collect();
+        let cached_links = cached_pages
+            .iter()
+            .map(|(src_file, record)| ((*src_file).clone(), record.links.clone()))
+            .collect();
+        self.page_labels.map_mut(|x| *x = cached_labels);
+        self.page_titles.map_mut(|x| *x = cached_titles);
+        self.page_search.map_mut(|x| *x = cached_search);
+        self.page_links.map_mut(|x| *x = cached_links);
+        let mut files = files;
+        let navigation = self.compile_pages_to_html_in_env(&resource_env, &files);
+        // Every page links to every other page, so skipped pages are stale
+        // whenever the navigation changes (e.g. a new page, or a new title).
+        let is_stale = build_cache
             .as_ref()
-            .map(|out_dir| {
-                match self.copy_images.as_ref() {
-                    Some(true) => resource_env.write_file_paths(out_dir),
-                    _ => resource_env.write_sym_links(out_dir),
+            .map(|x| x.navigation.as_ref() != Some(&navigation))
+            .unwrap_or(false);
+        if is_stale {
+            let skipped = self.files
+                .iter()
+                .filter(|x| !compiled.contains(&canonical_path(&x.src_file)))
+                .collect_vec();
+            self.compile_pages_to_html_in_env(&resource_env, &skipped);
+            compiled.extend(skipped.iter().map(|x| canonical_path(&x.src_file)));
+            files.extend(skipped);
+        }
+        // Skipped pages add the images of the build that compiled them (see
+        // `BuildCache::try_reuse`), but filtered builds only see some pages.
+        self.write_images(&resource_env, self.filter.is_none());
+        let graph = resource_env.dependencies();
+        let page_dependencies = self.files
+            .iter()
+            .map(|file_io_entry| {
+                let src_file = canonical_path(&file_io_entry.src_file);
+                let closure = build_cache
+                    .as_ref()
+                    .filter(|_| !compiled.contains(&src_file))
+                    .and_then(|x| x.pages.get(&src_file))
+                    .map(|x| x.dependencies.keys().cloned().collect())
+                    .unwrap_or_else(|| incremental::dependency_closure(&graph, &src_file));
+                (src_file, closure)
+            })
+            .collect();
+        self.update_build_cache(build_cache, &files, &navigation, &resource_env, &mut content_hashes);
+        let mut diagnostics = resource_env.diagnostics();
+        diagnostics.extend(self.check_links());
+        let diagnostics = crate::ss::diagnostics::normalize(diagnostics);
+        (diagnostics, page_dependencies)
+    }
+    /// Every page is processed before any is rendered, so that references
+    /// resolve across pages, see `crate::ss::labels`. Likewise, every page is
+    /// rendered before any is written, so that the site navigation includes
+    /// the title of each, see `crate::html::navigation`. The search index is
+    /// rewritten as well, see `crate::html::search`.
+    fn compile_pages_to_html_in_env(&self, env: &ResourceEnv, files: &[&FileIOEntry]) -> SiteNavigation {
+        let pages = self.page_paths();
+        let processed = files
+            .par_iter()
+            .filter_map(|file_io_entry| {
+                let is_html = file_io_entry.out_file.extension().is_some_and(|x| x == "html");
+                if !is_html {
+                    env.report(Diagnostic::error(format!(
+                        "the HTML output of {} should be a `.html` file, not {}",
+                        file_io_entry.src_file.display(),
+                        file_io_entry.out_file.display(),
+                    )));
+                    return None
                 }
+                let scope = self.page_scope(file_io_entry);
+                let scope = match self.route_prefix.as_ref() {
+                    Some(route_prefix) => scope.with_route_prefix(route_prefix),
+                    None => scope,
+                };
+                self.process_page(env, file_io_entry, scope, &pages)
+            })
+            .collect::<Vec<_>>();
+        let labels = self.label_index(env, files, &processed);
+        let rendered = processed
+            .into_par_iter()
+            .map(|page| self.render_page_to_html(env, page, &labels))
+            .collect::<Vec<_>>();
+        self.update_page_records(files, &rendered);
+        let navigation = self.site_navigation();
+        self.write_search_index(env, &navigation);
+        rendered
+            .into_par_iter()
+            .for_each(|page| {
+                self.write_page_to_html(env, page, &navigation);
             });
-        if !resource_env.empty_images() && result.is_none() {
-            eprintln!("[Warning] The Compiler has found images in your source code but no output dir has been specified.")
-        }
+        navigation
+    }
+    /// The canonical source path of every page.
+    fn page_paths(&self) -> HashSet<PathBuf> {
+        self.files
+            .iter()
+            .map(|x| canonical_path(&x.src_file))
+            .collect()
     }
-    fn compile_page_to_html(
+    /// Keyed by the canonical source path, see `Compiler::page_href`.
+    fn page_hrefs(&self) -> HashMap<PathBuf, String> {
+        self.files
+            .iter()
+            .map(|x| (canonical_path(&x.src_file), self.page_href(x)))
+            .collect()
+    }
+    /// Updates `Compiler::page_labels` with the labels of the given pages.
+    fn label_index(
         &self,
         env: &ResourceEnv,
-        file_io_entry: &FileIOEntry
-    ) -> TocPageEntry {
-        assert!(file_io_entry.out_file.extension().unwrap() == "html");
-        let subscript_std = crate::ss_v1_std::all_commands_list();
-        let base_dir = file_io_entry.page_mode
-            .as_ref()
-            .map(|page_mode| {
-                page_mode.src_base_dir.clone()
-            })
-            .unwrap_or_else(|| {
-                crate::utils::file_path_union(
-                    file_io_entry.src_file.as_path(),
-                    file_io_entry.out_file.as_path(),
-                ).unwrap()
+        files: &[&FileIOEntry],
+        processed: &[ProcessedPage],
+    ) -> LabelIndex {
+        let pages = self.page_paths();
+        let labels = self.page_labels.map_mut(|page_labels| {
+            for file_io_entry in files {
+                page_labels.insert(canonical_path(&file_io_entry.src_file), Vec::new());
+            }
+            for page in processed {
+                page_labels.insert(canonical_path(&page.file_io_entry.src_file), page.labels.clone());
+            }
+            page_labels.retain(|src_file, _| pages.contains(src_file));
+            page_labels.values().flatten().cloned().collect_vec()
+        });
+        LabelIndex::new(env, pages, labels)
+    }
+    /// Updates `Compiler::page_titles`, `Compiler::page_search` and
+    /// `Compiler::page_links` with the given pages.
+    fn update_page_records(&self, files: &[&FileIOEntry], rendered: &[RenderedPage]) {
+        let pages = self.page_paths();
+        self.page_titles.map_mut(|page_titles| {
+            for file_io_entry in files {
+                page_titles.insert(canonical_path(&file_io_entry.src_file), None);
+            }
+            for page in rendered {
+                page_titles.insert(canonical_path(&page.file_io_entry.src_file), page.toc.title());
+            }
+            page_titles.retain(|src_file, _| pages.contains(src_file));
+        });
+        self.page_search.map_mut(|page_search| {
+            for file_io_entry in files {
+                page_search.insert(canonical_path(&file_io_entry.src_file), Vec::new());
+            }
+            for page in rendered {
+                page_search.insert(canonical_path(&page.file_io_entry.src_file), page.search.clone());
+            }
+            page_search.retain(|src_file, _| pages.contains(src_file));
+        });
+        self.page_links.map_mut(|page_links| {
+            for file_io_entry in files {
+                page_links.insert(canonical_path(&file_io_entry.src_file), Vec::new());
+            }
+            for page in rendered {
+                page_links.insert(canonical_path(&page.file_io_entry.src_file), page.links.clone());
+            }
+            page_links.retain(|src_file, _| pages.contains(src_file));
+        });
+    }
+    /// Every page compiled so far (see `Compiler::page_titles`), in the
+    /// order of the output directory.
+    fn site_navigation(&self) -> SiteNavigation {
+        let page_titles = self.page_titles.into_clone();
+        let pages = self.files
+            .iter()
+            .filter_map(|file_io_entry| {
+                let title = page_titles.get(&canonical_path(&file_io_entry.src_file))?;
+                Some(NavPageEntry {
+                    rel_path: self.page_rel_path(file_io_entry),
+                    href: self.page_href(file_io_entry),
+                    title: title.clone(),
+                })
             });
-        let scope = crate::ss::SemanticScope::new(
+        let site_title = self.project_info.as_ref().and_then(|x| x.title.as_deref());
+        SiteNavigation::new(pages, site_title)
+    }
+    /// Every page compiled so far (see `Compiler::page_search`), in reading
+    /// order. Requires an output directory.
+    fn write_search_index(&self, env: &ResourceEnv, navigation: &SiteNavigation) {
+        let output_dir = match self.output_dir.as_ref() {
+            Some(output_dir) => output_dir,
+            None => return,
+        };
+        let page_search = self.page_search.into_clone();
+        let sections = self.files
+            .iter()
+            .filter_map(|file_io_entry| {
+                let sections = page_search.get(&canonical_path(&file_io_entry.src_file))?;
+                Some((self.page_href(file_io_entry), sections))
+            })
+            .collect::<HashMap<_, _>>();
+        let pages = navigation
+            .pages()
+            .into_iter()
+            .filter_map(|page| {
+                Some(SearchPage {
+                    href: page.href.clone(),
+                    title: crate::html::utils::unescape_html_text(&page.title),
+                    sections: sections.get(&page.href)?.to_vec(),
+                })
+            })
+            .collect();
+        let path = output_dir.join(crate::html::search::SEARCH_INDEX_FILE_NAME);
+        let result = std::fs::create_dir_all(output_dir)
+            .and_then(|_| std::fs::write(&path, SearchIndex {pages}.to_script()));
+        if let Err(error) = result {
+            env.report(Diagnostic::warning(format!(
+                "failed to write the search index to {}: {error}",
+                path.display(),
+            )));
+        }
+    }
+    /// The path of the output file relative to the output directory.
+    fn page_rel_path(&self, file_io_entry: &FileIOEntry) -> String {
+        self.output_dir
+            .as_ref()
+            .and_then(|out_dir| file_io_entry.out_file.strip_prefix(out_dir).ok())
+            .or_else(|| file_io_entry.out_file.file_name().map(Path::new))
+            .map(|x| x.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default()
+    }
+    /// Where links to the given page point to, i.e. its path relative to the
+    /// output directory (and route prefix).
+    fn page_href(&self, file_io_entry: &FileIOEntry) -> String {
+        self.site_href(&self.page_rel_path(file_io_entry))
+    }
+    /// The given path relative to the output directory, as a link.
+    fn site_href(&self, path: &str) -> String {
+        match self.route_prefix.as_ref() {
+            Some(prefix) => format!("/{prefix}/{path}"),
+            None => format!("/{path}"),
+        }
+    }
+    /// Every page shares the same environment, so that images are written
+    /// once, see `crate::compiler::assets`.
+    fn resource_env(&self) -> ResourceEnv {
+        ResourceEnv::default().with_asset_options(self.asset_options.clone().unwrap_or_default())
+    }
+    /// With `remove_stale`, every other file in the assets directory is
+    /// removed, which requires the assets of every page.
+    fn write_images(&self, resource_env: &ResourceEnv, remove_stale: bool) {
+        let out_dir = match self.output_dir.as_ref() {
+            Some(out_dir) => out_dir,
+            None => {
+                if !resource_env.empty_images() {
+                    resource_env.report(Diagnostic::warning(
+                        "The Compiler has found images in your source code but no output dir has been specified."
+                    ));
+                }
+                return
+            }
+        };
+        let assets = resource_env.assets().into_values().collect_vec();
+        let options = self.asset_options.clone().unwrap_or_default();
+        for diagnostic in assets::write_assets(out_dir, assets.clone(), &options) {
+            resource_env.report(diagnostic);
+        }
+        if remove_stale {
+            assets::remove_stale_assets(out_dir, &assets);
+        }
+    }
+    fn page_scope(&self, file_io_entry: &FileIOEntry) -> SemanticScope {
+        crate::ss::SemanticScope::new(
             self.project_dir.as_ref().unwrap(),
             &file_io_entry.src_file,
-            subscript_std,
-        );
-        let scope = match self.route_prefix.as_ref() {
-            Some(route_prefix) => scope.with_route_prefix(route_prefix),
-            None => scope,
+            crate::ss_v1_std::all_commands_list(),
+        )
+    }
+    /// Everything up until code-gen, see `Compiler::render_page_to_html`.
+    fn process_page<'a>(
+        &self,
+        env: &ResourceEnv,
+        file_io_entry: &'a FileIOEntry,
+        scope: SemanticScope,
+        pages: &HashSet<PathBuf>,
+    ) -> Option<ProcessedPage<'a>> {
+        let ss_ast = match crate::compiler::low_level_api::parse_process(env, &scope) {
+            Ok(ss_ast) => ss_ast,
+            Err(error) => {
+                env.report(Diagnostic::error(error.to_string()));
+                return None
+            }
         };
-        // let scope = scope.with_route_prefix()
-        let (html_env, page_html) = crate::compiler::low_level_api::compile_to_html(
-            env,
-            &scope,
-            self.debug_settings.as_ref(),
-        ).unwrap();
-        let page_script = crate::html::utils::math_env_to_html_script(
-            &html_env.math_env_clone()
-        );
+        if self.debug_settings.as_ref().map(|x| x.print_ast).unwrap_or(false) {
+            crate::compiler::low_level_api::print_ast(&scope, &ss_ast);
+        }
+        let page_href = self.page_href(file_io_entry);
+        let (ss_ast, mut labels) = crate::ss::labels::resolve_labels(env, &scope, &page_href, pages, ss_ast);
+        let (bibliography, citations) = crate::ss::bibliography::resolve_citations(env, &scope, &page_href, &ss_ast);
+        labels.extend(citations);
+        Some(ProcessedPage {file_io_entry, scope, ss_ast, labels, bibliography})
+    }
+    fn render_page_to_html<'a>(
+        &self,
+        env: &ResourceEnv,
+        page: ProcessedPage<'a>,
+        labels: &LabelIndex,
+    ) -> RenderedPage<'a> {
+        let ProcessedPage {file_io_entry, scope, ss_ast, bibliography, ..} = page;
+        let labels = labels.for_page(&file_io_entry.src_file);
+        let mut html_env = HtmlCodegenEnv::from_scope(&scope)
+            .with_resource_env(env)
+            .with_labels(&labels)
+            .with_bibliography(&bibliography);
+        if self.source_map == Some(true) {
+            let source_map = crate::ss::source_map::SourceMap::new(self.project_dir.as_ref());
+            html_env = html_env.with_source_map(&source_map);
+        }
+        let page_html = ss_ast.to_html(&html_env, &scope);
         let mut toc_page_entry = TocPageEntry{
             used_ids: Default::default(),
             src_path: file_io_entry.src_file.clone(),
@@ -412,12 +891,12 @@ impl Compiler {
             li_entries: Default::default(),
         };
         let page_html = crate::html::toc::toc_rewrites(
-            self.route_prefix.clone(),
-            &base_dir,
-            &file_io_entry.src_file,
+            &self.page_href(file_io_entry),
+            &self.page_hrefs(),
             &mut toc_page_entry,
             page_html,
         );
+        let search = crate::html::search::page_sections(&page_html);
         let main = crate::html::Node::Element(crate::html::Element{
             name: String::from("main"),
             attributes: HashMap::default(),
@@ -437,20 +916,98 @@ impl Compiler {
                 }
             ),
             main,
-            page_script,
         ]);
+        let links = html_env.links_clone();
+        RenderedPage {file_io_entry, toc: toc_page_entry, search, links, html}
+    }
+    fn write_page_to_html(&self, env: &ResourceEnv, page: RenderedPage, navigation: &SiteNavigation) {
+        let RenderedPage {file_io_entry, html, ..} = page;
+        let mut slots = navigation.to_template_slots(&self.page_href(file_io_entry));
+        let search_box = match self.output_dir {
+            Some(_) => crate::html::search::to_search_box(
+                &self.site_href(crate::html::search::SEARCH_INDEX_FILE_NAME)
+            ),
+            None => crate::html::Node::Fragment(Vec::new()),
+        };
+        slots.push(("site-search", search_box));
+        slots.push(("content", html));
         // Ideally the template file should be precompiled.
         // But if it's missing, we just compile it on the spot.
         let html = self.template_file
             .clone()
             .unwrap_or_else(TemplateFile::pre_compile_default)
-            .pack_content(html);
-        file_io_entry.out_file.parent().map(|dir| {
-            std::fs::create_dir_all(dir).unwrap();
-        });
-        std::fs::write(&file_io_entry.out_file, html.to_html_document()).unwrap();
-        toc_page_entry
+            .pack_slots(slots);
+        if let Some(dir) = file_io_entry.out_file.parent() {
+            if let Err(error) = std::fs::create_dir_all(dir) {
+                env.report(Diagnostic::error(format!("failed to create {}: {error}", dir.display())));
+                return
+            }
+        }
+        if let Err(error) = std::fs::write(&file_io_entry.out_file, html.to_html_document()) {
+            env.report(Diagnostic::error(format!(
+                "failed to write {}: {error}",
+                file_io_entry.out_file.display(),
+            )));
+        }
+    }
+    /// Compiles each page to a standalone `.tex` document. Images and drawings
+    /// (as PDFs) are written relative to the output file.
+    pub fn compile_pages_to_latex(&self) -> Vec<Diagnostic> {
+        let resource_env = self.resource_env();
+        let files = self.files
+            .iter()
+            .filter(|file_io_entry| {
+                let is_tex = file_io_entry.out_file.extension().is_some_and(|x| x == "tex");
+                if !is_tex {
+                    resource_env.report(Diagnostic::error(format!(
+                        "the LaTeX output of {} should be a `.tex` file, not {}",
+                        file_io_entry.src_file.display(),
+                        file_io_entry.out_file.display(),
+                    )));
+                }
+                is_tex
+            })
+            .collect_vec();
+        let pages = self.page_paths();
+        let processed = files
+            .par_iter()
+            .filter_map(|file_io_entry| {
+                let scope = self.page_scope(file_io_entry);
+                self.process_page(&resource_env, file_io_entry, scope, &pages)
+            })
+            .collect::<Vec<_>>();
+        let labels = self.label_index(&resource_env, &files, &processed);
+        processed
+            .into_par_iter()
+            .for_each(|page| {
+                self.render_page_to_latex(&resource_env, page, &labels);
+            });
+        crate::ss::diagnostics::normalize(resource_env.diagnostics())
+    }
+    fn render_page_to_latex(&self, env: &ResourceEnv, page: ProcessedPage, labels: &LabelIndex) {
+        let ProcessedPage {file_io_entry, scope, ss_ast, bibliography, ..} = page;
+        let labels = labels.for_page(&file_io_entry.src_file);
+        let (latex_env, body) = crate::compiler::low_level_api::ast_to_latex(env, &scope, &labels, &bibliography, ss_ast);
+        let title = self.project_info.as_ref().and_then(|x| x.title.as_deref());
+        let document = crate::ss::codegen::latex_document(title, &body);
+        let out_dir = file_io_entry.out_file
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        if let Err(error) = std::fs::create_dir_all(&out_dir) {
+            env.report(Diagnostic::error(format!("failed to create {}: {error}", out_dir.display())));
+            return
+        }
+        let options = self.asset_options.clone().unwrap_or_default();
+        for diagnostic in assets::write_assets(&out_dir, env.assets().into_values(), &options) {
+            env.report(diagnostic);
+        }
+        latex_env.write_drawings(&out_dir);
+        if let Err(error) = std::fs::write(&file_io_entry.out_file, document) {
+            env.report(Diagnostic::error(format!(
+                "failed to write {}: {error}",
+                file_io_entry.out_file.display(),
+            )));
+        }
     }
 }
-
-
@@ -0,0 +1,208 @@
+//! The asset pipeline. Images (see `ResourceEnv::add_image`) are written to
+//! the `static-assets` directory under content-addressed names, e.g.
+//! `diagram-1f3a2b4c5d6e7f80.png`, so that they can be cached indefinitely
+//! (the name changes with the contents), and images shared between pages (or
+//! copies of the same image) are written once. Large PNG and JPEG images are
+//! optionally downscaled and re-encoded, see `AssetOptions`.
+//!
+//! Full builds also remove stale assets (e.g. previous versions of an image),
+//! see `remove_stale_assets`.
+use std::collections::{BTreeMap, HashSet};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use image::{DynamicImage, ImageEncoder, ImageFormat};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use rayon::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::ss::diagnostics::Diagnostic;
+use super::incremental::hash_bytes;
+
+/// Relative to the output directory.
+pub const ASSETS_DIR_NAME: &str = "static-assets";
+
+/// For downscaled JPEG images, unless `AssetOptions::jpeg_quality` is given.
+const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssetOptions {
+    /// Wider PNG and JPEG images are downscaled to this width.
+    pub max_image_width: Option<u32>,
+    /// JPEG images are re-encoded with the given quality (from 1 to 100),
+    /// unless that makes them larger.
+    pub jpeg_quality: Option<u8>,
+}
+
+impl AssetOptions {
+    /// The options that affect the given image (if any), which are part of
+    /// its content hash.
+    fn key(&self, path: &Path) -> Option<String> {
+        match ImageFormat::from_path(path).ok()? {
+            ImageFormat::Png => self.max_image_width.map(|x| format!("{x}")),
+            ImageFormat::Jpeg if self.max_image_width.is_some() || self.jpeg_quality.is_some() => {
+                Some(format!("{:?} {:?}", self.max_image_width, self.jpeg_quality))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Asset {
+    /// The canonical path of the original file.
+    pub source: PathBuf,
+    /// The content hash of the original file (and of the options that affect
+    /// it).
+    pub hash: String,
+    /// E.g. `diagram-1f3a2b4c5d6e7f80.png`, relative to `ASSETS_DIR_NAME`.
+    /// Not `diagram.1f3a….png`, since older versions of LaTeX’s `graphicx`
+    /// take everything after the first dot as the extension.
+    pub file_name: String,
+}
+
+impl Asset {
+    pub fn new(source: impl AsRef<Path>, options: &AssetOptions) -> std::io::Result<Self> {
+        let source = source.as_ref();
+        let mut contents = std::fs::read(source)?;
+        if let Some(key) = options.key(source) {
+            contents.extend(key.bytes());
+        }
+        let hash = hash_bytes(&contents);
+        let stem = source
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file_name = match source.extension() {
+            Some(ext) => format!("{stem}-{hash}.{}", ext.to_string_lossy()),
+            None => format!("{stem}-{hash}"),
+        };
+        Ok(Asset {source: source.to_path_buf(), hash, file_name})
+    }
+    /// The given path relative to the output directory.
+    pub fn rel_path(&self) -> String {
+        format!("{ASSETS_DIR_NAME}/{}", self.file_name)
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// OUTPUT
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Writes the given assets to the output directory, skipping those that were
+/// written by a previous build. Returns a warning for each asset that failed
+/// to be written.
+pub fn write_assets(
+    output_dir: impl AsRef<Path>,
+    assets: impl IntoIterator<Item=Asset>,
+    options: &AssetOptions,
+) -> Vec<Diagnostic> {
+    let assets_dir = output_dir.as_ref().join(ASSETS_DIR_NAME);
+    // Identical files are processed once.
+    let mut groups: BTreeMap<String, Vec<Asset>> = BTreeMap::new();
+    for asset in assets {
+        groups.entry(asset.hash.clone()).or_default().push(asset);
+    }
+    groups
+        .into_par_iter()
+        .filter_map(|(_, assets)| {
+            let targets = assets
+                .iter()
+                .map(|x| assets_dir.join(&x.file_name))
+                .filter(|x| !x.exists())
+                .collect::<HashSet<_>>();
+            if targets.is_empty() {
+                return None
+            }
+            let source = &assets[0].source;
+            let result = std::fs::create_dir_all(&assets_dir)
+                .map_err(|x| x.to_string())
+                .and_then(|_| process_image(source, options))
+                .and_then(|contents| {
+                    targets
+                        .iter()
+                        .try_for_each(|target| std::fs::write(target, &contents))
+                        .map_err(|x| x.to_string())
+                });
+            let error = result.err()?;
+            let mut diagnostic = Diagnostic::warning(format!(
+                "failed to write the image {} to {}: {error}",
+                source.display(),
+                assets_dir.display(),
+            ));
+            diagnostic.file_path = Some(source.clone());
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+/// The contents of the given asset, downscaled and re-encoded as per the
+/// given options.
+fn process_image(source: &Path, options: &AssetOptions) -> Result<Vec<u8>, String> {
+    let original = std::fs::read(source).map_err(|x| x.to_string())?;
+    if options.key(source).is_none() {
+        return Ok(original)
+    }
+    let format = ImageFormat::from_path(source).map_err(|x| x.to_string())?;
+    let (width, _) = image::image_dimensions(source).map_err(|x| x.to_string())?;
+    let max_width = options.max_image_width.filter(|max_width| width > *max_width);
+    let reencode = format == ImageFormat::Jpeg && options.jpeg_quality.is_some();
+    if max_width.is_none() && !reencode {
+        return Ok(original)
+    }
+    let image = image::load_from_memory_with_format(&original, format).map_err(|x| x.to_string())?;
+    let image = match max_width {
+        // The height is scaled down proportionally.
+        Some(max_width) => image.resize(max_width, image.height(), FilterType::Lanczos3),
+        None => image,
+    };
+    let mut encoded = Cursor::new(Vec::new());
+    let result = match format {
+        ImageFormat::Png => PngEncoder::new(&mut encoded).write_image(
+            image.as_bytes(),
+            image.width(),
+            image.height(),
+            image.color(),
+        ),
+        _ => {
+            // JPEG has no alpha channel.
+            let image = DynamicImage::ImageRgb8(image.to_rgb8());
+            let quality = options.jpeg_quality.unwrap_or(DEFAULT_JPEG_QUALITY).clamp(1, 100);
+            JpegEncoder::new_with_quality(&mut encoded, quality).encode_image(&image)
+        }
+    };
+    result.map_err(|x| x.to_string())?;
+    let encoded = encoded.into_inner();
+    // Re-encoding doesn’t always pay off for images that weren’t downscaled.
+    if max_width.is_none() && encoded.len() >= original.len() {
+        return Ok(original)
+    }
+    Ok(encoded)
+}
+
+/// Removes every file in the assets directory besides the given assets,
+/// including the (symlinked or copied) images of older compiler versions.
+pub fn remove_stale_assets(output_dir: impl AsRef<Path>, assets: &[Asset]) {
+    fn go(dir: &Path, keep: &HashSet<&str>, is_root: bool) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let is_dir = entry.file_type().map(|x| x.is_dir()).unwrap_or(false);
+            if is_dir {
+                go(&path, keep, false);
+                let _ = std::fs::remove_dir(&path);
+                continue
+            }
+            let name = entry.file_name();
+            let is_current = is_root && keep.contains(name.to_string_lossy().as_ref());
+            if !is_current {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+    let keep = assets.iter().map(|x| x.file_name.as_str()).collect();
+    go(&output_dir.as_ref().join(ASSETS_DIR_NAME), &keep, true);
+}
@@ -0,0 +1,255 @@
+//! Incremental builds. The dependency closure of each page (i.e. the page
+//! itself and every file it pulls in, transitively) is stored along with
+//! content hashes in the output directory, so that later builds only
+//! recompile pages whose closure changed.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use serde::{Serialize, Deserialize};
+use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::env::canonical_path;
+use crate::ss::labels::Label;
+use crate::html::navigation::SiteNavigation;
+use crate::html::search::SearchSection;
+use crate::ss::env::LinkSource;
+use super::assets::Asset;
+use super::{Compiler, FileIOEntry};
+
+/// The dependency closure of each page, keyed by the canonical page path.
+pub type PageDependencies = HashMap<PathBuf, HashSet<PathBuf>>;
+
+/// Relative to the output directory.
+pub const BUILD_CACHE_FILE_NAME: &str = ".subscript-build-cache.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildCache {
+    /// Covers everything besides the sources that affects the output (e.g.
+    /// the compiler itself), see `Compiler::build_fingerprint`.
+    pub fingerprint: String,
+    /// Keyed by the canonical path of the page.
+    pub pages: BTreeMap<PathBuf, PageRecord>,
+    /// Skipped pages are recompiled whenever this changes, see
+    /// `crate::html::navigation`.
+    pub navigation: Option<SiteNavigation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageRecord {
+    pub out_file: PathBuf,
+    /// The content hash of each file in the dependency closure of the page.
+    pub dependencies: BTreeMap<PathBuf, String>,
+    /// Reported again whenever the page is skipped.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Still referenced by other pages when the page is skipped, see
+    /// `crate::ss::labels`.
+    pub labels: Vec<Label>,
+    /// Likewise for the site navigation, `None` for pages without headings.
+    pub title: Option<String>,
+    /// Likewise for the search index.
+    pub search: Vec<SearchSection>,
+    /// Likewise for the link checker.
+    pub links: Vec<LinkSource>,
+    /// Written (and kept) whenever the page is skipped, see
+    /// `crate::compiler::assets`.
+    pub assets: Vec<Asset>,
+}
+
+impl BuildCache {
+    pub fn load(output_dir: impl AsRef<Path>) -> Option<Self> {
+        let path = output_dir.as_ref().join(BUILD_CACHE_FILE_NAME);
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+    pub fn write(&self, output_dir: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::create_dir_all(output_dir.as_ref())?;
+        let path = output_dir.as_ref().join(BUILD_CACHE_FILE_NAME);
+        let contents = serde_json::to_string_pretty(self).unwrap();
+        std::fs::write(path, contents)
+    }
+    /// If the given page is up to date, its diagnostics and images (from the
+    /// build that compiled it) are added to the given environment.
+    pub fn try_reuse(
+        &self,
+        file_io_entry: &FileIOEntry,
+        hashes: &mut ContentHashes,
+        env: &ResourceEnv,
+    ) -> bool {
+        let src_file = canonical_path(&file_io_entry.src_file);
+        let record = match self.pages.get(&src_file) {
+            Some(record) => record,
+            None => return false,
+        };
+        let up_to_date = record.out_file == file_io_entry.out_file &&
+            record.out_file.exists() &&
+            record.dependencies
+                .iter()
+                .all(|(path, hash)| hashes.get(path).as_ref() == Some(hash));
+        if up_to_date {
+            for diagnostic in record.diagnostics.iter() {
+                env.report(diagnostic.clone());
+            }
+            env.add_assets(record.assets.clone());
+        }
+        up_to_date
+    }
+}
+
+/// Content hashes are memoized for the duration of a build, since most pages
+/// share the same includes.
+#[derive(Debug, Clone, Default)]
+pub struct ContentHashes(HashMap<PathBuf, Option<String>>);
+
+impl ContentHashes {
+    /// Returns `None` for files that can’t be read.
+    pub fn get(&mut self, path: impl AsRef<Path>) -> Option<String> {
+        self.0
+            .entry(path.as_ref().to_path_buf())
+            .or_insert_with(|| {
+                std::fs::read(path.as_ref()).ok().map(|x| hash_bytes(&x))
+            })
+            .clone()
+    }
+}
+
+pub(crate) fn hash_bytes(value: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// The given file and everything it depends on, transitively (see
+/// `ResourceEnv::dependencies`).
+pub fn dependency_closure(
+    graph: &HashMap<PathBuf, HashSet<PathBuf>>,
+    path: impl AsRef<Path>,
+) -> HashSet<PathBuf> {
+    let mut closure = HashSet::new();
+    let mut stack = vec![canonical_path(path)];
+    while let Some(path) = stack.pop() {
+        if let Some(dependencies) = graph.get(&path) {
+            stack.extend(dependencies.iter().filter(|x| !closure.contains(*x)).cloned());
+        }
+        closure.insert(path);
+    }
+    closure
+}
+
+impl Compiler {
+    /// Everything besides the sources that affects the output. The compiler
+    /// is tracked by the modification time of the executable, so rebuilding
+    /// the compiler invalidates the cache.
+    fn build_fingerprint(&self) -> String {
+        let compiler = std::env::current_exe()
+            .and_then(|x| x.metadata())
+            .and_then(|x| x.modified())
+            .ok();
+        let mut hashes = ContentHashes::default();
+        let (template, index) = match self.html_metadata.as_ref() {
+            Some(meta) => (
+                meta.html_template_path.as_ref().and_then(|x| hashes.get(x)),
+                meta.html_index_path.as_ref().and_then(|x| hashes.get(x)),
+            ),
+            None => (None, None),
+        };
+        // Pages compiled for the dev server include the live reload client.
+        let fingerprint = format!(
+            "{} {compiler:?} {:?} {:?} {:?} {template:?} {index:?} {} {:?}",
+            env!("CARGO_PKG_VERSION"),
+            self.route_prefix,
+            self.asset_options,
+            self.project_info.as_ref().and_then(|x| x.title.as_ref()),
+            self.dev_server.is_some(),
+            self.source_map,
+        );
+        hash_bytes(fingerprint.as_bytes())
+    }
+    /// Returns `None` unless incremental builds are enabled and the cache in
+    /// the output directory (if any) was produced with the same settings.
+    pub(super) fn load_build_cache(&self) -> Option<BuildCache> {
+        if self.incremental != Some(true) {
+            return None
+        }
+        let output_dir = self.output_dir.as_ref()?;
+        BuildCache::load(output_dir).filter(|x| x.fingerprint == self.build_fingerprint())
+    }
+    /// Records the dependency closure (and diagnostics) of each compiled page.
+    pub(super) fn update_build_cache(
+        &self,
+        build_cache: Option<BuildCache>,
+        compiled: &[&FileIOEntry],
+        navigation: &SiteNavigation,
+        env: &ResourceEnv,
+        hashes: &mut ContentHashes,
+    ) {
+        let output_dir = match (self.incremental, self.output_dir.as_ref()) {
+            (Some(_), Some(output_dir)) => output_dir,
+            _ => return,
+        };
+        let mut build_cache = build_cache.unwrap_or_default();
+        build_cache.fingerprint = self.build_fingerprint();
+        build_cache.navigation = Some(navigation.clone());
+        // Deleted pages, see `Compiler::remove_pages`.
+        build_cache.pages.retain(|src_file, _| src_file.exists());
+        let graph = env.dependencies();
+        // In a stable order, since pages are compiled in parallel.
+        let diagnostics = crate::ss::diagnostics::normalize(env.diagnostics());
+        let page_labels = self.page_labels.into_clone();
+        let page_titles = self.page_titles.into_clone();
+        let page_search = self.page_search.into_clone();
+        let page_links = self.page_links.into_clone();
+        let assets = env.assets();
+        for file_io_entry in compiled {
+            let src_file = canonical_path(&file_io_entry.src_file);
+            let closure = dependency_closure(&graph, &src_file);
+            let diagnostics = diagnostics
+                .iter()
+                .filter(|x| {
+                    x.file_path
+                        .as_ref()
+                        .map(|x| closure.contains(&canonical_path(x)))
+                        .unwrap_or(false)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut page_assets = closure
+                .iter()
+                .filter_map(|x| assets.get(x).cloned())
+                .collect::<Vec<_>>();
+            page_assets.sort_by(|x, y| x.source.cmp(&y.source));
+            // Unreadable (e.g. missing) files always trigger a recompile.
+            let dependencies = closure
+                .into_iter()
+                .map(|x| hashes.get(&x).map(|hash| (x, hash)))
+                .collect::<Option<BTreeMap<_, _>>>();
+            let labels = page_labels.get(&src_file).cloned().unwrap_or_default();
+            let title = page_titles.get(&src_file).cloned().flatten();
+            let search = page_search.get(&src_file).cloned().unwrap_or_default();
+            let links = page_links.get(&src_file).cloned().unwrap_or_default();
+            match dependencies {
+                Some(dependencies) => {
+                    build_cache.pages.insert(src_file, PageRecord {
+                        out_file: file_io_entry.out_file.clone(),
+                        dependencies,
+                        diagnostics,
+                        labels,
+                        title,
+                        search,
+                        links,
+                        assets: page_assets,
+                    });
+                }
+                None => {
+                    build_cache.pages.remove(&src_file);
+                }
+            }
+        }
+        if let Err(error) = build_cache.write(output_dir) {
+            env.report(Diagnostic::warning(format!(
+                "failed to write the build cache to {}: {error}",
+                output_dir.display(),
+            )));
+        }
+    }
+}
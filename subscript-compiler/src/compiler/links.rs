@@ -0,0 +1,192 @@
+//! Link checking. After each build, every internal `href` and `src` of every
+//! page (including those skipped by incremental builds) is resolved against
+//! the output directory, and its fragment (if any) against the anchor IDs of
+//! the page it points to. Broken links are reported at the command they came
+//! from (see `LinkSource`), or else at the page (e.g. for table of contents
+//! entries of included files).
+//!
+//! Links break whenever other pages change, so these diagnostics aren’t
+//! stored in the build cache.
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use crate::html::Node;
+use crate::ss::diagnostics::{Diagnostic, Severity};
+use crate::ss::env::canonical_path;
+use super::Compiler;
+
+/// The anchor IDs and links of a generated page.
+#[derive(Debug, Clone, Default)]
+struct OutputPage {
+    ids: HashSet<String>,
+    links: Vec<String>,
+}
+
+impl OutputPage {
+    fn parse(file_path: &Path) -> Option<Self> {
+        fn go(node: &Node, page: &mut OutputPage) {
+            match node {
+                Node::Element(element) => {
+                    page.ids.extend(element.attributes.get("id").cloned());
+                    for key in ["href", "src"] {
+                        page.links.extend(element.attributes.get(key).cloned());
+                    }
+                    for child in element.children.iter() {
+                        go(child, page);
+                    }
+                }
+                Node::Fragment(children) => {
+                    for child in children.iter() {
+                        go(child, page);
+                    }
+                }
+                Node::Text(_) | Node::Drawing(_) => (),
+            }
+        }
+        let source = std::fs::read_to_string(file_path).ok()?;
+        let mut page = OutputPage::default();
+        go(&Node::parse_str(source), &mut page);
+        Some(page)
+    }
+}
+
+/// Parsed on demand, since links may point to files that aren’t pages.
+#[derive(Debug, Default)]
+struct OutputPages(HashMap<PathBuf, Option<OutputPage>>);
+
+impl OutputPages {
+    fn get(&mut self, file_path: &Path) -> Option<&OutputPage> {
+        self.0
+            .entry(file_path.to_path_buf())
+            .or_insert_with(|| OutputPage::parse(file_path))
+            .as_ref()
+    }
+}
+
+/// E.g. `https://…`, `mailto:…` or `//cdn…`.
+fn is_external(href: &str) -> bool {
+    let scheme = href
+        .split_once(':')
+        .map(|(scheme, _)| !scheme.is_empty() && !scheme.contains(['/', '?', '#']))
+        .unwrap_or(false);
+    scheme || href.starts_with("//")
+}
+
+/// Resolves `.` and `..` components, without touching the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => {
+                result.pop();
+            }
+            component => result.push(component),
+        }
+    }
+    result
+}
+
+impl Compiler {
+    /// Returns a diagnostic for each broken link, which are errors with
+    /// `Compiler::deny_broken_links`.
+    pub(super) fn check_links(&self) -> Vec<Diagnostic> {
+        let output_dir = match self.output_dir.as_ref() {
+            Some(output_dir) => output_dir,
+            None => return Vec::new(),
+        };
+        let severity = match self.deny_broken_links {
+            Some(true) => Severity::Error,
+            _ => Severity::Warning,
+        };
+        let page_links = self.page_links.into_clone();
+        // The template links to the home page, which single files (e.g. with
+        // `compile-file`) don’t have.
+        let home_href = self.site_href("index.html");
+        let has_home_page = self.files.iter().any(|x| self.page_rel_path(x) == "index.html");
+        let mut output_pages = OutputPages::default();
+        let mut diagnostics = Vec::new();
+        for file_io_entry in self.files.iter() {
+            let links = match output_pages.get(&file_io_entry.out_file) {
+                Some(page) => page.links.clone(),
+                None => continue,
+            };
+            let src_file = canonical_path(&file_io_entry.src_file);
+            let mut sources = page_links.get(&src_file).cloned().unwrap_or_default();
+            for href in links {
+                if !has_home_page && href == home_href {
+                    continue
+                }
+                let error = match self.resolve_link(output_dir, &file_io_entry.out_file, &href, &mut output_pages) {
+                    Ok(()) => continue,
+                    Err(error) => error,
+                };
+                // Repeated links are matched up with their sources in order.
+                let source = sources
+                    .iter()
+                    .position(|x| x.value == href)
+                    .map(|ix| sources.remove(ix));
+                diagnostics.push(Diagnostic {
+                    severity,
+                    message: format!("broken link `{href}`: {error}"),
+                    file_path: source
+                        .as_ref()
+                        .and_then(|x| x.file_path.clone())
+                        .or_else(|| Some(file_io_entry.src_file.clone())),
+                    range: source.and_then(|x| x.range),
+                });
+            }
+        }
+        diagnostics
+    }
+    /// Links are relative to the given page, or (if absolute) to the output
+    /// directory and route prefix.
+    fn resolve_link(
+        &self,
+        output_dir: &Path,
+        out_file: &Path,
+        href: &str,
+        output_pages: &mut OutputPages,
+    ) -> Result<(), String> {
+        if href.is_empty() || is_external(href) {
+            return Ok(())
+        }
+        let (path, fragment) = href.split_once('#').unwrap_or((href, ""));
+        let path = path.split_once('?').map(|(path, _)| path).unwrap_or(path);
+        let target = if path.is_empty() {
+            out_file.to_path_buf()
+        } else if let Some(path) = path.strip_prefix('/') {
+            let path = match self.route_prefix.as_ref() {
+                Some(prefix) => path
+                    .strip_prefix(prefix.as_str())
+                    .and_then(|x| x.strip_prefix('/'))
+                    .ok_or_else(|| format!("not under the route prefix `/{prefix}`"))?,
+                None => path,
+            };
+            output_dir.join(path)
+        } else {
+            out_file.parent().unwrap_or(output_dir).join(path)
+        };
+        let target = normalize_path(&target);
+        let target = if target.is_dir() {target.join("index.html")} else {target};
+        let display = target
+            .strip_prefix(output_dir)
+            .unwrap_or(&target)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if !target.exists() {
+            return Err(format!("{display} doesn’t exist"))
+        }
+        let is_html = target.extension().map(|x| x == "html").unwrap_or(false);
+        if fragment.is_empty() || !is_html {
+            return Ok(())
+        }
+        let has_anchor = output_pages
+            .get(&target)
+            .map(|page| page.ids.contains(fragment))
+            .unwrap_or(false);
+        if !has_anchor {
+            return Err(format!("{display} has no `#{fragment}` anchor"))
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+//! A minimal static file server for previewing the output directory, along
+//! with live reloading VIA server-sent events. Meant for local development
+//! only, see the `serve` CLI command.
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use crate::data::Store;
+
+/// The server-sent events endpoint, see `TemplateFile::with_live_reload`.
+pub const LIVE_RELOAD_PATH: &str = "/__subscript/live-reload";
+
+#[derive(Debug, Clone)]
+pub struct DevServer {
+    pub output_dir: PathBuf,
+    pub route_prefix: Option<String>,
+    /// Open live reload connections.
+    clients: Store<Vec<TcpStream>>,
+}
+
+impl DevServer {
+    pub fn new(output_dir: impl AsRef<Path>, route_prefix: Option<String>) -> Self {
+        DevServer {
+            output_dir: output_dir.as_ref().to_path_buf(),
+            route_prefix,
+            clients: Store::default(),
+        }
+    }
+    /// Serves the output directory in the background.
+    pub fn start(&self, address: impl AsRef<str>) -> std::io::Result<()> {
+        let listener = TcpListener::bind(address.as_ref())?;
+        self.serve(listener);
+        Ok(())
+    }
+    fn serve(&self, listener: TcpListener) {
+        let server = self.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let server = server.clone();
+                std::thread::spawn(move || server.handle_connection(stream));
+            }
+        });
+    }
+    /// Tells every open page to reload itself.
+    pub fn reload(&self) {
+        self.clients.map_mut(|clients| {
+            clients.retain_mut(|client| {
+                client.write_all(b"data: reload\n\n").and_then(|_| client.flush()).is_ok()
+            });
+        });
+    }
+    fn handle_connection(&self, mut stream: TcpStream) {
+        let mut reader = match stream.try_clone() {
+            Ok(stream) => BufReader::new(stream),
+            Err(_) => return,
+        };
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).is_err() {
+            return
+        }
+        // The headers are of no use here.
+        let mut line = String::new();
+        while reader.read_line(&mut line).map(|x| x > 2).unwrap_or(false) {
+            line.clear();
+        }
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => (method, target),
+            _ => return respond(&mut stream, "400 Bad Request", "text/plain", b"Bad Request"),
+        };
+        if method != "GET" && method != "HEAD" {
+            return respond(&mut stream, "405 Method Not Allowed", "text/plain", b"Method Not Allowed");
+        }
+        let path = target.split(['?', '#']).next().unwrap_or_default();
+        if path == LIVE_RELOAD_PATH {
+            let headers = "HTTP/1.1 200 OK\r\n\
+                Content-Type: text/event-stream\r\n\
+                Cache-Control: no-cache\r\n\
+                Connection: keep-alive\r\n\r\n";
+            if stream.write_all(headers.as_bytes()).is_ok() {
+                self.clients.map_mut(move |clients| clients.push(stream));
+            }
+            return
+        }
+        let path = match self.route_prefix.as_ref() {
+            Some(prefix) if path == "/" => {
+                let location = format!("/{prefix}/");
+                let headers = format!("HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\n\r\n");
+                let _ = stream.write_all(headers.as_bytes());
+                return
+            }
+            Some(prefix) => path.strip_prefix(&format!("/{prefix}")).filter(|x| x.is_empty() || x.starts_with('/')),
+            None => Some(path),
+        };
+        match path.and_then(|x| self.resolve_file_path(x)) {
+            Some(file_path) => match std::fs::read(&file_path) {
+                Ok(contents) => {
+                    let body: &[u8] = if method == "HEAD" {&[]} else {&contents};
+                    respond(&mut stream, "200 OK", content_type(&file_path), body)
+                }
+                Err(_) => respond(&mut stream, "404 Not Found", "text/plain", b"Not Found"),
+            },
+            None => respond(&mut stream, "404 Not Found", "text/plain", b"Not Found"),
+        }
+    }
+    /// Returns `None` for paths outside of the output directory.
+    fn resolve_file_path(&self, path: &str) -> Option<PathBuf> {
+        let path = percent_encoding_rfc3986::percent_decode_str(path).ok()?.decode_utf8().ok()?;
+        let rel_path = Path::new(path.trim_start_matches('/'));
+        if rel_path.components().any(|x| !matches!(x, Component::Normal(_))) {
+            return None
+        }
+        let file_path = self.output_dir.join(rel_path);
+        if file_path.is_dir() {
+            return Some(file_path.join("index.html"))
+        }
+        Some(file_path)
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &[u8]) {
+    let headers = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len(),
+    );
+    let _ = stream.write_all(headers.as_bytes()).and_then(|_| stream.write_all(body));
+}
+
+fn content_type(file_path: &Path) -> &'static str {
+    match file_path.extension().and_then(|x| x.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("pdf") => "application/pdf",
+        Some("woff2") => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TESTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::SocketAddr;
+
+    /// Serves a temporary output directory on an unused port.
+    fn start(name: &str, route_prefix: Option<&str>) -> (DevServer, SocketAddr) {
+        let dir = std::env::temp_dir().join(format!("subscript-serve-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("output/a b")).unwrap();
+        std::fs::write(dir.join("output/index.html"), "home").unwrap();
+        std::fs::write(dir.join("output/a b/index.html"), "a").unwrap();
+        std::fs::write(dir.join("output/style.css"), "p {}").unwrap();
+        std::fs::write(dir.join("secret.txt"), "secret").unwrap();
+        let server = DevServer::new(dir.join("output"), route_prefix.map(String::from));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        server.serve(listener);
+        (server, address)
+    }
+
+    /// The status line, the `Content-Type` or `Location` header (if any),
+    /// and the body.
+    fn request(address: SocketAddr, request_line: &str) -> (String, String, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "{request_line}\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let mut lines = head.lines();
+        let status = lines.next().unwrap().to_owned();
+        let header = lines
+            .filter_map(|x| x.strip_prefix("Content-Type: ").or_else(|| x.strip_prefix("Location: ")))
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+        (status, header, body.to_owned())
+    }
+
+    fn response(status: &str, header: &str, body: &str) -> (String, String, String) {
+        (format!("HTTP/1.1 {status}"), header.to_owned(), body.to_owned())
+    }
+
+    #[test]
+    fn files_are_served_from_the_output_directory() {
+        let (server, address) = start("files", None);
+        let html = "text/html; charset=utf-8";
+        assert_eq!(request(address, "GET / HTTP/1.1"), response("200 OK", html, "home"));
+        assert_eq!(request(address, "GET /a%20b?x=1 HTTP/1.1"), response("200 OK", html, "a"));
+        assert_eq!(request(address, "GET /style.css HTTP/1.1"), response("200 OK", "text/css; charset=utf-8", "p {}"));
+        assert_eq!(request(address, "HEAD /style.css HTTP/1.1"), response("200 OK", "text/css; charset=utf-8", ""));
+        let not_found = response("404 Not Found", "text/plain", "Not Found");
+        assert_eq!(request(address, "GET /missing.html HTTP/1.1"), not_found);
+        // Nothing outside of the output directory.
+        assert_eq!(request(address, "GET /../secret.txt HTTP/1.1"), not_found);
+        assert_eq!(request(address, "GET /%2e%2e/secret.txt HTTP/1.1"), not_found);
+        assert_eq!(
+            request(address, "POST / HTTP/1.1"),
+            response("405 Method Not Allowed", "text/plain", "Method Not Allowed"),
+        );
+        assert_eq!(request(address, "GET"), response("400 Bad Request", "text/plain", "Bad Request"));
+        let _ = std::fs::remove_dir_all(server.output_dir.parent().unwrap());
+    }
+
+    #[test]
+    fn files_are_served_under_the_route_prefix() {
+        let (server, address) = start("route-prefix", Some("notes"));
+        assert_eq!(request(address, "GET / HTTP/1.1"), response("302 Found", "/notes/", ""));
+        assert_eq!(request(address, "GET /notes/ HTTP/1.1").2, "home");
+        assert_eq!(request(address, "GET /notes/a%20b/ HTTP/1.1").2, "a");
+        assert_eq!(request(address, "GET /notesa%20b/ HTTP/1.1").0, "HTTP/1.1 404 Not Found");
+        assert_eq!(request(address, "GET /a%20b/ HTTP/1.1").0, "HTTP/1.1 404 Not Found");
+        let _ = std::fs::remove_dir_all(server.output_dir.parent().unwrap());
+    }
+
+    #[test]
+    fn open_pages_are_told_to_reload() {
+        let (server, address) = start("live-reload", None);
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET {LIVE_RELOAD_PATH} HTTP/1.1\r\n\r\n").unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut head = String::new();
+        while !head.ends_with("\r\n\r\n") {
+            reader.read_line(&mut head).unwrap();
+        }
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n"), "{head}");
+        // The connection is registered after the headers are written.
+        while server.clients.map(Vec::len) == 0 {
+            std::thread::yield_now();
+        }
+        server.reload();
+        let mut event = String::new();
+        reader.read_line(&mut event).unwrap();
+        assert_eq!(event, "data: reload\n");
+        // Closed connections are dropped, once writing to them fails.
+        drop(reader);
+        for _ in 0..100 {
+            server.reload();
+            if server.clients.map(Vec::len) == 0 {
+                break
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(server.clients.map(Vec::len), 0);
+        let _ = std::fs::remove_dir_all(server.output_dir.parent().unwrap());
+    }
+}
@@ -1,83 +1,352 @@
-use std::{fmt::Display, path::{PathBuf, Path}, collections::HashMap};
+use std::{fmt::Display, path::{PathBuf, Path}, collections::{HashMap, HashSet}, time::Duration};
+use std::sync::mpsc::{channel, Receiver};
 use itertools::Itertools;
 use rayon::prelude::*;
-use futures::channel::mpsc::{channel, Receiver};
-use futures::{SinkExt, StreamExt};
 use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher, Config, EventKind};
 use notify::event::ModifyKind;
-use notify::event::DataChange;
 use crate::html::toc::TocPageEntry;
 use crate::html::template::TemplateFile;
 use crate::ss::{SemanticScope, HtmlCodegenEnv, ResourceEnv};
-use super::Compiler;
+use crate::ss::env::canonical_path;
+use super::{Compiler, FileIOEntry};
+use super::incremental::{ContentHashes, PageDependencies, dependency_closure};
 
+/// How long to wait for further events of a burst, see `next_burst`.
+const QUIET_PERIOD: Duration = Duration::from_millis(100);
 
-fn async_watcher() -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
-    let (mut tx, rx) = channel(1);
-    let watcher = RecommendedWatcher::new(move |res| {
-        futures::executor::block_on(async {
-            tx.send(res).await.unwrap();
-        })
-    }, Config::default())?;
+fn watcher() -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
+    let (tx, rx) = channel();
+    let watcher = RecommendedWatcher::new(tx, Config::default())?;
     Ok((watcher, rx))
 }
 
+/// Editors may save files by writing to them, or by renaming a new file over
+/// the old one. Files that no longer exist afterwards (i.e. deleted or
+/// renamed away) are removed, see `Compiler::remove_pages`.
+fn is_content_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) |
+        EventKind::Remove(_) |
+        EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any)
+    )
+}
+
+/// Saving a file usually results in a burst of events, so these are
+/// collected until none arrive for the given duration.
+fn next_burst<T>(rx: &Receiver<T>, quiet: Duration) -> Option<Vec<T>> {
+    let mut events = vec![rx.recv().ok()?];
+    // Either quiet or disconnected, in which case the next call returns `None`.
+    while let Ok(event) = rx.recv_timeout(quiet) {
+        events.push(event);
+    }
+    Some(events)
+}
+
+/// The (canonical) paths whose contents changed, except for those within the
+/// output directory, i.e. written by the compiler itself.
+fn changed_paths(events: Vec<notify::Result<Event>>, output_dir: Option<&Path>) -> HashSet<PathBuf> {
+    let mut changed = HashSet::new();
+    for res in events {
+        match res {
+            Ok(event) if is_content_change(&event.kind) => {
+                changed.extend(event.paths.iter().map(canonical_path));
+            }
+            Ok(_) => (),
+            Err(e) => println!("watch error: {e:?}"),
+        }
+    }
+    changed.retain(|x| output_dir.map(|dir| !x.starts_with(dir)).unwrap_or(true));
+    changed
+}
+
+/// The pages depending on any of the changed paths, or every page if
+/// `rebuild_all` (e.g. the template changed).
+fn pages_to_recompile(
+    files: &[FileIOEntry],
+    page_dependencies: &PageDependencies,
+    changed: &HashSet<PathBuf>,
+    rebuild_all: bool,
+) -> Vec<FileIOEntry> {
+    files
+        .iter()
+        .filter(|entry| {
+            let src_file = canonical_path(&entry.src_file);
+            let depends_on_changed = match page_dependencies.get(&src_file) {
+                Some(closure) => closure.iter().any(|x| changed.contains(x)),
+                None => changed.contains(&src_file),
+            };
+            rebuild_all || depends_on_changed
+        })
+        .cloned()
+        .collect_vec()
+}
+
 impl Compiler {
-    pub fn recompile(&self, resource_env: &mut ResourceEnv, source_path: impl AsRef<Path>) {
-        let toc_entries = self.files
+    /// Recompiles the given pages (e.g. those depending on a modified file),
+    /// and updates their dependency closures. Every other page is recompiled
+    /// as well if the site navigation changed (e.g. a new page, or a new
+    /// title), see `crate::html::navigation`.
+    pub fn recompile(&self, pages: &[FileIOEntry], page_dependencies: &mut PageDependencies) {
+        let resource_env = self.resource_env();
+        let build_cache = self.load_build_cache();
+        let previous_navigation = self.site_navigation();
+        let mut pages = pages.iter().collect_vec();
+        let navigation = self.compile_pages_to_html_in_env(&resource_env, &pages);
+        if navigation != previous_navigation {
+            let rest = self.files
+                .iter()
+                .filter(|x| !pages.iter().any(|page| page.src_file == x.src_file))
+                .collect_vec();
+            self.compile_pages_to_html_in_env(&resource_env, &rest);
+            pages.extend(rest);
+        }
+        for entry in pages.iter() {
+            println!("Recompiled: {:?}", entry.src_file);
+        }
+        // Only some pages are recompiled, so stale assets are left for the
+        // next full build to remove.
+        self.write_images(&resource_env, false);
+        let graph = resource_env.dependencies();
+        for entry in pages.iter() {
+            let src_file = canonical_path(&entry.src_file);
+            let closure = dependency_closure(&graph, &src_file);
+            page_dependencies.insert(src_file, closure);
+        }
+        let mut content_hashes = ContentHashes::default();
+        self.update_build_cache(build_cache, &pages, &navigation, &resource_env, &mut content_hashes);
+        let mut diagnostics = resource_env.diagnostics();
+        diagnostics.extend(self.check_links());
+        let diagnostics = crate::ss::diagnostics::normalize(diagnostics);
+        crate::ss::diagnostics::print_diagnostics(&diagnostics);
+        if let Some(dev_server) = self.dev_server.as_ref() {
+            dev_server.reload();
+        }
+    }
+    /// Files that affect every page, i.e. the template (and the SCSS files it
+    /// links to) and the index page.
+    fn site_source_files(&self) -> HashSet<PathBuf> {
+        let meta = match self.html_metadata.as_ref() {
+            Some(meta) => meta,
+            None => return HashSet::default(),
+        };
+        meta.html_template_path
+            .as_ref()
+            .map(TemplateFile::source_files)
+            .unwrap_or_default()
+            .into_iter()
+            .chain(meta.html_index_path.clone())
+            .map(canonical_path)
+            .collect()
+    }
+    /// The directories given to `Compiler::add_files_via_glob` are watched
+    /// recursively, everything else (e.g. files included from outside such)
+    /// is watched individually.
+    fn watch_list(&self, page_dependencies: &PageDependencies) -> Vec<(PathBuf, RecursiveMode)> {
+        let roots = self.source_globs
             .iter()
-            .filter_map(|entry| {
-                if entry.matches_path(source_path.as_ref()) {
-                    let toc_entry = self.compile_page_to_html(resource_env, &entry);
-                    println!("Recompiled: {:?}", entry.src_file);
-                    return Some(toc_entry);
-                }
-                None
-            })
+            .map(|x| canonical_path(&x.src_base_dir))
+            .unique()
             .collect_vec();
+        let files = self.files
+            .iter()
+            .map(|x| canonical_path(&x.src_file))
+            .chain(page_dependencies.values().flatten().cloned())
+            .chain(self.site_source_files())
+            .filter(|x| x.exists())
+            .filter(|x| !roots.iter().any(|root| x.starts_with(root)))
+            .unique();
+        roots
+            .iter()
+            .cloned()
+            .map(|x| (x, RecursiveMode::Recursive))
+            .chain(files.map(|x| (x, RecursiveMode::NonRecursive)))
+            .collect()
     }
-    async fn compile_watch_loop(self) -> notify::Result<()> {
-        let mut watching_files = Vec::new();
-        for entry in self.files.iter() {
-            watching_files.push(entry.src_file.clone());
+    fn watch_new_paths(
+        &self,
+        watcher: &mut RecommendedWatcher,
+        watching: &mut HashSet<PathBuf>,
+        page_dependencies: &PageDependencies,
+    ) -> notify::Result<()> {
+        for (path, mode) in self.watch_list(page_dependencies) {
+            if watching.insert(path.clone()) {
+                println!("\t-{path:?}");
+                watcher.watch(&path, mode)?;
+            }
+        }
+        Ok(())
+    }
+    /// Adds the given file if it’s a new page, i.e. it matches one of the
+    /// globs given to `Compiler::add_files_via_glob` (and the filter, if any).
+    fn add_new_page(self, path: &Path) -> Self {
+        let is_known = self.files
+            .iter()
+            .any(|x| canonical_path(&x.src_file) == path);
+        if is_known || !path.is_file() {
+            return self
         }
-        let _ = self.html_metadata
+        let is_filtered_out = self.filter
             .as_ref()
-            .and_then(|meta| meta.html_template_path.as_ref())
-            .map(|template_path| {
-                watching_files.push(template_path.clone());
+            .map(|x| !x.matching_files().iter().any(|x| canonical_path(x) == path))
+            .unwrap_or(false);
+        let source_glob = self.source_globs
+            .iter()
+            .find(|x| x.is_match(path))
+            .cloned();
+        match source_glob {
+            Some(glob) if !is_filtered_out => {
+                let rel_path = path
+                    .strip_prefix(canonical_path(&glob.src_base_dir))
+                    .unwrap();
+                let src_file_path = glob.src_base_dir.join(rel_path);
+                println!("Added: {:?}", src_file_path);
+                self.add_file_multi_page_mode(
+                    &glob.src_base_dir,
+                    &src_file_path,
+                    &glob.out_dir,
+                    &glob.out_ext,
+                )
+            }
+            _ => self,
+        }
+    }
+    /// Drops the pages within the given (deleted) paths, along with their
+    /// output files. Returns whether there were any.
+    fn remove_pages(&mut self, removed: &HashSet<PathBuf>, page_dependencies: &mut PageDependencies) -> bool {
+        let (pages, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.files)
+            .into_iter()
+            .partition(|entry| {
+                let src_file = canonical_path(&entry.src_file);
+                removed.iter().any(|x| src_file.starts_with(x))
             });
-        println!("Watching:");
-        let (mut watcher, mut rx) = async_watcher()?;
-        for file in watching_files.iter() {
-            println!("\t-{file:?}");
-            watcher.watch(file.as_ref(), RecursiveMode::NonRecursive)?;
+        self.files = rest;
+        for entry in pages.iter() {
+            println!("Removed: {:?}", entry.src_file);
+            page_dependencies.remove(&canonical_path(&entry.src_file));
+            let _ = std::fs::remove_file(&entry.out_file);
         }
-        while let Some(res) = rx.next().await {
-            match res {
-                Ok(event) => {
-                    match &event.kind {
-                        EventKind::Modify(ModifyKind::Data(DataChange::Content)) => {
-                            for path in event.paths.clone() {
-                                let mut resource_env = ResourceEnv::default();
-                                self.recompile(&mut resource_env, &path);
-                            }
-                        }
-                        _ => ()
-                    }
-                },
-                Err(e) => println!("watch error: {e:?}"),
+        !pages.is_empty()
+    }
+    fn compile_watch_loop(mut self, mut page_dependencies: PageDependencies) -> notify::Result<()> {
+        let output_dir = self.output_dir.as_ref().map(canonical_path);
+        let (mut watcher, rx) = watcher()?;
+        let mut watching = HashSet::default();
+        println!("Watching:");
+        self.watch_new_paths(&mut watcher, &mut watching, &page_dependencies)?;
+        while let Some(events) = next_burst(&rx, QUIET_PERIOD) {
+            let changed = changed_paths(events, output_dir.as_deref());
+            if changed.is_empty() {
+                continue
             }
+            let removed = changed
+                .iter()
+                .filter(|x| !x.exists())
+                .cloned()
+                .collect::<HashSet<_>>();
+            // Such are watched again once recreated.
+            watching.retain(|x| !removed.iter().any(|removed| x.starts_with(removed)));
+            // Every other page links to removed pages, see
+            // `crate::html::navigation`.
+            let pages_removed = self.remove_pages(&removed, &mut page_dependencies);
+            let site_changed = changed
+                .intersection(&self.site_source_files())
+                .next()
+                .is_some();
+            if site_changed {
+                self = self.compile_template();
+            }
+            for path in changed.iter() {
+                self = self.add_new_page(path);
+            }
+            let pages = pages_to_recompile(&self.files, &page_dependencies, &changed, site_changed || pages_removed);
+            if pages.is_empty() {
+                continue
+            }
+            self.recompile(&pages, &mut page_dependencies);
+            self.watch_new_paths(&mut watcher, &mut watching, &page_dependencies)?;
         }
         Ok(())
     }
     pub fn compile_html_watch_sources(self) {
-        self.compile_pages_to_html();
-        futures::executor::block_on(async {
-            if let Err(e) = self.compile_watch_loop().await {
-                println!("error: {:?}", e)
-            }
-        });
+        let compiler = self.compile_template();
+        let (diagnostics, page_dependencies) = compiler.compile_pages_to_html_with_dependencies();
+        crate::ss::diagnostics::print_diagnostics(&diagnostics);
+        if let Err(e) = compiler.compile_watch_loop(page_dependencies) {
+            println!("error: {:?}", e)
+        }
     }
 }
 
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TESTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange};
+
+    fn page(src_file: &str) -> FileIOEntry {
+        FileIOEntry {
+            src_file: PathBuf::from(src_file),
+            out_file: PathBuf::from(src_file).with_extension("html"),
+            page_mode: None,
+        }
+    }
+
+    fn paths(paths: &[&str]) -> HashSet<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn bursts_end_once_quiet() {
+        let (tx, rx) = channel();
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            tx.send(3).unwrap();
+            std::thread::sleep(Duration::from_millis(500));
+            tx.send(4).unwrap();
+        });
+        let quiet = Duration::from_millis(200);
+        assert_eq!(next_burst(&rx, quiet), Some(vec![1, 2, 3]));
+        assert_eq!(next_burst(&rx, quiet), Some(vec![4]));
+        sender.join().unwrap();
+        // The watcher is gone.
+        assert_eq!(next_burst(&rx, quiet), None);
+    }
+
+    #[test]
+    fn only_content_changes_outside_the_output_are_kept() {
+        let events = vec![
+            Ok(Event::new(EventKind::Create(CreateKind::File)).add_path(PathBuf::from("/notes/a.ss"))),
+            Ok(Event::new(EventKind::Access(AccessKind::Read)).add_path(PathBuf::from("/notes/b.ss"))),
+            Ok(Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content))).add_path(PathBuf::from("/out/a.html"))),
+            Err(notify::Error::generic("lost")),
+        ];
+        assert_eq!(changed_paths(events, Some(Path::new("/out"))), paths(&["/notes/a.ss"]));
+    }
+
+    #[test]
+    fn pages_depending_on_changes_are_recompiled() {
+        let files = [page("/notes/a.ss"), page("/notes/b.ss"), page("/notes/c.ss")];
+        let page_dependencies = PageDependencies::from_iter([
+            (PathBuf::from("/notes/a.ss"), paths(&["/notes/a.ss", "/notes/shared.ss"])),
+            (PathBuf::from("/notes/b.ss"), paths(&["/notes/b.ss"])),
+        ]);
+        let recompiled = |changed: &[&str], rebuild_all: bool| {
+            pages_to_recompile(&files, &page_dependencies, &paths(changed), rebuild_all)
+                .into_iter()
+                .map(|x| x.src_file.to_str().unwrap().to_owned())
+                .collect_vec()
+        };
+        assert_eq!(recompiled(&["/notes/shared.ss"], false), ["/notes/a.ss"]);
+        assert_eq!(recompiled(&["/notes/b.ss", "/notes/other.ss"], false), ["/notes/b.ss"]);
+        // Pages that weren’t compiled yet only depend on themselves.
+        assert_eq!(recompiled(&["/notes/c.ss"], false), ["/notes/c.ss"]);
+        assert_eq!(recompiled(&["/notes/other.ss"], false), Vec::<String>::new());
+        assert_eq!(recompiled(&["/notes/other.ss"], true), ["/notes/a.ss", "/notes/b.ss", "/notes/c.ss"]);
+    }
+}
@@ -214,8 +214,10 @@ impl Node {
         match self {
             Node::Text(node) => node,
             Node::Element(node) => {
+                // Sorted, so that the output is reproducible.
                 let attributes = node.attributes
                     .into_iter()
+                    .sorted()
                     .map(|(left, right)| -> String {
                         let mut result = String::new();
                         let key: &str = &left;
@@ -273,17 +275,15 @@ impl Node {
                 if v1 && v2 {
                     vec![dark_ui_mode, light_ui_mode].join("\n")
                 } else {
-                    let id = crate::utils::random_str_id();
                     let wrapper = TagBuilder::new("div")
                         .with_class("toggle-visibility")
-                        .with_id(&id)
                         .with_attr("data-visible", "false")
                         .push_child(
                             TagBuilder::new("header")
                                 .push_child(
                                     TagBuilder::new("button")
                                         .with_class("expand")
-                                        .with_attr("onclick", format!("expandDiv('{}')", id))
+                                        .with_attr("onclick", "expandDiv(this)")
                                         .push_child(
                                             TagBuilder::new("span")
                                                 .with_class("material-symbols-outlined")
@@ -292,7 +292,7 @@ impl Node {
                                 )
                                 .push_child(
                                     TagBuilder::new("button")
-                                        .with_attr("onclick", format!("collapseDiv('{}')", id))
+                                        .with_attr("onclick", "collapseDiv(this)")
                                         .with_class("collapse")
                                         .push_child(
                                             TagBuilder::new("span")
@@ -374,6 +374,10 @@ impl Node {
                 .collect::<String>()
         }
         match self {
+            // Prerendered math, see `HtmlCodegenEnv::add_inline_math_entry`.
+            Node::Element(cmd) if cmd.attributes.contains_key("data-math-node") => {
+                String::default()
+            },
             Node::Element(cmd) => {
                 cmd.children
                     .iter()
@@ -389,6 +393,28 @@ impl Node {
             Node::Drawing(x) => String::default(),
         }
     }
+    /// The (escaped) text of the node, e.g. for page titles. Math is skipped,
+    /// as with `Node::to_dashed_title`.
+    pub fn to_text_content(&self) -> String {
+        match self {
+            Node::Element(cmd) if cmd.attributes.contains_key("data-math-node") => {
+                String::default()
+            },
+            Node::Element(cmd) => {
+                cmd.children
+                    .iter()
+                    .map(Node::to_text_content)
+                    .collect::<String>()
+            },
+            Node::Fragment(xs) => {
+                xs  .iter()
+                    .map(Node::to_text_content)
+                    .collect::<String>()
+            },
+            Node::Text(value) => value.clone(),
+            Node::Drawing(_) => String::default(),
+        }
+    }
 }
 
 
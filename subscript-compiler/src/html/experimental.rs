@@ -64,126 +64,6 @@ impl html::Node {
             };
             node
         }
-        fn escape_latex_math(value: String) -> String {
-            let try_parse = |value: &str| {
-                std::panic::catch_unwind({
-                    let value = value.clone();
-                    move || {
-                        let scope = ss::SemanticScope::test_mode_empty();
-                        ss::parser::parse_source(&scope, value)
-                    }
-                })
-                .is_ok()
-            };
-            if try_parse(&value) {
-                return value;
-            }
-            let value = value
-                // .replace("\\left\\{", "\\left\\lbrace")
-                // .replace("\\left\\[", "\\left\\lbrack")
-                // .replace("\\left\\(", "\\left\\lparen")
-                // .replace("\\right\\}", "\\right\\rbrace")
-                // .replace("\\right\\]", "\\right\\rbrack")
-                // .replace("\\right\\)", "\\right\\rparen")
-                .replace("[", "\\lbrack-inline")
-                .replace("]", "\\rbrack-inline")
-                .replace("{", "\\lbrace-inline")
-                .replace("}", "\\rbrace-inline")
-                .replace("(", "\\lparen-inline")
-                .replace(")", "\\rparen-inline");
-            let value = value
-                .replace("\\lbrack-inline", "\\lbrack[inline]")
-                .replace("\\rbrack-inline", "\\rbrack[inline]")
-                .replace("\\lbrace-inline", "\\lbrace[inline]")
-                .replace("\\rbrace-inline", "\\rbrace[inline]")
-                .replace("\\lparen-inline", "\\lparen[inline]")
-                .replace("\\rparen-inline", "\\rparen[inline]");
-            assert!(try_parse(&value));
-            return value;
-
-            // let mut unbalanaced = false;
-            // if value.contains("\\left\\{") {
-            //     unbalanaced = true
-            // }
-            // if value.contains("\\left\\{") {
-            //     unbalanaced = true
-            // }
-            // if value.contains("\\right\\}") {
-            //     unbalanaced = true
-            // }
-            // if unbalanaced {
-            //     let value = value
-            //         .replace("\\left\\{", "\\left\\lbrace")
-            //         .replace("\\right\\}", "\\right\\rbrace")
-            //         .replace("\\left\\[", "\\left\\lbrack")
-            //         .replace("\\right\\]", "\\right\\rbrack")
-            //         .replace("\\left\\(", "\\right\\rbrack")
-            //         .replace("[", "\\lbrack{}")
-            //         .replace("]", "\\rbrack{}")
-            //         .replace("{", "\\lbrace{}")
-            //         .replace("}", "\\rbrace{}")
-            //         .replace("(", "\\lparen{}")
-            //         .replace(")", "\\rparen{}");
-            //     assert!(try_parse(value.clone()));
-            //     return value;
-            // }
-
-            // let pack = |value: String| -> String {
-            //     value
-            //     // value
-            //     //     .lines()
-            //     //     .map(|l| l.trim())
-            //     //     .collect_vec()
-            //     //     .join("\n")
-            // };
-            // if try_parse(value.clone()) {
-            //     return value;
-            // }
-
-            // let origional = value.clone();
-            
-            // let value = origional
-            //     .replace("[", "\\lbrack{}")
-            //     .replace("]", "\\rbrack{}")
-            //     .replace("(", "\\lparen{}")
-            //     .replace(")", "\\rparen{}");
-            // if try_parse(value.clone()) {
-            //     // return pack(value)
-            //     return value;
-            // }
-
-            // let value = origional
-            //     .replace("(", "\\lparen")
-            //     .replace(")", "\\rparen");
-            // if try_parse(value.clone()) {
-            //     // return pack(value)
-            //     return value;
-            // }
-
-            // let value = origional
-            //     .replace("(", "\\lparen")
-            //     .replace(")", "\\rparen");
-            // if try_parse(value.clone()).is_ok() {
-            //     return pack(value)
-            // }
-
-            // let value = origional
-            //     .replace("[", "\\lbrack{}")
-            //     .replace("]", "\\rbrack{}")
-            //     .replace("(", "\\lparen")
-            //     .replace(")", "\\rparen");
-            // if try_parse(value.clone()).is_ok() {
-            //     return pack(value)
-            // }
-            // unimplemented!()
-            // let value = value;
-                // .replace("[", "\\lbrack{}")
-                // .replace("]", "\\rbrack{}")
-                // .replace("(", "\\lparen")
-                // .replace(")", "\\rparen");
-            // assert!(try_parse(value.clone()));
-            // value
-        }
         match self {
             html::Node::Element(node) if &node.name == "tex" => {
                 let str = html::Node::Fragment(node.children).to_html_fragment_str();
@@ -322,3 +202,123 @@ impl html::Node {
         }
     }
 }
+
+/// TeX with unbalanced brackets (e.g. `\left\{`) doesn’t parse as Subscript,
+/// so these are rewritten as e.g. `\lbrace[inline]`.
+pub(crate) fn escape_latex_math(value: String) -> String {
+    let try_parse = |value: &str| {
+        let env = ss::ResourceEnv::default();
+        let scope = ss::SemanticScope::test_mode_empty();
+        let node = ss::parser::parse_source(&env, &scope, value);
+        ss::validation::validate_syntax(&env, &scope, &node);
+        !env.has_errors()
+    };
+    if try_parse(&value) {
+        return value;
+    }
+    let value = value
+        // .replace("\\left\\{", "\\left\\lbrace")
+        // .replace("\\left\\[", "\\left\\lbrack")
+        // .replace("\\left\\(", "\\left\\lparen")
+        // .replace("\\right\\}", "\\right\\rbrace")
+        // .replace("\\right\\]", "\\right\\rbrack")
+        // .replace("\\right\\)", "\\right\\rparen")
+        .replace("[", "\\lbrack-inline")
+        .replace("]", "\\rbrack-inline")
+        .replace("{", "\\lbrace-inline")
+        .replace("}", "\\rbrace-inline")
+        .replace("(", "\\lparen-inline")
+        .replace(")", "\\rparen-inline");
+    let value = value
+        .replace("\\lbrack-inline", "\\lbrack[inline]")
+        .replace("\\rbrack-inline", "\\rbrack[inline]")
+        .replace("\\lbrace-inline", "\\lbrace[inline]")
+        .replace("\\rbrace-inline", "\\rbrace[inline]")
+        .replace("\\lparen-inline", "\\lparen[inline]")
+        .replace("\\rparen-inline", "\\rparen[inline]");
+    assert!(try_parse(&value));
+    value
+
+    // let mut unbalanaced = false;
+    // if value.contains("\\left\\{") {
+    //     unbalanaced = true
+    // }
+    // if value.contains("\\left\\{") {
+    //     unbalanaced = true
+    // }
+    // if value.contains("\\right\\}") {
+    //     unbalanaced = true
+    // }
+    // if unbalanaced {
+    //     let value = value
+    //         .replace("\\left\\{", "\\left\\lbrace")
+    //         .replace("\\right\\}", "\\right\\rbrace")
+    //         .replace("\\left\\[", "\\left\\lbrack")
+    //         .replace("\\right\\]", "\\right\\rbrack")
+    //         .replace("\\left\\(", "\\right\\rbrack")
+    //         .replace("[", "\\lbrack{}")
+    //         .replace("]", "\\rbrack{}")
+    //         .replace("{", "\\lbrace{}")
+    //         .replace("}", "\\rbrace{}")
+    //         .replace("(", "\\lparen{}")
+    //         .replace(")", "\\rparen{}");
+    //     assert!(try_parse(value.clone()));
+    //     return value;
+    // }
+
+    // let pack = |value: String| -> String {
+    //     value
+    //     // value
+    //     //     .lines()
+    //     //     .map(|l| l.trim())
+    //     //     .collect_vec()
+    //     //     .join("\n")
+    // };
+    // if try_parse(value.clone()) {
+    //     return value;
+    // }
+
+    // let origional = value.clone();
+    
+    // let value = origional
+    //     .replace("[", "\\lbrack{}")
+    //     .replace("]", "\\rbrack{}")
+    //     .replace("(", "\\lparen{}")
+    //     .replace(")", "\\rparen{}");
+    // if try_parse(value.clone()) {
+    //     // return pack(value)
+    //     return value;
+    // }
+
+    // let value = origional
+    //     .replace("(", "\\lparen")
+    //     .replace(")", "\\rparen");
+    // if try_parse(value.clone()) {
+    //     // return pack(value)
+    //     return value;
+    // }
+
+    // let value = origional
+    //     .replace("(", "\\lparen")
+    //     .replace(")", "\\rparen");
+    // if try_parse(value.clone()).is_ok() {
+    //     return pack(value)
+    // }
+
+    // let value = origional
+    //     .replace("[", "\\lbrack{}")
+    //     .replace("]", "\\rbrack{}")
+    //     .replace("(", "\\lparen")
+    //     .replace(")", "\\rparen");
+    // if try_parse(value.clone()).is_ok() {
+    //     return pack(value)
+    // }
+    // unimplemented!()
+    // let value = value;
+        // .replace("[", "\\lbrack{}")
+        // .replace("]", "\\rbrack{}")
+        // .replace("(", "\\lparen")
+        // .replace(")", "\\rparen");
+    // assert!(try_parse(value.clone()));
+    // value
+}
@@ -0,0 +1,715 @@
+//! Build-time syntax highlighting, e.g. for `\pre[lang=rust]{…}`. The
+//! grammars are bundled in the binary, each being a handful of regexes (for
+//! comments, strings and such) along with keyword lists, so that highlighting
+//! is fast and needs no network access or client-side scripts.
+//!
+//! Tokens become `<span class="hl-*">` elements (see `Class`), which the
+//! default `index.css` styles for both light and dark color schemes, and code
+//! blocks are split into `<span class="line" data-line="n">` elements, for
+//! line numbers and highlighted lines.
+use std::ops::RangeInclusive;
+use std::path::Path;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::Node;
+use super::TagBuilder;
+use super::utils::escape_html_text;
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TOKENS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Comment,
+    String,
+    Number,
+    Keyword,
+    Type,
+    Constant,
+    Function,
+    Macro,
+    Attribute,
+    Tag,
+    Property,
+    Variable,
+}
+
+impl Class {
+    pub fn css_class(self) -> &'static str {
+        match self {
+            Class::Comment => "hl-comment",
+            Class::String => "hl-string",
+            Class::Number => "hl-number",
+            Class::Keyword => "hl-keyword",
+            Class::Type => "hl-type",
+            Class::Constant => "hl-constant",
+            Class::Function => "hl-function",
+            Class::Macro => "hl-macro",
+            Class::Attribute => "hl-attribute",
+            Class::Tag => "hl-tag",
+            Class::Property => "hl-property",
+            Class::Variable => "hl-variable",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// `None` for everything else, e.g. whitespace and operators.
+    pub class: Option<Class>,
+    pub text: &'a str,
+}
+
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// GRAMMARS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Rules are tried in order (at the leftmost position), before identifiers
+/// and numbers. Rule regexes must only use non-capturing groups.
+#[derive(Debug, Clone, Copy)]
+struct Spec {
+    /// E.g. `rust`, the first one is the canonical name.
+    names: &'static [&'static str],
+    extensions: &'static [&'static str],
+    rules: &'static [(Class, &'static str)],
+    identifier: &'static str,
+    number: Option<&'static str>,
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    constants: &'static [&'static str],
+    /// Identifiers and strings followed by one of the given characters, e.g.
+    /// `"key":` in JSON.
+    keys: Option<(&'static [char], Class)>,
+    /// E.g. `Vec` is a type and `MAX_LEN` a constant.
+    capitalized_types: bool,
+    /// Keywords are matched case insensitively (and given in lowercase).
+    case_insensitive: bool,
+}
+
+const IDENTIFIER: &str = r"[\p{L}_][\p{L}\p{N}_]*";
+const NUMBER: &str = r"0[xX][0-9a-fA-F_]+|0[bB][01_]+|0[oO][0-7_]+|\d[\d_]*(?:\.\d[\d_]*)?(?:[eE][+-]?\d+)?[\p{L}_]*";
+
+const BASE: Spec = Spec {
+    names: &[],
+    extensions: &[],
+    rules: &[],
+    identifier: IDENTIFIER,
+    number: Some(NUMBER),
+    keywords: &[],
+    types: &[],
+    constants: &[],
+    keys: None,
+    capitalized_types: false,
+    case_insensitive: false,
+};
+
+const C_COMMENTS: [(Class, &str); 2] = [
+    (Class::Comment, r"//[^\n]*"),
+    (Class::Comment, r"/\*[\s\S]*?\*/"),
+];
+const DOUBLE_QUOTED: &str = r#""(?:[^"\\\n]|\\[\s\S])*""#;
+const SINGLE_QUOTED: &str = r"'(?:[^'\\\n]|\\[\s\S])*'";
+
+const C_KEYWORDS: &[&str] = &[
+    "break", "case", "const", "continue", "default", "do", "else", "enum", "extern", "for",
+    "goto", "if", "inline", "register", "restrict", "return", "sizeof", "static", "struct",
+    "switch", "typedef", "union", "volatile", "while",
+];
+const C_TYPES: &[&str] = &[
+    "bool", "char", "double", "float", "int", "long", "short", "signed", "unsigned", "void",
+    "size_t", "ssize_t", "int8_t", "int16_t", "int32_t", "int64_t", "uint8_t", "uint16_t",
+    "uint32_t", "uint64_t", "FILE",
+];
+const JS_KEYWORDS: &[&str] = &[
+    "async", "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+    "default", "delete", "do", "else", "export", "extends", "finally", "for", "from",
+    "function", "get", "if", "import", "in", "instanceof", "let", "new", "of", "return", "set",
+    "static", "super", "switch", "this", "throw", "try", "typeof", "var", "void", "while",
+    "with", "yield",
+];
+
+const SPECS: &[Spec] = &[
+    Spec {
+        names: &["rust", "rs"],
+        extensions: &["rs"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, r##"b?r#*"[\s\S]*?"#*"##),
+            (Class::String, r#"b?"(?:[^"\\]|\\[\s\S])*""#),
+            (Class::String, r"b?'(?:[^'\\\n]|\\[^'\n]*)'"),
+            (Class::Variable, r"'[\p{L}_][\p{L}\p{N}_]*"),
+            (Class::Attribute, r"#!?\[[^\]\n]*\]"),
+            (Class::Macro, r"[\p{L}_][\p{L}\p{N}_]*!"),
+        ],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+            "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "type",
+            "unsafe", "use", "where", "while",
+        ],
+        types: &[
+            "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "str",
+            "u8", "u16", "u32", "u64", "u128", "usize", "Self",
+        ],
+        constants: &["true", "false"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["python", "py"],
+        extensions: &["py"],
+        rules: &[
+            (Class::Comment, r"#[^\n]*"),
+            (Class::String, r#"[rRbBuUfF]{0,2}"""[\s\S]*?""""#),
+            (Class::String, r"[rRbBuUfF]{0,2}'''[\s\S]*?'''"),
+            (Class::String, r#"[rRbBuUfF]{0,2}"(?:[^"\\\n]|\\[\s\S])*""#),
+            (Class::String, r"[rRbBuUfF]{0,2}'(?:[^'\\\n]|\\[\s\S])*'"),
+            (Class::Attribute, r"@[\p{L}_][\p{L}\p{N}_.]*"),
+        ],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+            "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+            "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+            "with", "yield", "match", "case", "self",
+        ],
+        types: &[
+            "bool", "bytes", "complex", "dict", "float", "frozenset", "int", "list", "object",
+            "set", "str", "tuple", "type",
+        ],
+        constants: &["True", "False", "None"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["javascript", "js", "jsx"],
+        extensions: &["js", "mjs", "cjs", "jsx"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+            (Class::String, r"`(?:[^`\\]|\\[\s\S])*`"),
+        ],
+        keywords: JS_KEYWORDS,
+        constants: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["typescript", "ts", "tsx"],
+        extensions: &["ts", "mts", "cts", "tsx"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+            (Class::String, r"`(?:[^`\\]|\\[\s\S])*`"),
+            (Class::Attribute, r"@[\p{L}_][\p{L}\p{N}_.]*"),
+        ],
+        keywords: &[
+            "abstract", "as", "async", "await", "break", "case", "catch", "class", "const",
+            "continue", "declare", "default", "delete", "do", "else", "enum", "export",
+            "extends", "finally", "for", "from", "function", "get", "if", "implements",
+            "import", "in", "instanceof", "interface", "is", "keyof", "let", "namespace", "new",
+            "of", "private", "protected", "public", "readonly", "return", "satisfies", "set",
+            "static", "super", "switch", "this", "throw", "try", "type", "typeof", "var",
+            "while", "yield",
+        ],
+        types: &[
+            "any", "bigint", "boolean", "never", "number", "object", "string", "symbol",
+            "unknown", "void",
+        ],
+        constants: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["c", "h"],
+        extensions: &["c", "h"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::Macro, r"#[ \t]*include[ \t]*<[^>\n]*>"),
+            (Class::Macro, r"#[ \t]*[\p{L}_]+"),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+        ],
+        keywords: C_KEYWORDS,
+        types: C_TYPES,
+        constants: &["true", "false", "NULL"],
+        ..BASE
+    },
+    Spec {
+        names: &["cpp", "c++", "cxx", "hpp"],
+        extensions: &["cpp", "cc", "cxx", "hpp", "hh", "hxx"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::Macro, r"#[ \t]*include[ \t]*<[^>\n]*>"),
+            (Class::Macro, r"#[ \t]*[\p{L}_]+"),
+            (Class::String, r#"R"\([\s\S]*?\)""#),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+        ],
+        keywords: &[
+            "auto", "break", "case", "catch", "class", "const", "constexpr", "consteval",
+            "continue", "decltype", "default", "delete", "do", "else", "enum", "explicit",
+            "export", "extern", "for", "friend", "goto", "if", "inline", "mutable", "namespace",
+            "new", "noexcept", "operator", "override", "private", "protected", "public",
+            "return", "sizeof", "static", "static_assert", "struct", "switch", "template",
+            "this", "throw", "try", "typedef", "typename", "union", "using", "virtual",
+            "volatile", "while",
+        ],
+        types: C_TYPES,
+        constants: &["true", "false", "nullptr", "NULL"],
+        ..BASE
+    },
+    Spec {
+        names: &["java"],
+        extensions: &["java"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, r#""""[\s\S]*?""""#),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+            (Class::Attribute, r"@[\p{L}_][\p{L}\p{N}_.]*"),
+        ],
+        keywords: &[
+            "abstract", "assert", "break", "case", "catch", "class", "const", "continue",
+            "default", "do", "else", "enum", "extends", "final", "finally", "for", "goto", "if",
+            "implements", "import", "instanceof", "interface", "native", "new", "package",
+            "private", "protected", "public", "record", "return", "static", "strictfp", "super",
+            "switch", "synchronized", "this", "throw", "throws", "transient", "try", "var",
+            "volatile", "while", "yield",
+        ],
+        types: &["boolean", "byte", "char", "double", "float", "int", "long", "short", "void"],
+        constants: &["true", "false", "null"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["go", "golang"],
+        extensions: &["go"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, r"`[^`]*`"),
+            (Class::String, SINGLE_QUOTED),
+        ],
+        keywords: &[
+            "break", "case", "chan", "const", "continue", "default", "defer", "else",
+            "fallthrough", "for", "func", "go", "goto", "if", "import", "interface", "map",
+            "package", "range", "return", "select", "struct", "switch", "type", "var",
+        ],
+        types: &[
+            "any", "bool", "byte", "complex64", "complex128", "error", "float32", "float64",
+            "int", "int8", "int16", "int32", "int64", "rune", "string", "uint", "uint8",
+            "uint16", "uint32", "uint64", "uintptr",
+        ],
+        constants: &["true", "false", "nil", "iota"],
+        ..BASE
+    },
+    Spec {
+        names: &["shell", "sh", "bash", "zsh", "console"],
+        extensions: &["sh", "bash", "zsh"],
+        rules: &[
+            (Class::Variable, r"\$\{[^}\n]*\}|\$[\p{L}\p{N}_@#?*!$-][\p{L}\p{N}_]*"),
+            (Class::Comment, r"#[^\n]*"),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, r"'[^']*'"),
+        ],
+        keywords: &[
+            "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function",
+            "if", "in", "local", "readonly", "return", "select", "then", "until", "while",
+        ],
+        constants: &["true", "false"],
+        number: None,
+        ..BASE
+    },
+    Spec {
+        names: &["json", "jsonc"],
+        extensions: &["json"],
+        rules: &[
+            C_COMMENTS[0],
+            C_COMMENTS[1],
+            (Class::String, DOUBLE_QUOTED),
+        ],
+        number: Some(r"-?\d+(?:\.\d+)?(?:[eE][+-]?\d+)?"),
+        constants: &["true", "false", "null"],
+        keys: Some((&[':'], Class::Property)),
+        ..BASE
+    },
+    Spec {
+        names: &["toml"],
+        extensions: &["toml"],
+        rules: &[
+            (Class::Comment, r"#[^\n]*"),
+            (Class::String, r#""""[\s\S]*?""""#),
+            (Class::String, r"'''[\s\S]*?'''"),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, r"'[^'\n]*'"),
+            (Class::Type, r"(?m)^[ \t]*\[\[?[^\]\n]*\]\]?"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_-]*",
+        constants: &["true", "false", "inf", "nan"],
+        keys: Some((&['=', '.'], Class::Property)),
+        ..BASE
+    },
+    Spec {
+        names: &["yaml", "yml"],
+        extensions: &["yaml", "yml"],
+        rules: &[
+            (Class::Comment, r"#[^\n]*"),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, r"'(?:[^'\n]|'')*'"),
+            (Class::Variable, r"[&*][\p{L}\p{N}_-]+"),
+            (Class::Tag, r"!![\p{L}]+"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_-]*",
+        constants: &["true", "false", "null", "yes", "no", "on", "off"],
+        keys: Some((&[':'], Class::Property)),
+        ..BASE
+    },
+    Spec {
+        names: &["html", "htm", "xhtml", "svg"],
+        extensions: &["html", "htm", "xhtml", "svg"],
+        rules: &[
+            (Class::Comment, r"<!--[\s\S]*?-->"),
+            (Class::Macro, r"<![^>]*>"),
+            (Class::Tag, r"</?[\p{L}][\p{L}\p{N}_:.-]*|/?>"),
+            (Class::String, r#""[^"]*""#),
+            (Class::Constant, r"&#?[\p{L}\p{N}]+;"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_:.-]*",
+        number: None,
+        keys: Some((&['='], Class::Attribute)),
+        ..BASE
+    },
+    Spec {
+        names: &["xml", "xsd", "xsl", "plist"],
+        extensions: &["xml", "xsd", "xsl", "plist"],
+        rules: &[
+            (Class::Comment, r"<!--[\s\S]*?-->"),
+            (Class::String, r"<!\[CDATA\[[\s\S]*?\]\]>"),
+            (Class::Macro, r"<[!?][^>]*>"),
+            (Class::Tag, r"</?[\p{L}_][\p{L}\p{N}_:.-]*|/?>"),
+            (Class::String, r#""[^"]*""#),
+            (Class::Constant, r"&#?[\p{L}\p{N}]+;"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_:.-]*",
+        number: None,
+        keys: Some((&['='], Class::Attribute)),
+        ..BASE
+    },
+    Spec {
+        names: &["css", "scss"],
+        extensions: &["css", "scss"],
+        rules: &[
+            C_COMMENTS[1],
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, SINGLE_QUOTED),
+            (Class::Keyword, r"@[\p{L}-]+|!important"),
+            (Class::Number, r"#[0-9a-fA-F]{3,8}\b"),
+            (Class::Variable, r"--[\p{L}\p{N}_-]+|\$[\p{L}_][\p{L}\p{N}_-]*"),
+            (Class::Tag, r"[.#][\p{L}_-][\p{L}\p{N}_-]*"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_-]*",
+        number: Some(r"\d*\.?\d+(?:%|[\p{L}]+)?"),
+        keys: Some((&[':'], Class::Property)),
+        ..BASE
+    },
+    Spec {
+        names: &["sql", "postgresql", "mysql", "sqlite"],
+        extensions: &["sql"],
+        rules: &[
+            (Class::Comment, r"--[^\n]*"),
+            C_COMMENTS[1],
+            (Class::String, r"'(?:[^']|'')*'"),
+            (Class::Variable, r#""(?:[^"]|"")*""#),
+        ],
+        keywords: &[
+            "add", "all", "alter", "and", "as", "asc", "begin", "between", "by", "case", "check",
+            "column", "commit", "constraint", "create", "cross", "database", "default",
+            "delete", "desc", "distinct", "drop", "else", "end", "exists", "foreign", "from",
+            "full", "group", "having", "if", "in", "index", "inner", "insert", "into", "is",
+            "join", "key", "left", "like", "limit", "not", "offset", "on", "or", "order",
+            "outer", "primary", "references", "returning", "right", "rollback", "select", "set",
+            "table", "then", "transaction", "union", "unique", "update", "values", "view",
+            "when", "where", "with",
+        ],
+        types: &[
+            "bigint", "blob", "boolean", "char", "date", "decimal", "float", "int", "integer",
+            "numeric", "real", "serial", "smallint", "text", "time", "timestamp", "varchar",
+        ],
+        constants: &["null", "true", "false"],
+        case_insensitive: true,
+        ..BASE
+    },
+    Spec {
+        names: &["haskell", "hs"],
+        extensions: &["hs", "lhs"],
+        rules: &[
+            (Class::Macro, r"\{-#[\s\S]*?#-\}"),
+            (Class::Comment, r"\{-[\s\S]*?-\}"),
+            (Class::Comment, r"--[^\n]*"),
+            (Class::String, DOUBLE_QUOTED),
+            (Class::String, r"'(?:[^'\\\n]|\\[^'\n]+)'"),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_']*",
+        keywords: &[
+            "case", "class", "data", "default", "deriving", "do", "else", "forall", "if",
+            "import", "in", "infix", "infixl", "infixr", "instance", "let", "module", "newtype",
+            "of", "qualified", "then", "type", "where",
+        ],
+        capitalized_types: true,
+        ..BASE
+    },
+    Spec {
+        names: &["subscript", "ss"],
+        extensions: &["ss"],
+        rules: &[
+            (Class::Comment, r"///[^\n]*"),
+            (Class::Keyword, r"\\[\p{L}\p{N}_:-]+"),
+            (Class::String, r#""[^"\n]*""#),
+        ],
+        identifier: r"[\p{L}_][\p{L}\p{N}_-]*",
+        number: None,
+        keys: Some((&['='], Class::Attribute)),
+        ..BASE
+    },
+    Spec {
+        names: &["text", "plaintext", "plain", "txt"],
+        extensions: &["txt"],
+        number: None,
+        ..BASE
+    },
+];
+
+#[derive(Debug)]
+pub struct Grammar {
+    spec: Spec,
+    /// The rules, identifiers and numbers, in that order, as one capture
+    /// group each.
+    regex: Regex,
+}
+
+lazy_static! {
+    static ref GRAMMARS: Vec<Grammar> = SPECS.iter().copied().map(Grammar::new).collect();
+}
+
+/// By name (e.g. `rust`) or file extension (e.g. `rs`), case insensitively.
+pub fn find_grammar(lang: &str) -> Option<&'static Grammar> {
+    let lang = lang.trim().to_lowercase();
+    GRAMMARS
+        .iter()
+        .find(|x| x.spec.names.contains(&lang.as_str()))
+        .or_else(|| GRAMMARS.iter().find(|x| x.spec.extensions.contains(&lang.as_str())))
+}
+
+pub fn grammar_for_path(path: &Path) -> Option<&'static Grammar> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    GRAMMARS.iter().find(|x| x.spec.extensions.contains(&ext.as_str()))
+}
+
+/// The canonical names of all bundled grammars.
+pub fn grammar_names() -> Vec<&'static str> {
+    GRAMMARS.iter().map(Grammar::name).collect()
+}
+
+impl Grammar {
+    fn new(spec: Spec) -> Self {
+        let groups = spec.rules
+            .iter()
+            .map(|(_, pattern)| *pattern)
+            .chain(Some(spec.identifier))
+            .chain(spec.number)
+            .map(|pattern| format!("({pattern})"))
+            .join("|");
+        let flags = if spec.case_insensitive {"(?i)"} else {""};
+        let regex = Regex::new(&format!("{flags}{groups}")).unwrap();
+        Grammar {spec, regex}
+    }
+    /// E.g. `rust`, for the `language-*` class.
+    pub fn name(&self) -> &'static str {
+        self.spec.names[0]
+    }
+    pub fn tokens<'a>(&self, code: &'a str) -> Vec<Token<'a>> {
+        let identifier_group = self.spec.rules.len() + 1;
+        let mut tokens = Vec::new();
+        let mut last = 0;
+        for captures in self.regex.captures_iter(code) {
+            let (group, found) = captures
+                .iter()
+                .enumerate()
+                .skip(1)
+                .find_map(|(ix, x)| Some((ix, x?)))
+                .unwrap();
+            if found.as_str().is_empty() {
+                continue
+            }
+            if last < found.start() {
+                tokens.push(Token {class: None, text: &code[last..found.start()]});
+            }
+            let rest = &code[found.end()..];
+            let class = match group {
+                _ if group == identifier_group => self.identifier_class(found.as_str(), rest),
+                _ if group > identifier_group => Some(Class::Number),
+                _ => match self.spec.rules[group - 1].0 {
+                    Class::String if self.is_key(rest) => self.spec.keys.map(|(_, x)| x),
+                    class => Some(class),
+                },
+            };
+            tokens.push(Token {class, text: found.as_str()});
+            last = found.end();
+        }
+        if last < code.len() {
+            tokens.push(Token {class: None, text: &code[last..]});
+        }
+        tokens
+    }
+    /// The tokens of each line, where tokens spanning multiple lines (e.g.
+    /// block comments) are split.
+    pub fn lines<'a>(&self, code: &'a str) -> Vec<Vec<Token<'a>>> {
+        split_lines(self.tokens(code))
+    }
+    fn is_key(&self, rest: &str) -> bool {
+        match self.spec.keys {
+            Some((suffixes, _)) => rest
+                .trim_start_matches([' ', '\t'])
+                .starts_with(suffixes),
+            None => false,
+        }
+    }
+    fn identifier_class(&self, word: &str, rest: &str) -> Option<Class> {
+        let lookup = match self.spec.case_insensitive {
+            true => word.to_lowercase(),
+            false => word.to_owned(),
+        };
+        let is_upper = |x: char| !x.is_alphabetic() || x.is_uppercase();
+        if self.spec.keywords.contains(&lookup.as_str()) {
+            Some(Class::Keyword)
+        } else if self.spec.types.contains(&lookup.as_str()) {
+            Some(Class::Type)
+        } else if self.spec.constants.contains(&lookup.as_str()) {
+            Some(Class::Constant)
+        } else if self.is_key(rest) {
+            self.spec.keys.map(|(_, x)| x)
+        } else if self.spec.capitalized_types && word.chars().next().map(char::is_uppercase).unwrap_or(false) {
+            match word.chars().count() > 1 && word.chars().all(is_upper) {
+                true => Some(Class::Constant),
+                false => Some(Class::Type),
+            }
+        } else if rest.starts_with('(') && !self.spec.keywords.is_empty() {
+            Some(Class::Function)
+        } else {
+            None
+        }
+    }
+}
+
+fn split_lines(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+    let mut lines = vec![Vec::new()];
+    for token in tokens {
+        for (ix, text) in token.text.split('\n').enumerate() {
+            if ix > 0 {
+                lines.push(Vec::new());
+            }
+            if !text.is_empty() {
+                lines.last_mut().unwrap().push(Token {class: token.class, text});
+            }
+        }
+    }
+    lines
+}
+
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// HTML
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Drops leading blank lines (as in `\pre{⏎…}`) and trailing whitespace.
+pub fn normalize_code(code: &str) -> String {
+    let code = code.replace("\r\n", "\n");
+    let mut code = code.as_str();
+    while let Some((first, rest)) = code.split_once('\n') {
+        if !first.trim().is_empty() {
+            break
+        }
+        code = rest;
+    }
+    code.trim_end().to_owned()
+}
+
+/// Parses e.g. `2,4-6` or `10-` (i.e. to the end). Lines are 1-based.
+pub fn parse_line_ranges(value: &str) -> Option<Vec<RangeInclusive<usize>>> {
+    value
+        .split(',')
+        .map(|part| {
+            let part = part.trim();
+            let number = |x: &str| x.trim().parse::<usize>().ok().filter(|x| *x > 0);
+            match part.split_once('-') {
+                Some((start, "")) => Some(number(start)?..=usize::MAX),
+                Some((start, end)) => Some(number(start)?..=number(end)?).filter(|x| !x.is_empty()),
+                None => number(part).map(|x| x..=x),
+            }
+        })
+        .collect()
+}
+
+fn tokens_to_html(tokens: Vec<Token>) -> Vec<Node> {
+    tokens
+        .into_iter()
+        .map(|token| match token.class {
+            Some(class) => TagBuilder::new("span")
+                .with_class(class.css_class())
+                .push_child(escape_html_text(token.text))
+                .finalize(),
+            None => Node::Text(escape_html_text(token.text)),
+        })
+        .collect()
+}
+
+/// The contents of a `<code>` element within a `<pre>`, i.e. one
+/// `<span class="line" data-line="n">` per line, where `n` counts from the
+/// given start, and such lines that are within `highlighted` (in terms of
+/// `n`) are marked with `data-highlighted`. Without a grammar, the code is
+/// shown as is.
+pub fn code_block_to_html(
+    grammar: Option<&Grammar>,
+    code: &str,
+    start: usize,
+    highlighted: &[RangeInclusive<usize>],
+) -> Vec<Node> {
+    let lines = match grammar {
+        Some(grammar) => grammar.lines(code),
+        None => split_lines(vec![Token {class: None, text: code}]),
+    };
+    let lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(ix, tokens)| {
+            let number = start + ix;
+            TagBuilder::new("span")
+                .with_class("line")
+                .with_attr("data-line", number.to_string())
+                .with_attr_if(highlighted.iter().any(|x| x.contains(&number)), "data-highlighted", "")
+                .with_children(tokens_to_html(tokens))
+                .finalize()
+        });
+    Itertools::intersperse(lines, Node::Text(String::from("\n"))).collect()
+}
+
+/// The contents of an inline `<code>` element.
+pub fn inline_code_to_html(grammar: Option<&Grammar>, code: &str) -> Vec<Node> {
+    match grammar {
+        Some(grammar) => tokens_to_html(grammar.tokens(code)),
+        None => tokens_to_html(vec![Token {class: None, text: code}]),
+    }
+}
@@ -0,0 +1,671 @@
+//! Converts HTML to Subscript source, e.g. to migrate notes written with
+//! other tools (see the `import-html` subcommand).
+//!
+//! The HTML is first cleaned up, i.e. math rendered by KaTeX or MathJax is
+//! mapped back to its TeX source, wrappers such as `<div>` are unwrapped and
+//! presentational attributes are dropped, and the result is then translated
+//! VIA `html::Node::html_to_subscript` and printed.
+use std::collections::{BTreeMap, HashSet};
+
+use crate::html;
+use crate::ss;
+
+/// Dropped along with their content.
+const DROPPED_TAGS: &[&str] = &[
+    "head", "title", "meta", "link", "style", "script", "noscript", "template", "base",
+];
+
+/// Dropped along with their content, and reported as unmapped, since their
+/// content isn’t text, e.g. `<svg>`.
+const OPAQUE_TAGS: &[&str] = &[
+    "svg", "canvas", "iframe", "embed", "form", "input", "button", "select", "textarea",
+    "nav", "math", "mjx-container",
+];
+
+/// Replaced with their content.
+const TRANSPARENT_TAGS: &[&str] = &["html", "body", "main", "div", "span"];
+
+/// Tags with a differently named Subscript equivalent.
+const RENAMED_TAGS: &[(&str, &str)] = &[("strike", "s"), ("tt", "code")];
+
+/// Custom tags that `html_to_subscript` already translates, along with all
+/// of their attributes, i.e. `tex`, `texblock` and `equation` become math
+/// commands, and `layout` becomes `\grid`.
+const LEGACY_TAGS: &[&str] = &["tex", "texblock", "equation", "layout", "note"];
+
+const INDENT: &str = "   ";
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// IMPORT
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+pub struct HtmlImport {
+    /// The generated Subscript source.
+    pub source: String,
+    /// Tags without a Subscript equivalent, and how often each occurs. Their
+    /// content is kept where possible, e.g. for `<font>`.
+    pub unmapped_tags: BTreeMap<String, usize>,
+}
+
+/// Converts the given HTML document or fragment to Subscript source.
+pub fn import_html_str(html_str: &str) -> HtmlImport {
+    let nodes = html::parser::parse_html_str_keep_whitespace(html_str).payload;
+    let mut importer = Importer::new();
+    let nodes = importer.nodes(nodes, false);
+    let nodes = escape_nodes(nodes);
+    let nodes = html::Node::Fragment(nodes)
+        .html_to_subscript()
+        .map(|x| flatten(vec![x]))
+        .unwrap_or_default();
+    let mut printer = Printer::default();
+    printer.blocks(&nodes, 0);
+    HtmlImport {
+        source: printer.output,
+        unmapped_tags: importer.unmapped_tags,
+    }
+}
+
+/// Syntax errors in the given (generated) source, this should be empty.
+pub fn check_syntax(
+    file_path: impl Into<std::path::PathBuf>,
+    source: &str,
+) -> Vec<ss::diagnostics::Diagnostic> {
+    let env = ss::ResourceEnv::default();
+    let mut scope = ss::SemanticScope::test_mode_empty();
+    scope.file_path = Some(file_path.into());
+    let node = ss::parser::parse_source(&env, &scope, source);
+    ss::validation::validate_syntax(&env, &scope, &node);
+    env.diagnostics()
+        .into_iter()
+        .filter(|x| x.is_error())
+        .collect()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// CLEANUP
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+struct Importer {
+    /// The tags of `crate::ss_v1_std::html_tags`, e.g. `p` for `\p`.
+    known_tags: HashSet<String>,
+    unmapped_tags: BTreeMap<String, usize>,
+}
+
+impl Importer {
+    fn new() -> Self {
+        let known_tags = crate::ss_v1_std::html_tags::all_supported_html_tags()
+            .into_iter()
+            .map(|x| x.identifier.unwrap_remove_slash().to_owned())
+            .collect();
+        Importer {known_tags, unmapped_tags: BTreeMap::default()}
+    }
+    fn report(&mut self, tag: &str) {
+        *self.unmapped_tags.entry(tag.to_owned()).or_default() += 1;
+    }
+    fn nodes(&mut self, nodes: Vec<html::Node>, in_pre: bool) -> Vec<html::Node> {
+        nodes
+            .into_iter()
+            .flat_map(|x| self.node(x, in_pre))
+            .collect()
+    }
+    fn node(&mut self, node: html::Node, in_pre: bool) -> Vec<html::Node> {
+        match node {
+            html::Node::Text(text) if in_pre => vec![html::Node::Text(text)],
+            html::Node::Text(text) => split_math_delimiters(&text),
+            html::Node::Fragment(xs) => self.nodes(xs, in_pre),
+            html::Node::Element(element) => self.element(element, in_pre),
+            html::Node::Drawing(_) => {
+                self.report("drawing");
+                Vec::new()
+            }
+        }
+    }
+    fn element(&mut self, element: html::Element, in_pre: bool) -> Vec<html::Node> {
+        if let Some(display) = math_display_mode(&element) {
+            let tex = match element.name.as_str() {
+                "script" => Some(text_content(&element.children)),
+                _ => tex_annotation(&element.children),
+            };
+            return match tex {
+                Some(tex) => vec![math_node(&tex, display)],
+                None => {
+                    self.report(&element.name);
+                    Vec::new()
+                }
+            }
+        }
+        // Rendered by MathJax, where the TeX is in a sibling
+        // `<script type="math/tex">`.
+        let is_mathjax_output = classes(&element)
+            .any(|x| x.starts_with("MathJax") || x.starts_with("MJX"));
+        if is_mathjax_output || DROPPED_TAGS.contains(&element.name.as_str()) {
+            return Vec::new()
+        }
+        if OPAQUE_TAGS.contains(&element.name.as_str()) {
+            self.report(&element.name);
+            return Vec::new()
+        }
+        if TRANSPARENT_TAGS.contains(&element.name.as_str()) {
+            let is_div = element.name == "div";
+            let children = self.nodes(element.children, in_pre);
+            // E.g. `<div>Some text</div>` used as a paragraph.
+            if is_div && !in_pre && is_inline_content(&children) {
+                return vec![new_element("p", Default::default(), children)]
+            }
+            return children
+        }
+        if LEGACY_TAGS.contains(&element.name.as_str()) {
+            return match element.name.as_str() {
+                "tex" | "texblock" | "equation" => vec![html::Node::Element(element)],
+                _ => {
+                    let children = self.nodes(element.children, in_pre);
+                    vec![new_element(&element.name, element.attributes, children)]
+                }
+            }
+        }
+        let name = RENAMED_TAGS
+            .iter()
+            .find(|(from, _)| *from == element.name)
+            .map(|(_, to)| to.to_string())
+            .unwrap_or(element.name);
+        if !self.known_tags.contains(&name) {
+            self.report(&name);
+            return self.nodes(element.children, in_pre)
+        }
+        let attributes = element.attributes
+            .into_iter()
+            .filter_map(|(key, value)| import_attribute(&name, &key, &value))
+            .collect();
+        let children = self.nodes(element.children, in_pre || name == "pre");
+        vec![new_element(&name, attributes, children)]
+    }
+}
+
+/// Only attributes that carry meaning are kept, as opposed to e.g. `style`
+/// or `class`.
+fn import_attribute(tag: &str, key: &str, value: &str) -> Option<(String, String)> {
+    let keep = || Some((key.to_owned(), value.to_owned()));
+    match (tag, key) {
+        ("a", "href" | "title") | ("abbr", "title") => keep(),
+        ("ol", "start") | ("td" | "th", "colspan" | "rowspan") => keep(),
+        ("img" | "audio" | "video" | "source" | "track", "src") => keep(),
+        ("img", "width") if value.chars().all(|x| x.is_ascii_digit()) => {
+            Some((key.to_owned(), format!("{value}px")))
+        }
+        ("img", "width") => keep(),
+        // E.g. `<code class="language-rust hljs">` is highlighted as
+        // `\code[lang="rust"]`, see `crate::html::highlight`.
+        ("pre" | "code", "class") => value
+            .split_whitespace()
+            .find_map(|x| x.strip_prefix("language-"))
+            .map(|lang| (String::from("lang"), lang.to_owned())),
+        _ => None,
+    }
+}
+
+fn new_element(
+    name: &str,
+    attributes: std::collections::HashMap<String, String>,
+    children: Vec<html::Node>,
+) -> html::Node {
+    html::Node::Element(html::Element {
+        name: name.to_owned(),
+        attributes,
+        children,
+    })
+}
+
+fn classes(element: &html::Element) -> impl Iterator<Item = &str> {
+    element.attributes
+        .get("class")
+        .map(|x| x.split_whitespace())
+        .into_iter()
+        .flatten()
+}
+
+fn is_block_tag(tag: &str) -> bool {
+    ss::BLOCK_COMMANDS.contains(&format!("\\{tag}").as_str()) || tag == "texblock"
+}
+
+fn is_inline_content(nodes: &[html::Node]) -> bool {
+    let has_text = nodes.iter().any(|x| match x {
+        html::Node::Text(text) => !text.trim().is_empty(),
+        html::Node::Element(_) => true,
+        _ => false,
+    });
+    let has_blocks = nodes.iter().any(|x| match x {
+        html::Node::Element(element) => is_block_tag(&element.name),
+        _ => false,
+    });
+    has_text && !has_blocks
+}
+
+fn text_content(nodes: &[html::Node]) -> String {
+    nodes
+        .iter()
+        .map(|x| match x {
+            html::Node::Text(text) => text.clone(),
+            html::Node::Element(element) => text_content(&element.children),
+            html::Node::Fragment(xs) => text_content(xs),
+            html::Node::Drawing(_) => String::new(),
+        })
+        .collect()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// MATH
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// `Some(true)` for display math, `Some(false)` for inline math, i.e.
+/// * KaTeX output (`.katex-display` and `.katex`), as well as ours (see
+///   `data-math-node`, which also wraps KaTeX output).
+/// * MathJax input (`<script type="math/tex; mode=display">`) and output
+///   (`<mjx-container display="true">`).
+/// * MathML, which has the TeX source if it was generated by either.
+fn math_display_mode(element: &html::Element) -> Option<bool> {
+    let attribute = |key: &str| element.attributes.get(key).map(String::as_str);
+    let has_class = |class: &str| classes(element).any(|x| x == class);
+    match element.name.as_str() {
+        "script" => attribute("type")
+            .filter(|x| x.starts_with("math/tex"))
+            .map(|x| x.contains("mode=display")),
+        "math" => Some(attribute("display") == Some("block")),
+        "mjx-container" => Some(attribute("display") == Some("true")),
+        _ if attribute("data-math-node").is_some() => Some(attribute("data-math-node") == Some("block")),
+        _ if has_class("katex-display") => Some(true),
+        _ if has_class("katex") => Some(false),
+        _ => None,
+    }
+}
+
+/// I.e. `<annotation encoding="application/x-tex">…</annotation>`.
+fn tex_annotation(nodes: &[html::Node]) -> Option<String> {
+    nodes.iter().find_map(|x| match x {
+        html::Node::Element(element) => {
+            let is_tex = element.name == "annotation" && element.attributes
+                .get("encoding")
+                .map(|x| x == "application/x-tex")
+                .unwrap_or(false);
+            if is_tex {
+                return Some(text_content(&element.children))
+            }
+            tex_annotation(&element.children)
+        }
+        html::Node::Fragment(xs) => tex_annotation(xs),
+        _ => None,
+    })
+}
+
+/// A `tex` or `texblock` element, see `html_to_subscript`.
+fn math_node(tex: &str, display: bool) -> html::Node {
+    let tex = escape_tex_braces(tex);
+    let (name, tex) = if display {
+        ("texblock", dedent(&tex))
+    } else {
+        ("tex", tex.split_whitespace().collect::<Vec<_>>().join(" "))
+    };
+    new_element(name, Default::default(), vec![html::Node::Text(tex)])
+}
+
+/// TeX’s `\{` and `\}` would be parsed as Subscript (e.g. `\{` is inline
+/// math), these are equivalent.
+fn escape_tex_braces(tex: &str) -> String {
+    let mut result = String::with_capacity(tex.len());
+    let mut chars = tex.chars().peekable();
+    while let Some(char) = chars.next() {
+        let replacement = match (char, chars.peek()) {
+            ('\\', Some('{')) => "\\lbrace",
+            ('\\', Some('}')) => "\\rbrace",
+            ('\\', Some('\\')) => "\\\\",
+            _ => {
+                result.push(char);
+                continue
+            }
+        };
+        chars.next();
+        result.push_str(replacement);
+        if chars.peek().map(|x| x.is_alphabetic()).unwrap_or(false) {
+            result.push(' ');
+        }
+    }
+    result
+}
+
+/// Math that wasn’t rendered yet, i.e. `\(…\)`, `\[…\]` and `$$…$$`.
+fn split_math_delimiters(text: &str) -> Vec<html::Node> {
+    const DELIMITERS: &[(&str, &str, bool)] = &[
+        ("\\(", "\\)", false),
+        ("\\[", "\\]", true),
+        ("$$", "$$", true),
+    ];
+    let mut nodes = Vec::new();
+    let mut rest = text;
+    loop {
+        let next = DELIMITERS
+            .iter()
+            .filter_map(|(open, close, display)| {
+                let start = rest.find(open)?;
+                let end = rest[start + open.len()..].find(close)? + start + open.len();
+                Some((start, end, open.len(), close.len(), *display))
+            })
+            .min_by_key(|(start, ..)| *start);
+        let (start, end, open_len, close_len, display) = match next {
+            Some(next) => next,
+            None => break,
+        };
+        if start > 0 {
+            nodes.push(html::Node::Text(rest[..start].to_owned()));
+        }
+        nodes.push(math_node(&rest[start + open_len..end], display));
+        rest = &rest[end + close_len..];
+    }
+    if !rest.is_empty() {
+        nodes.push(html::Node::Text(rest.to_owned()));
+    }
+    nodes
+}
+
+/// Removes the common indentation, and surrounding blank lines.
+fn dedent(code: &str) -> String {
+    let lines = code
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|x| x.is_empty())
+        .collect::<Vec<_>>();
+    let end = lines.iter().rposition(|x| !x.is_empty()).map_or(0, |x| x + 1);
+    let lines = &lines[..end];
+    let indent = lines
+        .iter()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.len() - x.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|x| x.get(indent..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TEXT ESCAPING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Makes sure that text parses as text, see `escape_text`, where adjacent
+/// text nodes (e.g. of unwrapped elements) are merged first.
+fn escape_nodes(nodes: Vec<html::Node>) -> Vec<html::Node> {
+    let mut merged: Vec<html::Node> = Vec::new();
+    for node in nodes {
+        match (merged.last_mut(), node) {
+            (Some(html::Node::Text(last)), html::Node::Text(text)) => last.push_str(&text),
+            (_, node) => merged.push(node),
+        }
+    }
+    let mut after_cmd = false;
+    merged
+        .into_iter()
+        .map(|node| match node {
+            html::Node::Text(text) => {
+                let text = escape_text(&text, after_cmd);
+                after_cmd = false;
+                html::Node::Text(text)
+            }
+            html::Node::Element(mut element) => {
+                after_cmd = true;
+                if !matches!(element.name.as_str(), "tex" | "texblock" | "equation") {
+                    element.children = escape_nodes(element.children);
+                }
+                html::Node::Element(element)
+            }
+            node => node,
+        })
+        .collect()
+}
+
+/// Brackets and quotation marks are only kept if they’re balanced, otherwise
+/// (and at the start of text following a command, where they’d be parsed as
+/// its attributes or arguments) these are replaced with their full-width
+/// equivalents, which is also how Subscript renders them. Likewise for `\`,
+/// and HTML that would otherwise be emitted as is, e.g. `<b`.
+fn escape_text(text: &str, after_cmd: bool) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut escaped = vec![false; chars.len()];
+    let mut pairs = vec![None; chars.len()];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut open_quote: Option<usize> = None;
+    for (ix, char) in chars.iter().enumerate() {
+        match char {
+            '(' | '[' | '{' => stack.push(ix),
+            ')' | ']' | '}' => {
+                let open = match char {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                match stack.last() {
+                    Some(last) if chars[*last] == open => {
+                        let last = stack.pop().unwrap();
+                        pairs[last] = Some(ix);
+                    }
+                    _ => escaped[ix] = true,
+                }
+            }
+            '"' => match open_quote.take() {
+                Some(_) => (),
+                None => open_quote = Some(ix),
+            },
+            _ => (),
+        }
+    }
+    for ix in stack.into_iter().chain(open_quote) {
+        escaped[ix] = true;
+    }
+    if after_cmd {
+        let first = chars.iter().position(|x| !x.is_whitespace());
+        if let Some((first, Some(close))) = first.map(|x| (x, pairs[x])) {
+            escaped[first] = true;
+            escaped[close] = true;
+        }
+    }
+    let mut result = String::with_capacity(text.len());
+    for (ix, char) in chars.iter().enumerate() {
+        let next = chars.get(ix + 1).copied().unwrap_or(' ');
+        match char {
+            '(' if escaped[ix] => result.push('（'),
+            ')' if escaped[ix] => result.push('）'),
+            '[' if escaped[ix] => result.push('［'),
+            ']' if escaped[ix] => result.push('］'),
+            '{' if escaped[ix] => result.push('｛'),
+            '}' if escaped[ix] => result.push('｝'),
+            '"' if escaped[ix] => result.push('＂'),
+            '\\' => result.push('＼'),
+            // I.e. a `///` comment.
+            '/' if result.ends_with("//") => result.push_str("\u{2060}/"),
+            '<' if next.is_ascii_alphabetic() || matches!(next, '/' | '!' | '?') => {
+                result.push_str("&lt;")
+            }
+            '&' if next.is_ascii_alphanumeric() || next == '#' => result.push_str("&amp;"),
+            _ => result.push(*char),
+        }
+    }
+    result
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// PRINTING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The children of fragments and of the curly brace arguments that
+/// `html_to_subscript` generates.
+fn flatten(nodes: Vec<ss::Node>) -> Vec<ss::Node> {
+    nodes
+        .into_iter()
+        .flat_map(|x| match x {
+            ss::Node::Fragment(xs) => flatten(xs),
+            ss::Node::Bracket(x) => flatten(x.value.children),
+            x => vec![x],
+        })
+        .collect()
+}
+
+fn is_block_node(node: &ss::Node) -> bool {
+    match node {
+        ss::Node::Cmd(cmd) => cmd.identifier.value.is_block_command(),
+        _ => false,
+    }
+}
+
+fn is_math_block(cmd: &ss::CmdCall) -> bool {
+    cmd.identifier.value == "\\math" || cmd.identifier.value == "\\equation"
+}
+
+/// The `[…]` of the given command.
+fn print_attributes(cmd: &ss::CmdCall) -> String {
+    let mut attributes = cmd.attributes
+        .clone()
+        .consume()
+        .into_iter()
+        .filter_map(|x| {
+            let key = x.key.as_stringified_attribute_value_str()?;
+            let value = x.value.as_stringified_attribute_value_str().unwrap_or_default();
+            if value.is_empty() {
+                return Some(key)
+            }
+            Some(format!("{key}=\"{}\"", value.replace('"', "'")))
+        })
+        .collect::<Vec<_>>();
+    if attributes.is_empty() {
+        return String::new()
+    }
+    attributes.sort();
+    format!("[{}]", attributes.join(", "))
+}
+
+#[derive(Default)]
+struct Printer {
+    output: String,
+}
+
+impl Printer {
+    fn line(&mut self, indent: usize, line: &str) {
+        self.output.push_str(&INDENT.repeat(indent));
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+    /// Block commands are printed on their own line, and text and inline
+    /// commands in between are joined to a single line.
+    fn blocks(&mut self, nodes: &[ss::Node], indent: usize) {
+        let mut inline: Vec<&ss::Node> = Vec::new();
+        for node in nodes {
+            if !is_block_node(node) {
+                inline.push(node);
+                continue
+            }
+            self.inline_line(&inline, indent);
+            inline.clear();
+            if let ss::Node::Cmd(cmd) = node {
+                self.block(cmd, indent);
+            }
+        }
+        self.inline_line(&inline, indent);
+    }
+    fn inline_line(&mut self, nodes: &[&ss::Node], indent: usize) {
+        let line = print_inline_nodes(nodes.iter().copied(), false);
+        let line = line.trim();
+        if !line.is_empty() {
+            self.line(indent, line);
+        }
+    }
+    fn block(&mut self, cmd: &ss::CmdCall, indent: usize) {
+        let head = format!("{}{}", cmd.identifier.value.as_str(), print_attributes(cmd));
+        let children = flatten(cmd.arguments.clone());
+        if cmd.identifier.value.is_heading_node() && !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        if is_math_block(cmd) {
+            self.line(indent, &format!("{head}{{"));
+            for line in dedent(&print_verbatim(&children)).lines() {
+                self.line(indent + 1, line);
+            }
+            self.line(indent, "}");
+            return
+        }
+        if cmd.identifier.value == "\\pre" {
+            let content = print_verbatim(&children);
+            self.line(indent, &format!("{head}{{{content}}}"));
+            return
+        }
+        if children.is_empty() {
+            self.line(indent, &head);
+            return
+        }
+        if !children.iter().any(is_block_node) {
+            let content = print_inline_nodes(&children, false);
+            self.line(indent, &format!("{head}{{{}}}", content.trim()));
+            return
+        }
+        self.line(indent, &format!("{head}{{"));
+        self.blocks(&children, indent + 1);
+        self.line(indent, "}");
+    }
+}
+
+fn print_verbatim(nodes: &[ss::Node]) -> String {
+    print_inline_nodes(nodes, true)
+}
+
+fn print_inline_nodes<'a>(nodes: impl IntoIterator<Item = &'a ss::Node>, verbatim: bool) -> String {
+    let mut result = String::new();
+    let mut after_ident = false;
+    for node in nodes {
+        let part = print_inline(node, verbatim);
+        // So that e.g. `\br` isn’t followed by letters.
+        let continues_ident = part
+            .chars()
+            .next()
+            .map(|x| x.is_alphanumeric() || matches!(x, ':' | '_' | '-' | '!'))
+            .unwrap_or(false);
+        if after_ident && continues_ident {
+            result.push(' ');
+        }
+        after_ident = matches!(node, ss::Node::Cmd(cmd) if cmd.arguments.is_empty());
+        result.push_str(&part);
+    }
+    result
+}
+
+/// With `verbatim`, whitespace is kept as is (e.g. in `\pre`), otherwise
+/// it’s collapsed as in HTML.
+fn print_inline(node: &ss::Node, verbatim: bool) -> String {
+    match node {
+        ss::Node::Text(text) if verbatim => text.value.clone(),
+        ss::Node::Text(text) => {
+            let mut result = String::with_capacity(text.value.len());
+            let mut in_whitespace = false;
+            for char in text.value.chars() {
+                if char.is_ascii_whitespace() {
+                    if !in_whitespace {
+                        result.push(' ');
+                    }
+                    in_whitespace = true;
+                    continue
+                }
+                in_whitespace = false;
+                result.push(char);
+            }
+            result
+        }
+        ss::Node::Cmd(cmd) => {
+            let children = flatten(cmd.arguments.clone());
+            let head = format!("{}{}", cmd.identifier.value.as_str(), print_attributes(cmd));
+            if cmd.arguments.is_empty() {
+                return head
+            }
+            format!("{head}{{{}}}", print_inline_nodes(&children, verbatim))
+        }
+        ss::Node::Fragment(xs) => print_inline_nodes(xs, verbatim),
+        ss::Node::Bracket(x) => print_inline_nodes(&x.value.children, verbatim),
+        _ => String::new(),
+    }
+}
@@ -6,7 +6,11 @@ pub mod parser;
 pub mod template;
 pub mod utils;
 pub mod experimental;
+pub mod import;
 pub mod toc;
+pub mod navigation;
+pub mod search;
+pub mod highlight;
 
 pub use ast::*;
 
@@ -0,0 +1,226 @@
+//! Site-wide navigation, i.e. a tree of every page mirroring the output
+//! directory, breadcrumbs, and previous/next links (in the pre-order of said
+//! tree). Provided to the template via the `<site-nav>`, `<breadcrumbs>` and
+//! `<page-nav>` slots, see `TemplateFile::pack_slots`.
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use super::Node;
+use super::TagBuilder;
+use super::utils::escape_html_text;
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// MODEL
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SiteNavigation {
+    pub root: NavNode,
+}
+
+/// A directory of the site, which may or may not have a page of its own.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NavNode {
+    /// The directory name (empty for the root).
+    pub name: String,
+    pub page: Option<NavPage>,
+    /// Sorted by directory name.
+    pub children: BTreeMap<String, NavNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NavPage {
+    pub href: String,
+    /// Escaped, see `Node::to_text_content`.
+    pub title: String,
+}
+
+/// A page of the site, see `SiteNavigation::new`.
+#[derive(Debug, Clone)]
+pub struct NavPageEntry {
+    /// The path of the output file relative to the output directory, e.g.
+    /// `chapter-1/index.html`.
+    pub rel_path: String,
+    pub href: String,
+    /// Pages without headings are named after their directory.
+    pub title: Option<String>,
+}
+
+impl SiteNavigation {
+    pub fn new(pages: impl IntoIterator<Item=NavPageEntry>, site_title: Option<&str>) -> Self {
+        let mut root = NavNode::default();
+        for entry in pages {
+            let segments = page_segments(&entry.rel_path);
+            let mut node = &mut root;
+            for segment in segments.iter() {
+                node = node.children
+                    .entry(segment.clone())
+                    .or_insert_with(|| NavNode {
+                        name: segment.clone(),
+                        ..Default::default()
+                    });
+            }
+            let title = entry.title
+                .or_else(|| segments.last().map(|x| escape_html_text(x)))
+                .or_else(|| site_title.map(escape_html_text))
+                .unwrap_or_else(|| String::from("Home"));
+            node.page = Some(NavPage {href: entry.href, title});
+        }
+        SiteNavigation {root}
+    }
+    /// Every page in pre-order, i.e. the reading order of the site.
+    pub fn pages(&self) -> Vec<&NavPage> {
+        fn go<'a>(node: &'a NavNode, pages: &mut Vec<&'a NavPage>) {
+            pages.extend(node.page.as_ref());
+            for child in node.children.values() {
+                go(child, pages);
+            }
+        }
+        let mut pages = Vec::new();
+        go(&self.root, &mut pages);
+        pages
+    }
+    /// The nodes from the root up to (and including) the given page.
+    fn path_to(&self, href: &str) -> Option<Vec<&NavNode>> {
+        fn go<'a>(node: &'a NavNode, href: &str, path: &mut Vec<&'a NavNode>) -> bool {
+            path.push(node);
+            if node.page.as_ref().map(|x| x.href == href).unwrap_or(false) {
+                return true
+            }
+            if node.children.values().any(|child| go(child, href, path)) {
+                return true
+            }
+            path.pop();
+            false
+        }
+        let mut path = Vec::new();
+        if go(&self.root, href, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+}
+
+/// E.g. `chapter-1/index.html` is `["chapter-1"]`, and `notes.html` is
+/// `["notes"]`.
+fn page_segments(rel_path: &str) -> Vec<String> {
+    let mut segments = rel_path
+        .split('/')
+        .filter(|x| !x.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+    if let Some(file_name) = segments.pop() {
+        let stem = file_name
+            .rsplit_once('.')
+            .map(|(stem, _)| stem.to_owned())
+            .unwrap_or(file_name);
+        if stem != "index" {
+            segments.push(stem);
+        }
+    }
+    segments
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// RENDERING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+impl SiteNavigation {
+    /// The template slots of the given page. Sites with a single page have no
+    /// navigation.
+    pub fn to_template_slots(&self, href: &str) -> Vec<(&'static str, Node)> {
+        let is_empty = self.pages().len() < 2;
+        let slot = |node: Node| if is_empty {Node::Fragment(Vec::new())} else {node};
+        vec![
+            ("site-nav", slot(self.to_site_tree(href))),
+            ("breadcrumbs", slot(self.to_breadcrumbs(href))),
+            ("page-nav", slot(self.to_page_nav(href))),
+        ]
+    }
+    /// The sidebar, with the given page marked as current.
+    pub fn to_site_tree(&self, href: &str) -> Node {
+        fn li(node: &NavNode, href: &str) -> Node {
+            let is_current = node.page.as_ref().map(|x| x.href == href).unwrap_or(false);
+            TagBuilder::new("li")
+                .with_attr_if(is_current, "data-current", "true")
+                .push_child(page_link(node, is_current))
+                .push_child_if(!node.children.is_empty(), || ul(node, href))
+                .finalize()
+        }
+        fn ul(node: &NavNode, href: &str) -> Node {
+            TagBuilder::new("ul")
+                .with_children(node.children.values().map(|child| li(child, href)))
+                .finalize()
+        }
+        // Sites without a root page start at the top level directories.
+        let tree = match self.root.page {
+            Some(_) => TagBuilder::new("ul")
+                .push_child(li(&self.root, href))
+                .finalize(),
+            None => ul(&self.root, href),
+        };
+        TagBuilder::new("nav")
+            .with_id("site-tree")
+            .push_child(tree)
+            .finalize()
+    }
+    /// From the root down to the given page, omitted for the root page.
+    pub fn to_breadcrumbs(&self, href: &str) -> Node {
+        let path = self.path_to(href).unwrap_or_default();
+        if path.len() < 2 {
+            return Node::Fragment(Vec::new())
+        }
+        let last = path.len() - 1;
+        let items = path
+            .iter()
+            .enumerate()
+            // Sites without a root page start at the top level directories.
+            .filter(|(_, node)| node.page.is_some() || !node.name.is_empty())
+            .map(|(ix, node)| {
+                TagBuilder::new("li")
+                    .push_child(page_link(node, ix == last))
+                    .finalize()
+            });
+        TagBuilder::new("nav")
+            .with_id("breadcrumbs")
+            .push_child(TagBuilder::new("ol").with_children(items))
+            .finalize()
+    }
+    /// Links to the previous and next page, in reading order.
+    pub fn to_page_nav(&self, href: &str) -> Node {
+        let pages = self.pages();
+        let ix = match pages.iter().position(|x| x.href == href) {
+            Some(ix) => ix,
+            None => return Node::Fragment(Vec::new()),
+        };
+        let prev = ix.checked_sub(1).and_then(|ix| pages.get(ix));
+        let next = pages.get(ix + 1);
+        let link = |page: &NavPage, rel: &str, label: &str| {
+            TagBuilder::new("a")
+                .with_attr("href", &page.href)
+                .with_attr("rel", rel)
+                .push_child(TagBuilder::new("span").with_attr_key("data-label").push_child(label))
+                .push_child(TagBuilder::new("span").with_attr_key("data-title").push_child(page.title.as_str()))
+                .finalize()
+        };
+        TagBuilder::new("nav")
+            .with_id("page-nav")
+            .push_child_option(prev, |page| link(page, "prev", "Previous"))
+            .push_child_option(next, |page| link(page, "next", "Next"))
+            .finalize()
+    }
+}
+
+/// Directories without a page aren’t links.
+fn page_link(node: &NavNode, is_current: bool) -> Node {
+    match node.page.as_ref() {
+        Some(page) => TagBuilder::new("a")
+            .with_attr("href", &page.href)
+            .with_attr_if(is_current, "aria-current", "page")
+            .push_child(page.title.as_str())
+            .finalize(),
+        None => TagBuilder::new("span")
+            .push_child(escape_html_text(&node.name))
+            .finalize(),
+    }
+}
@@ -15,11 +15,14 @@ use crate::html::ast::*;
 
 const REPORT_PARSER_ERRORS: bool = false;
 
-fn convert_impl(handle: &Handle) -> Vec<Node> {
+fn convert_impl(handle: &Handle, keep_whitespace: bool) -> Vec<Node> {
     let node = handle;
     match node.data {
         NodeData::Text { ref contents } => {
             let text = escape_default(&contents.borrow());
+            if keep_whitespace {
+                return vec![Node::Text(text)]
+            }
             let is_empty = text
                 .trim()
                 .split_ascii_whitespace()
@@ -53,7 +56,7 @@ fn convert_impl(handle: &Handle) -> Vec<Node> {
                 .borrow()
                 .iter()
                 .map(|x| {
-                    convert_impl(x)
+                    convert_impl(x, keep_whitespace)
                 })
                 .filter(|x| !x.is_empty())
                 .flatten()
@@ -72,7 +75,7 @@ fn convert_impl(handle: &Handle) -> Vec<Node> {
                 .borrow()
                 .iter()
                 .map(|x| {
-                    convert_impl(x)
+                    convert_impl(x, keep_whitespace)
                 })
                 .filter(|x| !x.is_empty())
                 .flatten()
@@ -81,8 +84,8 @@ fn convert_impl(handle: &Handle) -> Vec<Node> {
     }
 }
 
-fn convert_root(handle: &Handle, document_mode: bool) -> Vec<Node> {
-    let result = convert_impl(handle);
+fn convert_root(handle: &Handle, document_mode: bool, keep_whitespace: bool) -> Vec<Node> {
+    let result = convert_impl(handle, keep_whitespace);
     if !document_mode {
         match &result[..] {
             [Node::Element(element)] if (element.name == String::from("html")) => {
@@ -135,6 +138,16 @@ fn parser_config() -> html5ever::driver::ParseOpts {
 
 
 pub fn parse_html_str(html_str: &str) -> ParsedResult {
+    parse_html(html_str, false)
+}
+
+/// Like `parse_html_str`, but whitespace-only text is kept, since e.g. in
+/// `<b>x</b> <i>y</i>` it’s part of the content (see `crate::html::import`).
+pub fn parse_html_str_keep_whitespace(html_str: &str) -> ParsedResult {
+    parse_html(html_str, true)
+}
+
+fn parse_html(html_str: &str, keep_whitespace: bool) -> ParsedResult {
     use std::io::Cursor;
     use markup5ever::{QualName, Namespace, LocalName, Prefix};
     use markup5ever::interface::tree_builder::QuirksMode;
@@ -166,7 +179,7 @@ pub fn parse_html_str(html_str: &str) -> ParsedResult {
     };
     
     // TRAVERSE
-    let mut payload = convert_root(&dom.document, document_mode);
+    let mut payload = convert_root(&dom.document, document_mode, keep_whitespace);
 
     if !dom.errors.is_empty() {
         if REPORT_PARSER_ERRORS {
@@ -0,0 +1,136 @@
+//! Full-text search. Each page is split into sections at its headings (i.e.
+//! the anchors given by `crate::html::toc::toc_rewrites`), and the sections of
+//! every page are written to a script in the output directory, which the
+//! search box (see `to_search_box`) queries client-side. Math and drawings
+//! are excluded.
+use serde::{Serialize, Deserialize};
+use super::Node;
+use super::TagBuilder;
+use super::utils::unescape_html_text;
+
+/// Relative to the output directory.
+pub const SEARCH_INDEX_FILE_NAME: &str = "search-index.js";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// In reading order, see `crate::html::navigation`.
+    pub pages: Vec<SearchPage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchPage {
+    pub href: String,
+    pub title: String,
+    pub sections: Vec<SearchSection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchSection {
+    /// The anchor of the heading, empty for the text before the first heading.
+    pub id: String,
+    pub heading: String,
+    pub text: String,
+}
+
+impl SearchIndex {
+    /// Loaded via a script tag rather than fetched, so that it also works for
+    /// pages opened from the file system.
+    pub fn to_script(&self) -> String {
+        let json = serde_json::to_string(self).unwrap();
+        format!("window.SUBSCRIPT_SEARCH_INDEX = {json};\n")
+    }
+}
+
+/// The input and results list used by `ss-runtime.js`, along with the index
+/// at the given URL.
+pub fn to_search_box(index_href: &str) -> Node {
+    TagBuilder::new("div")
+        .with_id("site-search")
+        .push_child(
+            TagBuilder::new("input")
+                .with_id("site-search-input")
+                .with_attr("type", "search")
+                .with_attr("placeholder", "Search")
+                .with_attr("aria-label", "Search")
+        )
+        .push_child(TagBuilder::new("ol").with_id("site-search-results"))
+        .push_child(
+            TagBuilder::new("script")
+                .with_attr("src", index_href)
+                .with_attr_key("defer")
+        )
+        .finalize()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TEXT EXTRACTION
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Elements whose text would run into the next one’s otherwise.
+static BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "br", "dd", "div", "dl",
+    "dt", "figcaption", "figure", "footer", "header", "hr", "li", "ol",
+    "p", "pre", "section", "table", "td", "th", "tr", "ul",
+];
+
+/// Not text, or not worth indexing.
+static SKIPPED_ELEMENTS: &[&str] = &["script", "style", "svg", "img"];
+
+/// The sections of the given page (after `crate::html::toc::toc_rewrites`).
+pub fn page_sections(page: &Node) -> Vec<SearchSection> {
+    let mut sections = vec![SearchSection {
+        id: String::new(),
+        heading: String::new(),
+        text: String::new(),
+    }];
+    collect_sections(page, &mut sections);
+    sections
+        .into_iter()
+        .map(|section| SearchSection {
+            text: collapse_whitespace(&section.text),
+            ..section
+        })
+        .filter(|x| !x.heading.is_empty() || !x.text.is_empty())
+        .collect()
+}
+
+fn collect_sections(node: &Node, sections: &mut Vec<SearchSection>) {
+    match node {
+        Node::Element(element) if element.attributes.contains_key("data-math-node") => (),
+        Node::Element(element) if SKIPPED_ELEMENTS.contains(&element.name.as_str()) => (),
+        Node::Element(element) if element.is_heading_node() && element.attributes.contains_key("id") => {
+            let heading = unescape_html_text(&node.to_text_content());
+            sections.push(SearchSection {
+                id: element.attributes["id"].clone(),
+                heading: collapse_whitespace(&heading),
+                text: String::new(),
+            });
+        }
+        Node::Element(element) => {
+            let is_block = BLOCK_ELEMENTS.contains(&element.name.as_str());
+            let text = &mut sections.last_mut().unwrap().text;
+            if is_block {
+                text.push(' ');
+            }
+            for child in element.children.iter() {
+                collect_sections(child, sections);
+            }
+            if is_block {
+                sections.last_mut().unwrap().text.push(' ');
+            }
+        }
+        Node::Fragment(children) => {
+            for child in children.iter() {
+                collect_sections(child, sections);
+            }
+        }
+        Node::Text(value) => {
+            sections.last_mut().unwrap().text.push_str(&unescape_html_text(value));
+        }
+        Node::Drawing(_) => (),
+    }
+}
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
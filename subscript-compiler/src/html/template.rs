@@ -37,6 +37,19 @@ fn compile_scss_file<T: AsRef<Path>>(file_path: T) -> Result<String, grass::Erro
 // }
 
 
+/// SCSS links are relative to the template file.
+fn resolve_scss_href(template_src_path: &Path, href: &str) -> Option<PathBuf> {
+    if !href.ends_with(".scss") {
+        return None
+    }
+    let mut file_path = template_src_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    file_path.push(href);
+    file_path.canonicalize().ok()
+}
+
 #[derive(Debug, Clone)]
 enum HtmlCompileError {
     ScssFileNotFound {file_path: String},
@@ -79,17 +92,74 @@ impl TemplateFile {
             .include_subscript_defaults()
             .process_user_deps()
     }
-    pub fn pack_content(mut self, content: Node) -> Node {
+    pub fn pack_content(self, content: Node) -> Node {
+        self.pack_slots(vec![("content", content)])
+    }
+    /// Replaces each of the given elements (e.g. `<content>`) with its
+    /// contents, other slots are left as is.
+    pub fn pack_slots(self, slots: Vec<(&str, Node)>) -> Node {
+        let slots = HashMap::<&str, Node>::from_iter(slots);
         let f = |node: Node| -> Node {
             match node {
-                Node::Element(mut elem) if elem.has_name("content") => {
-                    content.clone()
+                Node::Element(elem) if slots.contains_key(elem.name.as_str()) => {
+                    slots[elem.name.as_str()].clone()
                 }
                 x => x,
             }
         };
         self.html.transform(Rc::new(f))
     }
+    /// Adds a script that reloads the page whenever the given server-sent
+    /// events endpoint sends a message, see `crate::compiler::serve`.
+    pub fn with_live_reload(mut self, endpoint: &str) -> Self {
+        let script = format!(
+            "new EventSource({endpoint:?}).onmessage = function() {{location.reload()}};"
+        );
+        let f = move |node: Node| -> Node {
+            match node {
+                Node::Element(mut elem) if elem.has_name("head") => {
+                    elem.children.push(Node::Element(Element{
+                        name: String::from("script"),
+                        attributes: HashMap::default(),
+                        children: vec![Node::Text(script.clone())],
+                    }));
+                    Node::Element(elem)
+                }
+                x => x,
+            }
+        };
+        self.html = self.html.transform(Rc::new(f));
+        self
+    }
+    /// The given template file and the SCSS files it links to, i.e. the files
+    /// that affect the compiled template.
+    pub fn source_files(src_path: impl AsRef<Path>) -> Vec<PathBuf> {
+        fn link_hrefs(node: &Node, hrefs: &mut Vec<String>) {
+            match node {
+                Node::Element(elem) => {
+                    if elem.has_name("link") {
+                        hrefs.extend(elem.get_attr_value("href").cloned());
+                    }
+                    for child in elem.children.iter() {
+                        link_hrefs(child, hrefs);
+                    }
+                }
+                Node::Fragment(xs) => {
+                    for child in xs.iter() {
+                        link_hrefs(child, hrefs);
+                    }
+                }
+                Node::Text(_) | Node::Drawing(_) => (),
+            }
+        }
+        let mut results = vec![src_path.as_ref().to_path_buf()];
+        if let Ok(source) = std::fs::read_to_string(src_path.as_ref()) {
+            let mut hrefs = Vec::new();
+            link_hrefs(&Node::parse_str(source), &mut hrefs);
+            results.extend(hrefs.iter().filter_map(|href| resolve_scss_href(src_path.as_ref(), href)));
+        }
+        results
+    }
     fn process_user_deps(mut self) -> Self {
         let template_src_path = self.template_src_path.clone();
         let f = |node: Node| -> Node {
@@ -97,24 +167,12 @@ impl TemplateFile {
                 Node::Element(elem) if elem.has_name("link") => {
                     let result = elem
                         .get_attr_value("href")
-                        .map(PathBuf::from)
-                        .filter(|path| {
-                            path.extension()
-                                .map(|ext| {
-                                    ext == "scss"
-                                })
-                                .unwrap_or(false)
-                        })
-                        .and_then(|x| {
+                        .filter(|href| href.ends_with(".scss"))
+                        .and_then(|href| {
                             template_src_path
-                                .clone()
-                                .map(|mut file_path| {
-                                    file_path.push(x);
-                                    file_path
-                                })
+                                .as_ref()
+                                .and_then(|x| resolve_scss_href(x, href))
                         })
-                        .and_then(|x| x.canonicalize().ok())
-                        .filter(|x| x.exists())
                         .ok_or_else(|| {
                             let file_path = elem.get_attr_value("href").unwrap().to_owned();
                             HtmlCompileError::ScssFileNotFound{file_path}
@@ -12,6 +12,7 @@ use super::Node;
 use super::TagBuilder;
 use super::NodeElementMutTraversal;
 use crate::ss::ast_data::HeadingType;
+use crate::ss::env::canonical_path;
 use crate::data::Store;
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
@@ -20,46 +21,64 @@ use crate::data::Store;
 
 
 pub struct TocRewritesTraversal<'a> {
-    route_prefix: Option<String>,
-    base_path: &'a PathBuf,
-    current_file: &'a PathBuf,
+    /// Where local headings link to, which (e.g. with `compile-file`) isn’t
+    /// necessarily the source path.
+    page_href: &'a str,
+    /// Keyed by the canonical source path, included pages are linked to,
+    /// headings of other included files are linked to locally.
+    page_hrefs: &'a HashMap<PathBuf, String>,
+    /// The IDs of the headings of each included page, as they are on that
+    /// page.
+    page_ids: &'a Store<HashMap<PathBuf, HashSet<String>>>,
     toc_entry: &'a Store<TocPageEntry>,
 }
 
+/// E.g. `overview`, `overview1`, `overview2`, …
+fn unique_id(title: &str, used_ids: &mut HashSet<String>) -> String {
+    let mut id = title.to_owned();
+    let mut ix = 1;
+    while used_ids.contains(&id) {
+        id = format!("{title}{ix}");
+        ix += 1;
+    }
+    used_ids.insert(id.clone());
+    id
+}
+
 impl<'a> NodeElementMutTraversal for TocRewritesTraversal<'a> {
     fn element(&self, element: &mut Element) {
         if element.is_heading_node() {
-            let mut dashed_title = element.children
-                .iter()
-                .map(Node::to_dashed_title)
-                .collect::<String>();
-            let mut original_title = dashed_title.clone();
-            let mut ix = 1;
-            while {self.toc_entry.map(|x| x.used_ids.contains(&dashed_title))} {
-                dashed_title = format!("{original_title}{ix}");
-                ix = ix + 1;
-            }
-            std::mem::drop(original_title);
-            self.toc_entry.map_mut(|x| x.used_ids.insert(dashed_title.clone()));
-            let mut is_local = false;
-            let path = element
-                .get_attr_value("source")
-                .map(PathBuf::from)
-                .unwrap_or_else(|| {
-                    is_local = true;
-                    self.current_file.clone()
-                });
-            let mut path = path
-                .strip_prefix(&self.base_path)
-                .map(Path::to_path_buf)
-                .unwrap_or(path);
-            path.set_extension("html");
-            let path = path.to_str().unwrap().to_string();
-            let _ = element.attributes.remove("source");
-            let href = (String::from("href"), match self.route_prefix.as_ref() {
-                None => format!("/{path}#{dashed_title}"),
-                Some(prefix) => format!("/{prefix}/{path}#{dashed_title}"),
+            // Labeled headings keep their id, see `crate::ss::labels`.
+            let label_id = element.get_attr_value("id").cloned();
+            let title = label_id.clone().unwrap_or_else(|| {
+                element.children
+                    .iter()
+                    .map(Node::to_dashed_title)
+                    .collect::<String>()
             });
+            let dashed_title = match label_id.as_ref() {
+                Some(_) => {
+                    self.toc_entry.map_mut(|x| x.used_ids.insert(title.clone()));
+                    title.clone()
+                }
+                None => self.toc_entry.map_mut(|x| unique_id(&title, &mut x.used_ids)),
+            };
+            let source = element.get_attr_value("source").map(canonical_path);
+            let is_local = source.is_none();
+            let included_page = source.and_then(|source| {
+                let page_href = self.page_hrefs.get(&source)?;
+                let id = match label_id.as_ref() {
+                    Some(_) => title.clone(),
+                    None => self.page_ids.map_mut(|x| unique_id(&title, x.entry(source).or_default())),
+                };
+                Some((page_href, id))
+            });
+            let _ = element.attributes.remove("source");
+            let href = match included_page {
+                Some((page_href, id)) => format!("{page_href}#{id}"),
+                None => format!("{}#{dashed_title}", self.page_href),
+            };
+            let href = (String::from("href"), href);
             let li_entry = Element {
                 name: String::from("li"),
                 attributes: HashMap::from_iter([
@@ -94,8 +113,13 @@ impl<'a> NodeElementMutTraversal for TocRewritesTraversal<'a> {
                     TocLiEntryType::External
                 }
             };
+            let is_h1 = element.unpack_heading_node() == Some(HeadingType::H1);
             self.toc_entry.map_mut(move |entry| {
-                entry.li_entries.push(TocLiEntry {node: li_entry, kind: source_type })
+                let li_entry = TocLiEntry {node: li_entry, kind: source_type};
+                if is_local && is_h1 && entry.page_title.is_none() {
+                    entry.page_title = Some(li_entry.clone());
+                }
+                entry.li_entries.push(li_entry)
             });
             element.attributes.insert(String::from("id"), dashed_title);
             element.children = vec![
@@ -109,18 +133,18 @@ impl<'a> NodeElementMutTraversal for TocRewritesTraversal<'a> {
     }
 }
 
+/// The given page hrefs are keyed by the canonical source path.
 pub fn toc_rewrites(
-    route_prefix: Option<String>,
-    base_path: &PathBuf,
-    current_file: &PathBuf,
+    page_href: &str,
+    page_hrefs: &HashMap<PathBuf, String>,
     toc_entry: &mut TocPageEntry,
     mut node: Node,
 ) -> Node {
     let toc_entry_ref = Store::new(toc_entry.clone());
     let visitor = TocRewritesTraversal {
-        route_prefix,
-        base_path,
-        current_file,
+        page_href,
+        page_hrefs,
+        page_ids: &Store::default(),
         toc_entry: &toc_entry_ref,
     };
     node.node_element_traversal(&visitor);
@@ -155,6 +179,13 @@ pub enum TocLiEntryType {
     External,
 }
 
+impl TocLiEntry {
+    /// The (escaped) text of the heading.
+    pub fn to_text_content(&self) -> String {
+        Node::Element(self.node.clone()).to_text_content().trim().to_owned()
+    }
+}
+
 impl TocLiEntryType {
     pub fn is_local(&self) -> bool {
         match self {
@@ -170,6 +201,18 @@ impl TocLiEntryType {
     }
 }
 
+impl TocPageEntry {
+    /// The first top level heading of the page itself (i.e. not one pulled in
+    /// via `\include`), or else its first heading.
+    pub fn title(&self) -> Option<String> {
+        self.page_title
+            .iter()
+            .chain(self.li_entries.iter().filter(|x| x.kind.is_local()))
+            .map(TocLiEntry::to_text_content)
+            .find(|x| !x.is_empty())
+    }
+}
+
 #[derive(Default)]
 pub struct TocPageRenderingOptions {
     pub is_index_page: bool,
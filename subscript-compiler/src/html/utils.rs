@@ -15,12 +15,19 @@ use crate::ss::ast_data::HeadingType;
 // MISCELLANEOUS
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 
-pub fn math_env_to_html_script(math: &crate::ss::env::MathEnv) -> Node {
-    Node::Element(Element{
-        name: String::from("script"),
-        attributes: Default::default(),
-        children: vec![
-            Node::Text(math.to_javascript())
-        ]
-    })
+/// Escapes text that will be emitted as is (see `Node::to_html_fragment_str`).
+pub fn escape_html_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The inverse of `escape_html_text`, e.g. for text that isn’t emitted as
+/// HTML (see `crate::html::search`).
+pub fn unescape_html_text(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
 }
@@ -10,11 +10,8 @@ use itertools::Itertools;
 
 
 fn translate<P: AsRef<Path>>(input: P) -> String {
-    let html_tree = std::fs::read_to_string(input.as_ref()).unwrap();
-    let html_tree = subscript_compiler::html::Node::parse_str(&html_tree);
-    let subscript = html_tree.html_to_subscript().unwrap();
-    let subscript_source = subscript.to_string();
-    subscript_source
+    let html = std::fs::read_to_string(input.as_ref()).unwrap();
+    subscript_compiler::html::import::import_html_str(&html).source
 }
 
 fn dev() {
@@ -72,7 +72,7 @@ impl ProjectSettings {
     }
     pub fn init_compiler(&self) -> crate::compiler::Compiler {
         // let file_glob = Glob::new("**/index.{ss}").unwrap();
-        let src_file_glob = "**/index.{ss}";
+        let src_file_glob = "**/index.{ss,md}";
         let compiler = crate::compiler::Compiler::new().add_files_via_glob(
             &self.manifest.project.locations.pages,
             src_file_glob,
@@ -118,7 +118,9 @@ pub mod manifest_format {
         pub title: Option<String>,
         #[serde(alias = "location")]
         #[serde(default)]
-        pub locations: ProjectLocations
+        pub locations: ProjectLocations,
+        #[serde(default)]
+        pub assets: ProjectAssets,
     }
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct ProjectLocations {
@@ -138,6 +140,15 @@ pub mod manifest_format {
             }
         }
     }
+    /// See `crate::compiler::assets::AssetOptions`.
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    #[serde(rename_all = "kebab-case")]
+    pub struct ProjectAssets {
+        #[serde(default)]
+        pub max_image_width: Option<u32>,
+        #[serde(default)]
+        pub jpeg_quality: Option<u8>,
+    }
     fn output_default_value() -> PathBuf {PathBuf::from("output")}
     fn pages_default_value() -> PathBuf {PathBuf::from("pages")}
     fn template_default_value() -> PathBuf {PathBuf::from("template")}
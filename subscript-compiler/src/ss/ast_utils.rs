@@ -17,14 +17,32 @@ use crate::compiler::low_level_api::CompilerError;
 use crate::ss::parser::IdentInitError;
 use crate::ss::ast_data::CmdCall;
 use crate::ss::{SemanticScope, ResourceEnv};
-use crate::ss::ast_data::{Node, Ann, Ident, Bracket, BracketType, Quotation};
+use crate::ss::ast_data::{Node, Ann, Ident, Bracket, BracketType, Quotation, CharRange};
 use crate::ss::{Attribute, Attributes};
 
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 // IDENT MISCELLANEOUS
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Commands that are written on their own line, e.g. by `html::import` and
+/// `ss::format`.
+///
+/// NOTE: The `layout_mode` of command declarations isn’t precise enough for
+/// this, e.g. `\p` and `\li` are declared as `LayoutKind::Both`.
+pub const BLOCK_COMMANDS: &[&str] = &[
+    "\\address", "\\article", "\\aside", "\\footer", "\\header", "\\section",
+    "\\h1", "\\h2", "\\h3", "\\h4", "\\h5", "\\h6", "\\blockquote", "\\dd", "\\dl", "\\dt",
+    "\\figcaption", "\\figure", "\\hr", "\\li", "\\ol", "\\ul", "\\p", "\\pre", "\\table",
+    "\\caption", "\\colgroup", "\\col", "\\thead", "\\tbody", "\\tfoot", "\\tr", "\\td", "\\th",
+    "\\details", "\\summary", "\\math", "\\equation", "\\grid", "\\note", "\\layout",
+    "\\include", "\\desmos", "\\geogebra", "\\plot", "\\dataTable", "\\chart",
+];
+
 impl Ident {
+    pub fn is_block_command(&self) -> bool {
+        BLOCK_COMMANDS.contains(&self.as_str())
+    }
     pub fn is_heading_node(&self) -> bool {
         let str_ref = self.as_str();
         str_ref == "\\h1" ||
@@ -45,7 +63,7 @@ impl Node {
         use crate::compiler::low_level_api::{parse_source, process_commands};
         let mut env = ResourceEnv::default();
         let scope = crate::ss::SemanticScope::default();
-        let nodes = parse_source(&scope, code.as_ref())?;
+        let nodes = parse_source(&env, &scope, code.as_ref())?;
         let nodes = process_commands(&mut env, &scope, nodes).defragment_node_tree();
         assert!(env.empty_images());
         Ok(nodes)
@@ -58,7 +76,7 @@ impl Node {
             scope.content_mode = crate::ss::ContentMode::Symbolic(crate::ss::SymbolicModeType::All);
             scope
         };
-        let nodes = parse_source(&scope, code.as_ref())?;
+        let nodes = parse_source(&env, &scope, code.as_ref())?;
         let nodes = process_commands(&mut env, &scope, nodes).defragment_node_tree();
         assert!(env.empty_images());
         Ok(nodes)
@@ -313,7 +331,20 @@ impl Node {
     /// - `Node::Symbol`
     /// - `Node::Quotation` (with quotes removed)
     /// - `Node::Fragment`
+    ///
+    /// Single quotes are only text, so e.g. `'dashed'` is unquoted here.
     pub fn as_stringified_attribute_value_str(self) -> Option<String> {
+        let is_quoted = matches!(self, Node::Quotation(_));
+        let value = self.stringify_attribute_value()?;
+        if is_quoted {
+            return Some(value)
+        }
+        match value.strip_prefix('\'').and_then(|x| x.strip_suffix('\'')) {
+            Some(unquoted) => Some(unquoted.to_owned()),
+            None => Some(value),
+        }
+    }
+    fn stringify_attribute_value(self) -> Option<String> {
         match self {
             Node::Ident(Ann{value, ..}) => Some(value.to_tex_ident().to_string()),
             Node::Text(Ann{value, ..}) => Some(value),
@@ -321,14 +352,14 @@ impl Node {
             Node::Quotation(Ann{value: Quotation{children, ..}, ..}) => {
                 let mut contents: Vec<String> = Vec::new();
                 for child in children {
-                    contents.push(child.as_stringified_attribute_value_str()?);
+                    contents.push(child.stringify_attribute_value()?);
                 }
                 Some(contents.join(""))
             }
             Node::Fragment(children) => {
                 let mut contents: Vec<String> = Vec::new();
                 for child in children {
-                    contents.push(child.as_stringified_attribute_value_str()?);
+                    contents.push(child.stringify_attribute_value()?);
                 }
                 Some(contents.join(""))
             }
@@ -796,6 +827,44 @@ impl Node {
             .into_iter()
             .find(|x| !x.is_whitespace())
     }
+    /// The source range spanned by this node, if known. For e.g. command
+    /// calls this is derived from the identifier and the trailing arguments,
+    /// since such are not annotated directly.
+    pub fn range(&self) -> Option<CharRange> {
+        fn span(nodes: &[Node]) -> Option<CharRange> {
+            let start = nodes.iter().find_map(Node::range)?;
+            let end = nodes.iter().rev().find_map(Node::range)?;
+            Some(CharRange::new(start.start, end.end))
+        }
+        match self {
+            Node::Cmd(cmd) => {
+                let start = cmd.identifier.range?;
+                let end = span(&cmd.arguments)
+                    .map(|x| x.end)
+                    .filter(|end| end.byte_index >= start.end.byte_index)
+                    .unwrap_or(start.end);
+                Some(CharRange::new(start.start, end))
+            }
+            Node::Bracket(Ann{range: Some(range), ..}) => Some(*range),
+            Node::Bracket(Ann{value, ..}) => {
+                let start = value.open.as_ref().and_then(Ann::start);
+                let end = value.close.as_ref().and_then(Ann::end);
+                CharRange::join(start, end).or_else(|| span(&value.children))
+            }
+            Node::Quotation(Ann{range: Some(range), ..}) => Some(*range),
+            Node::Quotation(Ann{value, ..}) => {
+                let start = value.open.as_ref().and_then(Ann::start);
+                let end = value.close.as_ref().and_then(Ann::end);
+                CharRange::join(start, end).or_else(|| span(&value.children))
+            }
+            Node::Fragment(xs) => span(xs),
+            Node::Ident(x) => x.range,
+            Node::Text(x) => x.range,
+            Node::Symbol(x) => x.range,
+            Node::InvalidToken(x) => x.range,
+            Node::Drawing(_) => None,
+        }
+    }
 }
 
 
@@ -0,0 +1,419 @@
+//! Citations. `\cite[key]` refers to an entry of the BibTeX file given by
+//! the page’s `\bibliography`, which lists the entries cited on the page, e.g.
+//! ```text
+//! Sorting is hard \cite[knuth98].
+//! \h2{References}
+//! \bibliography[src="refs.bib", style="author-year"]
+//! ```
+//! With `\bibliography[src="refs.bib", site]` it lists every entry of the file
+//! instead, and pages without a bibliography of their own cite (i.e. link to)
+//! such. The entries of site-wide bibliographies are labels, so this works
+//! just like `\ref`, see `crate::ss::labels`.
+pub mod bibtex;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use itertools::Itertools;
+use crate::ss::{Ann, CmdCall, Node, ResourceEnv, SemanticScope};
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::env::canonical_path;
+use crate::ss::labels::{first_attribute_key, include_path, Label, LabelIndex, LabelKind};
+use bibtex::Entry;
+
+pub const CITE_IDENT: &str = "\\cite";
+pub const BIBLIOGRAPHY_IDENT: &str = "\\bibliography";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CitationStyle {
+    /// E.g. `[1]`, numbered in order of citation (or by author for site-wide
+    /// bibliographies).
+    #[default]
+    Numeric,
+    /// E.g. `(Knuth 1998)`.
+    AuthorYear,
+}
+
+/// The bibliography of a page.
+#[derive(Debug, Clone, Default)]
+pub struct Bibliography {
+    pub style: CitationStyle,
+    /// In the order they’re listed.
+    pub entries: Vec<CitedEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CitedEntry {
+    pub entry: Entry,
+    /// E.g. `1` or `Knuth 1998`.
+    pub label: String,
+    /// What citations show, e.g. `[1]` or `(Knuth 1998)`.
+    pub text: String,
+}
+
+/// A resolved `\cite`.
+#[derive(Debug, Clone)]
+pub struct Citation {
+    pub key: String,
+    pub text: String,
+    pub href: String,
+    /// Whether the entry is listed on the current page, e.g. LaTeX documents
+    /// can only link to such.
+    pub is_local: bool,
+}
+
+/// The HTML id (and LaTeX label) of the listed entry.
+pub fn anchor_id(key: &str) -> String {
+    format!("cite-{key}")
+}
+
+impl Bibliography {
+    pub fn get(&self, key: &str) -> Option<&CitedEntry> {
+        self.entries.iter().find(|x| x.entry.key == key)
+    }
+    /// The entry cited by the given `\cite` command, from the page’s own
+    /// bibliography, or else from a site-wide one.
+    pub fn resolve(
+        &self,
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        labels: &LabelIndex,
+        cmd: &CmdCall,
+    ) -> Option<Citation> {
+        let report = |diagnostic: Diagnostic| {
+            env.report(diagnostic.with_range(cmd.identifier.range).with_scope(scope));
+        };
+        let key = match first_attribute_key(cmd) {
+            Some(key) => key,
+            None => {
+                report(Diagnostic::error(format!(
+                    "`{CITE_IDENT}` expects a BibTeX key, e.g. `{CITE_IDENT}[knuth98]`"
+                )));
+                return None
+            }
+        };
+        if let Some(entry) = self.get(&key) {
+            return Some(Citation {
+                href: format!("#{}", anchor_id(&key)),
+                text: entry.text.clone(),
+                key,
+                is_local: true,
+            })
+        }
+        match labels.lookup(env, scope, &anchor_id(&key)) {
+            Some(label) if label.kind == LabelKind::Citation => Some(Citation {
+                href: label.href.clone(),
+                text: label.text.clone(),
+                is_local: labels.is_local(label),
+                key,
+            }),
+            _ => {
+                report(Diagnostic::error(format!(
+                    "undefined citation `{key}`, is it missing from the page’s (or a site-wide) `{BIBLIOGRAPHY_IDENT}`?"
+                )));
+                None
+            }
+        }
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// CITATION PASS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Loads the bibliography of the given (processed) page, i.e. the entries
+/// cited on the page in the given `\bibliography`’s file. Site-wide
+/// bibliographies are also returned as labels.
+pub fn resolve_citations(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    page_href: &str,
+    node: &Node,
+) -> (Bibliography, Vec<Label>) {
+    let mut pass = CitationPass::default();
+    pass.node(scope, node);
+    let (bib_scope, cmd) = match pass.bibliographies.first() {
+        Some(first) => first,
+        None => return Default::default(),
+    };
+    for (scope, cmd) in pass.bibliographies.iter().skip(1) {
+        env.report(
+            Diagnostic::error(format!("a page can only have one `{BIBLIOGRAPHY_IDENT}`"))
+                .with_range(cmd.identifier.range)
+                .with_scope(scope)
+        );
+    }
+    let report = |diagnostic: Diagnostic| {
+        env.report(diagnostic.with_range(cmd.identifier.range).with_scope(bib_scope));
+    };
+    let style = match cmd.attributes.get_str_value("style").as_deref() {
+        None | Some("numeric") => CitationStyle::Numeric,
+        Some("author-year") => CitationStyle::AuthorYear,
+        Some(x) => {
+            report(Diagnostic::warning(format!(
+                "invalid `style` value `{x}`, expected `numeric` or `author-year`"
+            )));
+            CitationStyle::Numeric
+        }
+    };
+    let is_site = cmd.attributes.has_truthy_option("site");
+    let entries = match load_entries(env, bib_scope, cmd) {
+        Ok(entries) => entries,
+        Err(diagnostic) => {
+            report(diagnostic);
+            return Default::default()
+        }
+    };
+    let entries = if is_site {
+        entries.into_values().sorted_by_key(sort_key).collect_vec()
+    } else {
+        let cited = pass.cited
+            .iter()
+            .filter_map(|key| entries.get(key).cloned())
+            .collect_vec();
+        match style {
+            CitationStyle::Numeric => cited,
+            CitationStyle::AuthorYear => cited.into_iter().sorted_by_key(sort_key).collect(),
+        }
+    };
+    let bibliography = Bibliography {
+        entries: cited_entries(style, entries),
+        style,
+    };
+    let labels = if is_site {
+        let page = scope.file_path.as_ref().map(canonical_path).unwrap_or_default();
+        bibliography.entries
+            .iter()
+            .map(|x| Label {
+                id: anchor_id(&x.entry.key),
+                kind: LabelKind::Citation,
+                text: x.text.clone(),
+                page: page.clone(),
+                href: format!("{page_href}#{}", anchor_id(&x.entry.key)),
+                file_path: bib_scope.file_path.clone(),
+                range: cmd.identifier.range,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    (bibliography, labels)
+}
+
+#[derive(Default)]
+struct CitationPass {
+    /// Keys in order of their first citation.
+    cited: Vec<String>,
+    bibliographies: Vec<(SemanticScope, CmdCall)>,
+}
+
+impl CitationPass {
+    fn node(&mut self, scope: &SemanticScope, node: &Node) {
+        match node {
+            Node::Cmd(cmd) => self.cmd(scope, cmd),
+            Node::Bracket(Ann{value, ..}) => self.nodes(scope, &value.children),
+            Node::Quotation(Ann{value, ..}) => self.nodes(scope, &value.children),
+            Node::Fragment(xs) => self.nodes(scope, xs),
+            _ => (),
+        }
+    }
+    fn nodes(&mut self, scope: &SemanticScope, nodes: &[Node]) {
+        for node in nodes {
+            self.node(scope, node);
+        }
+    }
+    fn cmd(&mut self, scope: &SemanticScope, cmd: &CmdCall) {
+        // Otherwise it’s the HTML tag.
+        if cmd.has_name(CITE_IDENT) && cmd.arguments.is_empty() {
+            if let Some(key) = first_attribute_key(cmd).filter(|x| !self.cited.contains(x)) {
+                self.cited.push(key);
+            }
+        }
+        if cmd.has_name(BIBLIOGRAPHY_IDENT) {
+            self.bibliographies.push((scope.clone(), cmd.clone()));
+        }
+        let sub_scope = match include_path(scope, cmd) {
+            Some(src_path) if cmd.has_name("\\include") && crate::compiler::low_level_api::is_source_file(&src_path) => {
+                scope.new_file(src_path)
+            }
+            _ => scope.clone(),
+        };
+        self.nodes(&sub_scope, &cmd.arguments);
+    }
+}
+
+/// Keyed by the BibTeX key, duplicates are reported (the first one wins).
+fn load_entries(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    cmd: &CmdCall,
+) -> Result<HashMap<String, Entry>, Diagnostic> {
+    let src = cmd.attributes
+        .get_str_value("src")
+        .ok_or_else(|| Diagnostic::error(format!("`{BIBLIOGRAPHY_IDENT}` is missing the `src` attribute")))?;
+    let src_path = scope.normalize_file_path(&src).unwrap_or_else(|()| PathBuf::from(&src));
+    env.add_dependency(scope, &src_path);
+    let source = std::fs::read_to_string(&src_path).map_err(|_| {
+        Diagnostic::error(format!("bibliography file not found: {}", src_path.display()))
+    })?;
+    let entries = bibtex::parse(&source).map_err(|error| {
+        Diagnostic::error(format!("invalid BibTeX in {}, {error}", src_path.display()))
+    })?;
+    let mut map = HashMap::<String, Entry>::default();
+    for entry in entries {
+        if map.contains_key(&entry.key) {
+            env.report(
+                Diagnostic::warning(format!("duplicate BibTeX entry `{}` in {}", entry.key, src_path.display()))
+                    .with_range(cmd.identifier.range)
+                    .with_scope(scope)
+            );
+            continue
+        }
+        map.insert(entry.key.clone(), entry);
+    }
+    Ok(map)
+}
+
+/// Author-year labels that would otherwise be ambiguous get a suffix, e.g.
+/// `Knuth 1998a` and `Knuth 1998b`.
+fn cited_entries(style: CitationStyle, entries: Vec<Entry>) -> Vec<CitedEntry> {
+    let labels = match style {
+        CitationStyle::Numeric => (1..=entries.len()).map(|x| x.to_string()).collect_vec(),
+        CitationStyle::AuthorYear => {
+            let labels = entries.iter().map(author_year).collect_vec();
+            let counts = labels.iter().counts();
+            let mut seen = HashMap::<&String, u8>::default();
+            labels
+                .iter()
+                .map(|label| {
+                    if counts[label] < 2 {
+                        return label.clone()
+                    }
+                    let index = seen.entry(label).or_default();
+                    *index += 1;
+                    format!("{label}{}", (b'a' + (*index - 1) % 26) as char)
+                })
+                .collect_vec()
+        }
+    };
+    entries
+        .into_iter()
+        .zip(labels)
+        .map(|(entry, label)| CitedEntry {
+            text: match style {
+                CitationStyle::Numeric => format!("[{label}]"),
+                CitationStyle::AuthorYear => format!("({label})"),
+            },
+            entry,
+            label,
+        })
+        .collect()
+}
+
+/// E.g. `Knuth 1998`, `Knuth and Plass 1981` or `Knuth et al. 1989`.
+fn author_year(entry: &Entry) -> String {
+    let names = entry.names();
+    let last = |index: usize| bibtex::to_plain_text(&names[index].last);
+    let authors = match names.len() {
+        0 => entry.key.clone(),
+        1 => last(0),
+        2 if !names[1].is_others() => format!("{} and {}", last(0), last(1)),
+        _ => format!("{} et al.", last(0)),
+    };
+    let year = entry.get("year").map(bibtex::to_plain_text).unwrap_or_else(|| String::from("n.d."));
+    format!("{authors} {year}")
+}
+
+fn sort_key(entry: &Entry) -> (String, String, String, String) {
+    let names = entry.names()
+        .iter()
+        .map(|x| bibtex::to_plain_text(&x.last).to_lowercase())
+        .join(" ");
+    let field = |name: &str| entry.get(name).map(bibtex::to_plain_text).unwrap_or_default().to_lowercase();
+    (names, field("year"), field("title"), entry.key.clone())
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// FORMATTING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// A listed entry, for the code-gen of each target. Text is LaTeX (see
+/// `bibtex::to_plain_text`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryPart {
+    Text(String),
+    Emphasis(String),
+    Link {url: String, text: String},
+}
+
+impl CitedEntry {
+    /// E.g. “Donald E. Knuth (1998). *The Art of Computer Programming*.
+    /// Addison-Wesley.”
+    pub fn format(&self) -> Vec<EntryPart> {
+        let entry = &self.entry;
+        let mut parts = Vec::new();
+        let names = entry.names();
+        let mut authors = names
+            .iter()
+            .filter(|x| !x.is_others())
+            .map(bibtex::Name::full_name)
+            .collect_vec();
+        let mut author_list = match authors.len() {
+            _ if names.iter().any(bibtex::Name::is_others) => format!("{} et al.", authors.join(", ")),
+            0 => String::new(),
+            1 => authors.remove(0),
+            _ => {
+                let last = authors.pop().unwrap();
+                format!("{} and {last}", authors.join(", "))
+            }
+        };
+        if entry.get("author").is_none() && entry.get("editor").is_some() {
+            author_list.push_str(if names.len() > 1 {" (Eds.)"} else {" (Ed.)"});
+        }
+        let year = entry.get("year").map(|x| format!(" ({x})")).unwrap_or_default();
+        if !author_list.is_empty() || !year.is_empty() {
+            parts.push(EntryPart::Text(format!("{author_list}{year}. ")));
+        }
+        let is_book_like = matches!(
+            entry.entry_type.as_str(),
+            "book" | "booklet" | "manual" | "mastersthesis" | "phdthesis" | "proceedings" | "techreport"
+        );
+        if let Some(title) = entry.get("title") {
+            if is_book_like {
+                parts.push(EntryPart::Emphasis(title.to_owned()));
+            } else {
+                parts.push(EntryPart::Text(title.to_owned()));
+            }
+            parts.push(EntryPart::Text(sentence_end(title).to_owned()));
+        }
+        let container = entry.get("journal").map(|x| (None, x)).or_else(|| {
+            entry.get("booktitle").map(|x| (Some("In "), x))
+        });
+        if let Some((prefix, container)) = container {
+            parts.push(EntryPart::Text(prefix.unwrap_or_default().to_owned()));
+            parts.push(EntryPart::Emphasis(container.to_owned()));
+            let volume = entry.get("volume").map(|x| format!(", {x}")).unwrap_or_default();
+            let number = entry.get("number").map(|x| format!("({x})")).unwrap_or_default();
+            let pages = entry.get("pages").map(|x| format!(", {x}")).unwrap_or_default();
+            parts.push(EntryPart::Text(format!("{volume}{number}{pages}. ")));
+        }
+        let publisher = ["publisher", "school", "institution", "organization", "howpublished"]
+            .iter()
+            .find_map(|x| entry.get(x));
+        if let Some(publisher) = publisher {
+            parts.push(EntryPart::Text(format!("{publisher}{}", sentence_end(publisher))));
+        }
+        if let Some(doi) = entry.get("doi") {
+            parts.push(EntryPart::Link {url: format!("https://doi.org/{doi}"), text: format!("doi:{doi}")});
+        } else if let Some(url) = entry.get("url") {
+            parts.push(EntryPart::Link {url: url.to_owned(), text: url.to_owned()});
+        }
+        parts
+    }
+}
+
+fn sentence_end(value: &str) -> &'static str {
+    let ends_with_punctuation = bibtex::to_plain_text(value)
+        .trim_end()
+        .ends_with(['.', '?', '!']);
+    if ends_with_punctuation {" "} else {". "}
+}
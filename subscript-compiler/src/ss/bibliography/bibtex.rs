@@ -0,0 +1,597 @@
+//! A (lenient) BibTeX parser. Field values are kept as LaTeX, minus the
+//! outer delimiters, see `to_plain_text` for everything else.
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Lowercase, e.g. `article`.
+    pub entry_type: String,
+    pub key: String,
+    /// Keyed by the lowercase field name, with `@string` macros and `#`
+    /// concatenations resolved.
+    pub fields: HashMap<String, String>,
+}
+
+impl Entry {
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.fields
+            .get(field)
+            .map(String::as_str)
+            .filter(|x| !x.trim().is_empty())
+    }
+    /// The authors, or the editors if there are none.
+    pub fn names(&self) -> Vec<Name> {
+        self.get("author")
+            .or_else(|| self.get("editor"))
+            .map(names)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// One-based.
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Everything outside of entries is a comment, as is `@comment`. `@preamble`
+/// is ignored.
+pub fn parse(source: &str) -> Result<Vec<Entry>, ParseError> {
+    let parser = Parser {
+        chars: source.chars().collect(),
+        position: 0,
+        strings: HashMap::default(),
+    };
+    parser.entries()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// PARSER
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    /// `@string` macros.
+    strings: HashMap<String, String>,
+}
+
+impl Parser {
+    fn entries(mut self) -> Result<Vec<Entry>, ParseError> {
+        let mut entries = Vec::new();
+        loop {
+            while self.peek().map(|x| x != '@').unwrap_or(false) {
+                self.bump();
+            }
+            if self.bump().is_none() {
+                return Ok(entries)
+            }
+            self.skip_whitespace();
+            let entry_type = self.identifier().to_lowercase();
+            self.skip_whitespace();
+            let close = match self.bump() {
+                Some('{') => '}',
+                Some('(') => ')',
+                _ => return Err(self.error(format!("expected `{{` after `@{entry_type}`"))),
+            };
+            match entry_type.as_str() {
+                "comment" => {
+                    self.delimited(close)?;
+                }
+                "preamble" => {
+                    self.value()?;
+                    self.skip_whitespace();
+                    self.expect(close)?;
+                }
+                "string" => {
+                    let (name, value) = self.field()?;
+                    self.strings.insert(name, value);
+                    self.skip_whitespace();
+                    self.expect(close)?;
+                }
+                _ => entries.push(self.entry(entry_type, close)?),
+            }
+        }
+    }
+    fn entry(&mut self, entry_type: String, close: char) -> Result<Entry, ParseError> {
+        self.skip_whitespace();
+        let key = self.identifier();
+        if key.is_empty() {
+            return Err(self.error(format!("expected a citation key after `@{entry_type}`")))
+        }
+        let mut fields = HashMap::default();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(x) if x == close => {
+                    self.bump();
+                    break
+                }
+                Some(',') => {
+                    self.bump();
+                    continue
+                }
+                None => return Err(self.error(format!("unterminated entry `{key}`"))),
+                Some(_) => (),
+            }
+            let (name, value) = self.field()?;
+            fields.insert(name, value);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(x) if x == close => (),
+                _ => return Err(self.error(format!("expected `,` or `{close}` in entry `{key}`"))),
+            }
+        }
+        Ok(Entry {entry_type, key, fields})
+    }
+    fn field(&mut self) -> Result<(String, String), ParseError> {
+        self.skip_whitespace();
+        let name = self.identifier().to_lowercase();
+        if name.is_empty() {
+            return Err(self.error("expected a field name"))
+        }
+        self.skip_whitespace();
+        self.expect('=')?;
+        let value = self.value()?;
+        Ok((name, value))
+    }
+    /// Concatenates the `#` separated parts.
+    fn value(&mut self) -> Result<String, ParseError> {
+        let mut value = String::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('{') => {
+                    self.bump();
+                    value.push_str(&self.delimited('}')?);
+                }
+                Some('"') => {
+                    self.bump();
+                    value.push_str(&self.delimited('"')?);
+                }
+                Some(x) if x.is_ascii_digit() => {
+                    while let Some(x) = self.peek().filter(char::is_ascii_digit) {
+                        value.push(x);
+                        self.bump();
+                    }
+                }
+                Some(_) => {
+                    let name = self.identifier().to_lowercase();
+                    if name.is_empty() {
+                        return Err(self.error("expected a value"))
+                    }
+                    let expansion = self.strings
+                        .get(&name)
+                        .cloned()
+                        .or_else(|| month(&name).map(ToOwned::to_owned))
+                        .unwrap_or(name);
+                    value.push_str(&expansion);
+                }
+                None => return Err(self.error("unexpected end of file")),
+            }
+            self.skip_whitespace();
+            if self.peek() != Some('#') {
+                return Ok(value)
+            }
+            self.bump();
+        }
+    }
+    /// Everything up until the given (unnested) delimiter, which is consumed.
+    fn delimited(&mut self, close: char) -> Result<String, ParseError> {
+        let mut depth = 0usize;
+        let mut value = String::new();
+        loop {
+            let x = match self.bump() {
+                Some(x) => x,
+                None => return Err(self.error(format!("expected `{close}`"))),
+            };
+            match x {
+                x if x == close && depth == 0 => return Ok(value),
+                '{' => depth += 1,
+                '}' if depth == 0 => return Err(self.error("unbalanced `}`")),
+                '}' => depth -= 1,
+                '\\' => {
+                    value.push(x);
+                    if let Some(next) = self.bump() {
+                        value.push(next);
+                    }
+                    continue
+                }
+                _ => (),
+            }
+            value.push(x);
+        }
+    }
+    fn identifier(&mut self) -> String {
+        let mut identifier = String::new();
+        while let Some(x) = self.peek() {
+            if x.is_whitespace() || "{}(),=\"#%".contains(x) {
+                break
+            }
+            identifier.push(x);
+            self.bump();
+        }
+        identifier
+    }
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            return Ok(())
+        }
+        Err(self.error(format!("expected `{expected}`")))
+    }
+    /// Also skips `%` line comments.
+    fn skip_whitespace(&mut self) {
+        while let Some(x) = self.peek() {
+            if x == '%' {
+                while self.peek().map(|x| x != '\n').unwrap_or(false) {
+                    self.bump();
+                }
+            } else if x.is_whitespace() {
+                self.bump();
+            } else {
+                break
+            }
+        }
+    }
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+    fn bump(&mut self) -> Option<char> {
+        let x = self.peek()?;
+        self.position += 1;
+        Some(x)
+    }
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        let line = self.chars[..self.position.min(self.chars.len())]
+            .iter()
+            .filter(|x| **x == '\n')
+            .count();
+        ParseError {line: line + 1, message: message.into()}
+    }
+}
+
+fn month(name: &str) -> Option<&'static str> {
+    let month = match name {
+        "jan" => "January",
+        "feb" => "February",
+        "mar" => "March",
+        "apr" => "April",
+        "may" => "May",
+        "jun" => "June",
+        "jul" => "July",
+        "aug" => "August",
+        "sep" => "September",
+        "oct" => "October",
+        "nov" => "November",
+        "dec" => "December",
+        _ => return None,
+    };
+    Some(month)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// NAMES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// As LaTeX, like field values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Name {
+    pub first: String,
+    /// Including the “von” part and suffix (e.g. “Jr”), if any.
+    pub last: String,
+}
+
+impl Name {
+    /// I.e. `and others`, which styles show as “et al.”.
+    pub fn is_others(&self) -> bool {
+        self.first.is_empty() && self.last == "others"
+    }
+    /// E.g. `Donald E. Knuth`.
+    pub fn full_name(&self) -> String {
+        if self.first.is_empty() {
+            return self.last.clone()
+        }
+        format!("{} {}", self.first, self.last)
+    }
+}
+
+/// Splits a list of `and` separated names, each of which is given as
+/// `First von Last`, `von Last, First` or `von Last, Jr, First`.
+pub fn names(value: &str) -> Vec<Name> {
+    let words = words(value);
+    words
+        .split(|x| x.eq_ignore_ascii_case("and"))
+        .filter(|x| !x.is_empty())
+        .map(name)
+        .collect()
+}
+
+fn name(words: &[String]) -> Name {
+    let parts = words
+        .split(|x| x == ",")
+        .map(|x| x.join(" "))
+        .collect::<Vec<_>>();
+    match parts.as_slice() {
+        [last, first] => Name {first: first.clone(), last: last.clone()},
+        [last, suffix, first, ..] => Name {first: first.clone(), last: format!("{last} {suffix}")},
+        _ => {
+            // The “von” part starts at the first lowercase word.
+            let split = words[..words.len() - 1]
+                .iter()
+                .position(|x| x.chars().next().map(char::is_lowercase).unwrap_or(false))
+                .unwrap_or(words.len() - 1);
+            Name {first: words[..split].join(" "), last: words[split..].join(" ")}
+        }
+    }
+}
+
+/// Splits at whitespace outside of braces, commas are words of their own.
+fn words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut depth = 0usize;
+    for x in value.chars() {
+        match x {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+        if depth == 0 && (x.is_whitespace() || x == ',') {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            if x == ',' {
+                words.push(String::from(","));
+            }
+            continue
+        }
+        word.push(x);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// PLAIN TEXT
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Removes braces and resolves the usual LaTeX escapes, accents and
+/// ligatures, e.g. `{G\"o}del` → `Gödel`. Other commands are dropped, keeping
+/// their arguments.
+pub fn to_plain_text(value: &str) -> String {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let x = chars[index];
+        index += 1;
+        match x {
+            '{' | '}' | '$' => (),
+            '~' => result.push('\u{a0}'),
+            '-' if chars.get(index) == Some(&'-') => {
+                if chars.get(index + 1) == Some(&'-') {
+                    result.push('—');
+                    index += 2;
+                } else {
+                    result.push('–');
+                    index += 1;
+                }
+            }
+            '\\' => {
+                let start = index;
+                while chars.get(index).map(char::is_ascii_alphabetic).unwrap_or(false) {
+                    index += 1;
+                }
+                let command = chars[start..index].iter().collect::<String>();
+                if command.is_empty() {
+                    // A symbol, e.g. `\&` or `\"o`.
+                    let symbol = match chars.get(index) {
+                        Some(x) => *x,
+                        None => break,
+                    };
+                    index += 1;
+                    match combining_accent(&symbol.to_string()) {
+                        Some(accent) => {
+                            let (base, next) = accent_base(&chars, index);
+                            index = next;
+                            result.push_str(&base);
+                            result.push(accent);
+                        }
+                        None => result.push(symbol),
+                    }
+                    continue
+                }
+                // The space after a command name is part of it.
+                while chars.get(index).map(|x| *x == ' ').unwrap_or(false) {
+                    index += 1;
+                }
+                if let Some(accent) = combining_accent(&command) {
+                    let (base, next) = accent_base(&chars, index);
+                    index = next;
+                    result.push_str(&base);
+                    result.push(accent);
+                    continue
+                }
+                let replacement = match command.as_str() {
+                    "ss" => "ß",
+                    "o" => "ø",
+                    "O" => "Ø",
+                    "ae" => "æ",
+                    "AE" => "Æ",
+                    "oe" => "œ",
+                    "OE" => "Œ",
+                    "aa" => "å",
+                    "AA" => "Å",
+                    "l" => "ł",
+                    "L" => "Ł",
+                    "i" => "ı",
+                    "j" => "ȷ",
+                    "TeX" => "TeX",
+                    "LaTeX" => "LaTeX",
+                    _ => "",
+                };
+                result.push_str(replacement);
+            }
+            x if x.is_whitespace() => {
+                if !result.ends_with(' ') {
+                    result.push(' ');
+                }
+            }
+            x => result.push(x),
+        }
+    }
+    result
+}
+
+fn combining_accent(command: &str) -> Option<char> {
+    let accent = match command {
+        "\"" => '\u{308}',
+        "'" => '\u{301}',
+        "`" => '\u{300}',
+        "^" => '\u{302}',
+        "~" => '\u{303}',
+        "=" => '\u{304}',
+        "." => '\u{307}',
+        "u" => '\u{306}',
+        "v" => '\u{30c}',
+        "H" => '\u{30b}',
+        "r" => '\u{30a}',
+        "c" => '\u{327}',
+        "k" => '\u{328}',
+        _ => return None,
+    };
+    Some(accent)
+}
+
+/// The accented character, i.e. `o` in `\"o` or `\"{o}`, and the index after
+/// such.
+fn accent_base(chars: &[char], index: usize) -> (String, usize) {
+    match chars.get(index) {
+        Some('{') => {
+            let end = chars[index..]
+                .iter()
+                .position(|x| *x == '}')
+                .map(|x| index + x)
+                .unwrap_or(chars.len());
+            let base = chars[index + 1..end].iter().collect::<String>();
+            (to_plain_text(&base), (end + 1).min(chars.len()))
+        }
+        Some(x) => (x.to_string(), index + 1),
+        None => (String::new(), index),
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TESTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+Anything outside of entries is a comment.
+@string{ aw = "Addison-Wesley" }
+@comment{ @book{ignored, title = {Ignored}} }
+@preamble{ "\newcommand{\noop}[1]{}" }
+@Book{knuth98,
+    Author = {Donald E. Knuth},
+    title = "The Art of {C}omputer Programming",
+    publisher = aw # " Professional",
+    month = dec,
+    year = 1998, % Trailing comma and line comment.
+}
+@article(godel31,
+    author = {Kurt G{\"o}del and others},
+    title = {{\"U}ber formal unentscheidbare S{\"a}tze {\{}I{\}}},
+    note = "Quoted {"} braces",
+)
+"#;
+
+    #[test]
+    fn entries_are_parsed() {
+        let entries = parse(SOURCE).unwrap();
+        assert_eq!(entries.len(), 2, "{entries:?}");
+        let book = &entries[0];
+        assert_eq!(book.entry_type, "book");
+        assert_eq!(book.key, "knuth98");
+        assert_eq!(book.get("author"), Some("Donald E. Knuth"));
+        // Braces are kept, as LaTeX, but for the outer delimiters.
+        assert_eq!(book.get("title"), Some("The Art of {C}omputer Programming"));
+        // `@string` macros, `#` concatenations and month abbreviations.
+        assert_eq!(book.get("publisher"), Some("Addison-Wesley Professional"));
+        assert_eq!(book.get("month"), Some("December"));
+        assert_eq!(book.get("year"), Some("1998"));
+        let article = &entries[1];
+        assert_eq!(article.entry_type, "article");
+        assert_eq!(article.get("title"), Some(r#"{\"U}ber formal unentscheidbare S{\"a}tze {\{}I{\}}"#));
+        assert_eq!(article.get("note"), Some(r#"Quoted {"} braces"#));
+        // Accents are combining characters.
+        assert_eq!(to_plain_text(article.get("title").unwrap()), "U\u{308}ber formal unentscheidbare Sa\u{308}tze {I}");
+        let names = article.names();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].full_name(), r#"Kurt G{\"o}del"#);
+        assert!(names[1].is_others());
+    }
+
+    #[test]
+    fn names_are_split() {
+        let name = |first: &str, last: &str| Name {first: first.to_owned(), last: last.to_owned()};
+        assert_eq!(names("Donald E. Knuth"), vec![name("Donald E.", "Knuth")]);
+        assert_eq!(names("Ludwig van Beethoven"), vec![name("Ludwig", "van Beethoven")]);
+        assert_eq!(names("van Beethoven, Ludwig"), vec![name("Ludwig", "van Beethoven")]);
+        assert_eq!(names("de la Fontaine, Jr, Jean"), vec![name("Jean", "de la Fontaine Jr")]);
+        assert_eq!(names("Ford, Jr., Henry"), vec![name("Henry", "Ford Jr.")]);
+        // Braces protect spaces (and `and`), single words are last names.
+        assert_eq!(names("{Barnes and Noble, Inc.} and Plato"), vec![
+            name("", "{Barnes and Noble, Inc.}"),
+            name("", "Plato"),
+        ]);
+        assert_eq!(names("Alfred North Whitehead AND Russell, Bertrand"), vec![
+            name("Alfred North", "Whitehead"),
+            name("Bertrand", "Russell"),
+        ]);
+        assert_eq!(names("  "), Vec::new());
+    }
+
+    #[test]
+    fn malformed_entries_are_errors() {
+        let error = |source: &str| parse(source).unwrap_err();
+        assert_eq!(error("@book knuth98, title = {A}}"), ParseError {
+            line: 1,
+            message: String::from("expected `{` after `@book`"),
+        });
+        assert_eq!(error("@book{, title = {A}}").message, "expected a citation key after `@book`");
+        assert_eq!(error("@book{knuth98,\n  title = {A}\n  year = 1998}"), ParseError {
+            line: 3,
+            message: String::from("expected `,` or `}` in entry `knuth98`"),
+        });
+        assert_eq!(error("@book{knuth98, title {A}}").message, "expected `=`");
+        assert_eq!(error("@book{knuth98, title = }").message, "expected a value");
+        assert_eq!(error("@book{knuth98, title = {A},").message, "unterminated entry `knuth98`");
+        assert_eq!(error("@book{knuth98,\n\n title = {A").to_string(), "line 3: expected `}`");
+        assert_eq!(error("@book{knuth98, title = \"A}\"}").message, "unbalanced `}`");
+    }
+
+    #[test]
+    fn truncated_sources_dont_panic() {
+        let chars = SOURCE.chars().collect::<Vec<_>>();
+        for end in 0..chars.len() {
+            let source = chars[..end].iter().collect::<String>();
+            let _ = parse(&source);
+        }
+    }
+}
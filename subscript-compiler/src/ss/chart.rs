@@ -0,0 +1,210 @@
+//! Static charts of tabular data (see `crate::ss::data`), i.e. `\chart`,
+//! e.g. `\chart[src="trials.csv", type="bar", x="Trial", y="Mass, Volume"]`.
+//! Like `\plot`s, charts are figures (see `crate::ss::figure`) drawn at
+//! compile time.
+use itertools::Itertools;
+use crate::ss::figure::{fit_interval, legend, Axes, Element, Figure, Paint, Stroke};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartKind {
+    #[default]
+    Line,
+    Bar,
+    Scatter,
+}
+
+impl ChartKind {
+    pub const NAMES: &'static [&'static str] = &["line", "bar", "scatter"];
+    pub fn parse(value: &str) -> Option<ChartKind> {
+        match value.trim() {
+            "line" => Some(ChartKind::Line),
+            "bar" => Some(ChartKind::Bar),
+            "scatter" => Some(ChartKind::Scatter),
+            _ => None,
+        }
+    }
+    fn name(self) -> &'static str {
+        match self {
+            ChartKind::Line => "Line chart",
+            ChartKind::Bar => "Bar chart",
+            ChartKind::Scatter => "Scatter chart",
+        }
+    }
+}
+
+/// A y column.
+#[derive(Debug, Clone)]
+pub struct Series {
+    pub name: String,
+    /// Per row, where missing (or non-numeric) values are `None`.
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Chart {
+    pub kind: ChartKind,
+    /// The x cell of each row, e.g. bar chart categories.
+    pub x_labels: Vec<String>,
+    /// The x values of each row, if the x column is numeric (bar charts are
+    /// always categorical).
+    pub x_values: Option<Vec<Option<f64>>>,
+    pub series: Vec<Series>,
+    /// The x interval (of numeric x columns), by default the data’s.
+    pub domain: Option<(f64, f64)>,
+    /// The y interval, by default the data’s.
+    pub range: Option<(f64, f64)>,
+    pub grid: bool,
+    pub x_label: Option<String>,
+    pub y_label: Option<String>,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Default for Chart {
+    fn default() -> Self {
+        Chart {
+            kind: ChartKind::Line,
+            x_labels: Vec::new(),
+            x_values: None,
+            series: Vec::new(),
+            domain: None,
+            range: None,
+            grid: false,
+            x_label: None,
+            y_label: None,
+            width: 600.0,
+            height: 400.0,
+        }
+    }
+}
+
+impl Chart {
+    /// Numeric x values, or the row indices for categories.
+    fn xs(&self) -> Vec<Option<f64>> {
+        match (self.kind, self.x_values.as_ref()) {
+            (ChartKind::Bar, _) | (_, None) => (0..self.x_labels.len()).map(|x| Some(x as f64)).collect(),
+            (_, Some(values)) => values.clone(),
+        }
+    }
+    fn is_categorical(&self) -> bool {
+        self.kind == ChartKind::Bar || self.x_values.is_none()
+    }
+    fn bounds(&self, xs: &[Option<f64>]) -> ((f64, f64), (f64, f64)) {
+        let rows = self.x_labels.len();
+        let domain = match (self.domain, self.is_categorical()) {
+            (_, true) => (-0.5, rows.max(1) as f64 - 0.5),
+            (Some(domain), false) => domain,
+            (None, false) => {
+                let xs = xs.iter().flatten().copied().filter(|x| x.is_finite()).collect_vec();
+                match xs.iter().copied().reduce(f64::min).zip(xs.iter().copied().reduce(f64::max)) {
+                    Some((lo, hi)) if hi - lo > 1e-9 && self.kind == ChartKind::Line => (lo, hi),
+                    Some(_) => fit_interval(&mut xs.clone(), false),
+                    None => (0.0, 1.0),
+                }
+            }
+        };
+        let mut ys = self.series
+            .iter()
+            .flat_map(|x| x.values.iter().flatten().copied())
+            .filter(|x| x.is_finite())
+            .collect_vec();
+        let range = match self.range {
+            Some(range) => range,
+            // Bars start at zero, so only the other end is padded.
+            None if self.kind == ChartKind::Bar => {
+                let lo = ys.iter().copied().fold(0.0, f64::min);
+                let hi = ys.iter().copied().fold(0.0, f64::max);
+                let padding = if hi - lo > 1e-9 {(hi - lo) * 0.1} else {1.0};
+                (if lo < 0.0 {lo - padding} else {0.0}, if hi > 0.0 || lo == 0.0 {hi + padding} else {0.0})
+            }
+            None => fit_interval(&mut ys, false),
+        };
+        (domain, range)
+    }
+    pub fn to_figure(&self) -> Figure {
+        let xs = self.xs();
+        let (domain, range) = self.bounds(&xs);
+        let axes = Axes {
+            width: self.width,
+            height: self.height,
+            domain,
+            range,
+            grid: self.grid,
+            x_label: self.x_label.clone(),
+            y_label: self.y_label.clone(),
+            x_categories: if self.is_categorical() {Some(self.x_labels.clone())} else {None},
+        };
+        let frame = axes.frame();
+        let mut elements = axes.to_elements(&frame);
+        let mut data = Vec::new();
+        let count = self.series.len().max(1) as f64;
+        for (ix, series) in self.series.iter().enumerate() {
+            let paint = Paint::Series(ix);
+            let points = xs
+                .iter()
+                .zip(series.values.iter())
+                .map(|(x, y)| Some((frame.x((*x)?), frame.y((*y)?))))
+                .collect_vec();
+            match self.kind {
+                ChartKind::Line => {
+                    // Missing values break the line.
+                    let segments = points
+                        .split(Option::is_none)
+                        .map(|xs| xs.iter().flatten().copied().collect_vec())
+                        .filter(|xs| !xs.is_empty())
+                        .collect_vec();
+                    for segment in segments.iter().filter(|x| x.len() == 1) {
+                        data.push(Element::Circle {at: segment[0], radius: 2.5, fill: paint.clone()});
+                    }
+                    data.push(Element::Path {segments, stroke: Stroke::new(paint, 2.0)});
+                }
+                ChartKind::Scatter => {
+                    for at in points.into_iter().flatten() {
+                        data.push(Element::Circle {at, radius: 3.5, fill: paint.clone()});
+                    }
+                }
+                ChartKind::Bar => {
+                    // Grouped bars, 80% of each category.
+                    let width = 0.8 / count;
+                    for (row, value) in series.values.iter().enumerate() {
+                        let value = match value {
+                            Some(value) => *value,
+                            None => continue,
+                        };
+                        let left = frame.x(row as f64 - 0.4 + width * ix as f64);
+                        let right = frame.x(row as f64 - 0.4 + width * (ix + 1) as f64);
+                        let (top, bottom) = (frame.y(value.max(0.0)), frame.y(value.min(0.0)));
+                        data.push(Element::Rect {
+                            at: (left + 0.5, top),
+                            size: ((right - left - 1.0).max(1.0), bottom - top),
+                            fill: paint.clone(),
+                            stroke: None,
+                        });
+                    }
+                }
+            }
+        }
+        elements.push(frame.clip(data));
+        if self.series.len() > 1 {
+            let swatch = if self.kind == ChartKind::Line {2.0} else {8.0};
+            let entries = self.series
+                .iter()
+                .enumerate()
+                .map(|(ix, x)| (x.name.clone(), Stroke::new(Paint::Series(ix), swatch)))
+                .collect_vec();
+            elements.extend(legend(&frame, &entries));
+        }
+        let names = self.series.iter().map(|x| x.name.as_str()).join(", ");
+        let title = match (names.is_empty(), self.x_label.as_ref()) {
+            (true, _) => String::from("Empty chart"),
+            (false, Some(x)) => format!("{} of {names} by {x}", self.kind.name()),
+            (false, None) => format!("{} of {names}", self.kind.name()),
+        };
+        Figure {
+            width: self.width,
+            height: self.height,
+            title,
+            elements,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! I’ve overhauled the parser (didn’t realize how bad the previous implementation was), and the core compiler data models, with a unified interface for command declarations, where commands can be implemented and made available in a very fined tuned manner.
 //! So you can have commands that are available based on parent command scope (for instance the `\row{…}` cmd is only available if it's nested under the `\table` cmd (doesn’t need to be a direct descendant)), block/inline mode, or content mode (i.e. text (the default) or the multitude “symbolic modes” (such as math, chemistry, both, etc.)). For instance, LaTeX technically has two different fraction macros, where one is for block display modes and the other for inline fractions (can’t remember what it’s called), with the interface I have: you can use the came command identifier for both, and the compiler will automatically select the appropriate version.
-//! **Although at the time of this writing, not all information is propagated during relevant AST traversals.** Also there needs to be support for defining documentation for a given command, which I haven’t yet got to. 
+//! **Although at the time of this writing, not all information is propagated during relevant AST traversals.** Documentation for a given command is defined via `CmdDeclBuilder::documentation`, although most commands don’t have any yet. 
 //! Defining/declaring SS commands in rust is somewhat awkward and very verbose, and perhaps could be better, but the real innovation here (as opposed to previous implementations) is that all commands are defined in a manner that (in theory) is easily fed to autocomplete engines. Furthermore, everything pertaining to a given command is defined in one place, from post-parser structure to target specific code-gens. Furthermore, for a given processing stage, all commands are essentially processed in a single traversal. 
 
 use std::borrow::Cow;
@@ -25,20 +25,62 @@ pub struct CmdDeclaration {
     pub child_env: Option<ChildEnvNamespaceDecl>,
     pub attributes: HashMap<AttributeKey, Option<AttributeValue>>,
     pub ignore_attributes: bool,
+    /// Whether the declared attributes are all there is, so that others (and
+    /// invalid values) are reported. Most commands (e.g. HTML tags) pass
+    /// arbitrary attributes along.
+    pub strict_attributes: bool,
     pub arguments: VariableArguments,
     pub processors: CmdCodegen,
     /// Just the the default implementation.
-    pub internal: InternalCmdDeclOptions
+    pub internal: InternalCmdDeclOptions,
+    /// Markdown formatted documentation, this is what e.g. the language server
+    /// shows on hover.
+    pub documentation: Option<String>,
+}
+
+impl CmdDeclaration {
+    /// The different ways this command can be invoked, e.g. `\h1{…}` or
+    /// `\include[…]`; used for hover info and autocomplete.
+    pub fn signatures(&self) -> Vec<String> {
+        let attrs = if self.ignore_attributes {""} else {"[…]"};
+        self.arguments.0
+            .iter()
+            .map(|instance| {
+                let mut signature = format!("{}{attrs}", self.identifier.as_str());
+                match &instance.ty {
+                    Left(Override::NoArguments) => (),
+                    Left(Override::AllFollowingCurlyBraces) => {
+                        signature.push_str("{…}…");
+                    }
+                    Right(arguments) => {
+                        for argument in arguments {
+                            signature.push_str(match argument {
+                                ArgumentType::CurlyBrace => "{…}",
+                                ArgumentType::SquareParen => "[…]",
+                                ArgumentType::Parens => "(…)",
+                            });
+                        }
+                    }
+                }
+                signature
+            })
+            .unique()
+            .collect_vec()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct InternalCmdDeclOptions {
     pub automatically_apply_rewrites: bool,
+    /// Arguments are kept as is, i.e. commands within such aren’t processed
+    /// or validated (e.g. the template of `\define`).
+    pub verbatim_arguments: bool,
 }
 impl Default for InternalCmdDeclOptions {
     fn default() -> Self {
         InternalCmdDeclOptions {
            automatically_apply_rewrites: true,
+           verbatim_arguments: false,
         }
     }
 }
@@ -265,7 +307,7 @@ impl ArgumentType {
 pub struct All;
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AttributeKey {
     pub key: String,
     pub required: IsRequired,
@@ -278,6 +320,12 @@ impl AttributeKey {
             required: IsRequired::Optional
         }
     }
+    pub fn required_attr(name: &str) -> Self {
+        AttributeKey {
+            key: name.to_owned(),
+            required: IsRequired::Required
+        }
+    }
     pub fn is_required(&self) -> bool {
         match self.required {
             IsRequired::Required => true,
@@ -299,6 +347,15 @@ pub struct AttributeValue {
     pub required: IsRequired,
 }
 
+impl AttributeValue {
+    pub fn new(value_ty: AttributeValueType) -> Self {
+        AttributeValue {
+            value_ty,
+            required: IsRequired::Required,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AttributeValueType {
     /// A FilePath is also a string but is a more specific declaration of intent. 
@@ -307,10 +364,43 @@ pub enum AttributeValueType {
     String,
     /// Some arbitrary integer.
     Int,
+    /// E.g. `true`, `false`, `1`, `0`, or a bare flag.
+    Bool,
+    /// Some arbitrary decimal number, e.g. `0.5` or `-2`.
+    Number,
+    /// One of the given (case-sensitive) values.
+    OneOf(&'static [&'static str]),
+}
+
+impl AttributeValueType {
+    /// Bare flags (e.g. `\desmos[grid]`) have an empty value.
+    pub fn is_valid(&self, value: &str) -> bool {
+        let value = value.trim();
+        match self {
+            AttributeValueType::FilePath => true,
+            AttributeValueType::String => true,
+            AttributeValueType::Int => value.parse::<i64>().is_ok(),
+            AttributeValueType::Bool => matches!(value, "" | "true" | "false" | "1" | "0"),
+            AttributeValueType::Number => value.parse::<f64>().map(f64::is_finite).unwrap_or(false),
+            AttributeValueType::OneOf(values) => values.contains(&value),
+        }
+    }
+    /// E.g. `an integer` or `one of solid, dashed`, for diagnostics and
+    /// completions.
+    pub fn describe(&self) -> String {
+        match self {
+            AttributeValueType::FilePath => String::from("a file path"),
+            AttributeValueType::String => String::from("a string"),
+            AttributeValueType::Int => String::from("an integer"),
+            AttributeValueType::Bool => String::from("a boolean"),
+            AttributeValueType::Number => String::from("a number"),
+            AttributeValueType::OneOf(values) => format!("one of {}", values.join(", ")),
+        }
+    }
 }
 
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IsRequired {
     Optional,
     Required,
@@ -2,15 +2,29 @@ use std::{collections::HashMap, borrow::BorrowMut};
 use itertools::Itertools;
 use rayon::prelude::*;
 use crate::ss::ast_data::Attribute;
-use crate::ss::{Node, Ident, Ann, CmdCall};
+use crate::ss::{Node, Ident, Ann, CmdCall, ContentMode};
 use crate::ss::cmd_decl::{CmdCodegen, CmdDeclaration};
-use crate::ss::{SemanticScope, HtmlCodegenEnv, LatexCodegenEnv};
+use crate::ss::{SemanticScope, HtmlCodegenEnv, LatexCodegenEnv, ResourceEnv};
+use crate::ss::diagnostics::Diagnostic;
 use crate::html;
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 // MISCELLANEOUS
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 
+/// Commands are matched against the same declarations during processing, so
+/// this shouldn’t happen unless the AST was modified in between.
+fn report_missing_codegen(env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall, target: &str) {
+    env.report(
+        Diagnostic::error(format!(
+            "`{}` has no {target} code-gen in this context",
+            cmd.identifier.value.as_str(),
+        ))
+        .with_range(cmd.identifier.range)
+        .with_scope(scope)
+    );
+}
+
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 // HTML - CODE-GEN
@@ -25,6 +39,11 @@ pub fn default_cmd_html_cg(env: &HtmlCodegenEnv, scope: &SemanticScope, cmd: Cmd
         .filter_map(Attribute::to_key_value_str)
         .map(|(k, v)| (k, v.unwrap_or_default()))
         .collect::<HashMap<_, _>>();
+    for key in ["href", "src"] {
+        if let Some(value) = attributes.get(key) {
+            env.add_link(scope, cmd.identifier.range, value);
+        }
+    }
     let arguments = cmd.arguments
         .into_iter()
         .flat_map(Node::unblock_root_curly_brace)
@@ -57,14 +76,36 @@ impl Node {
     pub fn to_html(self, env: &HtmlCodegenEnv, scope: &SemanticScope) -> crate::html::ast::Node {
         match self {
             Node::Cmd(cmd) => {
-                // TODO
-                scope.cmd_call_to_html(env, cmd).unwrap()
+                let has_codegen = scope.cmd_decls
+                    .get(&cmd.identifier.value)
+                    .map(|xs| xs.iter().any(|x| scope.match_cmd(&x.parent_env)))
+                    .unwrap_or(false);
+                if !has_codegen {
+                    report_missing_codegen(&env.resource_env, scope, &cmd, "HTML");
+                    let children = cmd.arguments
+                        .into_iter()
+                        .map(|x| x.to_html(env, scope))
+                        .collect::<Vec<_>>();
+                    return crate::html::ast::Node::Fragment(children)
+                }
+                let range = cmd.identifier.range;
+                let is_block = cmd.identifier.value.is_block_command();
+                let html = scope.cmd_call_to_html(env, cmd).unwrap();
+                match env.source_map.as_ref() {
+                    Some(source_map) if is_block => source_map.annotate(scope, range, html),
+                    _ => html,
+                }
             }
             Node::Ident(Ann{value, ..}) => {
                 crate::html::ast::Node::Text(value.to_tex_ident().to_owned())
             }
             Node::Bracket(Ann{value, ..}) => {
-                let brackets = value.to_unicode_brackets();
+                // Code, e.g. `fn main() {…}`, is shown as written.
+                let brackets = if scope.in_code_scope() {
+                    value.to_ascii_brackets()
+                } else {
+                    value.to_unicode_brackets()
+                };
                 let xs = value.children
                     .into_iter()
                     .map(|x| x.to_html(env, scope))
@@ -97,7 +138,11 @@ impl Node {
                 }
             }
             Node::Quotation(Ann{value, ..}) => {
-                let brackets = value.to_unicode_quotation();
+                let brackets = if scope.in_code_scope() {
+                    None
+                } else {
+                    value.to_unicode_quotation()
+                };
                 let xs = value.children
                     .into_iter()
                     .map(|x| x.to_html(env, scope))
@@ -157,6 +202,9 @@ impl Node {
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 
 pub fn default_cmd_latex_cg(env: &LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    if scope.content_mode == ContentMode::Text {
+        return text_mode_latex_cg(env, scope, cmd)
+    }
     let name = cmd.identifier.value.to_tex_ident();
     let arguments = cmd.arguments
         .into_iter()
@@ -166,6 +214,151 @@ pub fn default_cmd_latex_cg(env: &LatexCodegenEnv, scope: &SemanticScope, cmd: C
     format!("{name}{arguments}")
 }
 
+/// For (text mode) commands without a dedicated LaTeX code-gen, which are
+/// mostly HTML tags. Anything without a LaTeX equivalent is rendered as its
+/// contents.
+fn text_mode_latex_cg(env: &LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    let name = cmd.identifier.value.unwrap_remove_slash().to_owned();
+    let href = cmd.attributes.get_str_value("href");
+    let arguments = cmd.arguments
+        .into_iter()
+        .flat_map(Node::unblock_root_curly_brace)
+        .collect_vec();
+    let columns = arguments.iter().map(latex_table_columns).max().unwrap_or(0);
+    let cells = || {
+        arguments
+            .iter()
+            .filter(|x| !x.is_whitespace())
+            .map(|x| x.clone().to_latex(env, scope))
+            .join(" & ")
+    };
+    let children = arguments
+        .iter()
+        .map(|x| x.clone().to_latex(env, scope))
+        .collect::<String>();
+    match name.as_str() {
+        "h1" => format!("\n\\section*{{{children}}}\n"),
+        "h2" => format!("\n\\subsection*{{{children}}}\n"),
+        "h3" => format!("\n\\subsubsection*{{{children}}}\n"),
+        "h4" => format!("\n\\paragraph*{{{children}}}\n"),
+        "h5" | "h6" => format!("\n\\subparagraph*{{{children}}}\n"),
+        "p" => format!("\n{}\n\n", children.trim()),
+        "b" | "strong" => format!("\\textbf{{{children}}}"),
+        "i" | "em" | "cite" | "dfn" | "var" => format!("\\emph{{{children}}}"),
+        "u" | "ins" => format!("\\underline{{{children}}}"),
+        "s" | "del" => format!("\\sout{{{children}}}"),
+        "code" | "kbd" | "samp" => format!("\\texttt{{{children}}}"),
+        "small" => format!("{{\\small {children}}}"),
+        "sub" => format!("\\textsubscript{{{children}}}"),
+        "sup" => format!("\\textsuperscript{{{children}}}"),
+        "q" => format!("``{children}''"),
+        "a" => match href {
+            Some(href) => {
+                let href = href.replace('\\', "/").replace('%', "\\%").replace('#', "\\#");
+                format!("\\href{{{href}}}{{{children}}}")
+            }
+            None => children,
+        },
+        "br" => String::from("\\newline\n"),
+        "hr" => String::from("\n\\noindent\\rule{\\linewidth}{0.4pt}\n"),
+        "ul" => format!("\n\\begin{{itemize}}\n{children}\n\\end{{itemize}}\n"),
+        "ol" => format!("\n\\begin{{enumerate}}\n{children}\n\\end{{enumerate}}\n"),
+        "dl" => format!("\n\\begin{{description}}\n{children}\n\\end{{description}}\n"),
+        "li" | "dd" => format!("\\item {}\n", children.trim()),
+        "dt" => format!("\\item[{}] ", children.trim()),
+        "blockquote" => format!("\n\\begin{{quote}}\n{children}\n\\end{{quote}}\n"),
+        "pre" => format!("\n\\begin{{flushleft}}\\ttfamily\n{children}\n\\end{{flushleft}}\n"),
+        "figure" => format!("\n\\begin{{center}}\n{children}\n\\end{{center}}\n"),
+        "figcaption" | "caption" => format!("\n\\emph{{{}}}\n", children.trim()),
+        "table" if columns > 0 => {
+            let spec = "l".repeat(columns);
+            format!("\n\\begin{{center}}\\begin{{tabular}}{{{spec}}}\n{children}\\end{{tabular}}\\end{{center}}\n")
+        }
+        "tr" => format!("{} \\\\\n", cells()),
+        "th" => format!("\\textbf{{{}}}", children.trim()),
+        "td" => children.trim().to_owned(),
+        // Media without a printable equivalent.
+        "audio" | "video" | "track" | "source" | "object" | "map" | "area" => String::new(),
+        _ => children,
+    }
+}
+
+/// The number of columns of a `\table`, i.e. the widest `\row` or `\tr`.
+fn latex_table_columns(node: &Node) -> usize {
+    match node {
+        Node::Cmd(cmd) if cmd.identifier.value == "\\row" => {
+            cmd.arguments.iter().filter(|x| x.is_curly_brace()).count()
+        }
+        Node::Cmd(cmd) if cmd.identifier.value == "\\tr" => {
+            cmd.arguments
+                .iter()
+                .cloned()
+                .flat_map(Node::unblock_root_curly_brace)
+                .filter(|x| x.is_cmd_with_name("\\td") || x.is_cmd_with_name("\\th"))
+                .count()
+        }
+        Node::Cmd(cmd) if cmd.identifier.value == "\\table" => 0,
+        Node::Cmd(cmd) => cmd.arguments.iter().map(latex_table_columns).max().unwrap_or(0),
+        Node::Bracket(Ann{value, ..}) => {
+            value.children.iter().map(latex_table_columns).max().unwrap_or(0)
+        }
+        Node::Fragment(xs) => xs.iter().map(latex_table_columns).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Escapes characters that are special in LaTeX text mode.
+pub fn escape_latex_text(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\textbackslash{}"),
+            '~' => result.push_str("\\textasciitilde{}"),
+            '^' => result.push_str("\\textasciicircum{}"),
+            '{' | '}' | '$' | '&' | '#' | '%' | '_' => {
+                result.push('\\');
+                result.push(c);
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Wraps the given body in a standalone LaTeX document, with all packages
+/// required by the LaTeX code-gen of the standard library.
+pub fn latex_document(title: Option<&str>, body: &str) -> String {
+    let title = title
+        .map(|title| format!("\\title{{{}}}\n\\date{{}}\n", escape_latex_text(title)))
+        .unwrap_or_default();
+    let maketitle = if title.is_empty() {""} else {"\\maketitle\n"};
+    format!(
+        "{LATEX_PREAMBLE}{title}\\begin{{document}}\n{maketitle}{}\n\\end{{document}}\n",
+        body.trim(),
+    )
+}
+
+const LATEX_PREAMBLE: &str = r#"\documentclass[11pt]{article}
+\usepackage[utf8]{inputenc}
+\usepackage[T1]{fontenc}
+\usepackage[margin=1in]{geometry}
+\usepackage{amsmath}
+\usepackage{mathtools}
+\usepackage{amssymb}
+\usepackage[version=4]{mhchem}
+\usepackage{xcolor}
+\usepackage{graphicx}
+\usepackage[export]{adjustbox}
+\usepackage{tikz}
+\usepackage{multicol}
+\usepackage{framed}
+\usepackage[normalem]{ulem}
+\usepackage{hyperref}
+\newenvironment{note}{\begin{quote}}{\end{quote}}
+\newenvironment{boxednote}{\begin{framed}}{\end{framed}}
+\newenvironment{grid}[1]{\begin{multicols}{#1}}{\end{multicols}}
+"#;
+
 // fn apply_cmd(env: &LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> Option<String> {
 //     // let cmd_decl: CmdDeclaration = env.commands.get(&cmd.identifier.value)?.clone();
 //     // let code_gen: &dyn CmdCodegen = cmd_decl.processors.0.as_ref();
@@ -185,16 +378,30 @@ impl Node {
     pub fn to_latex(self, env: &LatexCodegenEnv, scope: &SemanticScope) -> String {
         match self {
             Node::Cmd(cmd) => {
-                // TODO
-                let result = scope.cmd_call_to_latex(env, cmd.clone());
-                if result.is_none() {
-                    println!("[{}] NO CODE-GEN {:?}", scope.cmd_decls.len(), cmd.identifier.value());
+                if scope.get_cmd_decl(&env.resource_env, &cmd).is_none() {
+                    report_missing_codegen(&env.resource_env, scope, &cmd, "LaTeX");
+                    return cmd.arguments
+                        .into_iter()
+                        .map(|x| x.to_latex(env, scope))
+                        .collect::<String>()
                 }
-                result.unwrap()
+                scope.cmd_call_to_latex(env, cmd).unwrap()
+            }
+            Node::Ident(Ann{value, ..}) if scope.content_mode == ContentMode::Text => {
+                escape_latex_text(value.as_str())
             }
             Node::Ident(Ann{value, ..}) => {
                 value.to_tex_ident().to_owned()
             }
+            Node::Bracket(Ann{value, ..}) if scope.content_mode == ContentMode::Text => {
+                let children = value.children
+                    .into_iter()
+                    .map(|x| x.to_latex(env, scope))
+                    .collect::<String>();
+                let open = value.open.map(|x| escape_latex_text(&x.value)).unwrap_or_default();
+                let close = value.close.map(|x| escape_latex_text(&x.value)).unwrap_or_default();
+                format!("{open}{children}{close}")
+            }
             Node::Bracket(Ann{value, ..}) => {
                 let brackets = value.to_ascii_brackets();
                 let children = value.children
@@ -219,6 +426,23 @@ impl Node {
                     }
                 }
             }
+            Node::Quotation(Ann{value, ..}) if scope.content_mode == ContentMode::Text => {
+                let children = value.children
+                    .into_iter()
+                    .map(|x| x.to_latex(env, scope))
+                    .collect::<String>();
+                let open = value.open.as_ref().map(|x| x.value.as_str());
+                let close = value.close.as_ref().map(|x| x.value.as_str());
+                match (open, close) {
+                    (Some("\""), Some("\"")) => format!("``{children}''"),
+                    (Some("'"), Some("'")) => format!("`{children}'"),
+                    (open, close) => format!(
+                        "{}{children}{}",
+                        open.map(escape_latex_text).unwrap_or_default(),
+                        close.map(escape_latex_text).unwrap_or_default(),
+                    ),
+                }
+            }
             Node::Quotation(Ann{value, ..}) => {
                 let brackets = value.to_ascii_quotation();
                 let children = value.children
@@ -243,6 +467,11 @@ impl Node {
                     }
                 }
             }
+            Node::Text(Ann{value, ..}) |
+            Node::Symbol(Ann{value, ..}) |
+            Node::InvalidToken(Ann{value, ..}) if scope.content_mode == ContentMode::Text => {
+                escape_latex_text(&value)
+            }
             Node::Text(Ann{value, ..}) => {
                 value
             }
@@ -252,8 +481,15 @@ impl Node {
             Node::InvalidToken(Ann{value, ..}) => {
                 value
             }
-            Node::Drawing(str) => {
-                unimplemented!()
+            Node::Drawing(drawing) if drawing.is_empty() => {
+                String::new()
+            }
+            Node::Drawing(drawing) => {
+                let path = env.add_drawing(drawing);
+                format!(
+                    "\n\\begin{{center}}\\includegraphics[width=\\linewidth]{{{}}}\\end{{center}}\n",
+                    path.to_string_lossy(),
+                )
             }
             Node::Fragment(xs) => {
                 xs  .into_iter()
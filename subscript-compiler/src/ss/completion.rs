@@ -0,0 +1,289 @@
+//! Context aware completions, i.e. what commands (or attributes) are valid at
+//! a given position in a source file.
+//!
+//! Completions are derived from the same command declarations the compiler
+//! uses, so e.g. `\row` is only suggested when nested under `\table`, and
+//! symbolic mode commands are only suggested within `\math{…}`, `\equation{…}`
+//! and so forth.
+use itertools::Itertools;
+use crate::ss::{Ann, Node, ResourceEnv, SemanticScope};
+use crate::ss::cmd_decl::{AttributeValueType, CmdDeclaration};
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// DATA TYPES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+pub struct CompletionItem {
+    /// E.g. `\row` for commands or `src` for attributes.
+    pub label: String,
+    pub kind: CompletionKind,
+    /// The byte index of where the partially typed `label` starts (i.e. the
+    /// text from here up to the cursor should be replaced).
+    pub replace_start: usize,
+    pub signatures: Vec<String>,
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum CompletionKind {
+    Command,
+    Attribute {
+        required: bool,
+        /// A `None` value is a flag attribute, e.g. `\note[boxed]{…}`.
+        value_ty: Option<AttributeValueType>,
+    },
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// COMPLETIONS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Parses and processes the given source, and returns the completions at the
+/// given byte index.
+pub fn completions_for_source(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    source: &str,
+    byte_index: usize,
+) -> Vec<CompletionItem> {
+    let ast = crate::ss::parser::parse_source(env, scope, source).apply_commands(env, scope);
+    completions(env, scope, source, &ast, byte_index)
+}
+
+/// Same as `completions_for_source`, where `ast` is the already processed
+/// (i.e. `apply_commands`) tree of `source`.
+pub fn completions(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    source: &str,
+    ast: &Node,
+    byte_index: usize,
+) -> Vec<CompletionItem> {
+    let byte_index = floor_char_boundary(source, byte_index);
+    if let Some(prefix_start) = command_prefix_start(source, byte_index) {
+        let scope = scope_at(env, scope, ast, prefix_start);
+        return command_completions(&scope, &source[prefix_start..byte_index], prefix_start)
+    }
+    if let Some(context) = attribute_context(source, byte_index) {
+        let scope = scope_at(env, scope, ast, context.ident_start);
+        return attribute_completions(&scope, &context)
+    }
+    Vec::new()
+}
+
+fn command_completions(
+    scope: &SemanticScope,
+    prefix: &str,
+    replace_start: usize,
+) -> Vec<CompletionItem> {
+    scope.cmd_decls
+        .iter()
+        .filter(|decl| decl.identifier.as_str().starts_with(prefix))
+        .filter(|decl| scope.match_cmd(&decl.parent_env))
+        // Overloads are merged, and `group_by` only groups adjacent ones.
+        .sorted_by(|l, r| l.identifier.as_str().cmp(r.identifier.as_str()))
+        .group_by(|decl| decl.identifier.clone())
+        .into_iter()
+        .map(|(identifier, decls)| {
+            let decls = decls.collect_vec();
+            CompletionItem {
+                label: identifier.as_str().to_owned(),
+                kind: CompletionKind::Command,
+                replace_start,
+                signatures: decls
+                    .iter()
+                    .flat_map(|decl| decl.signatures())
+                    .unique()
+                    .collect(),
+                documentation: decls
+                    .iter()
+                    .find_map(|decl| decl.documentation.clone()),
+            }
+        })
+        .collect()
+}
+
+fn attribute_completions(
+    scope: &SemanticScope,
+    context: &AttributeContext,
+) -> Vec<CompletionItem> {
+    let decls: Vec<&CmdDeclaration> = scope.cmd_decls
+        .iter()
+        .filter(|decl| decl.identifier.as_str() == context.identifier)
+        .filter(|decl| scope.match_cmd(&decl.parent_env))
+        .filter(|decl| !decl.ignore_attributes)
+        .collect();
+    decls
+        .iter()
+        .flat_map(|decl| decl.attributes.iter())
+        .filter(|(key, _)| key.key.starts_with(&context.prefix))
+        .filter(|(key, _)| !context.existing_keys.contains(&key.key))
+        .unique_by(|(key, _)| key.key.clone())
+        .map(|(key, value)| CompletionItem {
+            label: key.key.clone(),
+            kind: CompletionKind::Attribute {
+                required: key.is_required(),
+                value_ty: value.as_ref().map(|x| x.value_ty.clone()),
+            },
+            replace_start: context.prefix_start,
+            signatures: Vec::new(),
+            documentation: None,
+        })
+        .sorted_by(|l, r| l.label.cmp(&r.label))
+        .collect()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// SCOPE RESOLUTION
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The semantic scope at the given byte index, i.e. with all parent commands,
+/// content and layout modes of the enclosing command arguments.
+pub fn scope_at(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    node: &Node,
+    byte_index: usize,
+) -> SemanticScope {
+    let is_within = |node: &&Node| {
+        node.range()
+            .map(|x| x.start.byte_index < byte_index && byte_index < x.end.byte_index)
+            .unwrap_or(false)
+    };
+    let descend = |scope: &SemanticScope, nodes: &[Node]| {
+        nodes
+            .iter()
+            .find(is_within)
+            .map(|x| scope_at(env, scope, x, byte_index))
+            .unwrap_or_else(|| scope.clone())
+    };
+    match node {
+        Node::Cmd(cmd) => {
+            // Only the arguments are within the child scope, not the
+            // identifier itself.
+            match cmd.arguments.iter().find(is_within) {
+                Some(argument) if scope.get_cmd_decl(env, cmd).is_some() => {
+                    let child_scope = scope.new_scope(env, cmd);
+                    scope_at(env, &child_scope, argument, byte_index)
+                }
+                Some(argument) => scope_at(env, scope, argument, byte_index),
+                None => scope.clone(),
+            }
+        }
+        Node::Bracket(Ann{value, ..}) => descend(scope, &value.children),
+        Node::Quotation(Ann{value, ..}) => descend(scope, &value.children),
+        Node::Fragment(xs) => descend(scope, xs),
+        _ => scope.clone(),
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// SOURCE TEXT HELPERS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Same as the parser’s notion of identifier characters (sans the leading
+/// slash).
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == ':' || c == '_' || c == '-'
+}
+
+fn floor_char_boundary(source: &str, mut byte_index: usize) -> usize {
+    byte_index = byte_index.min(source.len());
+    while !source.is_char_boundary(byte_index) {
+        byte_index -= 1;
+    }
+    byte_index
+}
+
+/// If the cursor is at the end of a partially typed command (e.g. `\ro`),
+/// returns the byte index of the slash.
+fn command_prefix_start(source: &str, byte_index: usize) -> Option<usize> {
+    let before = &source[..byte_index];
+    let ident_start = before
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_ident_char(*c))
+        .last()
+        .map(|(ix, _)| ix)
+        .unwrap_or(byte_index);
+    before[..ident_start]
+        .ends_with('\\')
+        .then(|| ident_start - 1)
+}
+
+struct AttributeContext {
+    /// The command name, including the slash.
+    identifier: String,
+    /// The byte index of the command identifier.
+    ident_start: usize,
+    /// The partially typed attribute key.
+    prefix: String,
+    prefix_start: usize,
+    /// Keys that are already given before the cursor.
+    existing_keys: Vec<String>,
+}
+
+/// If the cursor is within the attribute brackets of a command (e.g.
+/// `\img[src="…", wi`) and positioned at a key (not a value), returns the
+/// relevant info.
+fn attribute_context(source: &str, byte_index: usize) -> Option<AttributeContext> {
+    let before = &source[..byte_index];
+    let mut in_quotes = false;
+    let mut open_ix = None;
+    for (ix, c) in before.char_indices().rev() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '[' if !in_quotes => {
+                open_ix = Some(ix);
+                break
+            }
+            // Attributes are never nested within other brackets.
+            ']' | '{' | '}' if !in_quotes => return None,
+            _ => (),
+        }
+    }
+    let open_ix = open_ix?;
+    let name = before[..open_ix]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_ident_char(*c))
+        .last()
+        .map(|(ix, _)| ix)?;
+    if !before[..name].ends_with('\\') {
+        return None
+    }
+    let ident_start = name - 1;
+    let mut existing_keys = Vec::new();
+    let mut segment_start = open_ix + 1;
+    let mut in_quotes = false;
+    for (ix, c) in before[open_ix + 1..].char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                let segment = &before[segment_start..open_ix + 1 + ix];
+                let key = segment.split('=').next().unwrap_or("").trim();
+                if !key.is_empty() {
+                    existing_keys.push(key.to_owned());
+                }
+                segment_start = open_ix + 1 + ix + 1;
+            }
+            _ => (),
+        }
+    }
+    let segment = &before[segment_start..];
+    if in_quotes || segment.contains('=') {
+        return None
+    }
+    let prefix = segment.trim_start();
+    if !prefix.chars().all(is_ident_char) {
+        return None
+    }
+    Some(AttributeContext {
+        identifier: source[ident_start..open_ix].to_owned(),
+        ident_start,
+        prefix: prefix.to_owned(),
+        prefix_start: byte_index - prefix.len(),
+        existing_keys,
+    })
+}
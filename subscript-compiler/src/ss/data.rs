@@ -0,0 +1,239 @@
+//! Tabular data from CSV files, for `\dataTable` (or `\include`d CSV files)
+//! and `\chart`, e.g.
+//! ```text
+//! \dataTable[src="trials.csv", columns="Time, Mass", sort="-Mass", decimals=2]
+//! \chart[src="trials.csv", type="line", x="Time", y="Mass"]
+//! ```
+//! Columns are referred to by their name (i.e. the last header row, ignoring
+//! whitespace) or their 1-based index.
+use std::cmp::Ordering;
+use std::path::Path;
+use itertools::Itertools;
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// CSV
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The delimiter of the given file, i.e. tabs for `.tsv` files, otherwise
+/// commas.
+pub fn default_delimiter(path: &Path) -> char {
+    match path.extension().and_then(|x| x.to_str()) {
+        Some("tsv") => '\t',
+        _ => ',',
+    }
+}
+
+/// Whether `\include` turns the given file into a table.
+pub fn is_data_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|x| x.to_str()), Some("csv") | Some("tsv"))
+}
+
+/// Parses RFC 4180 style records, i.e. fields may be quoted (e.g.
+/// `"1,5"`), where quotes are doubled (e.g. `"a ""b"""`) and line breaks are
+/// kept. Blank lines are skipped, and the error is about the first
+/// unterminated quote.
+pub fn parse_csv(source: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut chars = source.chars().peekable();
+    let mut line = 1;
+    // Whether the current record has any content, so blank lines are skipped.
+    let mut started = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.trim().is_empty() => {
+                let start_line = line;
+                field.clear();
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => return Err(format!("unterminated quote on line {start_line}")),
+                    }
+                }
+                // E.g. the space of `"a" ,b`.
+                while chars.peek().is_some_and(|x| *x != delimiter && *x != '\n' && *x != '\r') {
+                    chars.next();
+                }
+                started = true;
+            }
+            c if c == delimiter => {
+                record.push(std::mem::take(&mut field).trim().to_owned());
+                started = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => (),
+            '\n' | '\r' => {
+                line += 1;
+                if started || !field.trim().is_empty() {
+                    record.push(std::mem::take(&mut field).trim().to_owned());
+                    records.push(std::mem::take(&mut record));
+                }
+                field.clear();
+                started = false;
+            }
+            c => field.push(c),
+        }
+    }
+    if started || !field.trim().is_empty() {
+        record.push(field.trim().to_owned());
+        records.push(record);
+    }
+    Ok(records)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TABLES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Table {
+    /// Usually one row, or none.
+    pub header: Vec<Vec<String>>,
+    /// Of the same width as the header rows, i.e. short rows are padded.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// E.g. `1.5`, `-2e3` or `+7`, but not `inf` or `NaN`.
+pub fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let is_numeric = !value.is_empty() && value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    if !is_numeric {
+        return None
+    }
+    value.parse::<f64>().ok().filter(|x| x.is_finite())
+}
+
+/// E.g. `1,234.50` for `1234.5` with 2 decimals and thousands separators.
+/// Without `decimals` the number is kept as written (except for the
+/// separators).
+pub fn format_number(value: &str, decimals: Option<usize>, thousands: bool) -> String {
+    let number = match parse_number(value) {
+        Some(number) => number,
+        None => return value.to_owned(),
+    };
+    let text = match decimals {
+        Some(decimals) => format!("{number:.decimals$}"),
+        None => value.trim().trim_start_matches('+').to_owned(),
+    };
+    if !thousands || text.contains(['e', 'E']) {
+        return text
+    }
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", text.as_str()),
+    };
+    let (integer, fraction) = match digits.find('.') {
+        Some(ix) => digits.split_at(ix),
+        None => (digits, ""),
+    };
+    let grouped = integer
+        .chars()
+        .rev()
+        .chunks(3)
+        .into_iter()
+        .map(|x| x.collect::<String>())
+        .join(",")
+        .chars()
+        .rev()
+        .collect::<String>();
+    format!("{sign}{grouped}{fraction}")
+}
+
+impl Table {
+    /// The first `header_rows` records are the header.
+    pub fn new(mut records: Vec<Vec<String>>, header_rows: usize) -> Table {
+        let width = records.iter().map(Vec::len).max().unwrap_or(0);
+        for record in records.iter_mut() {
+            record.resize(width, String::new());
+        }
+        let header_rows = header_rows.min(records.len());
+        let rows = records.split_off(header_rows);
+        Table {header: records, rows}
+    }
+    pub fn width(&self) -> usize {
+        self.header.first().or(self.rows.first()).map_or(0, Vec::len)
+    }
+    /// The last header row, or the 1-based indices without a header.
+    pub fn column_names(&self) -> Vec<String> {
+        match self.header.last() {
+            Some(names) => names.clone(),
+            None => (1..=self.width()).map(|x| x.to_string()).collect(),
+        }
+    }
+    /// By name (ignoring whitespace, since attribute values drop it) or by
+    /// 1-based index.
+    pub fn find_column(&self, name: &str) -> Option<usize> {
+        let normalize = |x: &str| x.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        let name = normalize(name);
+        if let Some(ix) = self.column_names().iter().position(|x| normalize(x) == name) {
+            return Some(ix)
+        }
+        name.parse::<usize>().ok().filter(|x| (1..=self.width()).contains(x)).map(|x| x - 1)
+    }
+    /// E.g. `Time, Mass` or `1, 3-5`.
+    pub fn find_columns(&self, spec: &str) -> Result<Vec<usize>, String> {
+        let mut columns = Vec::new();
+        for item in spec.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            if let Some(ix) = self.find_column(item) {
+                columns.push(ix);
+                continue
+            }
+            let range = item
+                .split_once('-')
+                .and_then(|(a, b)| Some((self.find_column(a)?, self.find_column(b)?)))
+                .filter(|(a, b)| a <= b);
+            match range {
+                Some((a, b)) => columns.extend(a..=b),
+                None => return Err(format!("unknown column `{item}`")),
+            }
+        }
+        Ok(columns)
+    }
+    /// The given columns, in the given order.
+    pub fn select(&self, columns: &[usize]) -> Table {
+        let pick = |row: &Vec<String>| columns.iter().map(|ix| row[*ix].clone()).collect_vec();
+        Table {
+            header: self.header.iter().map(pick).collect(),
+            rows: self.rows.iter().map(pick).collect(),
+        }
+    }
+    /// Whether the non-empty cells of the column are numbers (and there are
+    /// any).
+    pub fn is_numeric(&self, column: usize) -> bool {
+        let mut values = self.rows.iter().map(|x| x[column].as_str()).filter(|x| !x.trim().is_empty()).peekable();
+        values.peek().is_some() && values.all(|x| parse_number(x).is_some())
+    }
+    /// Numerically for numeric columns, otherwise alphabetically (ignoring
+    /// case). Empty cells come last either way, and the sort is stable.
+    pub fn sort_by(&mut self, column: usize, descending: bool) {
+        let numeric = self.is_numeric(column);
+        self.rows.sort_by(|a, b| {
+            let (a, b) = (a[column].trim(), b[column].trim());
+            match (a.is_empty(), b.is_empty()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Greater,
+                (false, true) => return Ordering::Less,
+                (false, false) => (),
+            }
+            let ordering = if numeric {
+                parse_number(a).partial_cmp(&parse_number(b)).unwrap_or(Ordering::Equal)
+            } else {
+                a.to_lowercase().cmp(&b.to_lowercase())
+            };
+            if descending {ordering.reverse()} else {ordering}
+        });
+    }
+}
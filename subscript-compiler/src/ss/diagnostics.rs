@@ -0,0 +1,164 @@
+//! Errors and warnings found while compiling a source file.
+//!
+//! Every compiler stage (parser, post-parser, `\include` handling and
+//! code-gen) reports into the `ResourceEnv` of the given compilation (see
+//! `ResourceEnv::report`), and it’s up to the caller to print (or otherwise
+//! present) what was collected.
+use std::path::{Path, PathBuf};
+use itertools::Itertools;
+use serde::{Serialize, Deserialize};
+use crate::ss::{CharRange, SemanticScope};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// The file the `range` points into, if any.
+    pub file_path: Option<PathBuf>,
+    /// Source locations are lost for nodes created during processing, so
+    /// this may be `None`.
+    pub range: Option<CharRange>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            file_path: None,
+            range: None,
+        }
+    }
+    pub fn warning(message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            message: message.into(),
+            file_path: None,
+            range: None,
+        }
+    }
+    pub fn with_range(mut self, range: Option<CharRange>) -> Self {
+        self.range = range;
+        self
+    }
+    /// Sets the file path to the scope’s current file.
+    pub fn with_scope(mut self, scope: &SemanticScope) -> Self {
+        self.file_path = scope.file_path.clone();
+        self
+    }
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+    /// Renders the diagnostic in a `file:line:column` format, followed by an
+    /// excerpt of the offending source line (if available). E.g.
+    /// ```text
+    /// error: unmatched `}`
+    ///   --> pages/index.ss:3:12
+    ///    |
+    ///  3 | \h1{Hello}}
+    ///    |           ^
+    /// ```
+    pub fn render(&self, source: Option<&str>) -> String {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let mut output = format!("{severity}: {}", self.message);
+        let file_path = self.file_path
+            .as_ref()
+            .map(|x| x.to_string_lossy().to_string());
+        let location = match (self.range, source) {
+            (Some(range), Some(source)) => Some(SourceLocation::new(source, range)),
+            _ => None,
+        };
+        match (file_path, location.as_ref()) {
+            (Some(file_path), Some(location)) => {
+                output.push_str(&format!(
+                    "\n  --> {file_path}:{}:{}",
+                    location.line,
+                    location.column,
+                ));
+            }
+            (Some(file_path), None) => {
+                output.push_str(&format!("\n  --> {file_path}"));
+            }
+            (None, _) => (),
+        }
+        if let Some(location) = location {
+            let gutter = location.line.to_string().len();
+            let padding = " ".repeat(gutter);
+            let marker = "^".repeat(location.width.max(1));
+            output.push_str(&format!(
+                "\n{padding} |\n{} | {}\n{padding} | {}{marker}",
+                location.line,
+                location.line_str,
+                " ".repeat(location.column - 1),
+            ));
+        }
+        output
+    }
+}
+
+struct SourceLocation<'a> {
+    /// 1-based.
+    line: usize,
+    /// 1-based, in characters.
+    column: usize,
+    /// Number of characters to underline, within `line_str`.
+    width: usize,
+    line_str: &'a str,
+}
+
+impl<'a> SourceLocation<'a> {
+    fn new(source: &'a str, range: CharRange) -> Self {
+        let start = range.start.byte_index.min(source.len());
+        let end = range.end.byte_index.clamp(start, source.len());
+        let line_start = source[..start].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let line_end = source[start..].find('\n').map(|x| start + x).unwrap_or(source.len());
+        let line_str = source[line_start..line_end].trim_end_matches('\r');
+        let (line, column) = range.start.line_column(source);
+        let width = source[start..end.min(line_end)].chars().count();
+        SourceLocation {line, column, width, line_str}
+    }
+}
+
+/// Sorted by file and source position, with duplicates removed (e.g. the same
+/// included file may be processed by multiple pages).
+pub fn normalize(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .unique()
+        .sorted_by_key(|x| {
+            let position = x.range.map(|x| x.start.byte_index);
+            (x.file_path.clone(), position, x.severity)
+        })
+        .collect()
+}
+
+/// Prints all diagnostics to stderr, along with excerpts from their source
+/// files.
+pub fn print_diagnostics(diagnostics: &[Diagnostic]) {
+    let mut sources: std::collections::HashMap<&Path, Option<String>> = Default::default();
+    for diagnostic in diagnostics {
+        let source = diagnostic.file_path
+            .as_deref()
+            .and_then(|path| {
+                sources
+                    .entry(path)
+                    .or_insert_with(|| std::fs::read_to_string(path).ok())
+                    .as_deref()
+            });
+        eprintln!("{}\n", diagnostic.render(source));
+    }
+    let errors = diagnostics.iter().filter(|x| x.is_error()).count();
+    let warnings = diagnostics.len() - errors;
+    if errors > 0 || warnings > 0 {
+        eprintln!("{errors} error(s), {warnings} warning(s)");
+    }
+}
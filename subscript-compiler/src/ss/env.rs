@@ -12,6 +12,12 @@ use crate::ss::cmd_decl::CmdDeclaration;
 use crate::ss::cmd_decl::ParentEnvNamespaceDecl;
 use crate::ss::CmdCall;
 use crate::ss::cmd_decl::CmdCodegen;
+use crate::ss::macro_decl::MacroDeclarations;
+use crate::ss::labels::LabelIndex;
+use crate::ss::bibliography::Bibliography;
+use crate::ss::source_map::SourceMap;
+use crate::ss::diagnostics::Diagnostic;
+use crate::compiler::assets::{Asset, AssetOptions};
 use crate::data::Store;
 
 use super::Attributes;
@@ -67,6 +73,15 @@ impl CommandDeclarations {
     pub fn len(&self) -> usize {
         self.map.len()
     }
+    pub fn get(&self, ident: &Ident) -> Option<&[CmdDeclaration]> {
+        self.map.get(ident).map(Vec::as_slice)
+    }
+    pub fn contains(&self, ident: &Ident) -> bool {
+        self.map.contains_key(ident)
+    }
+    pub fn iter(&self) -> impl Iterator<Item=&CmdDeclaration> {
+        self.map.values().flatten()
+    }
 }
 
 
@@ -79,84 +94,125 @@ pub struct ResourceEnv(Store<ResourceEnvData>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ResourceEnvData {
-    pub image_paths: Vec<ImagePath>,
+    /// Keyed by the canonical path of the original file, see
+    /// `crate::compiler::assets`.
+    pub assets: HashMap<PathBuf, Asset>,
+    pub asset_options: AssetOptions,
     pub includes: HashMap<PathBuf, IncludeCache>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// The files each source file pulls in (VIA `\include`, `\img`, etc.),
+    /// keyed by canonical paths, see `crate::compiler::incremental`.
+    pub dependencies: HashMap<PathBuf, HashSet<PathBuf>>,
 }
 
 impl ResourceEnv {
+    pub fn with_asset_options(self, options: AssetOptions) -> Self {
+        self.0.map_mut(move |x| x.asset_options = options);
+        self
+    }
     pub fn empty_images(&self) -> bool {
-        self.0.map(|x| x.image_paths.is_empty())
+        self.0.map(|x| x.assets.is_empty())
     }
-    // pub fn merge(mut self, other: ResourceEnv) {
-    //     // let left = self.0.into_clone().image_paths;
-    //     // let right = other.0.into_clone().image_paths;
-    //     // self
-    //     unimplemented!()
-    // }
+    /// Returns the URL of the (content-addressed) image, see
+    /// `crate::compiler::assets`.
     pub fn add_image(&self, scope: &SemanticScope, img_src: impl AsRef<Path>) -> Option<String> {
         let abs_file_file = img_src.as_ref().canonicalize().ok()?;
-        let abs_base_path = scope.base_path.as_ref().unwrap();
-        let abs_base_path = abs_base_path.canonicalize().unwrap();
-        let rel_file_file = abs_file_file.strip_prefix(&abs_base_path);
-        match rel_file_file {
-            Ok(rel_file_file) => {
-                let rel_file_file = {
-                    let mut path = PathBuf::from("static-assets");
-                    path.push(rel_file_file);
-                    path
-                };
-                let image_paths = ImagePath {
-                    original: img_src.as_ref().to_path_buf(),
-                    rel_path: rel_file_file.clone(),
-                    abs_path: abs_file_file,
-                    route_prefix: scope.route_prefix.clone(),
-                };
-                self.0.map_mut(move |x| x.image_paths.push(image_paths.clone()));
-                Some({
-                    let rel_file_file = rel_file_file.to_str().unwrap().to_owned();
-                    if let Some(prefix) = scope.route_prefix.as_ref() {
-                        format!("/{prefix}/{rel_file_file}")
-                        // format!("/{rel_file_file}")
-                    } else {
-                        format!("/{rel_file_file}")
-                    }
-                })
-            }
-            Err(msg) => {
-                println!("WHAT? {abs_base_path:?} / {abs_file_file:?}: {msg}");
-                None
+        self.add_dependency(scope, &abs_file_file);
+        // An empty base path is the current directory, e.g. for `--source
+        // index.ss`.
+        let abs_base_path = scope.base_path
+            .as_deref()
+            .map(|x| if x.as_os_str().is_empty() { Path::new(".") } else { x })
+            .and_then(|x| x.canonicalize().ok());
+        let abs_base_path = match abs_base_path {
+            Some(abs_base_path) => abs_base_path,
+            None => {
+                self.report(
+                    Diagnostic::error(format!(
+                        "failed to resolve the project directory of image {}",
+                        abs_file_file.display(),
+                    ))
+                    .with_scope(scope)
+                );
+                return None
             }
+        };
+        if !abs_file_file.starts_with(&abs_base_path) {
+            self.report(
+                Diagnostic::warning(format!(
+                    "image {} is outside of the project directory {}",
+                    abs_file_file.display(),
+                    abs_base_path.display(),
+                ))
+                .with_scope(scope)
+            );
+            return None
         }
-    }
-    pub fn image_paths(&self) -> Vec<ImagePath> {
-        self.0.map(|x| x.image_paths.clone())
-    }
-    pub fn write_file_paths(&self, output_dir: impl AsRef<Path>) {
-        let image_paths = self.0.map(|x| x.image_paths.clone());
-        for ImagePath{original, rel_path, abs_path, route_prefix} in image_paths {
-            let mut out_img_path = output_dir.as_ref().to_path_buf().clone();
-            out_img_path.push(&rel_path);
-            if let Some(parent) = out_img_path.parent() {
-                std::fs::create_dir_all(&parent).unwrap();
+        // Images shared between pages are hashed once.
+        let asset = match self.0.map(|x| x.assets.get(&abs_file_file).cloned()) {
+            Some(asset) => asset,
+            None => {
+                let options = self.0.map(|x| x.asset_options.clone());
+                match Asset::new(&abs_file_file, &options) {
+                    Ok(asset) => asset,
+                    Err(error) => {
+                        self.report(
+                            Diagnostic::warning(format!(
+                                "failed to read the image {}: {error}",
+                                abs_file_file.display(),
+                            ))
+                            .with_scope(scope)
+                        );
+                        return None
+                    }
+                }
             }
-            let contents = std::fs::read(&abs_path).unwrap();
-            std::fs::write(out_img_path, contents).unwrap();
+        };
+        let rel_path = asset.rel_path();
+        self.add_assets(vec![asset]);
+        if let Some(prefix) = scope.route_prefix.as_ref() {
+            Some(format!("/{prefix}/{rel_path}"))
+        } else {
+            Some(format!("/{rel_path}"))
         }
     }
-    pub fn write_sym_links(&self, output_dir: impl AsRef<Path>) {
-        use std::os::unix::fs::symlink;
-        let image_paths = self.0.map(|x| x.image_paths.clone());
-        for ImagePath{original, rel_path, abs_path, route_prefix} in image_paths {
-            let mut out_img_path = output_dir.as_ref().to_path_buf().clone();
-            out_img_path.push(&rel_path);
-            if let Some(parent) = out_img_path.parent() {
-                std::fs::create_dir_all(&parent).unwrap();
-            }
-            if !out_img_path.exists() {
-                symlink(abs_path, out_img_path).unwrap();
+    /// E.g. the images of pages skipped by incremental builds.
+    pub fn add_assets(&self, assets: Vec<Asset>) {
+        self.0.map_mut(move |x| {
+            for asset in assets {
+                x.assets.insert(asset.source.clone(), asset);
             }
+        });
+    }
+    pub fn assets(&self) -> HashMap<PathBuf, Asset> {
+        self.0.map(|x| x.assets.clone())
+    }
+    pub fn report(&self, diagnostic: Diagnostic) {
+        self.0.map_mut(move |x| x.diagnostics.push(diagnostic));
+    }
+    /// Everything reported so far, see `crate::ss::diagnostics::normalize`.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.0.map(|x| x.diagnostics.clone())
+    }
+    pub fn has_errors(&self) -> bool {
+        self.0.map(|x| x.diagnostics.iter().any(Diagnostic::is_error))
+    }
+    /// Records that the scope’s current file depends on the given file.
+    pub fn add_dependency(&self, scope: &SemanticScope, path: impl AsRef<Path>) {
+        if let Some(file_path) = scope.file_path.as_ref() {
+            let file_path = canonical_path(file_path);
+            let path = canonical_path(path);
+            self.0.map_mut(move |x| {
+                x.dependencies
+                    .entry(file_path)
+                    .or_default()
+                    .insert(path);
+            });
         }
     }
+    pub fn dependencies(&self) -> HashMap<PathBuf, HashSet<PathBuf>> {
+        self.0.map(|x| x.dependencies.clone())
+    }
     pub fn get_include_cache(&self, path: impl AsRef<Path>) -> Option<IncludeCache> {
         self.0.map(move |data| {
             data.includes.get(path.as_ref()).map(Clone::clone)
@@ -186,12 +242,19 @@ impl ResourceEnv {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct ImagePath {
-    original: PathBuf,
-    rel_path: PathBuf,
-    abs_path: PathBuf,
-    route_prefix: Option<String>,
+/// Files that don’t exist (yet, or anymore) are resolved relative to their
+/// nearest existing (canonical) ancestor if possible.
+pub fn canonical_path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    path.canonicalize()
+        .ok()
+        .or_else(|| {
+            path.ancestors().skip(1).find_map(|ancestor| {
+                let rest = path.strip_prefix(ancestor).ok()?;
+                Some(ancestor.canonicalize().ok()?.join(rest))
+            })
+        })
+        .unwrap_or_else(|| path.to_path_buf())
 }
 
 #[derive(Debug, Clone)]
@@ -237,6 +300,8 @@ pub struct SemanticScope {
     pub scope: Vec<Ident>,
     pub content_mode: ContentMode,
     pub layout_mode: LayoutMode,
+    /// Macros defined VIA `\define` so far, see `crate::ss::macro_decl`.
+    pub macros: MacroDeclarations,
 }
 
 impl SemanticScope {
@@ -263,6 +328,7 @@ impl SemanticScope {
             scope: Vec::default(),
             content_mode: ContentMode::default(),
             layout_mode: LayoutMode::default(),
+            macros: MacroDeclarations::default(),
         }
     }
     pub fn with_route_prefix(mut self, route_prefix: impl AsRef<str>) -> Self {
@@ -281,6 +347,7 @@ impl SemanticScope {
             scope: Vec::default(),
             content_mode: ContentMode::default(),
             layout_mode: LayoutMode::default(),
+            macros: MacroDeclarations::default(),
         }
     }
     /// **WARNING**: This is for testing only. Depending on what you’re doing,
@@ -303,6 +370,7 @@ impl SemanticScope {
             scope: Vec::default(),
             content_mode: ContentMode::default(),
             layout_mode: LayoutMode::default(),
+            macros: MacroDeclarations::default(),
         };
         scope
     }
@@ -379,6 +447,11 @@ impl SemanticScope {
             .iter()
             .any(|x| x.is_heading_node())
     }
+    /// I.e. within `\code`, `\pre` or `\geogebra` (whose commands are passed
+    /// along), where text is shown as written.
+    pub fn in_code_scope(&self) -> bool {
+        self.has_parent("\\code") || self.has_parent("\\pre") || self.has_parent("\\geogebra")
+    }
     pub fn get_cmd_decl<'a>(&self, env: &ResourceEnv, cmd_call: &CmdCall) -> Option<&CmdDeclaration> {
         let cmd_set = self.cmd_decls.map.get(&cmd_call.identifier.value);
         if let Some(cmd_set) = cmd_set {
@@ -425,7 +498,17 @@ impl SemanticScope {
         let cmd_decl = self.get_cmd_decl(&env.resource_env, &cmd_call)?;
         // let code_gen = cmd_decl.processors;
         let sub_scope = self.new_scope(&env.resource_env, &cmd_call);
-        return Some(cmd_decl.processors.to_latex(env, &sub_scope, cmd_call))
+        // Labeled targets, see `crate::ss::labels`.
+        let anchor = cmd_call.attributes
+            .get_str_value("id")
+            .filter(|id| {
+                let label = env.labels.get(id);
+                self.content_mode == ContentMode::Text && label.map(|x| env.labels.is_local(x)).unwrap_or(false)
+            })
+            .map(|id| format!("\\phantomsection\\label{{{id}}}"))
+            .unwrap_or_default();
+        let latex = cmd_decl.processors.to_latex(env, &sub_scope, cmd_call);
+        Some(format!("{anchor}{latex}"))
     }
     pub fn new_scope(
         &self,
@@ -469,6 +552,23 @@ impl Default for SemanticScope {
 pub struct HtmlCodegenEnv {
     pub math_env: Store<MathEnv>,
     pub resource_env: ResourceEnv,
+    /// For resolving `\ref`s, see `crate::ss::labels`.
+    pub labels: LabelIndex,
+    /// For resolving `\cite`s, see `crate::ss::bibliography`.
+    pub bibliography: Bibliography,
+    /// Where each `href` and `src` came from, see
+    /// `crate::compiler::links`.
+    pub links: Store<Vec<LinkSource>>,
+    /// See `crate::ss::source_map`.
+    pub source_map: Option<SourceMap>,
+}
+
+/// An `href` or `src` attribute along with its source location.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LinkSource {
+    pub value: String,
+    pub file_path: Option<PathBuf>,
+    pub range: Option<crate::ss::CharRange>,
 }
 
 impl HtmlCodegenEnv {
@@ -477,41 +577,137 @@ impl HtmlCodegenEnv {
             ..Default::default()
         }
     }
+    /// So e.g. diagnostics are reported to the same environment that was used
+    /// for processing the AST.
+    pub fn with_resource_env(mut self, resource_env: &ResourceEnv) -> Self {
+        self.resource_env = resource_env.clone();
+        self
+    }
+    pub fn with_labels(mut self, labels: &LabelIndex) -> Self {
+        self.labels = labels.clone();
+        self
+    }
+    pub fn with_bibliography(mut self, bibliography: &Bibliography) -> Self {
+        self.bibliography = bibliography.clone();
+        self
+    }
+    pub fn with_source_map(mut self, source_map: &SourceMap) -> Self {
+        self.source_map = Some(source_map.clone());
+        self
+    }
+    /// The given range is that of the command, see `MathEnv::new_id`.
     pub fn add_inline_math_entry<'a>(
         &self,
+        scope: &SemanticScope,
+        range: Option<crate::ss::CharRange>,
         code: String,
         unique: bool,
     ) -> crate::html::Element {
-        self.math_env.map_mut(move |math_env| {
-            math_env.add_inline_entry(code.clone(), unique)
-        })
+        let element = self.math_env.map_mut(|math_env| {
+            let id = math_env.new_id(scope, range, &code);
+            math_env.add_inline_entry(id, code.clone(), unique)
+        });
+        self.prerender_math(scope, range, element, &code, LayoutMode::Inline)
     }
     pub fn add_block_entry<'a>(
         &self,
+        scope: &SemanticScope,
+        range: Option<crate::ss::CharRange>,
         code: String,
         unique: bool,
     ) -> crate::html::Element {
-        self.math_env.map_mut(move |math_env| {
-            math_env.add_block_entry(code.clone(), unique)
-        })
+        let element = self.math_env.map_mut(|math_env| {
+            let id = math_env.new_id(scope, range, &code);
+            math_env.add_block_entry(id, code.clone(), unique)
+        });
+        self.prerender_math(scope, range, element, &code, LayoutMode::Block)
+    }
+    /// Fills the given placeholder with the rendered math, or with the
+    /// (escaped) source code if KaTeX rejects it.
+    fn prerender_math(
+        &self,
+        scope: &SemanticScope,
+        range: Option<crate::ss::CharRange>,
+        mut element: crate::html::Element,
+        code: &str,
+        mode: LayoutMode,
+    ) -> crate::html::Element {
+        match render_math(code, mode) {
+            Ok(math) => {
+                element.children.push(crate::html::Node::Text(math));
+            }
+            Err(error) => {
+                self.resource_env.report(
+                    Diagnostic::warning(format!("invalid math `{code}`: {error}"))
+                        .with_range(range)
+                        .with_scope(scope)
+                );
+                element.attributes.insert(String::from("data-math-error"), String::new());
+                element.children.push(crate::html::Node::Text(
+                    crate::html::utils::escape_html_text(code)
+                ));
+            }
+        }
+        element
     }
     pub fn math_env_clone(&self) -> MathEnv {
         self.math_env.into_clone()
     }
+    pub fn add_link(&self, scope: &SemanticScope, range: Option<crate::ss::CharRange>, value: &str) {
+        let link = LinkSource {
+            value: value.to_owned(),
+            file_path: scope.file_path.clone(),
+            range,
+        };
+        self.links.map_mut(|links| links.push(link));
+    }
+    pub fn links_clone(&self) -> Vec<LinkSource> {
+        self.links.into_clone()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct MathEnv {
     pub entries: Vec<MathCodeEntry>,
+    /// The IDs of the page so far, see `MathEnv::new_id`.
+    pub used_ids: HashSet<String>,
 }
 
 impl MathEnv {
+    /// Derived from the file (relative to the project directory), source
+    /// position and code of the entry, so that rebuilding an unchanged page
+    /// yields the same HTML, and editing a page only changes the IDs of the
+    /// math that was edited. Files included more than once get a suffix.
+    pub fn new_id(
+        &mut self,
+        scope: &SemanticScope,
+        range: Option<crate::ss::CharRange>,
+        code: &str,
+    ) -> String {
+        let file_path = scope.file_path.as_ref().map(|file_path| {
+            scope.base_path
+                .as_ref()
+                .and_then(|base_path| file_path.strip_prefix(base_path).ok())
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .replace('\\', "/")
+        });
+        let key = format!("{file_path:?} {range:?} {code}");
+        let id = format!("math-{}", crate::compiler::incremental::hash_bytes(key.as_bytes()));
+        let mut result = id.clone();
+        let mut counter = 1;
+        while !self.used_ids.insert(result.clone()) {
+            counter += 1;
+            result = format!("{id}-{counter}");
+        }
+        result
+    }
     pub fn add_inline_entry<'a>(
         &mut self,
+        id: String,
         code: String,
         unique: bool,
     ) -> crate::html::Element {
-        let id = crate::utils::random_str_id();
         let mut attributes: HashMap<String, String> = Default::default();
         if unique {
             attributes.insert(String::from("id"), id.clone());
@@ -529,10 +725,10 @@ impl MathEnv {
     }
     pub fn add_block_entry<'a>(
         &mut self,
+        id: String,
         code: String,
         unique: bool,
     ) -> crate::html::Element {
-        let id = crate::utils::random_str_id();
         let mut attributes: HashMap<String, String> = Default::default();
         if unique {
             attributes.insert(String::from("id"), id.clone());
@@ -548,58 +744,6 @@ impl MathEnv {
             children: Vec::new(),
         }
     }
-    pub fn to_javascript(&self) -> String {
-        self.entries
-            .iter()
-            .map(|x| {
-                let code = format!("{:?}", x.code);
-                let id = x.id.clone();
-                let display_mode = match x.mode {
-                    LayoutMode::Block => true,
-                    LayoutMode::Both => true,
-                    LayoutMode::Inline => false,
-                };
-                // let options: &[(String, String)] = &[
-                //     (String::from("throwOnError"), String::from("true")),
-                //     (String::from("displayMode"), match x.mode {
-                //         LayoutMode::Block => String::from("true"),
-                //         LayoutMode::Both => String::from("true"),
-                //         LayoutMode::Inline => String::from("false"),
-                //     }),
-                //     (String::from("strict"), String::from("false")),
-                //     (String::from("trust"), String::from("true")),
-                // ];
-                // let options = options
-                //     .into_iter()
-                //     .map(|(k, v)| format!("{k}:{v}"))
-                //     .join(",");
-                // let options = format!("{{options}}");
-                let options: &[(String, String)] = &[
-                    (String::from("throwOnError"), String::from("false")),
-                    (String::from("displayMode"), match x.mode {
-                        LayoutMode::Block => String::from("true"),
-                        LayoutMode::Both => String::from("true"),
-                        LayoutMode::Inline => String::from("false"),
-                    }),
-                    (String::from("strict"), String::from("false")),
-                    (String::from("trust"), String::from("true")),
-                ];
-                let options = options
-                    .into_iter()
-                    .map(|(k, v)| format!("{k}: {v}"))
-                    .join(",");
-                let options = format!("{{{options}}}");
-                let render_code = if x.unique {
-                    format!("katex.render({code}, document.getElementById('{id}'), {options});\n")
-                } else {
-                    format!(
-                        "document.querySelectorAll('[data-math-target=\"{id}\"]').forEach(function(x){{katex.render({code}, x, {options});}})\n"
-                    )
-                };
-                format!("try{{\n{render_code}\n}}catch(msg){{console.log(\"Error\", msg)}}\n")
-            })
-            .join("\n")
-    }
 }
 
 #[derive(Debug, Clone)]
@@ -610,6 +754,36 @@ pub struct MathCodeEntry {
     pub unique: bool,
 }
 
+/// Renders the given TeX code to MathML at build time (VIA the KaTeX bundled
+/// with the `katex` crate). Browsers render MathML natively, so the output
+/// doesn’t depend on any stylesheets, fonts or scripts.
+pub fn render_math(code: &str, mode: LayoutMode) -> Result<String, String> {
+    let options = katex::Opts::builder()
+        .display_mode(mode != LayoutMode::Inline)
+        .output_type(katex::OutputType::Mathml)
+        .throw_on_error(true)
+        .trust(true)
+        .build()
+        .unwrap();
+    katex::render_with_opts(code, options).map_err(|error| match error {
+        // E.g. `String("ParseError: KaTeX parse error: Expected 'EOF', got '}'
+        // at position 4: x^2}̲")`, the source excerpt is redundant here.
+        katex::Error::JsExecError(message) => {
+            let message = message
+                .split_once("KaTeX parse error: ")
+                .map(|(_, x)| x)
+                .unwrap_or(&message);
+            message
+                .split(" at position ")
+                .next()
+                .unwrap_or(message)
+                .trim_end_matches("\")")
+                .to_owned()
+        }
+        error => error.to_string(),
+    })
+}
+
 
 
 // ――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
@@ -621,7 +795,13 @@ pub struct MathCodeEntry {
 pub struct LatexCodegenEnv {
     pub resource_env: ResourceEnv,
     // pub commands: CommandDeclarations,
-    // pub drawings: HashMap<String, crate::ss_drawing::Drawing>,
+    /// Drawings referenced by the generated document, keyed by their path
+    /// relative to the output file (see `LatexCodegenEnv::write_drawings`).
+    pub drawings: Store<HashMap<PathBuf, ss_freeform_format::DrawingDataModel>>,
+    /// For resolving `\ref`s, see `crate::ss::labels`.
+    pub labels: LabelIndex,
+    /// For resolving `\cite`s, see `crate::ss::bibliography`.
+    pub bibliography: Bibliography,
 }
 
 impl LatexCodegenEnv {
@@ -630,4 +810,52 @@ impl LatexCodegenEnv {
             ..Default::default()
         }
     }
+    pub fn with_resource_env(mut self, resource_env: &ResourceEnv) -> Self {
+        self.resource_env = resource_env.clone();
+        self
+    }
+    pub fn with_labels(mut self, labels: &LabelIndex) -> Self {
+        self.labels = labels.clone();
+        self
+    }
+    pub fn with_bibliography(mut self, bibliography: &Bibliography) -> Self {
+        self.bibliography = bibliography.clone();
+        self
+    }
+    /// Returns the path (relative to the output file) that the drawing will be
+    /// exported to as a PDF. Identical drawings share the same file.
+    pub fn add_drawing(&self, drawing: ss_freeform_format::DrawingDataModel) -> PathBuf {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        serde_json::to_string(&drawing).unwrap().hash(&mut hasher);
+        let path = PathBuf::from(format!("drawings/{:016x}.pdf", hasher.finish()));
+        let rel_path = path.clone();
+        self.drawings.map_mut(move |drawings| {
+            drawings.insert(rel_path, drawing);
+        });
+        path
+    }
+    pub fn write_drawings(&self, output_dir: impl AsRef<Path>) {
+        let drawings = self.drawings.into_clone();
+        for (rel_path, drawing) in drawings {
+            let mut out_path = output_dir.as_ref().to_path_buf();
+            out_path.push(&rel_path);
+            if let Some(parent) = out_path.parent() {
+                if let Err(error) = std::fs::create_dir_all(parent) {
+                    self.resource_env.report(Diagnostic::error(format!(
+                        "failed to create {}: {error}",
+                        parent.display(),
+                    )));
+                    continue
+                }
+            }
+            let pdf = drawing.to_pdf(&ss_freeform_format::ColorScheme::Light);
+            if let Err(error) = std::fs::write(&out_path, pdf) {
+                self.resource_env.report(Diagnostic::error(format!(
+                    "failed to write {}: {error}",
+                    out_path.display(),
+                )));
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,623 @@
+//! Static figures generated at compile time, e.g. `\plot`s and `\chart`s
+//! (see `crate::ss::plot` and `crate::ss::chart`). A figure is a handful of
+//! primitives in pixel coordinates (with the y axis pointing down, like SVG),
+//! which render to inline SVG for HTML, once per color scheme (like
+//! drawings, see the `data-svg-color-scheme` attribute), and to TikZ for
+//! LaTeX, so figures need no client-side scripts and also work in print.
+use itertools::Itertools;
+use ss_freeform_format::ColorScheme;
+use crate::html;
+use crate::html::utils::escape_html_text;
+use crate::ss::codegen::escape_latex_text;
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// DATA TYPES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Colors that differ between color schemes, except for `Paint::Custom`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    /// Text and such.
+    Foreground,
+    /// E.g. the background of legends.
+    Background,
+    Axis,
+    Grid,
+    /// The nth color of the series palette, e.g. for the nth function of a
+    /// plot.
+    Series(usize),
+    /// E.g. `#c74440`, for both color schemes.
+    Custom(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl LineStyle {
+    pub fn parse(value: &str) -> Option<LineStyle> {
+        match value.trim() {
+            "solid" => Some(LineStyle::Solid),
+            "dashed" => Some(LineStyle::Dashed),
+            "dotted" => Some(LineStyle::Dotted),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub paint: Paint,
+    pub width: f64,
+    pub style: LineStyle,
+}
+
+impl Stroke {
+    pub fn new(paint: Paint, width: f64) -> Self {
+        Stroke {paint, width, style: LineStyle::Solid}
+    }
+    pub fn with_style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Where text is placed relative to its position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// Centered below the position, e.g. x axis ticks.
+    Below,
+    /// Centered above the position.
+    Above,
+    /// Vertically centered left of the position, e.g. y axis ticks.
+    Left,
+    /// Vertically centered right of the position, e.g. legend entries.
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+    Line {
+        from: (f64, f64),
+        to: (f64, f64),
+        stroke: Stroke,
+    },
+    /// Disconnected segments, e.g. of `tan(x)`, each being a polyline.
+    Path {
+        segments: Vec<Vec<(f64, f64)>>,
+        stroke: Stroke,
+    },
+    Rect {
+        at: (f64, f64),
+        size: (f64, f64),
+        fill: Paint,
+        stroke: Option<Stroke>,
+    },
+    Text {
+        at: (f64, f64),
+        text: String,
+        anchor: Anchor,
+        paint: Paint,
+        /// Rotated counterclockwise, e.g. y axis labels.
+        vertical: bool,
+    },
+    /// E.g. the markers of scatter charts.
+    Circle {
+        at: (f64, f64),
+        radius: f64,
+        fill: Paint,
+    },
+    /// The given elements are clipped to the given rectangle, e.g. plotted
+    /// functions to the plot area.
+    Clip {
+        at: (f64, f64),
+        size: (f64, f64),
+        children: Vec<Element>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Figure {
+    pub width: f64,
+    pub height: f64,
+    /// A short description, e.g. `Plot of y = x^2`, for screen readers.
+    pub title: String,
+    pub elements: Vec<Element>,
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// COLORS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+const LIGHT_SERIES: &[&str] = &["#c74440", "#2d70b3", "#388c46", "#6042a6", "#fa7e19", "#000000"];
+/// Brighter versions of `LIGHT_SERIES`.
+const DARK_SERIES: &[&str] = &["#ff7b72", "#79c0ff", "#7ee787", "#d2a8ff", "#ffa657", "#f5f5f5"];
+
+impl Paint {
+    pub fn to_css(&self, scheme: &ColorScheme) -> String {
+        let color = match (self, scheme) {
+            (Paint::Foreground, ColorScheme::Light) => "#333333",
+            (Paint::Foreground, ColorScheme::Dark) => "#f5f5f5",
+            (Paint::Background, ColorScheme::Light) => "#ffffff",
+            (Paint::Background, ColorScheme::Dark) => "#313036",
+            (Paint::Axis, ColorScheme::Light) => "#555555",
+            (Paint::Axis, ColorScheme::Dark) => "#bbbbbb",
+            (Paint::Grid, ColorScheme::Light) => "#e5e5e5",
+            (Paint::Grid, ColorScheme::Dark) => "#4a4950",
+            (Paint::Series(ix), ColorScheme::Light) => LIGHT_SERIES[ix % LIGHT_SERIES.len()],
+            (Paint::Series(ix), ColorScheme::Dark) => DARK_SERIES[ix % DARK_SERIES.len()],
+            (Paint::Custom(color), _) => return escape_html_text(color),
+        };
+        color.to_owned()
+    }
+    /// E.g. `{rgb,255:red,199;green,68;blue,64}`, TikZ doesn’t know CSS
+    /// colors, so other custom colors are black.
+    fn to_tikz(&self) -> String {
+        let css = self.to_css(&ColorScheme::Light);
+        let rgb = parse_hex_color(&css).unwrap_or([0, 0, 0]);
+        format!("{{rgb,255:red,{};green,{};blue,{}}}", rgb[0], rgb[1], rgb[2])
+    }
+}
+
+/// E.g. `#c74440` or `#f00`.
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None
+    }
+    let channel = |x: &str| u8::from_str_radix(x, 16).ok();
+    match hex.len() {
+        3 => Some([
+            channel(&hex[0..1])? * 17,
+            channel(&hex[1..2])? * 17,
+            channel(&hex[2..3])? * 17,
+        ]),
+        6 => Some([channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?]),
+        _ => None,
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// AXES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The axes of plots and charts, i.e. everything but the data.
+#[derive(Debug, Clone)]
+pub struct Axes {
+    pub width: f64,
+    pub height: f64,
+    pub domain: (f64, f64),
+    pub range: (f64, f64),
+    pub grid: bool,
+    pub x_label: Option<String>,
+    pub y_label: Option<String>,
+    /// Labels of the x ticks at `0`, `1`, `2`, …, e.g. the categories of a
+    /// bar chart, instead of numbered ticks.
+    pub x_categories: Option<Vec<String>>,
+}
+
+/// The plot area within the figure, i.e. without the space for ticks and
+/// labels.
+pub struct Frame {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub domain: (f64, f64),
+    pub range: (f64, f64),
+}
+
+impl Frame {
+    pub fn x(&self, x: f64) -> f64 {
+        self.left + (x - self.domain.0) / (self.domain.1 - self.domain.0) * (self.right - self.left)
+    }
+    pub fn y(&self, y: f64) -> f64 {
+        self.bottom - (y - self.range.0) / (self.range.1 - self.range.0) * (self.bottom - self.top)
+    }
+    /// The given elements clipped to the plot area.
+    pub fn clip(&self, children: Vec<Element>) -> Element {
+        Element::Clip {
+            at: (self.left, self.top),
+            size: (self.right - self.left, self.bottom - self.top),
+            children,
+        }
+    }
+}
+
+/// E.g. `0.1`, `2` or `50`, so that there are about 5 to 10 ticks.
+fn tick_step(span: f64) -> f64 {
+    let rough = span / 8.0;
+    let magnitude = 10f64.powf(rough.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|x| x * magnitude)
+        .find(|x| *x >= rough)
+        .unwrap_or(10.0 * magnitude)
+}
+
+fn ticks((min, max): (f64, f64)) -> (f64, Vec<f64>) {
+    let step = tick_step(max - min);
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    let ticks = (first..=last).map(|ix| ix as f64 * step).collect();
+    (step, ticks)
+}
+
+/// E.g. `0.5` rather than `0.5000000001`.
+pub fn format_tick(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    let text = format!("{value:.decimals$}");
+    if text.trim_start_matches('-').chars().all(|c| c == '0' || c == '.') {
+        return String::from("0")
+    }
+    // A proper minus sign.
+    text.replace('-', "−")
+}
+
+/// The interval of the given values with some padding. With `trim` the
+/// extremes are ignored, which is robust against asymptotes (e.g. of `1/x`).
+pub fn fit_interval(values: &mut Vec<f64>, trim: bool) -> (f64, f64) {
+    values.retain(|x| x.is_finite());
+    if values.is_empty() {
+        return (-10.0, 10.0)
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let (lo, hi) = if trim {
+        let cut = values.len() / 50;
+        (values[cut], values[values.len() - 1 - cut])
+    } else {
+        (values[0], values[values.len() - 1])
+    };
+    if hi - lo < 1e-9 {
+        return (lo - 1.0, hi + 1.0)
+    }
+    let padding = (hi - lo) * 0.1;
+    (lo - padding, hi + padding)
+}
+
+impl Axes {
+    pub fn frame(&self) -> Frame {
+        Frame {
+            left: if self.y_label.is_some() {64.0} else {44.0},
+            top: 12.0,
+            right: self.width - 16.0,
+            bottom: self.height - if self.x_label.is_some() {44.0} else {26.0},
+            domain: self.domain,
+            range: self.range,
+        }
+    }
+    /// The grid, axes (at zero if visible), ticks and labels. Tick labels
+    /// are along the edges, so they don’t cover the data.
+    pub fn to_elements(&self, frame: &Frame) -> Vec<Element> {
+        let (domain, range) = (self.domain, self.range);
+        let mut elements = Vec::new();
+        let x_ticks = match self.x_categories.as_ref() {
+            Some(categories) => {
+                // Every nth category, so labels don’t overlap.
+                let every = ((categories.len() as f64 * 50.0) / (frame.right - frame.left)).ceil().max(1.0) as usize;
+                categories
+                    .iter()
+                    .enumerate()
+                    .filter(|(ix, _)| ix % every == 0)
+                    .map(|(ix, x)| (ix as f64, x.clone()))
+                    .collect_vec()
+            }
+            None => {
+                let (step, ticks) = ticks(domain);
+                ticks.into_iter().map(|x| (x, format_tick(x, step))).collect_vec()
+            }
+        };
+        let (y_step, y_ticks) = ticks(range);
+        if self.grid {
+            for (x, _) in &x_ticks {
+                let x = frame.x(*x);
+                elements.push(Element::Line {from: (x, frame.top), to: (x, frame.bottom), stroke: Stroke::new(Paint::Grid, 1.0)});
+            }
+            for y in &y_ticks {
+                let y = frame.y(*y);
+                elements.push(Element::Line {from: (frame.left, y), to: (frame.right, y), stroke: Stroke::new(Paint::Grid, 1.0)});
+            }
+        }
+        let x_axis = if range.0 <= 0.0 && 0.0 <= range.1 {frame.y(0.0)} else {frame.bottom};
+        let y_axis = match self.x_categories {
+            None if domain.0 <= 0.0 && 0.0 <= domain.1 => frame.x(0.0),
+            _ => frame.left,
+        };
+        let axis = Stroke::new(Paint::Axis, 1.0);
+        elements.push(Element::Line {from: (frame.left, x_axis), to: (frame.right, x_axis), stroke: axis.clone()});
+        elements.push(Element::Line {from: (y_axis, frame.top), to: (y_axis, frame.bottom), stroke: axis.clone()});
+        for (x, label) in x_ticks {
+            let at = frame.x(x);
+            elements.push(Element::Line {from: (at, x_axis - 3.0), to: (at, x_axis + 3.0), stroke: axis.clone()});
+            elements.push(Element::Text {
+                at: (at, frame.bottom + 6.0),
+                text: label,
+                anchor: Anchor::Below,
+                paint: Paint::Foreground,
+                vertical: false,
+            });
+        }
+        for y in &y_ticks {
+            let at = frame.y(*y);
+            elements.push(Element::Line {from: (y_axis - 3.0, at), to: (y_axis + 3.0, at), stroke: axis.clone()});
+            elements.push(Element::Text {
+                at: (frame.left - 6.0, at),
+                text: format_tick(*y, y_step),
+                anchor: Anchor::Left,
+                paint: Paint::Foreground,
+                vertical: false,
+            });
+        }
+        if let Some(label) = self.x_label.as_ref() {
+            elements.push(Element::Text {
+                at: ((frame.left + frame.right) / 2.0, self.height - 6.0),
+                text: label.clone(),
+                anchor: Anchor::Above,
+                paint: Paint::Foreground,
+                vertical: false,
+            });
+        }
+        if let Some(label) = self.y_label.as_ref() {
+            elements.push(Element::Text {
+                at: (14.0, (frame.top + frame.bottom) / 2.0),
+                text: label.clone(),
+                anchor: Anchor::Center,
+                paint: Paint::Foreground,
+                vertical: true,
+            });
+        }
+        elements
+    }
+}
+
+/// A legend in the top right corner of the plot area, with a line of the
+/// given stroke per label.
+pub fn legend(frame: &Frame, entries: &[(String, Stroke)]) -> Vec<Element> {
+    if entries.is_empty() {
+        return Vec::new()
+    }
+    let longest = entries.iter().map(|(x, _)| x.chars().count()).max().unwrap_or(0);
+    let width = 40.0 + longest as f64 * 7.0;
+    let at = (frame.right - width - 8.0, frame.top + 8.0);
+    let mut elements = vec![Element::Rect {
+        at,
+        size: (width, 8.0 + 18.0 * entries.len() as f64),
+        fill: Paint::Background,
+        stroke: Some(Stroke::new(Paint::Grid, 1.0)),
+    }];
+    for (row, (label, stroke)) in entries.iter().enumerate() {
+        let y = at.1 + 13.0 + 18.0 * row as f64;
+        elements.push(Element::Line {from: (at.0 + 8.0, y), to: (at.0 + 28.0, y), stroke: stroke.clone()});
+        elements.push(Element::Text {
+            at: (at.0 + 34.0, y),
+            text: label.clone(),
+            anchor: Anchor::Right,
+            paint: Paint::Foreground,
+            vertical: false,
+        });
+    }
+    elements
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// SVG
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Coordinates are rounded, so the output is compact and reproducible.
+fn num(x: f64) -> String {
+    let x = (x * 100.0).round() / 100.0;
+    // No `-0`.
+    if x == 0.0 {String::from("0")} else {x.to_string()}
+}
+
+fn points_str(points: &[(f64, f64)]) -> String {
+    points.iter().map(|(x, y)| format!("{},{}", num(*x), num(*y))).join(" ")
+}
+
+fn stroke_attrs(stroke: &Stroke, scheme: &ColorScheme) -> String {
+    let dash = match stroke.style {
+        LineStyle::Solid => String::new(),
+        LineStyle::Dashed => format!(" stroke-dasharray=\"{} {}\"", num(stroke.width * 4.0), num(stroke.width * 3.0)),
+        LineStyle::Dotted => format!(" stroke-dasharray=\"{} {}\" stroke-linecap=\"round\"", num(stroke.width * 0.1), num(stroke.width * 2.5)),
+    };
+    format!(
+        "stroke=\"{}\" stroke-width=\"{}\"{dash}",
+        stroke.paint.to_css(scheme),
+        num(stroke.width),
+    )
+}
+
+impl Element {
+    fn to_svg(&self, scheme: &ColorScheme, clip_id: &mut dyn FnMut() -> String) -> String {
+        match self {
+            Element::Line {from, to, stroke} => format!(
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" {}/>",
+                num(from.0), num(from.1), num(to.0), num(to.1),
+                stroke_attrs(stroke, scheme),
+            ),
+            Element::Path {segments, stroke} => segments
+                .iter()
+                .filter(|x| x.len() > 1)
+                .map(|points| format!(
+                    "<polyline points=\"{}\" fill=\"none\" stroke-linejoin=\"round\" {}/>",
+                    points_str(points),
+                    stroke_attrs(stroke, scheme),
+                ))
+                .collect(),
+            Element::Rect {at, size, fill, stroke} => format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"{}/>",
+                num(at.0), num(at.1), num(size.0), num(size.1),
+                fill.to_css(scheme),
+                stroke.as_ref().map(|x| format!(" {}", stroke_attrs(x, scheme))).unwrap_or_default(),
+            ),
+            Element::Text {at, text, anchor, paint, vertical} => {
+                let (text_anchor, baseline) = match anchor {
+                    Anchor::Below => ("middle", "hanging"),
+                    Anchor::Above => ("middle", "auto"),
+                    Anchor::Left => ("end", "middle"),
+                    Anchor::Right => ("start", "middle"),
+                    Anchor::Center => ("middle", "middle"),
+                };
+                let transform = if *vertical {
+                    format!(" transform=\"rotate(-90 {} {})\"", num(at.0), num(at.1))
+                } else {
+                    String::new()
+                };
+                format!(
+                    "<text x=\"{}\" y=\"{}\" text-anchor=\"{text_anchor}\" dominant-baseline=\"{baseline}\" fill=\"{}\"{transform}>{}</text>",
+                    num(at.0), num(at.1),
+                    paint.to_css(scheme),
+                    escape_html_text(text),
+                )
+            }
+            Element::Circle {at, radius, fill} => format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>",
+                num(at.0), num(at.1), num(*radius),
+                fill.to_css(scheme),
+            ),
+            Element::Clip {at, size, children} => {
+                let id = clip_id();
+                let children = children.iter().map(|x| x.to_svg(scheme, clip_id)).collect::<String>();
+                format!(
+                    "<clipPath id=\"{id}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/></clipPath><g clip-path=\"url(#{id})\">{children}</g>",
+                    num(at.0), num(at.1), num(size.0), num(size.1),
+                )
+            }
+        }
+    }
+}
+
+impl Figure {
+    /// An `<svg>` element, like drawings one per color scheme (see
+    /// `data-svg-color-scheme`). IDs (of clip paths) are derived from the
+    /// figure, so they’re unique for different figures (and color schemes)
+    /// on the same page, while the output stays reproducible.
+    pub fn to_html(&self, scheme: &ColorScheme) -> html::Node {
+        use std::hash::{Hash, Hasher};
+        let scheme_name = match scheme {
+            ColorScheme::Dark => "dark-mode",
+            ColorScheme::Light => "light-mode",
+        };
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        format!("{:?}{scheme_name}", self).hash(&mut hasher);
+        let prefix = format!("figure-{:016x}", hasher.finish());
+        let mut counter = 0;
+        let mut clip_id = || {
+            counter += 1;
+            format!("{prefix}-{counter}")
+        };
+        let elements = self.elements
+            .iter()
+            .map(|x| x.to_svg(scheme, &mut clip_id))
+            .collect::<String>();
+        let title = format!("<title>{}</title>", escape_html_text(&self.title));
+        html::TagBuilder::new("svg")
+            .with_attr("viewBox", format!("0 0 {} {}", num(self.width), num(self.height)))
+            .with_attr("xmlns", "http://www.w3.org/2000/svg")
+            .with_attr("role", "img")
+            .with_attr("style", format!("max-width: {}px;", num(self.width)))
+            .with_attr("font-size", "12")
+            .with_attr("data-figure", "")
+            .with_attr("data-svg-color-scheme", scheme_name)
+            // Already escaped.
+            .push_child(html::Node::Text(title + &elements))
+            .finalize()
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TIKZ
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Pixels are 0.2mm (so a 600px wide figure is 12cm), unless the figure
+/// wouldn’t fit the page.
+fn tikz_unit(width: f64) -> f64 {
+    (0.02f64).min(16.0 / width.max(1.0))
+}
+
+fn tikz_stroke(stroke: &Stroke) -> String {
+    let style = match stroke.style {
+        LineStyle::Solid => "",
+        LineStyle::Dashed => ", dashed",
+        LineStyle::Dotted => ", dotted",
+    };
+    format!("draw={}, line width={}pt{style}", stroke.paint.to_tikz(), num(stroke.width * 0.75))
+}
+
+fn tikz_point((x, y): (f64, f64)) -> String {
+    format!("({},{})", num(x), num(y))
+}
+
+impl Element {
+    fn to_tikz(&self) -> String {
+        match self {
+            Element::Line {from, to, stroke} => {
+                format!("\\draw[{}] {} -- {};\n", tikz_stroke(stroke), tikz_point(*from), tikz_point(*to))
+            }
+            Element::Path {segments, stroke} => segments
+                .iter()
+                .filter(|x| x.len() > 1)
+                .map(|points| format!(
+                    "\\draw[{}, line join=round] {};\n",
+                    tikz_stroke(stroke),
+                    points.iter().map(|x| tikz_point(*x)).join(" -- "),
+                ))
+                .collect(),
+            Element::Rect {at, size, fill, stroke} => format!(
+                "\\path[fill={}{}] {} rectangle {};\n",
+                fill.to_tikz(),
+                stroke.as_ref().map(|x| format!(", {}", tikz_stroke(x))).unwrap_or_default(),
+                tikz_point(*at),
+                tikz_point((at.0 + size.0, at.1 + size.1)),
+            ),
+            Element::Text {at, text, anchor, paint, vertical} => {
+                // TikZ anchors are the side of the text at the position.
+                let anchor = match anchor {
+                    Anchor::Below => "north",
+                    Anchor::Above => "south",
+                    Anchor::Left => "east",
+                    Anchor::Right => "west",
+                    Anchor::Center => "center",
+                };
+                let rotate = if *vertical {", rotate=90"} else {""};
+                // `inputenc` doesn’t know the minus sign of tick labels.
+                let text = escape_latex_text(text).replace('−', "\\(-\\)");
+                format!(
+                    "\\node[anchor={anchor}, inner sep=0pt, text={}, font=\\footnotesize{rotate}] at {} {{{}}};\n",
+                    paint.to_tikz(),
+                    tikz_point(*at),
+                    text,
+                )
+            }
+            Element::Circle {at, radius, fill} => format!(
+                "\\fill[{}] {} circle ({});\n",
+                fill.to_tikz(),
+                tikz_point(*at),
+                num(*radius),
+            ),
+            Element::Clip {at, size, children} => format!(
+                "\\begin{{scope}}\n\\clip {} rectangle {};\n{}\\end{{scope}}\n",
+                tikz_point(*at),
+                tikz_point((at.0 + size.0, at.1 + size.1)),
+                children.iter().map(Element::to_tikz).collect::<String>(),
+            ),
+        }
+    }
+}
+
+impl Figure {
+    /// Needs `\usepackage{tikz}`, the light color scheme is used.
+    pub fn to_tikz(&self) -> String {
+        let unit = tikz_unit(self.width);
+        let elements = self.elements.iter().map(Element::to_tikz).collect::<String>();
+        format!(
+            "\\begin{{tikzpicture}}[x={}cm, y=-{}cm]\n{elements}\\end{{tikzpicture}}",
+            unit, unit,
+        )
+    }
+}
@@ -0,0 +1,794 @@
+//! A source formatter for `.ss` files, i.e. `subscript-compiler fmt`.
+//!
+//! The parser drops `///` comments, so formatting works on a lossless token
+//! tree of the source instead of the AST, and only ever changes whitespace:
+//! * Nested lines are indented by one level (three spaces) per unclosed
+//!   bracket, and closing brackets that start a line line up with the line
+//!   of the opening bracket.
+//! * Block commands (see `ss::BLOCK_COMMANDS`), e.g. `\p` or `\li`, start
+//!   on their own line, and their arguments are either kept on one line, or
+//!   written as an indented body if they span lines or contain blocks.
+//! * Attribute lists are written as `[key=value, flag]`, or with one
+//!   attribute per line if they span lines.
+//! * `\math` and `\equation` bodies are reindented as a whole, keeping the
+//!   relative indentation of the TeX, while `\pre` and `\code` are kept as
+//!   written.
+//! * Trailing whitespace is removed, runs of blank lines are collapsed into
+//!   one, and the file ends with a single newline.
+//!
+//! Lines are never joined, since that could comment out code (or TeX after a
+//! `%`). As a safeguard, the formatted source is parsed again and compared
+//! against the original (see `is_equivalent`), and if their meaning differs,
+//! the source is left as is.
+use std::path::Path;
+use crate::ss::{self, Node, ResourceEnv, SemanticScope};
+use crate::ss::diagnostics::Diagnostic;
+
+const INDENT: &str = "   ";
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// API
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+pub enum FormatError {
+    /// The source doesn’t parse, so it isn’t formatted.
+    Syntax(Vec<Diagnostic>),
+    /// The formatted source would mean something else than the original,
+    /// which is a bug of the formatter.
+    ChangedMeaning,
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::Syntax(diagnostics) => {
+                write!(f, "the source has {} syntax error(s)", diagnostics.len())
+            }
+            FormatError::ChangedMeaning => {
+                write!(f, "formatting would change the meaning of the source")
+            }
+        }
+    }
+}
+
+/// Formats the given source, see the module docs.
+pub fn format_source(source: &str) -> Result<String, FormatError> {
+    format_impl(&SemanticScope::test_mode_empty(), source)
+}
+
+/// Reads and formats the given file, where syntax errors point into the file.
+pub fn format_file(file_path: impl AsRef<Path>) -> Result<String, FormatError> {
+    let file_path = file_path.as_ref();
+    let source = std::fs::read_to_string(file_path).map_err(|error| {
+        let message = format!("failed to read {}: {error}", file_path.display());
+        FormatError::Syntax(vec![Diagnostic::error(message)])
+    })?;
+    let mut scope = SemanticScope::test_mode_empty();
+    scope.file_path = Some(file_path.to_path_buf());
+    format_impl(&scope, &source)
+}
+
+/// Whether the two sources differ only in whitespace that doesn’t matter,
+/// i.e. they have the same comments and parse to the same tree, where
+/// * runs of whitespace are the same as a single space, and
+/// * whitespace around block commands, their arguments, and the separators
+///   of attribute lists is ignored.
+///
+/// The content of `\pre` and `\code` arguments must be the same verbatim.
+pub fn is_equivalent(left: &str, right: &str) -> bool {
+    comments(left) == comments(right) && canonical(left) == canonical(right)
+}
+
+fn format_impl(scope: &SemanticScope, source: &str) -> Result<String, FormatError> {
+    let env = ResourceEnv::default();
+    let node = ss::parser::parse_source(&env, scope, source);
+    ss::validation::validate_syntax(&env, scope, &node);
+    let errors = env.diagnostics()
+        .into_iter()
+        .filter(|x| x.is_error())
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        return Err(FormatError::Syntax(errors))
+    }
+    let tokens = tokenize(source);
+    let trees = to_trees(&mut tokens.into_iter());
+    let mut printer = Printer::default();
+    printer.children(&trees, 0);
+    let formatted = printer.finish();
+    if !is_equivalent(source, &formatted) {
+        return Err(FormatError::ChangedMeaning)
+    }
+    Ok(formatted)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TOKENS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {Ident, Open, Close, Quote, Comment, Newline, Space, Text}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+}
+
+fn is_ident_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, ':' | '_' | '-' | '!')
+}
+
+fn is_special_char(char: char) -> bool {
+    matches!(char, '\\' | '{' | '[' | '(' | '}' | ']' | ')' | '"' | ' ' | '\t' | '\n')
+}
+
+/// Splits the source into tokens, where concatenating the token texts gives
+/// back the source, except that `\r\n` newlines become `\n`.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(char) = rest.chars().next() {
+        let (kind, len) = if rest.starts_with("///") {
+            let end = rest.find('\n').unwrap_or(rest.len());
+            let end = if rest[..end].ends_with('\r') {end - 1} else {end};
+            (TokenKind::Comment, end)
+        } else if rest.starts_with("\r\n") {
+            (TokenKind::Newline, 2)
+        } else {
+            match char {
+                '\\' => {
+                    let len = rest[1..]
+                        .find(|x: char| !is_ident_char(x))
+                        .unwrap_or(rest.len() - 1);
+                    (TokenKind::Ident, len + 1)
+                }
+                '{' | '[' | '(' => (TokenKind::Open, 1),
+                '}' | ']' | ')' => (TokenKind::Close, 1),
+                '"' => (TokenKind::Quote, 1),
+                '\n' => (TokenKind::Newline, 1),
+                ' ' | '\t' => {
+                    let len = rest.find(|x| x != ' ' && x != '\t').unwrap_or(rest.len());
+                    (TokenKind::Space, len)
+                }
+                _ => {
+                    let mut len = char.len_utf8();
+                    for (ix, x) in rest.char_indices().skip(1) {
+                        if is_special_char(x) || rest[ix..].starts_with("///") || rest[ix..].starts_with("\r\n") {
+                            break
+                        }
+                        len = ix + x.len_utf8();
+                    }
+                    (TokenKind::Text, len)
+                }
+            }
+        };
+        let text = if kind == TokenKind::Newline {"\n"} else {&rest[..len]};
+        tokens.push(Token{kind, text});
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+/// The text of every `///` comment.
+fn comments(source: &str) -> Vec<&str> {
+    tokenize(source)
+        .into_iter()
+        .filter(|x| x.kind == TokenKind::Comment)
+        .map(|x| x.text.trim_end())
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+enum Tree<'a> {
+    Token(Token<'a>),
+    Group(Group<'a>),
+}
+
+#[derive(Debug, Clone)]
+struct Group<'a> {
+    open: Token<'a>,
+    children: Vec<Tree<'a>>,
+    close: Option<Token<'a>>,
+}
+
+impl<'a> Tree<'a> {
+    fn token(&self) -> Option<Token<'a>> {
+        match self {
+            Tree::Token(token) => Some(*token),
+            Tree::Group(_) => None,
+        }
+    }
+    fn is(&self, kind: TokenKind) -> bool {
+        self.token().map(|x| x.kind == kind).unwrap_or(false)
+    }
+    fn is_whitespace(&self) -> bool {
+        self.is(TokenKind::Space) || self.is(TokenKind::Newline)
+    }
+    fn has_newline(&self) -> bool {
+        match self {
+            Tree::Token(token) => token.kind == TokenKind::Newline,
+            Tree::Group(group) => group.children.iter().any(Tree::has_newline),
+        }
+    }
+    fn has_comment_or_group(&self) -> bool {
+        match self {
+            Tree::Token(token) => token.kind == TokenKind::Comment,
+            Tree::Group(_) => true,
+        }
+    }
+    fn write_raw(&self, out: &mut String) {
+        match self {
+            Tree::Token(token) => out.push_str(token.text),
+            Tree::Group(group) => {
+                out.push_str(group.open.text);
+                group.children.iter().for_each(|x| x.write_raw(out));
+                if let Some(close) = group.close {
+                    out.push_str(close.text);
+                }
+            }
+        }
+    }
+}
+
+fn to_trees<'a>(tokens: &mut impl Iterator<Item=Token<'a>>) -> Vec<Tree<'a>> {
+    let mut trees = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token.kind {
+            TokenKind::Open => {
+                let mut children = to_trees(tokens);
+                let close = match children.last() {
+                    Some(Tree::Token(x)) if x.kind == TokenKind::Close => Some(*x),
+                    _ => None,
+                };
+                if close.is_some() {
+                    children.pop();
+                }
+                trees.push(Tree::Group(Group{open: token, children, close}));
+            }
+            TokenKind::Close => {
+                trees.push(Tree::Token(token));
+                return trees
+            }
+            _ => trees.push(Tree::Token(token)),
+        }
+    }
+    trees
+}
+
+fn raw(trees: &[Tree]) -> String {
+    let mut out = String::new();
+    trees.iter().for_each(|x| x.write_raw(&mut out));
+    out
+}
+
+fn trim_whitespace<'t, 'a>(mut trees: &'t [Tree<'a>]) -> &'t [Tree<'a>] {
+    while trees.first().map(Tree::is_whitespace).unwrap_or(false) {
+        trees = &trees[1..];
+    }
+    while trees.last().map(Tree::is_whitespace).unwrap_or(false) {
+        trees = &trees[..trees.len() - 1];
+    }
+    trees
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// COMMANDS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+fn is_block_command(name: &str) -> bool {
+    ss::BLOCK_COMMANDS.contains(&name)
+}
+
+fn is_math_block(name: &str) -> bool {
+    name == "\\math" || name == "\\equation"
+}
+
+fn is_verbatim(name: &str) -> bool {
+    name == "\\pre" || name == "\\code"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArgKind {
+    Attributes,
+    Verbatim,
+    Math,
+    Block,
+    Normal,
+}
+
+impl ArgKind {
+    fn new(name: &str, open: &str) -> ArgKind {
+        match open {
+            // E.g. `\[x, y]`, which is math rather than attributes.
+            "[" if name == "\\" => ArgKind::Normal,
+            "[" => ArgKind::Attributes,
+            _ if is_verbatim(name) => ArgKind::Verbatim,
+            _ if is_math_block(name) => ArgKind::Math,
+            _ if is_block_command(name) => ArgKind::Block,
+            _ => ArgKind::Normal,
+        }
+    }
+}
+
+/// An ident along with its arguments, i.e. the `[…]` and `{…}` groups that
+/// follow it, possibly after spaces, or any other tree.
+enum Item {
+    Cmd {
+        ident: usize,
+        /// Exclusive.
+        end: usize,
+    },
+    Single(usize),
+}
+
+fn items(trees: &[Tree]) -> Vec<Item> {
+    let is_arg = |tree: &Tree| match tree {
+        Tree::Group(group) => group.open.text == "[" || group.open.text == "{",
+        _ => false,
+    };
+    let mut items = Vec::new();
+    let mut ix = 0;
+    while ix < trees.len() {
+        if !trees[ix].is(TokenKind::Ident) {
+            items.push(Item::Single(ix));
+            ix += 1;
+            continue
+        }
+        let mut end = ix + 1;
+        loop {
+            let mut next = end;
+            while next < trees.len() && trees[next].is(TokenKind::Space) {
+                next += 1;
+            }
+            if next < trees.len() && is_arg(&trees[next]) {
+                end = next + 1;
+            } else {
+                break
+            }
+        }
+        items.push(Item::Cmd{ident: ix, end});
+        ix = end;
+    }
+    items
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// PRINTER
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Default)]
+struct Printer<'a> {
+    out: String,
+    /// The indentation level of the current line.
+    line_indent: usize,
+    /// Newlines that have yet to be written, i.e. nothing has been written
+    /// on the current line if this isn’t zero.
+    newlines: usize,
+    /// A space that is written before the next token of the current line.
+    space: Option<&'a str>,
+}
+
+impl<'a> Printer<'a> {
+    fn at_line_start(&self) -> bool {
+        self.newlines > 0 || self.out.is_empty()
+    }
+    fn write(&mut self, indent: usize, text: &str) {
+        if self.at_line_start() {
+            if !self.out.is_empty() {
+                self.out.push_str(&"\n".repeat(self.newlines.min(2)));
+            }
+            self.out.push_str(&INDENT.repeat(indent));
+            self.line_indent = indent;
+            self.newlines = 0;
+        } else if let Some(space) = self.space {
+            self.out.push_str(space);
+        }
+        self.space = None;
+        self.out.push_str(text);
+    }
+    fn space(&mut self, text: &'a str) {
+        if !self.at_line_start() {
+            self.space = Some(text);
+        }
+    }
+    fn newline(&mut self) {
+        if !self.out.is_empty() {
+            self.newlines += 1;
+        }
+        self.space = None;
+    }
+    fn ensure_newline(&mut self) {
+        if !self.at_line_start() {
+            self.newline();
+        }
+    }
+    fn finish(mut self) -> String {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+    fn children(&mut self, trees: &[Tree<'a>], indent: usize) {
+        let mut after_block = false;
+        for item in items(trees) {
+            match item {
+                Item::Single(ix) => match &trees[ix] {
+                    Tree::Token(token) => match token.kind {
+                        TokenKind::Space => {
+                            if !after_block {
+                                self.space(token.text);
+                            }
+                        }
+                        TokenKind::Newline => {
+                            self.newline();
+                            after_block = false;
+                        }
+                        TokenKind::Comment => {
+                            if after_block {
+                                self.space(" ");
+                            }
+                            self.write(indent, token.text);
+                            after_block = false;
+                        }
+                        _ => {
+                            if after_block {
+                                self.ensure_newline();
+                            }
+                            self.write(indent, token.text);
+                            after_block = false;
+                        }
+                    },
+                    Tree::Group(group) => {
+                        if after_block {
+                            self.ensure_newline();
+                        }
+                        self.group(group, ArgKind::Normal, indent);
+                        after_block = false;
+                    }
+                },
+                Item::Cmd{ident, end} => {
+                    let name = trees[ident].token().unwrap().text;
+                    let is_block = is_block_command(name);
+                    if is_block || after_block {
+                        self.ensure_newline();
+                    }
+                    self.write(indent, name);
+                    for tree in &trees[ident + 1..end] {
+                        match tree {
+                            Tree::Group(group) => {
+                                let kind = ArgKind::new(name, group.open.text);
+                                self.group(group, kind, indent);
+                            }
+                            Tree::Token(token) => {
+                                if !is_block {
+                                    self.space(token.text);
+                                }
+                            }
+                        }
+                    }
+                    after_block = is_block;
+                }
+            }
+        }
+    }
+    fn group(&mut self, group: &Group<'a>, kind: ArgKind, indent: usize) {
+        self.write(indent, group.open.text);
+        let open_indent = self.line_indent;
+        let is_multiline = group.children.iter().any(Tree::has_newline);
+        match kind {
+            ArgKind::Verbatim => {
+                self.out.push_str(&raw(&group.children));
+            }
+            ArgKind::Attributes if !group.children.iter().any(Tree::has_comment_or_group) => {
+                match split_attributes(&raw(&group.children)) {
+                    Some(attributes) => self.attributes(attributes, is_multiline, open_indent),
+                    None => self.children(&group.children, open_indent + 1),
+                }
+            }
+            ArgKind::Math if is_multiline => {
+                self.math_body(&raw(&group.children), open_indent);
+            }
+            ArgKind::Math | ArgKind::Block if is_multiline || has_blocks(&group.children) => {
+                self.block_body(&group.children, open_indent);
+            }
+            ArgKind::Block => {
+                self.children(trim_whitespace(&group.children), open_indent + 1);
+            }
+            _ => {
+                self.children(&group.children, open_indent + 1);
+            }
+        }
+        if let Some(close) = group.close {
+            self.write(open_indent, close.text);
+        }
+    }
+    fn block_body(&mut self, children: &[Tree<'a>], open_indent: usize) {
+        let mut children = trim_whitespace(children);
+        // A comment after the opening bracket stays there.
+        if let Some(Tree::Token(token)) = children.first() {
+            if token.kind == TokenKind::Comment {
+                self.space(" ");
+                self.write(open_indent, token.text);
+                children = trim_whitespace(&children[1..]);
+            }
+        }
+        self.ensure_newline();
+        self.children(children, open_indent + 1);
+        self.ensure_newline();
+    }
+    fn attributes(&mut self, attributes: Vec<String>, is_multiline: bool, open_indent: usize) {
+        if !is_multiline || attributes.len() < 2 {
+            self.write(open_indent, &attributes.join(", "));
+            return
+        }
+        let last = attributes.len() - 1;
+        for (ix, attribute) in attributes.into_iter().enumerate() {
+            self.ensure_newline();
+            let separator = if ix == last {""} else {","};
+            self.write(open_indent + 1, &format!("{attribute}{separator}"));
+        }
+        self.ensure_newline();
+    }
+    /// Writes TeX lines, keeping their indentation relative to each other.
+    fn math_body(&mut self, body: &str, open_indent: usize) {
+        let width = |line: &str| -> usize {
+            line.chars()
+                .take_while(|x| x.is_whitespace())
+                .map(|x| if x == '\t' {INDENT.len()} else {1})
+                .sum()
+        };
+        let mut lines = body.split('\n').map(str::trim_end).collect::<Vec<_>>();
+        // Text after the opening bracket has no indentation of its own.
+        let first = lines.remove(0).trim_start();
+        let min_width = lines
+            .iter()
+            .filter(|x| !x.is_empty())
+            .map(|x| width(x))
+            .min()
+            .unwrap_or(0);
+        let mut lines = lines
+            .into_iter()
+            .map(|line| {
+                let extra = width(line).saturating_sub(min_width);
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}", " ".repeat(extra), line.trim_start())
+                }
+            })
+            .collect::<Vec<_>>();
+        if !first.is_empty() {
+            lines.insert(0, first.to_string());
+        }
+        while lines.first().map(|x| x.is_empty()).unwrap_or(false) {
+            lines.remove(0);
+        }
+        while lines.last().map(|x| x.is_empty()).unwrap_or(false) {
+            lines.pop();
+        }
+        for line in lines {
+            self.ensure_newline();
+            if line.is_empty() {
+                self.newline();
+            } else {
+                self.write(open_indent + 1, &line);
+            }
+        }
+        self.ensure_newline();
+    }
+}
+
+fn has_blocks(trees: &[Tree]) -> bool {
+    trees.iter().any(|x| {
+        x.token()
+            .map(|x| x.kind == TokenKind::Ident && is_block_command(x.text))
+            .unwrap_or(false)
+    })
+}
+
+/// The attributes of an attribute list, as `key=value` or `flag`, or `None`
+/// if the list can’t be split as such, e.g. it has an empty entry.
+fn split_attributes(source: &str) -> Option<Vec<String>> {
+    let mut parts = vec![String::new()];
+    let mut in_quote = false;
+    for char in source.chars() {
+        match char {
+            '"' => {
+                in_quote = !in_quote;
+                parts.last_mut().unwrap().push(char);
+            }
+            ',' if !in_quote => parts.push(String::new()),
+            _ => parts.last_mut().unwrap().push(char),
+        }
+    }
+    parts
+        .into_iter()
+        .map(|part| {
+            let mut in_quote = false;
+            let eq = part.char_indices().find(|(_, x)| {
+                if *x == '"' {
+                    in_quote = !in_quote;
+                }
+                *x == '=' && !in_quote
+            });
+            let attribute = match eq {
+                Some((ix, _)) => format!("{}={}", part[..ix].trim(), part[ix + 1..].trim()),
+                None => part.trim().to_string(),
+            };
+            Some(attribute).filter(|x| !x.is_empty())
+        })
+        .collect()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// MEANING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, PartialEq)]
+enum Canonical {
+    Ident(String),
+    Open(String),
+    Close(String),
+    Word(String),
+    Symbol(String),
+    /// The content of `\pre` or `\code`.
+    Verbatim(String),
+    Space,
+}
+
+/// The parsed source as a flat list of tokens, along with whether whitespace
+/// around each token may change.
+fn canonical(source: &str) -> Vec<Canonical> {
+    let env = ResourceEnv::default();
+    let node = ss::parser::parse_source(&env, &SemanticScope::test_mode_empty(), source);
+    let mut tokens = Vec::new();
+    canonical_nodes(&flatten(vec![node]), false, &mut tokens);
+    let mut result: Vec<(Canonical, bool)> = Vec::new();
+    for (token, is_boundary) in tokens {
+        let previous_is_space = result.last().map(|x| x.0 == Canonical::Space);
+        match token {
+            Canonical::Space if previous_is_space.unwrap_or(true) => (),
+            Canonical::Space if result.last().map(|x| x.1).unwrap_or(true) => (),
+            _ if is_boundary && previous_is_space == Some(true) => {
+                result.pop();
+                result.push((token, is_boundary));
+            }
+            _ => result.push((token, is_boundary)),
+        }
+    }
+    if result.last().map(|x| x.0 == Canonical::Space).unwrap_or(false) {
+        result.pop();
+    }
+    result.into_iter().map(|x| x.0).collect()
+}
+
+fn flatten(nodes: Vec<Node>) -> Vec<Node> {
+    nodes
+        .into_iter()
+        .flat_map(|node| match node {
+            Node::Fragment(xs) => flatten(xs),
+            node => vec![node],
+        })
+        .collect()
+}
+
+fn canonical_nodes(nodes: &[Node], in_attributes: bool, out: &mut Vec<(Canonical, bool)>) {
+    let mut ix = 0;
+    while ix < nodes.len() {
+        let node = &nodes[ix];
+        ix += 1;
+        let name = match node {
+            Node::Ident(ident) => ident.value.as_str().to_string(),
+            node => {
+                canonical_node(node, in_attributes, out);
+                continue
+            }
+        };
+        let is_block = is_block_command(&name);
+        out.push((Canonical::Ident(name.clone()), is_block));
+        // The arguments.
+        loop {
+            let mut next = ix;
+            while next < nodes.len() && nodes[next].is_whitespace() && !has_newline(&nodes[next]) {
+                next += 1;
+            }
+            let bracket = match nodes.get(next) {
+                Some(Node::Bracket(bracket)) => bracket,
+                _ => break,
+            };
+            let open = bracket.value.open.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            if open != "[" && open != "{" {
+                break
+            }
+            if is_block {
+                ix = next;
+            }
+            nodes[ix..next].iter().for_each(|x| canonical_node(x, in_attributes, out));
+            ix = next + 1;
+            let kind = ArgKind::new(&name, &open);
+            let is_boundary = is_block || kind == ArgKind::Attributes;
+            let close = bracket.value.close.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            let children = flatten(bracket.value.children.clone());
+            out.push((Canonical::Open(open), is_boundary));
+            if kind == ArgKind::Verbatim {
+                let text = children.iter().map(raw_node).collect::<String>();
+                out.push((Canonical::Verbatim(text), false));
+            } else {
+                canonical_nodes(&children, kind == ArgKind::Attributes, out);
+            }
+            out.push((Canonical::Close(close), is_boundary));
+        }
+    }
+}
+
+fn has_newline(node: &Node) -> bool {
+    matches!(node, Node::Text(text) if text.value.contains('\n'))
+}
+
+fn canonical_node(node: &Node, in_attributes: bool, out: &mut Vec<(Canonical, bool)>) {
+    match node {
+        Node::Text(text) => {
+            let mut word = String::new();
+            for char in text.value.chars() {
+                if char.is_whitespace() {
+                    if !word.is_empty() {
+                        out.push((Canonical::Word(std::mem::take(&mut word)), false));
+                    }
+                    out.push((Canonical::Space, false));
+                } else {
+                    word.push(char);
+                }
+            }
+            if !word.is_empty() {
+                out.push((Canonical::Word(word), false));
+            }
+        }
+        Node::Symbol(symbol) => {
+            let is_separator = in_attributes && (symbol.value == "," || symbol.value == "=");
+            out.push((Canonical::Symbol(symbol.value.clone()), is_separator));
+        }
+        Node::InvalidToken(token) => {
+            out.push((Canonical::Symbol(token.value.clone()), false));
+        }
+        Node::Ident(_) => canonical_nodes(std::slice::from_ref(node), in_attributes, out),
+        Node::Bracket(bracket) => {
+            let open = bracket.value.open.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            let close = bracket.value.close.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            out.push((Canonical::Open(open), false));
+            canonical_nodes(&flatten(bracket.value.children.clone()), false, out);
+            out.push((Canonical::Close(close), false));
+        }
+        Node::Quotation(quote) => {
+            let open = quote.value.open.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            let close = quote.value.close.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            out.push((Canonical::Open(open), false));
+            canonical_nodes(&flatten(quote.value.children.clone()), false, out);
+            out.push((Canonical::Close(close), false));
+        }
+        Node::Fragment(xs) => canonical_nodes(&flatten(xs.clone()), in_attributes, out),
+        Node::Cmd(_) | Node::Drawing(_) => {
+            out.push((Canonical::Word(node.to_string()), false));
+        }
+    }
+}
+
+/// The source text of a parsed node, without comments.
+fn raw_node(node: &Node) -> String {
+    match node {
+        Node::Text(x) | Node::Symbol(x) | Node::InvalidToken(x) => x.value.clone(),
+        Node::Ident(x) => x.value.as_str().to_string(),
+        Node::Bracket(x) => {
+            let open = x.value.open.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            let close = x.value.close.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            format!("{open}{}{close}", x.value.children.iter().map(raw_node).collect::<String>())
+        }
+        Node::Quotation(x) => {
+            let open = x.value.open.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            let close = x.value.close.as_ref().map(|x| x.value.clone()).unwrap_or_default();
+            format!("{open}{}{close}", x.value.children.iter().map(raw_node).collect::<String>())
+        }
+        Node::Fragment(xs) => xs.iter().map(raw_node).collect(),
+        node => node.to_string(),
+    }
+}
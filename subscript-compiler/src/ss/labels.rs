@@ -0,0 +1,509 @@
+//! Cross references. A `\label[id]` names the heading, equation, drawing or
+//! note it follows (or is inside of), e.g.
+//! ```text
+//! \h2{Energy}\label[energy]
+//! \equation{E = mc^2 \label[eq:energy]}
+//! See \ref[energy] and \eqref[eq:energy].
+//! ```
+//! Labels are collected from every page before any page is rendered (see
+//! `Compiler::compile_pages_to_html`), so references resolve across the whole
+//! project. Equations, drawings and notes are numbered per page, in source
+//! order; a reference to a heading shows its title.
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use itertools::Itertools;
+use serde::{Serialize, Deserialize};
+use crate::ss::{Ann, CharRange, CmdCall, Node, ResourceEnv, SemanticScope};
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::env::canonical_path;
+
+pub const LABEL_IDENT: &str = "\\label";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LabelKind {
+    Heading,
+    Equation,
+    Figure,
+    Note,
+    /// An entry of a site-wide bibliography, see `crate::ss::bibliography`.
+    Citation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub id: String,
+    pub kind: LabelKind,
+    /// What references show, i.e. the number (or tag) of equations, drawings
+    /// and notes, and the title of headings.
+    pub text: String,
+    /// The canonical path of the page that defines the label.
+    pub page: PathBuf,
+    pub href: String,
+    /// Where the `\label` is, which may be an included file.
+    pub file_path: Option<PathBuf>,
+    pub range: Option<CharRange>,
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LABEL INDEX
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The labels of every page in the project, shared by the code-gen of each
+/// page.
+#[derive(Debug, Clone, Default)]
+pub struct LabelIndex {
+    data: Arc<LabelIndexData>,
+    /// The canonical path of the page being compiled, see
+    /// `LabelIndex::for_page`.
+    page: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+struct LabelIndexData {
+    labels: HashMap<String, Label>,
+    /// Canonical paths, see `LabelIndex::resolve`.
+    pages: Vec<PathBuf>,
+}
+
+impl LabelIndex {
+    /// Reports labels that are defined more than once, the first definition
+    /// (by page and source position) wins.
+    pub fn new(
+        env: &ResourceEnv,
+        pages: impl IntoIterator<Item=PathBuf>,
+        labels: impl IntoIterator<Item=Label>,
+    ) -> Self {
+        let mut map: HashMap<String, Label> = HashMap::default();
+        let labels = labels
+            .into_iter()
+            .sorted_by_key(|x| {
+                (x.page.clone(), x.file_path.clone(), x.range.map(|x| x.start.byte_index))
+            });
+        for label in labels {
+            if let Some(first) = map.get(&label.id) {
+                env.report(Diagnostic {
+                    file_path: label.file_path.clone(),
+                    range: label.range,
+                    ..Diagnostic::error(format!(
+                        "label `{}` is already defined in {}",
+                        label.id,
+                        first.file_path.as_ref().unwrap_or(&first.page).display(),
+                    ))
+                });
+                continue
+            }
+            map.insert(label.id.clone(), label);
+        }
+        let data = LabelIndexData {
+            labels: map,
+            pages: pages.into_iter().collect(),
+        };
+        LabelIndex {data: Arc::new(data), page: None}
+    }
+    pub fn for_page(&self, page: impl AsRef<Path>) -> Self {
+        LabelIndex {data: self.data.clone(), page: Some(canonical_path(page))}
+    }
+    pub fn get(&self, id: &str) -> Option<&Label> {
+        self.data.labels.get(id)
+    }
+    /// Whether the given label is defined on the current page, e.g. LaTeX
+    /// documents can only link to such.
+    pub fn is_local(&self, label: &Label) -> bool {
+        self.page.as_ref() == Some(&label.page)
+    }
+    /// The label referenced by the given `\ref` (or `\eqref`) command. The
+    /// current file depends on the page that defines it, and unresolved
+    /// references depend on every page, since any of them may define it
+    /// later (see `crate::compiler::incremental`).
+    pub fn resolve(&self, env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall) -> Option<&Label> {
+        let report = |diagnostic: Diagnostic| {
+            env.report(diagnostic.with_range(cmd.identifier.range).with_scope(scope));
+        };
+        let id = match first_attribute_key(cmd) {
+            Some(id) => id,
+            None => {
+                report(Diagnostic::error(format!(
+                    "`{}` expects a label, e.g. `{}[my-label]`",
+                    cmd.identifier.value.as_str(),
+                    cmd.identifier.value.as_str(),
+                )));
+                return None
+            }
+        };
+        let label = self.lookup(env, scope, &id);
+        if label.is_none() {
+            report(Diagnostic::error(format!("undefined label `{id}`")));
+        }
+        label
+    }
+    /// Like `LabelIndex::resolve`, without reporting anything.
+    pub fn lookup(&self, env: &ResourceEnv, scope: &SemanticScope, id: &str) -> Option<&Label> {
+        match self.get(id) {
+            Some(label) => {
+                env.add_dependency(scope, &label.page);
+                Some(label)
+            }
+            None => {
+                for page in self.data.pages.iter() {
+                    env.add_dependency(scope, page);
+                }
+                None
+            }
+        }
+    }
+}
+
+pub(crate) fn first_attribute_key(cmd: &CmdCall) -> Option<String> {
+    cmd.attributes
+        .clone()
+        .consume()
+        .into_iter()
+        .next()
+        .and_then(|x| x.to_key_value_str())
+        .map(|(key, _)| key)
+        .filter(|x| !x.is_empty())
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LABEL PASS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Attaches each `\label` of the given (processed) page to its target, i.e.
+/// sets the target’s `id` (and number), and removes the `\label` commands.
+///
+/// Labels in included files that are pages themselves are left to such, so
+/// `pages` should be the canonical paths of every page in the project.
+pub fn resolve_labels(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    page_href: &str,
+    pages: &HashSet<PathBuf>,
+    node: Node,
+) -> (Node, Vec<Label>) {
+    let mut pass = LabelPass {
+        env,
+        page: scope.file_path.as_ref().map(canonical_path).unwrap_or_default(),
+        page_href,
+        pages,
+        counters: HashMap::default(),
+        labels: Vec::new(),
+    };
+    let node = pass.node(scope, node);
+    (node, pass.labels)
+}
+
+struct LabelPass<'a> {
+    env: &'a ResourceEnv,
+    page: PathBuf,
+    page_href: &'a str,
+    pages: &'a HashSet<PathBuf>,
+    counters: HashMap<LabelKind, usize>,
+    labels: Vec<Label>,
+}
+
+impl<'a> LabelPass<'a> {
+    fn node(&mut self, scope: &SemanticScope, node: Node) -> Node {
+        match node {
+            Node::Cmd(cmd) if cmd.has_name(LABEL_IDENT) => {
+                self.report_unattached(scope, &cmd);
+                Node::Fragment(Vec::new())
+            }
+            Node::Cmd(cmd) => self.cmd(scope, cmd, None),
+            Node::Bracket(Ann{value: mut bracket, range}) => {
+                bracket.children = self.nodes(scope, bracket.children);
+                Node::Bracket(Ann{value: bracket, range})
+            }
+            Node::Quotation(Ann{value: mut quotation, range}) => {
+                quotation.children = self.nodes(scope, quotation.children);
+                Node::Quotation(Ann{value: quotation, range})
+            }
+            Node::Fragment(xs) => Node::Fragment(self.nodes(scope, xs)),
+            node => node,
+        }
+    }
+    /// A label applies to the preceding sibling (ignoring whitespace).
+    fn nodes(&mut self, scope: &SemanticScope, nodes: Vec<Node>) -> Vec<Node> {
+        let mut nodes = nodes.into_iter().map(Some).collect_vec();
+        let mut result = Vec::with_capacity(nodes.len());
+        for index in 0..nodes.len() {
+            let cmd = match nodes[index].take() {
+                Some(Node::Cmd(cmd)) => cmd,
+                Some(node) => {
+                    result.push(self.node(scope, node));
+                    continue
+                }
+                None => continue,
+            };
+            if cmd.has_name(LABEL_IDENT) {
+                self.report_unattached(scope, &cmd);
+                continue
+            }
+            let next = (index + 1..nodes.len())
+                .find(|ix| !nodes[*ix].as_ref().map(Node::is_whitespace).unwrap_or(false));
+            let next_label = next.filter(|ix| {
+                nodes[*ix]
+                    .as_ref()
+                    .and_then(Node::get_cmd)
+                    .map(|x| x.has_name(LABEL_IDENT))
+                    .unwrap_or(false)
+            });
+            let label = match (self.label_kind(scope, &cmd), next_label) {
+                (Some(_), Some(ix)) => nodes[ix].take().and_then(|x| match x {
+                    Node::Cmd(label) => Some(label),
+                    _ => None,
+                }),
+                _ => None,
+            };
+            result.push(self.cmd(scope, cmd, label));
+        }
+        result
+    }
+    fn cmd(&mut self, scope: &SemanticScope, mut cmd: CmdCall, label: Option<CmdCall>) -> Node {
+        if cmd.has_name("\\include") {
+            return self.include(scope, cmd, label)
+        }
+        let kind = self.label_kind(scope, &cmd);
+        // Otherwise the label applies to the innermost enclosing target.
+        let label = match (kind, label) {
+            (Some(_), None) => take_inner_label(&mut cmd, |x| self.label_kind(scope, x).is_some()),
+            (_, label) => label,
+        };
+        if let Some(kind) = kind {
+            self.attach(scope, &mut cmd, kind, label);
+        }
+        cmd.arguments = cmd.arguments
+            .into_iter()
+            .map(|x| self.node(scope, x))
+            .collect();
+        Node::Cmd(cmd)
+    }
+    fn include(&mut self, scope: &SemanticScope, mut cmd: CmdCall, label: Option<CmdCall>) -> Node {
+        let src_path = include_path(scope, &cmd);
+        let is_page = src_path
+            .as_ref()
+            .map(|x| self.pages.contains(&canonical_path(x)) && canonical_path(x) != self.page)
+            .unwrap_or(false);
+        if is_page {
+            cmd.arguments = cmd.arguments.into_iter().map(strip_labels).collect();
+            return Node::Cmd(cmd)
+        }
+        if self.label_kind(scope, &cmd) == Some(LabelKind::Figure) {
+            self.attach(scope, &mut cmd, LabelKind::Figure, label);
+            return Node::Cmd(cmd)
+        }
+        let sub_scope = match src_path.as_ref() {
+            Some(src_path) if crate::compiler::low_level_api::is_source_file(src_path) => {
+                scope.new_file(src_path)
+            }
+            _ => scope.clone(),
+        };
+        cmd.arguments = cmd.arguments
+            .into_iter()
+            .map(|x| self.node(&sub_scope, x))
+            .collect();
+        Node::Cmd(cmd)
+    }
+    /// Equations are also numbered VIA the `num` attribute, everything else
+    /// only when labeled.
+    fn label_kind(&self, scope: &SemanticScope, cmd: &CmdCall) -> Option<LabelKind> {
+        if cmd.is_heading_node() {
+            return Some(LabelKind::Heading)
+        }
+        match cmd.identifier.value.as_str() {
+            "\\equation" | "\\math" => Some(LabelKind::Equation),
+            "\\note" => Some(LabelKind::Note),
+            "\\include" => {
+                let is_drawing = include_path(scope, cmd)
+                    .as_ref()
+                    .and_then(|x| x.extension())
+                    .and_then(|x| x.to_str())
+                    .map(ss_freeform_format::SS1FreeformSuite::is_ss1_drawing_file_ext)
+                    .unwrap_or(false);
+                is_drawing.then_some(LabelKind::Figure)
+            }
+            _ => None,
+        }
+    }
+    fn attach(
+        &mut self,
+        scope: &SemanticScope,
+        cmd: &mut CmdCall,
+        kind: LabelKind,
+        label: Option<CmdCall>,
+    ) {
+        let is_numbered_equation = cmd.has_name("\\equation") &&
+            cmd.attributes.has_truthy_option("num");
+        if label.is_none() && !is_numbered_equation {
+            return
+        }
+        let id = label.as_ref().and_then(|label| self.label_id(scope, label));
+        let text = match kind {
+            LabelKind::Heading => {
+                let title = cmd.arguments.iter().map(plain_text).collect::<String>();
+                title.split_whitespace().join(" ")
+            }
+            LabelKind::Equation => match cmd.attributes.get_str_value("tag") {
+                Some(tag) => tag,
+                None => {
+                    let number = self.next_number(kind).to_string();
+                    cmd.attributes.insert("tag", number.clone());
+                    // Otherwise `\math` ignores the tag.
+                    if cmd.has_name("\\math") && !cmd.attributes.has_attr("preset") {
+                        cmd.attributes.insert("preset", "centered");
+                    }
+                    number
+                }
+            },
+            LabelKind::Figure | LabelKind::Note => {
+                let number = self.next_number(kind).to_string();
+                cmd.attributes.insert("data-number", number.clone());
+                number
+            }
+            // Not a `\label` target, see `crate::ss::bibliography`.
+            LabelKind::Citation => return,
+        };
+        let (id, label) = match (id, label) {
+            (Some(id), Some(label)) => (id, label),
+            _ => return,
+        };
+        cmd.attributes.insert("id", id.clone());
+        self.labels.push(Label {
+            href: format!("{}#{id}", self.page_href),
+            id,
+            kind,
+            text,
+            page: self.page.clone(),
+            file_path: scope.file_path.clone(),
+            range: label.identifier.range,
+        });
+    }
+    fn next_number(&mut self, kind: LabelKind) -> usize {
+        let counter = self.counters.entry(kind).or_default();
+        *counter += 1;
+        *counter
+    }
+    /// Ids end up in URLs and LaTeX, so only a safe subset is allowed.
+    fn label_id(&self, scope: &SemanticScope, label: &CmdCall) -> Option<String> {
+        let report = |message: String| {
+            self.env.report(
+                Diagnostic::error(message)
+                    .with_range(label.identifier.range)
+                    .with_scope(scope)
+            );
+        };
+        let id = match first_attribute_key(label) {
+            Some(id) => id,
+            None => {
+                report(format!("`{LABEL_IDENT}` expects an id, e.g. `{LABEL_IDENT}[my-label]`"));
+                return None
+            }
+        };
+        let is_valid = id.chars().all(|x| {
+            x.is_alphanumeric() || x == '-' || x == '_' || x == ':' || x == '.'
+        });
+        if !is_valid {
+            report(format!("invalid label `{id}`, use letters, digits, `-`, `_`, `:` or `.`"));
+            return None
+        }
+        Some(id)
+    }
+    fn report_unattached(&self, scope: &SemanticScope, label: &CmdCall) {
+        self.env.report(
+            Diagnostic::error(format!(
+                "`{LABEL_IDENT}` must follow (or be inside of) a heading, equation, drawing or note"
+            ))
+            .with_range(label.identifier.range)
+            .with_scope(scope)
+        );
+    }
+}
+
+/// Removes the first `\label` among the direct children of the given
+/// command’s arguments, e.g. `\equation{E = mc^2 \label[x]}`, unless it
+/// follows a target of its own (e.g. `\note{\h2{…}\label[x]…}`).
+fn take_inner_label(cmd: &mut CmdCall, is_target: impl Fn(&CmdCall) -> bool) -> Option<CmdCall> {
+    for argument in cmd.arguments.iter_mut() {
+        let children = match argument {
+            Node::Bracket(Ann{value, ..}) => &mut value.children,
+            Node::Fragment(xs) => xs,
+            _ => continue,
+        };
+        let position = children
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.get_cmd().map(|x| x.has_name(LABEL_IDENT)).unwrap_or(false))
+            .map(|(ix, _)| ix)
+            .find(|ix| {
+                let previous = children[..*ix].iter().rev().find(|x| !x.is_whitespace());
+                !previous.and_then(Node::get_cmd).map(&is_target).unwrap_or(false)
+            });
+        if let Some(Node::Cmd(label)) = position.map(|ix| children.remove(ix)) {
+            return Some(label)
+        }
+    }
+    None
+}
+
+/// For included pages, whose labels point to the page itself.
+fn strip_labels(node: Node) -> Node {
+    match node {
+        Node::Cmd(mut cmd) => {
+            cmd.arguments = cmd.arguments.into_iter().map(strip_labels).collect();
+            Node::Cmd(cmd)
+        }
+        Node::Bracket(Ann{value: mut bracket, range}) => {
+            bracket.children = strip_label_nodes(bracket.children);
+            Node::Bracket(Ann{value: bracket, range})
+        }
+        Node::Quotation(Ann{value: mut quotation, range}) => {
+            quotation.children = strip_label_nodes(quotation.children);
+            Node::Quotation(Ann{value: quotation, range})
+        }
+        Node::Fragment(xs) => Node::Fragment(strip_label_nodes(xs)),
+        node => node,
+    }
+}
+
+fn strip_label_nodes(nodes: Vec<Node>) -> Vec<Node> {
+    nodes
+        .into_iter()
+        .filter(|x| !x.get_cmd().map(|x| x.has_name(LABEL_IDENT)).unwrap_or(false))
+        .map(strip_labels)
+        .collect()
+}
+
+/// See `crate::ss_v1_std::core::handle_include`.
+pub(crate) fn include_path(scope: &SemanticScope, cmd: &CmdCall) -> Option<PathBuf> {
+    let src = cmd.attributes.get_str_value("src")?;
+    Some(scope.normalize_file_path(&src).unwrap_or_else(|()| Path::new(&src).to_path_buf()))
+}
+
+fn plain_text(node: &Node) -> String {
+    match node {
+        Node::Cmd(cmd) => cmd.arguments.iter().map(plain_text).collect(),
+        Node::Ident(Ann{value, ..}) => value.to_tex_ident().to_owned(),
+        Node::Bracket(Ann{value, ..}) => {
+            let children = value.children.iter().map(plain_text).collect::<String>();
+            match value.kind() {
+                Some(crate::ss::BracketType::CurlyBrace) => children,
+                _ => format!(
+                    "{}{children}{}",
+                    value.open.as_ref().map(|x| x.value.as_str()).unwrap_or_default(),
+                    value.close.as_ref().map(|x| x.value.as_str()).unwrap_or_default(),
+                ),
+            }
+        }
+        Node::Quotation(Ann{value, ..}) => format!(
+            "{}{}{}",
+            value.open.as_ref().map(|x| x.value.as_str()).unwrap_or_default(),
+            value.children.iter().map(plain_text).collect::<String>(),
+            value.close.as_ref().map(|x| x.value.as_str()).unwrap_or_default(),
+        ),
+        Node::Text(Ann{value, ..}) |
+        Node::Symbol(Ann{value, ..}) |
+        Node::InvalidToken(Ann{value, ..}) => value.clone(),
+        Node::Drawing(_) => String::new(),
+        Node::Fragment(xs) => xs.iter().map(plain_text).collect(),
+    }
+}
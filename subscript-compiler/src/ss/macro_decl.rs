@@ -0,0 +1,234 @@
+//! User-defined macros, i.e. commands declared in `.ss` source VIA `\define`
+//! (see `crate::ss_v1_std::core`) instead of in Rust. For instance
+//! ```text
+//! \define[name="card", params="title, body", cols="3"]{
+//!     \note[boxed]{\h2{\title}\grid[col="\cols"]{\body}}
+//! }
+//! \card[cols="2"]{Some Title}{…}
+//! ```
+//! Each parameter (a curly brace argument) and each other attribute (with its
+//! default value) is referenced in the template as an identifier, and
+//! invocations are expanded in the post-parser by rewriting such VIA
+//! `RewriteRule`s, the result is then processed like any other source.
+//!
+//! A definition applies to the rest of the enclosing block, which includes
+//! everything after an `\include` of a file (e.g. a shared prelude) that
+//! defines it.
+use std::collections::HashMap;
+use std::sync::Arc;
+use itertools::Itertools;
+use crate::ss::{Ann, Attributes, Ident, Node, ResourceEnv, RewriteRule, SemanticScope};
+use crate::ss::diagnostics::Diagnostic;
+
+pub const DEFINE_IDENT: &str = "\\define";
+
+#[derive(Debug, Clone)]
+pub struct MacroDeclaration {
+    pub identifier: Ident,
+    /// One curly brace argument each, in the given order.
+    pub parameters: Vec<Ident>,
+    /// Optional attributes and their default values (without quotes).
+    pub attributes: Vec<(String, Vec<Node>)>,
+    pub template: Vec<Node>,
+}
+
+impl MacroDeclaration {
+    /// From the attributes and template (i.e. curly brace argument) of a
+    /// `\define` command.
+    pub fn parse(attributes: &Attributes, template: &Node) -> Result<Self, String> {
+        let parse_ident = |name: &str| -> Result<Ident, String> {
+            let name = name.trim_start_matches('\\');
+            let is_valid = !name.is_empty() && name.chars().all(|x| {
+                x.is_alphanumeric() || x == ':' || x == '_' || x == '-'
+            });
+            if !is_valid {
+                return Err(format!("invalid macro or parameter name `{name}`"))
+            }
+            Ok(Ident::from(format!("\\{name}")).unwrap())
+        };
+        let identifier = attributes
+            .get_str_value("name")
+            .ok_or_else(|| format!("`{DEFINE_IDENT}` is missing the `name` attribute"))
+            .and_then(|x| parse_ident(&x))?;
+        let parameters = attributes
+            .get_str_value("params")
+            .unwrap_or_default()
+            .split([',', ' '])
+            .filter(|x| !x.is_empty())
+            .map(parse_ident)
+            .collect::<Result<Vec<_>, _>>()?;
+        let attributes = attributes
+            .clone()
+            .consume()
+            .into_iter()
+            .filter_map(|attribute| {
+                let key = attribute.key
+                    .defragment_node_tree()
+                    .trim_whitespace()
+                    .into_text()?
+                    .consume();
+                Some((key, unquote(&attribute.value)))
+            })
+            .filter(|(key, _)| key != "name" && key != "params")
+            .map(|(key, value)| parse_ident(&key).map(|_| (key, value)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let template = template
+            .get_curly_brace_children()
+            .map(trim_edges)
+            .ok_or_else(|| format!("`{DEFINE_IDENT}` expects a curly brace template"))?;
+        Ok(MacroDeclaration {identifier, parameters, attributes, template})
+    }
+    /// Matches an invocation at the start of the given nodes, i.e. the
+    /// identifier, optional attributes and a curly brace argument for each
+    /// parameter. Returns the expanded (but unprocessed) template, along with
+    /// the number of nodes consumed.
+    ///
+    /// The arguments are processed beforehand in the given (i.e. the
+    /// caller’s) scope, so that nested invocations such as `\box{\box{…}}`
+    /// aren’t mistaken for recursion.
+    pub fn expand(
+        &self,
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        nodes: &[Node],
+    ) -> Option<(Node, usize)> {
+        let ident = nodes.first()?.get_ident_ref()?;
+        let report = |diagnostic: Diagnostic| {
+            env.report(diagnostic.with_range(ident.range).with_scope(scope));
+        };
+        let skip_whitespace = |mut index: usize| {
+            while nodes.get(index).and_then(Node::get_whitespace_ref).is_some() {
+                index += 1;
+            }
+            index
+        };
+        let mut index = 1;
+        let attributes = nodes
+            .get(skip_whitespace(index))
+            .and_then(Attributes::parse_as_attribute_node);
+        if attributes.is_some() {
+            index = skip_whitespace(index) + 1;
+        }
+        let attributes = attributes.unwrap_or_default();
+        for key in attributes.get_str_keys().into_iter().sorted() {
+            if !self.attributes.iter().any(|(x, _)| *x == key) {
+                report(Diagnostic::warning(format!(
+                    "unknown attribute `{key}` for `{}`, it will be ignored",
+                    ident.value.as_str(),
+                )));
+            }
+        }
+        let mut arguments = Vec::with_capacity(self.parameters.len());
+        for _ in self.parameters.iter() {
+            match nodes.get(skip_whitespace(index)).and_then(Node::get_curly_brace_children) {
+                Some(children) => {
+                    let argument = match Node::Fragment(children.to_vec()).apply_commands(env, scope) {
+                        Node::Fragment(xs) => xs,
+                        node => vec![node],
+                    };
+                    arguments.push(argument);
+                    index = skip_whitespace(index) + 1;
+                }
+                None => {
+                    report(Diagnostic::error(format!(
+                        "`{}` expects {} argument(s), but was given {}",
+                        ident.value.as_str(),
+                        self.parameters.len(),
+                        arguments.len(),
+                    )));
+                    return Some((Node::Fragment(Vec::new()), index))
+                }
+            }
+        }
+        let attribute_values = self.attributes
+            .iter()
+            .map(|(key, default)| {
+                let value = attributes
+                    .get(key.as_str())
+                    .map(|x| unquote(&x.value))
+                    .unwrap_or_else(|| default.clone());
+                (Ident::from(format!("\\{key}")).unwrap(), value)
+            })
+            .collect_vec();
+        let rewrites = self.parameters
+            .iter()
+            .cloned()
+            .zip(arguments)
+            .chain(attribute_values)
+            .map(|(parameter, target)| RewriteRule {
+                pattern: vec![Node::Ident(Ann::unannotated(parameter))],
+                target,
+            })
+            .collect_vec();
+        let expanded = Node::Fragment(self.template.clone()).apply_rewrite_rules(&rewrites);
+        Some((expanded, index))
+    }
+}
+
+/// Without the leading and trailing whitespace, e.g. the line breaks around
+/// a multi-line template.
+fn trim_edges(nodes: &[Node]) -> Vec<Node> {
+    let is_content = |x: &Node| x.get_whitespace_ref().is_none();
+    let start = nodes.iter().position(is_content).unwrap_or(nodes.len());
+    let end = nodes.iter().rposition(is_content).map(|x| x + 1).unwrap_or(start);
+    nodes[start..end].to_vec()
+}
+
+/// Attribute values without the surrounding quotes (if any).
+fn unquote(node: &Node) -> Vec<Node> {
+    match node {
+        Node::Quotation(Ann{value, ..}) => value.children.clone(),
+        Node::Fragment(xs) if xs.len() == 1 => unquote(&xs[0]),
+        Node::Fragment(xs) => xs.clone(),
+        node => vec![node.clone()],
+    }
+}
+
+/// The macros in scope, see `SemanticScope::macros`.
+#[derive(Debug, Clone, Default)]
+pub struct MacroDeclarations {
+    map: HashMap<Ident, Arc<MacroDeclaration>>,
+    /// The macros currently being expanded, for detecting recursive
+    /// definitions.
+    expanding: Vec<Ident>,
+}
+
+impl MacroDeclarations {
+    pub fn get(&self, ident: &Ident) -> Option<&MacroDeclaration> {
+        self.map.get(ident).map(AsRef::as_ref)
+    }
+    pub fn iter(&self) -> impl Iterator<Item=&MacroDeclaration> {
+        self.map.values().map(AsRef::as_ref)
+    }
+    /// Later definitions replace earlier ones of the same name.
+    pub fn insert(&mut self, macro_decl: MacroDeclaration) {
+        self.map.insert(macro_decl.identifier.clone(), Arc::new(macro_decl));
+    }
+    pub fn is_expanding(&self, ident: &Ident) -> bool {
+        self.expanding.contains(ident)
+    }
+    pub fn push_expanding(&mut self, ident: Ident) {
+        self.expanding.push(ident);
+    }
+}
+
+/// The macros defined by the given processed node, i.e. its top-level
+/// `\define` commands, including those of included files.
+pub fn defined_macros(node: &Node) -> Vec<MacroDeclaration> {
+    match node {
+        Node::Cmd(cmd) if cmd.identifier.value == DEFINE_IDENT => cmd.arguments
+            .first()
+            .and_then(|template| MacroDeclaration::parse(&cmd.attributes, template).ok())
+            .into_iter()
+            .collect(),
+        Node::Cmd(cmd) if cmd.identifier.value == "\\include" => cmd.arguments
+            .iter()
+            .flat_map(defined_macros)
+            .collect(),
+        Node::Fragment(xs) => xs
+            .iter()
+            .flat_map(defined_macros)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,1236 @@
+//! The Markdown front end, for pages and `\include` targets with the `.md`
+//! extension (see `is_markdown_file`). Parses (a subset of) CommonMark with
+//! `$…$` and `$$…$$` math into the same (unprocessed) AST as
+//! `crate::ss::parser`, e.g. `# Title` becomes `\h1{Title}`, `$x^2$` becomes
+//! `\{x^2}` and `$$x^2$$` becomes `\math{x^2}`, so that everything after
+//! parsing (commands, labels, images, etc.) works as it does for `.ss` files.
+//! Math is parsed as Subscript, as if it was written in a `.ss` file.
+//!
+//! Supported:
+//! * Blocks: ATX and setext headings, paragraphs, block quotes, (nested)
+//!   bullet and ordered lists, fenced and indented code blocks, math blocks
+//!   and thematic breaks. YAML front matter is skipped.
+//! * Inlines: emphasis, strong emphasis, strikethrough (`~~…~~`), code
+//!   spans, math, links (inline, reference and autolinks), images, hard line
+//!   breaks and backslash escapes.
+//!
+//! Raw HTML is treated as text, as it would be in a `.ss` file.
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+use itertools::Itertools;
+use crate::ss::{Node, Ann, Ident, Bracket, Quotation, CharIndex, CharRange};
+use crate::ss::{SemanticScope, ResourceEnv};
+
+pub const MARKDOWN_FILE_EXT: &str = "md";
+
+pub fn is_markdown_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .map(|x| x == MARKDOWN_FILE_EXT)
+        .unwrap_or(false)
+}
+
+/// Errors (e.g. unbalanced brackets in math) are reported to the given
+/// `env`, as with `crate::ss::parser::parse_source`.
+pub fn parse_source<T: AsRef<str>>(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    source: T,
+) -> Node {
+    let source = source.as_ref();
+    let mut offset = 0;
+    let mut lines = Vec::new();
+    for text in source.split('\n') {
+        lines.push(Line {offset, text: text.strip_suffix('\r').unwrap_or(text)});
+        offset += text.len() + 1;
+    }
+    let mut parser = Parser {
+        env,
+        scope,
+        index: SourceIndex::new(source),
+        definitions: HashMap::default(),
+    };
+    let lines = skip_front_matter(&lines);
+    let blocks = parser.blocks(lines);
+    let nodes = blocks
+        .into_iter()
+        .flat_map(|x| parser.block_to_nodes(x, false))
+        .collect();
+    Node::Fragment(nodes).defragment_node_tree()
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// SOURCE LOCATIONS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+struct SourceIndex<'a> {
+    source: &'a str,
+    /// The byte and char offset of each line.
+    lines: Vec<(usize, usize)>,
+}
+
+impl<'a> SourceIndex<'a> {
+    fn new(source: &'a str) -> Self {
+        let mut lines = vec![(0, 0)];
+        for (char_index, (byte_index, c)) in source.char_indices().enumerate() {
+            if c == '\n' {
+                lines.push((byte_index + 1, char_index + 1));
+            }
+        }
+        SourceIndex {source, lines}
+    }
+    fn char_index(&self, byte_index: usize) -> CharIndex {
+        let line_index = self.lines.partition_point(|(x, _)| *x <= byte_index) - 1;
+        let (line_start, line_char_index) = self.lines[line_index];
+        let char_index = line_char_index + self.source[line_start..byte_index].chars().count();
+        CharIndex {byte_index, char_index, line_index}
+    }
+    fn range(&self, start: usize, end: usize) -> Option<CharRange> {
+        Some(CharRange::new(self.char_index(start), self.char_index(end)))
+    }
+}
+
+/// For ranges of a substring (that starts at `base`) of the source.
+fn shift_range(range: CharRange, base: CharIndex) -> CharRange {
+    let shift_index = |x: CharIndex| CharIndex {
+        byte_index: base.byte_index + x.byte_index,
+        char_index: base.char_index + x.char_index,
+        line_index: base.line_index + x.line_index,
+    };
+    CharRange::new(shift_index(range.start), shift_index(range.end))
+}
+
+fn shift_ranges(node: Node, base: CharIndex) -> Node {
+    let shift = move |range: Option<CharRange>| range.map(|x| shift_range(x, base));
+    let shift_ann = move |x: Ann<String>| Ann::join(shift(x.range), x.value);
+    node.transform(Rc::new(move |node| match node {
+        Node::Ident(x) => Node::Ident(Ann::join(shift(x.range), x.value)),
+        Node::Text(x) => Node::Text(shift_ann(x)),
+        Node::Symbol(x) => Node::Symbol(shift_ann(x)),
+        Node::InvalidToken(x) => Node::InvalidToken(shift_ann(x)),
+        Node::Bracket(Ann {range, mut value}) => {
+            value.open = value.open.map(shift_ann);
+            value.close = value.close.map(shift_ann);
+            Node::Bracket(Ann::join(shift(range), value))
+        }
+        Node::Quotation(Ann {range, mut value}) => {
+            value.open = value.open.map(shift_ann);
+            value.close = value.close.map(shift_ann);
+            Node::Quotation(Ann::join(shift(range), value))
+        }
+        node => node,
+    }))
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LINES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// A line (or the rest of a line, e.g. without the `>` of a block quote),
+/// without the line break.
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    /// The byte offset of `text` in the source.
+    offset: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+    /// The width of the leading whitespace, where tabs are expanded to the
+    /// next multiple of 4 columns.
+    fn indent(&self) -> usize {
+        let mut width = 0;
+        for c in self.text.chars() {
+            match c {
+                ' ' => width += 1,
+                '\t' => width += 4 - width % 4,
+                _ => break,
+            }
+        }
+        width
+    }
+    /// Removes up to the given number of columns of leading whitespace.
+    fn dedent(self, columns: usize) -> Line<'a> {
+        let mut width = 0;
+        let mut len = 0;
+        for c in self.text.chars() {
+            if width >= columns {
+                break
+            }
+            match c {
+                ' ' => width += 1,
+                '\t' => width += 4 - width % 4,
+                _ => break,
+            }
+            len += 1;
+        }
+        self.skip(len)
+    }
+    fn skip(self, len: usize) -> Line<'a> {
+        Line {offset: self.offset + len, text: &self.text[len..]}
+    }
+    fn take(self, len: usize) -> Line<'a> {
+        Line {offset: self.offset, text: &self.text[..len]}
+    }
+    fn trim_start(self) -> Line<'a> {
+        self.skip(self.text.len() - self.text.trim_start().len())
+    }
+    fn trim_end(self) -> Line<'a> {
+        self.take(self.text.trim_end().len())
+    }
+}
+
+/// A line that is indented by at most 3 columns, without the indentation.
+fn unindented(line: Line) -> Option<Line> {
+    if line.indent() < 4 {
+        return Some(line.trim_start())
+    }
+    None
+}
+
+fn skip_front_matter<'a, 'b>(lines: &'b [Line<'a>]) -> &'b [Line<'a>] {
+    if lines.first().map(|x| x.text.trim_end() == "---").unwrap_or(false) {
+        let end = lines
+            .iter()
+            .skip(1)
+            .position(|x| x.text.trim_end() == "---" || x.text.trim_end() == "...");
+        if let Some(end) = end {
+            return &lines[end + 2..]
+        }
+    }
+    lines
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// BLOCK STARTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The level, the `#` marker and the contents.
+fn atx_heading(line: Line) -> Option<(usize, Line, Line)> {
+    let line = unindented(line)?;
+    let level = line.text.chars().take_while(|x| *x == '#').count();
+    if level == 0 || level > 6 {
+        return None
+    }
+    let rest = line.skip(level);
+    if !rest.text.is_empty() && !rest.text.starts_with([' ', '\t']) {
+        return None
+    }
+    let mut contents = rest.trim_start().trim_end();
+    // The optional closing sequence, e.g. `## Title ##`.
+    let without_closing = contents.text.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        contents = contents.take(without_closing.trim_end().len());
+    }
+    Some((level, line.take(level), contents))
+}
+
+fn setext_underline(line: Line) -> Option<usize> {
+    let text = unindented(line)?.text.trim_end();
+    match text.chars().next()? {
+        '=' if text.chars().all(|x| x == '=') => Some(1),
+        '-' if text.chars().all(|x| x == '-') => Some(2),
+        _ => None,
+    }
+}
+
+fn is_thematic_break(line: Line) -> bool {
+    let line = match unindented(line) {
+        Some(line) => line,
+        None => return false,
+    };
+    let mut chars = line.text.chars().filter(|x| !x.is_whitespace());
+    match chars.next() {
+        Some(c @ ('-' | '*' | '_')) => {
+            let rest = chars.collect_vec();
+            rest.len() >= 2 && rest.into_iter().all(|x| x == c)
+        }
+        _ => false,
+    }
+}
+
+/// The fence character, its length and the info string.
+fn code_fence(line: Line) -> Option<(char, usize, Line)> {
+    let line = unindented(line)?;
+    let fence = line.text.chars().next().filter(|x| *x == '`' || *x == '~')?;
+    let len = line.text.chars().take_while(|x| *x == fence).count();
+    if len < 3 {
+        return None
+    }
+    let info = line.skip(len).trim_start().trim_end();
+    if fence == '`' && info.text.contains('`') {
+        return None
+    }
+    Some((fence, len, info))
+}
+
+fn is_closing_code_fence(line: Line, fence: char, len: usize) -> bool {
+    unindented(line)
+        .map(|line| {
+            let fence_len = line.text.chars().take_while(|x| *x == fence).count();
+            fence_len >= len && line.text[fence_len..].trim().is_empty()
+        })
+        .unwrap_or(false)
+}
+
+/// The `$$` marker.
+fn math_fence(line: Line) -> Option<Line> {
+    unindented(line).filter(|x| x.text.starts_with("$$")).map(|x| x.take(2))
+}
+
+/// The `>` marker and the rest of the line.
+fn block_quote_marker(line: Line) -> Option<(Line, Line)> {
+    let line = unindented(line)?;
+    if !line.text.starts_with('>') {
+        return None
+    }
+    Some((line.take(1), line.skip(1).dedent(1)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ListKind {
+    /// The bullet character.
+    Bullet(char),
+    /// The delimiter, i.e. `.` or `)`.
+    Ordered(char),
+}
+
+struct ListItemStart<'a> {
+    kind: ListKind,
+    number: Option<u64>,
+    marker: Line<'a>,
+    /// The rest of the first line.
+    contents: Line<'a>,
+    /// The indentation of the item’s contents, so that following lines
+    /// with at least as much indentation belong to the item.
+    width: usize,
+}
+
+fn list_item_start(line: Line) -> Option<ListItemStart> {
+    let indent = line.indent();
+    let line = unindented(line)?;
+    let text = line.text;
+    let (kind, number, marker_len) = match text.chars().next()? {
+        c @ ('-' | '*' | '+') => (ListKind::Bullet(c), None, 1),
+        _ => {
+            let digits = text.chars().take_while(|x| x.is_ascii_digit()).count();
+            if digits == 0 || digits > 9 {
+                return None
+            }
+            let delimiter = text[digits..].chars().next().filter(|x| *x == '.' || *x == ')')?;
+            (ListKind::Ordered(delimiter), text[..digits].parse().ok(), digits + 1)
+        }
+    };
+    let rest = line.skip(marker_len);
+    if !rest.text.is_empty() && !rest.text.starts_with([' ', '\t']) {
+        return None
+    }
+    let spaces = rest.indent();
+    let (contents, width) = if rest.is_blank() {
+        (rest.trim_start(), indent + marker_len + 1)
+    } else if spaces > 4 {
+        // The contents are an indented code block.
+        (rest.dedent(1), indent + marker_len + 1)
+    } else {
+        (rest.trim_start(), indent + marker_len + spaces)
+    };
+    Some(ListItemStart {kind, number, marker: line.take(marker_len), contents, width})
+}
+
+/// Lines that don’t interrupt a paragraph are part of it, even without
+/// indentation.
+fn interrupts_paragraph(line: Line) -> bool {
+    if line.indent() >= 4 {
+        return false
+    }
+    let is_list_item = list_item_start(line)
+        .map(|x| !x.contents.is_blank() && x.number.map(|x| x == 1).unwrap_or(true))
+        .unwrap_or(false);
+    atx_heading(line).is_some() ||
+    is_thematic_break(line) ||
+    code_fence(line).is_some() ||
+    math_fence(line).is_some() ||
+    block_quote_marker(line).is_some() ||
+    is_list_item
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LINK DEFINITIONS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+struct LinkDefinition {
+    url: String,
+    title: Option<String>,
+}
+
+/// Link labels are case-insensitive, and consecutive whitespace is treated
+/// as a single space.
+fn normalize_link_label(label: &str) -> String {
+    label.split_whitespace().join(" ").to_lowercase()
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '\\' && next.is_ascii_punctuation() => {
+                result.push(*next);
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// E.g. `<url>` or `url`, where parentheses must be balanced, followed by
+/// the rest of the given text.
+fn link_destination(text: &str) -> Option<(String, &str)> {
+    if let Some(rest) = text.strip_prefix('<') {
+        let end = rest.find(['>', '\n'])?;
+        if !rest[end..].starts_with('>') {
+            return None
+        }
+        return Some((unescape(&rest[..end]), &rest[end + 1..]))
+    }
+    let mut depth = 0;
+    let mut end = text.len();
+    let mut escaped = false;
+    for (ix, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                end = ix;
+                break
+            }
+            ')' => depth -= 1,
+            c if c.is_whitespace() || c.is_control() => {
+                end = ix;
+                break
+            }
+            _ => {}
+        }
+    }
+    if end == 0 || depth != 0 {
+        return None
+    }
+    Some((unescape(&text[..end]), &text[end..]))
+}
+
+/// E.g. `"title"`, `'title'` or `(title)`, followed by the rest of the given
+/// text.
+fn link_title(text: &str) -> Option<(String, &str)> {
+    let close = match text.chars().next()? {
+        '"' => '"',
+        '\'' => '\'',
+        '(' => ')',
+        _ => return None,
+    };
+    let mut escaped = false;
+    for (ix, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == close => return Some((unescape(&text[1..ix]), &text[ix + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// E.g. `[label]: url "title"`, which must fit on one line.
+fn link_definition(line: Line) -> Option<(String, LinkDefinition)> {
+    let rest = unindented(line)?.text.strip_prefix('[')?;
+    let close = rest.find(']')?;
+    let label = &rest[..close];
+    if label.trim().is_empty() || label.contains('[') {
+        return None
+    }
+    let rest = rest[close + 1..].strip_prefix(':')?.trim_start();
+    let (url, rest) = link_destination(rest)?;
+    let title = match rest.trim() {
+        "" => None,
+        // The title must be separated from the URL.
+        _ if !rest.starts_with([' ', '\t']) => return None,
+        rest => {
+            let (title, rest) = link_title(rest)?;
+            if !rest.trim().is_empty() {
+                return None
+            }
+            Some(title)
+        }
+    };
+    Some((normalize_link_label(label), LinkDefinition {url, title}))
+}
+
+/// The rest of an inline link after the `(`, e.g. `url "title")`. Returns
+/// the length up to and including the `)`.
+fn inline_link_destination(text: &str) -> Option<(String, Option<String>, usize)> {
+    let rest = text.trim_start();
+    let (url, rest) = match rest.starts_with(')') {
+        true => (String::new(), rest),
+        false => link_destination(rest)?,
+    };
+    let after_url = rest.trim_start();
+    let (title, rest) = match after_url.starts_with(['"', '\'', '(']) && after_url.len() < rest.len() {
+        true => {
+            let (title, rest) = link_title(after_url)?;
+            (Some(title), rest.trim_start())
+        }
+        false => (None, after_url),
+    };
+    if !rest.starts_with(')') {
+        return None
+    }
+    Some((url, title, text.len() - rest.len() + 1))
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// BLOCKS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone)]
+enum Block<'a> {
+    Heading {level: usize, marker: Line<'a>, lines: Vec<Line<'a>>},
+    Paragraph(Vec<Line<'a>>),
+    Code {marker: Line<'a>, info: Option<&'a str>, lines: Vec<Line<'a>>},
+    Math {marker: Line<'a>, lines: Vec<Line<'a>>},
+    Quote {marker: Line<'a>, blocks: Vec<Block<'a>>},
+    List {
+        kind: ListKind,
+        number: Option<u64>,
+        tight: bool,
+        items: Vec<(Line<'a>, Vec<Block<'a>>)>,
+    },
+    Rule(Line<'a>),
+}
+
+struct Parser<'a> {
+    env: &'a ResourceEnv,
+    scope: &'a SemanticScope,
+    index: SourceIndex<'a>,
+    /// Link reference definitions, which may appear after their use.
+    definitions: HashMap<String, LinkDefinition>,
+}
+
+impl<'a> Parser<'a> {
+    fn blocks(&mut self, lines: &[Line<'a>]) -> Vec<Block<'a>> {
+        let mut blocks = Vec::new();
+        let mut ix = 0;
+        while ix < lines.len() {
+            let line = lines[ix];
+            if line.is_blank() {
+                ix += 1;
+                continue
+            }
+            if line.indent() >= 4 {
+                // INDENTED CODE BLOCK
+                let mut end = ix;
+                while end < lines.len() && (lines[end].is_blank() || lines[end].indent() >= 4) {
+                    end += 1;
+                }
+                while lines[end - 1].is_blank() {
+                    end -= 1;
+                }
+                let code = lines[ix..end].iter().map(|x| x.dedent(4)).collect();
+                blocks.push(Block::Code {marker: line.dedent(4).take(0), info: None, lines: code});
+                ix = end;
+                continue
+            }
+            if let Some((fence, len, info)) = code_fence(line) {
+                let indent = line.indent();
+                let mut end = ix + 1;
+                while end < lines.len() && !is_closing_code_fence(lines[end], fence, len) {
+                    end += 1;
+                }
+                let code = lines[ix + 1..end.min(lines.len())]
+                    .iter()
+                    .map(|x| x.dedent(indent))
+                    .collect();
+                let marker = line.trim_start().take(len);
+                let info = info.text.split_whitespace().next();
+                blocks.push(Block::Code {marker, info, lines: code});
+                ix = end + 1;
+                continue
+            }
+            if let Some(marker) = math_fence(line) {
+                // Either `$$…$$` on one line, or `$$` … `$$` on several.
+                let rest = unindented(line).unwrap().skip(2);
+                let mut math = Vec::new();
+                ix += 1;
+                match rest.text.find("$$") {
+                    Some(end) => math.push(rest.take(end)),
+                    None => {
+                        if !rest.is_blank() {
+                            math.push(rest);
+                        }
+                        while ix < lines.len() {
+                            let line = lines[ix];
+                            ix += 1;
+                            if let Some(end) = line.text.find("$$") {
+                                math.push(line.take(end));
+                                break
+                            }
+                            math.push(line);
+                        }
+                    }
+                }
+                blocks.push(Block::Math {marker, lines: math});
+                continue
+            }
+            if let Some((level, marker, contents)) = atx_heading(line) {
+                blocks.push(Block::Heading {level, marker, lines: vec![contents]});
+                ix += 1;
+                continue
+            }
+            if is_thematic_break(line) {
+                blocks.push(Block::Rule(line.trim_start().trim_end()));
+                ix += 1;
+                continue
+            }
+            if let Some((marker, _)) = block_quote_marker(line) {
+                let mut quoted: Vec<Line> = Vec::new();
+                while ix < lines.len() {
+                    let line = lines[ix];
+                    if let Some((_, rest)) = block_quote_marker(line) {
+                        quoted.push(rest);
+                        ix += 1;
+                        continue
+                    }
+                    // A paragraph continuation line (without the `>`).
+                    let is_lazy = !line.is_blank() &&
+                        !quoted.last().map(Line::is_blank).unwrap_or(true) &&
+                        !interrupts_paragraph(line);
+                    if is_lazy {
+                        quoted.push(line);
+                        ix += 1;
+                        continue
+                    }
+                    break
+                }
+                let blocks_ = self.blocks(&quoted);
+                blocks.push(Block::Quote {marker, blocks: blocks_});
+                continue
+            }
+            if let Some(first) = list_item_start(line) {
+                let (block, end) = self.list(lines, ix, first.kind);
+                blocks.push(block);
+                ix = end;
+                continue
+            }
+            // PARAGRAPH
+            let mut paragraph = vec![line];
+            let mut setext_heading: Option<(usize, Line)> = None;
+            ix += 1;
+            while ix < lines.len() {
+                let line = lines[ix];
+                if line.is_blank() {
+                    break
+                }
+                if let Some(level) = setext_underline(line) {
+                    setext_heading = Some((level, line.trim_start().trim_end()));
+                    ix += 1;
+                    break
+                }
+                if interrupts_paragraph(line) {
+                    break
+                }
+                paragraph.push(line);
+                ix += 1;
+            }
+            while let Some((label, definition)) = paragraph.first().and_then(|x| link_definition(*x)) {
+                self.definitions.entry(label).or_insert(definition);
+                paragraph.remove(0);
+            }
+            match setext_heading {
+                _ if paragraph.is_empty() => {}
+                Some((level, marker)) => blocks.push(Block::Heading {level, marker, lines: paragraph}),
+                None => blocks.push(Block::Paragraph(paragraph)),
+            }
+        }
+        blocks
+    }
+    /// Returns the list and the index of the line after it.
+    fn list(&mut self, lines: &[Line<'a>], mut ix: usize, kind: ListKind) -> (Block<'a>, usize) {
+        let mut items = Vec::new();
+        let mut tight = true;
+        let mut number = None;
+        while ix < lines.len() {
+            let item = match list_item_start(lines[ix]) {
+                Some(item) if item.kind == kind && !is_thematic_break(lines[ix]) => item,
+                _ => break,
+            };
+            if items.is_empty() {
+                number = item.number;
+            }
+            let mut item_lines = vec![item.contents];
+            ix += 1;
+            while ix < lines.len() {
+                let line = lines[ix];
+                if line.is_blank() || line.indent() >= item.width {
+                    item_lines.push(line.dedent(item.width));
+                    ix += 1;
+                    continue
+                }
+                // A paragraph continuation line (without indentation).
+                let is_lazy = !item_lines.last().map(Line::is_blank).unwrap_or(true) &&
+                    !interrupts_paragraph(line) &&
+                    list_item_start(line).is_none();
+                if is_lazy {
+                    item_lines.push(line);
+                    ix += 1;
+                    continue
+                }
+                break
+            }
+            let mut trailing_blank_lines = 0;
+            while item_lines.len() > 1 && item_lines.last().map(Line::is_blank).unwrap_or(false) {
+                item_lines.pop();
+                trailing_blank_lines += 1;
+            }
+            let is_followed_by_item = lines
+                .get(ix)
+                .and_then(|x| list_item_start(*x))
+                .map(|x| x.kind == kind)
+                .unwrap_or(false);
+            let blocks = self.blocks(&item_lines);
+            // I.e. blank lines between items, or between the blocks of an
+            // item.
+            if (trailing_blank_lines > 0 && is_followed_by_item) ||
+               (blocks.len() > 1 && item_lines.iter().any(Line::is_blank)) {
+                tight = false;
+            }
+            items.push((item.marker, blocks));
+        }
+        (Block::List {kind, number, tight, items}, ix)
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// NODE HELPERS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+fn ident(name: &str, range: Option<CharRange>) -> Node {
+    Node::Ident(Ann::join(range, Ident::from(name).unwrap()))
+}
+
+/// E.g. `[href="…", title="…"]`.
+fn attributes_node(attributes: Vec<(&str, String)>) -> Node {
+    let mut children = Vec::new();
+    for (key, value) in attributes {
+        if !children.is_empty() {
+            children.push(Node::Symbol(",".into()));
+        }
+        let value = Node::Quotation(Ann::unannotated(Quotation {
+            open: Some("\"".into()),
+            close: Some("\"".into()),
+            children: vec![Node::new_text(value)],
+        }));
+        children.extend([Node::new_text(key), Node::Symbol("=".into()), value]);
+    }
+    Node::new_square_paren(children)
+}
+
+/// The (unprocessed) nodes of a command call, e.g. `\a[href="…"]{…}`.
+fn cmd_call(
+    name: &str,
+    range: Option<CharRange>,
+    attributes: Vec<(&str, String)>,
+    arguments: Option<Vec<Node>>,
+) -> Vec<Node> {
+    let mut nodes = vec![ident(name, range)];
+    if !attributes.is_empty() {
+        nodes.push(attributes_node(attributes));
+    }
+    if let Some(arguments) = arguments {
+        nodes.push(Node::new_curly_brace(arguments));
+    }
+    nodes
+}
+
+impl<'a> Parser<'a> {
+    fn line_range(&self, line: Line) -> Option<CharRange> {
+        self.index.range(line.offset, line.offset + line.text.len())
+    }
+    /// The given math (that starts at the given offset of the source) as a
+    /// curly brace argument, e.g. for `\math`.
+    fn math_argument(&self, offset: usize, code: &str) -> Node {
+        let env = ResourceEnv::default();
+        let base = self.index.char_index(offset);
+        let nodes = crate::ss::parser::parse_source(&env, self.scope, code);
+        for diagnostic in env.diagnostics() {
+            let range = diagnostic.range.map(|x| shift_range(x, base));
+            self.env.report(diagnostic.with_range(range));
+        }
+        let children = shift_ranges(nodes, base).unfragment_root();
+        let range = self.index.range(offset, offset + code.len());
+        Node::Bracket(Ann::join(range, Bracket {
+            open: Some("{".into()),
+            close: Some("}".into()),
+            children,
+        }))
+    }
+    fn inline_nodes(&self, lines: &[Line]) -> Vec<Node> {
+        let mut lines = lines.iter().map(|x| x.trim_start()).collect_vec();
+        if let Some(last) = lines.last_mut() {
+            *last = last.trim_end();
+        }
+        let span = Span::new(&lines);
+        let inlines = InlineParser {parser: self, span: &span};
+        inlines.nodes(0, span.text.len())
+    }
+    fn block_to_nodes(&self, block: Block, tight: bool) -> Vec<Node> {
+        match block {
+            Block::Heading {level, marker, lines} => {
+                let name = format!("\\h{level}");
+                cmd_call(&name, self.line_range(marker), vec![], Some(self.inline_nodes(&lines)))
+            }
+            Block::Paragraph(lines) if tight => self.inline_nodes(&lines),
+            Block::Paragraph(lines) => {
+                let range = self.line_range(lines[0].trim_start().take(0));
+                cmd_call("\\p", range, vec![], Some(self.inline_nodes(&lines)))
+            }
+            Block::Code {marker, info, lines} => {
+                let code = lines.iter().map(|x| x.text).join("\n");
+                let range = match (lines.first(), lines.last()) {
+                    (Some(first), Some(last)) => self.index.range(first.offset, last.offset + last.text.len()),
+                    _ => None,
+                };
+                // Highlighted, see `crate::html::highlight`.
+                let attributes = info
+                    .map(|lang| vec![("lang", unescape(lang))])
+                    .unwrap_or_default();
+                let code = cmd_call("\\code", None, attributes, Some(vec![Node::Text(Ann::join(range, code))]));
+                cmd_call("\\pre", self.line_range(marker), vec![], Some(code))
+            }
+            Block::Math {marker, lines} => {
+                let code = lines.iter().map(|x| x.text).join("\n");
+                let offset = lines.first().map(|x| x.offset).unwrap_or(marker.offset + 2);
+                let argument = self.math_argument(offset, &code);
+                let mut nodes = cmd_call("\\math", self.line_range(marker), vec![], None);
+                nodes.push(argument);
+                nodes
+            }
+            Block::Quote {marker, blocks} => {
+                let children = blocks
+                    .into_iter()
+                    .flat_map(|x| self.block_to_nodes(x, false))
+                    .collect();
+                cmd_call("\\blockquote", self.line_range(marker), vec![], Some(children))
+            }
+            Block::List {kind, number, tight, items} => {
+                let range = items.first().and_then(|(marker, _)| self.line_range(*marker));
+                let (name, attributes) = match (kind, number) {
+                    (ListKind::Bullet(_), _) => ("\\ul", vec![]),
+                    (ListKind::Ordered(_), Some(number)) if number != 1 => {
+                        ("\\ol", vec![("start", number.to_string())])
+                    }
+                    (ListKind::Ordered(_), _) => ("\\ol", vec![]),
+                };
+                let children = items
+                    .into_iter()
+                    .flat_map(|(marker, blocks)| {
+                        let children = blocks
+                            .into_iter()
+                            .flat_map(|x| self.block_to_nodes(x, tight))
+                            .collect();
+                        cmd_call("\\li", self.line_range(marker), vec![], Some(children))
+                    })
+                    .collect();
+                cmd_call(name, range, attributes, Some(children))
+            }
+            Block::Rule(line) => cmd_call("\\hr", self.line_range(line), vec![], None),
+        }
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// INLINES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The inline contents of a block, where lines are joined with `\n`.
+struct Span {
+    text: String,
+    /// The offset of each line in `text` and in the source.
+    offsets: Vec<(usize, usize)>,
+}
+
+impl Span {
+    fn new(lines: &[Line]) -> Self {
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(lines.len());
+        for (ix, line) in lines.iter().enumerate() {
+            if ix > 0 {
+                text.push('\n');
+            }
+            offsets.push((text.len(), line.offset));
+            text.push_str(line.text);
+        }
+        Span {text, offsets}
+    }
+    fn source_offset(&self, ix: usize) -> usize {
+        let line = self.offsets.partition_point(|(x, _)| *x <= ix).saturating_sub(1);
+        self.offsets
+            .get(line)
+            .map(|(text_offset, source_offset)| source_offset + ix - text_offset)
+            .unwrap_or(0)
+    }
+}
+
+struct InlineParser<'p, 'a> {
+    parser: &'p Parser<'a>,
+    span: &'p Span,
+}
+
+struct Link {
+    /// The offsets of the text between the brackets.
+    label: (usize, usize),
+    url: String,
+    title: Option<String>,
+    /// The offset after the link.
+    end: usize,
+}
+
+impl<'p, 'a> InlineParser<'p, 'a> {
+    fn range(&self, start: usize, end: usize) -> Option<CharRange> {
+        self.parser.index.range(self.span.source_offset(start), self.span.source_offset(end))
+    }
+    fn char_at(&self, ix: usize) -> Option<char> {
+        self.span.text.get(ix..).and_then(|x| x.chars().next())
+    }
+    fn char_before(&self, ix: usize) -> Option<char> {
+        self.span.text.get(..ix).and_then(|x| x.chars().next_back())
+    }
+    fn run_len(&self, ix: usize, c: char, end: usize) -> usize {
+        self.span.text[ix..end].chars().take_while(|x| *x == c).count()
+    }
+    /// The end of the code span that starts at the given offset (if it is
+    /// one), i.e. the offset of the closing backticks.
+    fn code_span_end(&self, start: usize, end: usize) -> Option<usize> {
+        let len = self.run_len(start, '`', end);
+        let mut ix = start + len;
+        while let Some(found) = self.span.text[ix..end].find('`') {
+            let close = ix + found;
+            let close_len = self.run_len(close, '`', end);
+            if close_len == len {
+                return Some(close)
+            }
+            ix = close + close_len;
+        }
+        None
+    }
+    /// The offset of the closing delimiter run (and its length) for
+    /// emphasis that opens before the given offset.
+    fn emphasis_closer(&self, c: char, start: usize, end: usize, min_len: usize) -> Option<(usize, usize)> {
+        let mut ix = start;
+        while ix < end {
+            let next = self.char_at(ix)?;
+            if next == '\\' {
+                ix += 1 + self.char_at(ix + 1).map(char::len_utf8).unwrap_or(0);
+                continue
+            }
+            if next == '`' {
+                match self.code_span_end(ix, end) {
+                    Some(close) => ix = close + self.run_len(close, '`', end),
+                    None => ix += self.run_len(ix, '`', end),
+                }
+                continue
+            }
+            if next != c {
+                ix += next.len_utf8();
+                continue
+            }
+            let len = self.run_len(ix, c, end);
+            let before = self.char_before(ix);
+            let after = self.char_at(ix + len).filter(|_| ix + len < end);
+            let right_flanking = ix > start &&
+                before.map(|x| !x.is_whitespace()).unwrap_or(false) &&
+                (c == '*' || !after.map(char::is_alphanumeric).unwrap_or(false));
+            let left_flanking = after.map(|x| !x.is_whitespace()).unwrap_or(false);
+            // Runs in the middle of a word open nested emphasis.
+            let is_nested_opener = min_len == 1 && len >= 2 && left_flanking;
+            if right_flanking && len >= min_len && !is_nested_opener {
+                return Some((ix, len))
+            }
+            ix += len;
+        }
+        None
+    }
+    /// The closing `$` of inline math.
+    fn math_closer(&self, start: usize, end: usize) -> Option<usize> {
+        let mut ix = start;
+        while ix < end {
+            let next = self.char_at(ix)?;
+            if next == '\\' {
+                ix += 1 + self.char_at(ix + 1).map(char::len_utf8).unwrap_or(0);
+                continue
+            }
+            let is_closer = next == '$' &&
+                ix > start &&
+                !self.char_before(ix).map(char::is_whitespace).unwrap_or(true) &&
+                !self.char_at(ix + 1).filter(|_| ix + 1 < end).map(|x| x.is_ascii_digit()).unwrap_or(false);
+            if is_closer {
+                return Some(ix)
+            }
+            ix += next.len_utf8();
+        }
+        None
+    }
+    /// For the `[` at the given offset.
+    fn link(&self, start: usize, end: usize) -> Option<Link> {
+        let text = &self.span.text;
+        let mut depth = 0;
+        let mut ix = start + 1;
+        let mut close = None;
+        while ix < end {
+            let next = self.char_at(ix)?;
+            match next {
+                '\\' => {
+                    ix += 1 + self.char_at(ix + 1).map(char::len_utf8).unwrap_or(0);
+                    continue
+                }
+                '`' => {
+                    ix = match self.code_span_end(ix, end) {
+                        Some(close) => close + self.run_len(close, '`', end),
+                        None => ix + self.run_len(ix, '`', end),
+                    };
+                    continue
+                }
+                '[' => depth += 1,
+                ']' if depth == 0 => {
+                    close = Some(ix);
+                    break
+                }
+                ']' => depth -= 1,
+                _ => {}
+            }
+            ix += next.len_utf8();
+        }
+        let close = close?;
+        let label = (start + 1, close);
+        let rest = &text[close + 1..end];
+        if let Some(destination) = rest.strip_prefix('(') {
+            if let Some((url, title, len)) = inline_link_destination(destination) {
+                return Some(Link {label, url, title, end: close + 2 + len})
+            }
+        }
+        // REFERENCE LINKS, i.e. `[text][label]`, `[label][]` or `[label]`.
+        let (reference, end) = match rest.strip_prefix('[').and_then(|x| Some((x, x.find(']')?))) {
+            Some((_, 0)) => (&text[label.0..label.1], close + 3),
+            Some((x, len)) => (&x[..len], close + 3 + len),
+            None => (&text[label.0..label.1], close + 1),
+        };
+        let definition = self.parser.definitions.get(&normalize_link_label(reference))?;
+        Some(Link {
+            label,
+            url: definition.url.clone(),
+            title: definition.title.clone(),
+            end,
+        })
+    }
+    /// E.g. `<https://example.com>`, returns the URL and the offset after
+    /// the link.
+    fn autolink(&self, start: usize, end: usize) -> Option<(String, usize)> {
+        let rest = &self.span.text[start + 1..end];
+        let close = rest.find(['>', '<', ' ', '\n'])?;
+        if !rest[close..].starts_with('>') {
+            return None
+        }
+        let url = &rest[..close];
+        let is_uri = url
+            .split_once(':')
+            .map(|(scheme, _)| scheme.len() >= 2 && scheme.chars().all(|x| x.is_ascii_alphanumeric() || "+.-".contains(x)))
+            .unwrap_or(false);
+        let is_email = !is_uri && url.split_once('@').map(|(l, r)| !l.is_empty() && r.contains('.')).unwrap_or(false);
+        match (is_uri, is_email) {
+            (true, _) => Some((url.to_owned(), start + close + 2)),
+            (_, true) => Some((format!("mailto:{url}"), start + close + 2)),
+            _ => None,
+        }
+    }
+    fn nodes(&self, start: usize, end: usize) -> Vec<Node> {
+        let text = &self.span.text;
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut pending = String::new();
+        let mut pending_start = start;
+        let flush = |nodes: &mut Vec<Node>, pending: &mut String, pending_start: &mut usize, ix: usize| {
+            if !pending.is_empty() {
+                let range = self.range(*pending_start, ix);
+                nodes.push(Node::Text(Ann::join(range, std::mem::take(pending))));
+            }
+            *pending_start = ix;
+        };
+        let mut ix = start;
+        while ix < end {
+            let c = self.char_at(ix).unwrap();
+            let next = self.char_at(ix + c.len_utf8()).filter(|_| ix + c.len_utf8() < end);
+            match c {
+                '\\' if next == Some('\n') => {
+                    flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                    nodes.extend(cmd_call("\\br", self.range(ix, ix + 1), vec![], None));
+                    ix += 1;
+                    pending_start = ix;
+                    continue
+                }
+                '\\' if next.map(|x| x.is_ascii_punctuation()).unwrap_or(false) => {
+                    pending.push(next.unwrap());
+                    ix += 2;
+                    continue
+                }
+                '\n' => {
+                    // Two or more trailing spaces are a hard line break.
+                    let trailing_spaces = pending.len() - pending.trim_end_matches(' ').len();
+                    pending.truncate(pending.len() - trailing_spaces);
+                    if trailing_spaces >= 2 {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix - trailing_spaces);
+                        nodes.extend(cmd_call("\\br", self.range(ix - trailing_spaces, ix), vec![], None));
+                        pending_start = ix;
+                    }
+                    pending.push('\n');
+                    ix += 1;
+                    continue
+                }
+                '`' => {
+                    let len = self.run_len(ix, '`', end);
+                    if let Some(close) = self.code_span_end(ix, end) {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let mut code = text[ix + len..close].replace('\n', " ");
+                        let is_padded = code.starts_with(' ') && code.ends_with(' ') && !code.trim().is_empty();
+                        if is_padded {
+                            code = code[1..code.len() - 1].to_owned();
+                        }
+                        let code = Node::Text(Ann::join(self.range(ix + len, close), code));
+                        nodes.extend(cmd_call("\\code", self.range(ix, ix + len), vec![], Some(vec![code])));
+                        ix = close + len;
+                        pending_start = ix;
+                        continue
+                    }
+                    pending.push_str(&text[ix..ix + len]);
+                    ix += len;
+                    continue
+                }
+                '$' if next == Some('$') => {
+                    let close = text[ix + 2..end].find("$$").map(|x| ix + 2 + x).filter(|x| *x > ix + 2);
+                    if let Some(close) = close {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let argument = self.parser.math_argument(self.span.source_offset(ix + 2), &text[ix + 2..close]);
+                        nodes.extend(cmd_call("\\math", self.range(ix, ix + 2), vec![], None));
+                        nodes.push(argument);
+                        ix = close + 2;
+                        pending_start = ix;
+                        continue
+                    }
+                    pending.push_str("$$");
+                    ix += 2;
+                    continue
+                }
+                '$' if next.map(|x| !x.is_whitespace()).unwrap_or(false) => {
+                    if let Some(close) = self.math_closer(ix + 1, end) {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let argument = self.parser.math_argument(self.span.source_offset(ix + 1), &text[ix + 1..close]);
+                        nodes.extend(cmd_call("\\", self.range(ix, ix + 1), vec![], None));
+                        nodes.push(argument);
+                        ix = close + 1;
+                        pending_start = ix;
+                        continue
+                    }
+                }
+                '*' | '_' => {
+                    let len = self.run_len(ix, c, end);
+                    let after = self.char_at(ix + len).filter(|_| ix + len < end);
+                    let before = self.char_before(ix).filter(|_| ix > start);
+                    let left_flanking = after.map(|x| !x.is_whitespace()).unwrap_or(false) &&
+                        (c == '*' || !before.map(char::is_alphanumeric).unwrap_or(false));
+                    let strong = Some(len)
+                        .filter(|len| *len >= 2 && left_flanking)
+                        .and_then(|_| self.emphasis_closer(c, ix + 2, end, 2))
+                        .map(|closer| ("\\strong", 2, closer));
+                    let emphasis = strong.or_else(|| {
+                        Some(len)
+                            .filter(|_| left_flanking)
+                            .and_then(|_| self.emphasis_closer(c, ix + 1, end, 1))
+                            .map(|closer| ("\\em", 1, closer))
+                    });
+                    if let Some((name, delimiter_len, (close, close_len))) = emphasis {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let children = self.nodes(ix + delimiter_len, close + close_len - delimiter_len);
+                        let range = self.range(ix, ix + delimiter_len);
+                        nodes.extend(cmd_call(name, range, vec![], Some(children)));
+                        ix = close + close_len;
+                        pending_start = ix;
+                        continue
+                    }
+                    pending.push_str(&text[ix..ix + len]);
+                    ix += len;
+                    continue
+                }
+                '~' if next == Some('~') => {
+                    let close = text[ix + 2..end]
+                        .find("~~")
+                        .map(|x| ix + 2 + x)
+                        .filter(|x| *x > ix + 2 && !self.char_before(*x).map(char::is_whitespace).unwrap_or(true));
+                    if let Some(close) = close {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let children = self.nodes(ix + 2, close);
+                        nodes.extend(cmd_call("\\del", self.range(ix, ix + 2), vec![], Some(children)));
+                        ix = close + 2;
+                        pending_start = ix;
+                        continue
+                    }
+                    pending.push_str("~~");
+                    ix += 2;
+                    continue
+                }
+                '!' if next == Some('[') => {
+                    if let Some(link) = self.link(ix + 1, end) {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        // `\img` has no `alt` attribute, so the description
+                        // is dropped.
+                        let attributes = vec![("src", link.url)];
+                        nodes.extend(cmd_call("\\img", self.range(ix, ix + 2), attributes, None));
+                        ix = link.end;
+                        pending_start = ix;
+                        continue
+                    }
+                }
+                '[' => {
+                    if let Some(link) = self.link(ix, end) {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let mut attributes = vec![("href", link.url)];
+                        if let Some(title) = link.title {
+                            attributes.push(("title", title));
+                        }
+                        let children = self.nodes(link.label.0, link.label.1);
+                        nodes.extend(cmd_call("\\a", self.range(ix, ix + 1), attributes, Some(children)));
+                        ix = link.end;
+                        pending_start = ix;
+                        continue
+                    }
+                }
+                '<' => {
+                    if let Some((url, link_end)) = self.autolink(ix, end) {
+                        flush(&mut nodes, &mut pending, &mut pending_start, ix);
+                        let label = Node::Text(Ann::join(self.range(ix + 1, link_end - 1), text[ix + 1..link_end - 1].to_owned()));
+                        nodes.extend(cmd_call("\\a", self.range(ix, ix + 1), vec![("href", url)], Some(vec![label])));
+                        ix = link_end;
+                        pending_start = ix;
+                        continue
+                    }
+                }
+                _ => {}
+            }
+            pending.push(c);
+            ix += c.len_utf8();
+        }
+        flush(&mut nodes, &mut pending, &mut pending_start, end);
+        nodes
+    }
+}
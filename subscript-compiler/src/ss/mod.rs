@@ -8,8 +8,21 @@ pub mod parser;
 pub mod utils;
 pub mod env;
 pub mod cmd_decl;
+pub mod macro_decl;
 pub mod codegen;
 pub mod post_parser;
+pub mod completion;
+pub mod diagnostics;
+pub mod validation;
+pub mod labels;
+pub mod bibliography;
+pub mod markdown;
+pub mod format;
+pub mod source_map;
+pub mod figure;
+pub mod plot;
+pub mod data;
+pub mod chart;
 
 pub use ast_data::*;
 pub use ast_utils::*;
@@ -10,7 +10,8 @@ use itertools::Itertools;
 use serde::{Serialize, Deserialize};
 use unicode_segmentation::UnicodeSegmentation;
 use rayon::prelude::*;
-use crate::ss::SemanticScope;
+use crate::ss::{SemanticScope, ResourceEnv};
+use crate::ss::diagnostics::Diagnostic;
 
 
 // ――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
@@ -153,7 +154,7 @@ struct Quotation {
 // INDEXING DATA TYPES
 // ――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 
-#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CharIndex {
     pub byte_index: usize,
     pub char_index: usize,
@@ -168,9 +169,17 @@ impl CharIndex {
             line_index: 0,
         }
     }
+    /// The 1-based line and column (in characters) within the given source.
+    pub fn line_column(&self, source: &str) -> (usize, usize) {
+        let index = self.byte_index.min(source.len());
+        let line_start = source[..index].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let line = source[..index].matches('\n').count() + 1;
+        let column = source[line_start..index].chars().count() + 1;
+        (line, column)
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CharRange {
     pub start: CharIndex,
     pub end: CharIndex,
@@ -759,6 +768,7 @@ pub enum ParserError {
 }
 
 fn parse_words<'a>(
+    env: &ResourceEnv,
     scope: &SemanticScope,
     words: &mut VecDeque<Word<'a>>,
     parent: Option<(OpenWord<'a>)>,
@@ -801,7 +811,12 @@ fn parse_words<'a>(
                 return (nodes, Some(current))
             }
             (Some(WordType::Quotation("\"")), _) => {
-                nodes.push(to_node(current).unwrap())
+                match to_node(current.clone()) {
+                    Some(x) => nodes.push(x),
+                    None => {
+                        nodes.push(ParserAst::Symbol(current.str));
+                    },
+                }
             }
             (Some(WordType::Quotation("'")), ty) => {
                 match to_node(current.clone()) {
@@ -813,7 +828,7 @@ fn parse_words<'a>(
             }
             (_, WordType::OpenBracket("{")) => {
                 let open_ty = current.to_open_type().unwrap();
-                let (children, close) = parse_words(scope, words, Some(current.clone()));
+                let (children, close) = parse_words(env, scope, words, Some(current.clone()));
                 let close_ty = close.clone().and_then(|close| close.to_close_type());
                 match ((open_ty, close_ty)) {
                     (OpenType::Bracket("{"), Some(CloseType::Bracket("}"))) => {
@@ -825,6 +840,7 @@ fn parse_words<'a>(
                         nodes.push(result);
                     }
                     (OpenType::Bracket("{"), None) => {
+                        report_unclosed(env, scope, &current.str);
                         let result = ParserAst::Bracket(Bracket{
                             children,
                             open: Some(current.str),
@@ -837,7 +853,7 @@ fn parse_words<'a>(
             }
             (_, WordType::OpenBracket("[")) => {
                 let open_ty = current.to_open_type().unwrap();
-                let (children, close) = parse_words(scope, words, Some(current.clone()));
+                let (children, close) = parse_words(env, scope, words, Some(current.clone()));
                 let close_ty = close.clone().and_then(|close| close.to_close_type());
                 match ((open_ty, close_ty)) {
                     (OpenType::Bracket("["), Some(CloseType::Bracket("]"))) => {
@@ -849,6 +865,7 @@ fn parse_words<'a>(
                         nodes.push(result);
                     }
                     (OpenType::Bracket("["), None) => {
+                        report_unclosed(env, scope, &current.str);
                         let result = ParserAst::Bracket(Bracket{
                             children,
                             open: Some(current.str),
@@ -861,7 +878,7 @@ fn parse_words<'a>(
             }
             (_, WordType::OpenBracket("(")) => {
                 let open_ty = current.to_open_type().unwrap();
-                let (children, close) = parse_words(scope, words, Some(current.clone()));
+                let (children, close) = parse_words(env, scope, words, Some(current.clone()));
                 let close_ty = close.clone().and_then(|close| close.to_close_type());
                 match ((open_ty, close_ty)) {
                     (OpenType::Bracket("("), Some(CloseType::Bracket(")"))) => {
@@ -873,6 +890,7 @@ fn parse_words<'a>(
                         nodes.push(result);
                     }
                     (OpenType::Bracket("("), None) => {
+                        report_unclosed(env, scope, &current.str);
                         let result = ParserAst::Bracket(Bracket{
                             children,
                             open: Some(current.str),
@@ -885,7 +903,7 @@ fn parse_words<'a>(
             }
             (_, WordType::Quotation("\"")) => {
                 let open_ty = current.to_open_type().unwrap();
-                let (children, close) = parse_words(scope, words, Some(current.clone()));
+                let (children, close) = parse_words(env, scope, words, Some(current.clone()));
                 let close_ty = close.clone().and_then(|close| close.to_close_type());
                 match ((open_ty, close_ty)) {
                     (OpenType::Quotation("\""), Some(CloseType::Quotation("\""))) => {
@@ -897,6 +915,7 @@ fn parse_words<'a>(
                         nodes.push(result);
                     }
                     (OpenType::Quotation("\""), None) => {
+                        report_unclosed(env, scope, &current.str);
                         let result = ParserAst::Quotation(Quotation{
                             children,
                             open: Some(current.str),
@@ -909,7 +928,7 @@ fn parse_words<'a>(
             }
             (_, WordType::Quotation("'")) => {
                 let open_ty = current.to_open_type().unwrap();
-                let (children, close) = parse_words(scope, words, Some(current.clone()));
+                let (children, close) = parse_words(env, scope, words, Some(current.clone()));
                 let close_ty = close.clone().and_then(|close| close.to_close_type());
                 match ((open_ty, close_ty)) {
                     (OpenType::Quotation("'"), Some(CloseType::Quotation("'"))) => {
@@ -921,6 +940,7 @@ fn parse_words<'a>(
                         nodes.push(result);
                     }
                     (OpenType::Quotation("'"), None) => {
+                        report_unclosed(env, scope, &current.str);
                         let result = ParserAst::Quotation(Quotation{
                             children,
                             open: Some(current.str),
@@ -935,12 +955,10 @@ fn parse_words<'a>(
                 let result = to_node(current.clone());
                 if result.is_none() {
                     // INVALID CASES
-                    match res {
-                        (None, WordType::CloseBracket("}")) => {
-                            nodes.push(ParserAst::InvalidToken(current.str));
-                        }
-                        res => unimplemented!("What to do? {parent:#?}   {current:?}")
-                    }
+                    // The remaining cases are close brackets that don’t
+                    // match the parent’s open bracket (or have no parent).
+                    // These are reported by `crate::ss::validation`.
+                    nodes.push(ParserAst::InvalidToken(current.str));
                 }
                 // VALID
                 if let Some(result) = result {
@@ -953,9 +971,23 @@ fn parse_words<'a>(
 }
 
 
-pub fn parse_source<T: AsRef<str>>(scope: &SemanticScope, source: T) -> crate::ss::ast_data::Node {
+fn report_unclosed(env: &ResourceEnv, scope: &SemanticScope, open: &Ann<String>) {
+    let diagnostic = match open.value.as_str() {
+        // Stray quotation marks are fairly common in prose.
+        "\"" | "'" => Diagnostic::warning("unclosed quotation mark"),
+        x => Diagnostic::error(format!("unclosed `{x}`")),
+    };
+    env.report(diagnostic.with_range(open.range).with_scope(scope));
+}
+
+/// Errors such as unbalanced brackets are reported to the given `env`.
+pub fn parse_source<T: AsRef<str>>(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    source: T,
+) -> crate::ss::ast_data::Node {
     let mut words = init_words(source.as_ref());
-    let (ast, res) = parse_words(scope, &mut words, None);
+    let (ast, res) = parse_words(env, scope, &mut words, None);
     assert!(res.is_none());
     let ast = ast
         .into_iter()
@@ -0,0 +1,662 @@
+//! Compile-time function plotting, i.e. `\plot`, e.g.
+//! ```text
+//! \plot[domain="-pi, pi", grid]{
+//!     \expr[label="sin"]{sin(x)}
+//!     x^2/4
+//! }
+//! ```
+//! Expressions use calculator syntax (e.g. `2x^2 - sin(x)/x`, see
+//! `parse_expressions`), and are sampled at compile time into a
+//! `crate::ss::figure::Figure`. Besides functions of `x`, plots can be
+//! parametric (`cos(t), sin(t)`) or polar (`1 + cos(theta)`).
+use std::f64::consts::{E, PI, TAU};
+use itertools::Itertools;
+use crate::ss::figure::{fit_interval, legend, Axes, Element, Figure, LineStyle, Paint, Stroke};
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// EXPRESSIONS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Number(f64),
+    Op(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Func {
+    Sin, Cos, Tan, Sec, Csc, Cot,
+    Asin, Acos, Atan, Sinh, Cosh, Tanh,
+    Sqrt, Cbrt, Abs, Exp, Ln, Log, Log2,
+    Floor, Ceil, Round, Sign, Min, Max,
+}
+
+const FUNCTIONS: &[(&str, Func)] = &[
+    ("sin", Func::Sin), ("cos", Func::Cos), ("tan", Func::Tan),
+    ("sec", Func::Sec), ("csc", Func::Csc), ("cot", Func::Cot),
+    ("asin", Func::Asin), ("acos", Func::Acos), ("atan", Func::Atan),
+    ("arcsin", Func::Asin), ("arccos", Func::Acos), ("arctan", Func::Atan),
+    ("sinh", Func::Sinh), ("cosh", Func::Cosh), ("tanh", Func::Tanh),
+    ("sqrt", Func::Sqrt), ("cbrt", Func::Cbrt), ("abs", Func::Abs),
+    ("exp", Func::Exp), ("ln", Func::Ln), ("log", Func::Log), ("log2", Func::Log2),
+    ("floor", Func::Floor), ("ceil", Func::Ceil), ("round", Func::Round),
+    ("sign", Func::Sign), ("min", Func::Min), ("max", Func::Max),
+];
+
+const CONSTANTS: &[(&str, f64)] = &[("pi", PI), ("π", PI), ("tau", TAU), ("τ", TAU), ("e", E)];
+
+impl Func {
+    fn arity(self) -> usize {
+        match self {
+            Func::Min | Func::Max => 2,
+            _ => 1,
+        }
+    }
+    fn apply(self, args: &[f64]) -> f64 {
+        let x = args[0];
+        match self {
+            Func::Sin => x.sin(),
+            Func::Cos => x.cos(),
+            Func::Tan => x.tan(),
+            Func::Sec => 1.0 / x.cos(),
+            Func::Csc => 1.0 / x.sin(),
+            Func::Cot => 1.0 / x.tan(),
+            Func::Asin => x.asin(),
+            Func::Acos => x.acos(),
+            Func::Atan => x.atan(),
+            Func::Sinh => x.sinh(),
+            Func::Cosh => x.cosh(),
+            Func::Tanh => x.tanh(),
+            Func::Sqrt => x.sqrt(),
+            Func::Cbrt => x.cbrt(),
+            Func::Abs => x.abs(),
+            Func::Exp => x.exp(),
+            Func::Ln => x.ln(),
+            Func::Log => x.log10(),
+            Func::Log2 => x.log2(),
+            Func::Floor => x.floor(),
+            Func::Ceil => x.ceil(),
+            Func::Round => x.round(),
+            Func::Sign => if x == 0.0 {0.0} else {x.signum()},
+            Func::Min => x.min(args[1]),
+            Func::Max => x.max(args[1]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f64),
+    /// The plot’s variable, e.g. `x`.
+    Variable,
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Call(Func, Vec<Expr>),
+}
+
+/// A parsed expression of (at most) one variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression(Expr);
+
+impl Expression {
+    /// Results may be non-finite, e.g. `1/x` at `0`.
+    pub fn eval(&self, value: f64) -> f64 {
+        fn eval(expr: &Expr, value: f64) -> f64 {
+            match expr {
+                Expr::Number(x) => *x,
+                Expr::Variable => value,
+                Expr::Neg(x) => -eval(x, value),
+                Expr::Binary(op, l, r) => {
+                    let (l, r) = (eval(l, value), eval(r, value));
+                    match op {
+                        BinOp::Add => l + r,
+                        BinOp::Sub => l - r,
+                        BinOp::Mul => l * r,
+                        BinOp::Div => l / r,
+                        BinOp::Pow => l.powf(r),
+                    }
+                }
+                Expr::Call(func, args) => {
+                    let args = args.iter().map(|x| eval(x, value)).collect_vec();
+                    func.apply(&args)
+                }
+            }
+        }
+        eval(&self.0, value)
+    }
+}
+
+/// Identifiers are resolved while tokenizing, e.g. `2pi x` is `2 * π * x`.
+#[derive(Debug, Clone, PartialEq)]
+enum Lexeme {
+    Token(Token),
+    Variable,
+    Func(Func, String),
+}
+
+fn tokenize(source: &str, variables: &[&str]) -> Result<Vec<Lexeme>, String> {
+    let chars = source.chars().collect_vec();
+    let mut lexemes = Vec::new();
+    let mut ix = 0;
+    while ix < chars.len() {
+        let c = chars[ix];
+        if c.is_whitespace() {
+            ix += 1;
+        } else if c.is_ascii_digit() || (c == '.' && chars.get(ix + 1).is_some_and(char::is_ascii_digit)) {
+            let start = ix;
+            while ix < chars.len() && (chars[ix].is_ascii_digit() || chars[ix] == '.') {
+                ix += 1;
+            }
+            // E.g. `1e-3`, but not `2e` (i.e. `2 * e`).
+            if ix + 1 < chars.len() && matches!(chars[ix], 'e' | 'E') {
+                let digits_at = if matches!(chars[ix + 1], '+' | '-') {ix + 2} else {ix + 1};
+                if chars.get(digits_at).is_some_and(char::is_ascii_digit) {
+                    ix = digits_at;
+                    while ix < chars.len() && chars[ix].is_ascii_digit() {
+                        ix += 1;
+                    }
+                }
+            }
+            let number = chars[start..ix].iter().collect::<String>();
+            let number = number.parse::<f64>().map_err(|_| format!("invalid number `{number}`"))?;
+            lexemes.push(Lexeme::Token(Token::Number(number)));
+        } else if c.is_alphabetic() {
+            let start = ix;
+            while ix < chars.len() && (chars[ix].is_alphanumeric() || chars[ix] == '_') {
+                ix += 1;
+            }
+            let name = chars[start..ix].iter().collect::<String>();
+            let lexeme = if variables.contains(&name.as_str()) {
+                Lexeme::Variable
+            } else if let Some((_, value)) = CONSTANTS.iter().find(|(x, _)| *x == name) {
+                Lexeme::Token(Token::Number(*value))
+            } else if let Some((_, func)) = FUNCTIONS.iter().find(|(x, _)| *x == name) {
+                Lexeme::Func(*func, name)
+            } else if variables.is_empty() {
+                return Err(format!("unknown name `{name}`"))
+            } else {
+                return Err(format!(
+                    "unknown name `{name}`, expected {} or a function",
+                    variables.iter().map(|x| format!("`{x}`")).join(", "),
+                ))
+            };
+            lexemes.push(lexeme);
+        } else {
+            let op = match c {
+                '+' | '-' | '*' | '/' | '^' | '(' | ')' | ',' | '|' => c,
+                '−' => '-',
+                '·' | '×' => '*',
+                '÷' => '/',
+                _ => return Err(format!("unexpected `{c}`")),
+            };
+            // `**` is also exponentiation.
+            if op == '*' && chars.get(ix + 1) == Some(&'*') {
+                ix += 1;
+                lexemes.push(Lexeme::Token(Token::Op('^')));
+            } else {
+                lexemes.push(Lexeme::Token(Token::Op(op)));
+            }
+            ix += 1;
+        }
+    }
+    Ok(lexemes)
+}
+
+struct Parser {
+    lexemes: Vec<Lexeme>,
+    ix: usize,
+    /// Within `|…|`, where `|` closes rather than starts an absolute value.
+    abs_depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Lexeme> {
+        self.lexemes.get(self.ix)
+    }
+    fn peek_op(&self) -> Option<char> {
+        match self.peek() {
+            Some(Lexeme::Token(Token::Op(op))) => Some(*op),
+            _ => None,
+        }
+    }
+    fn expect_op(&mut self, op: char) -> Result<(), String> {
+        match self.peek_op() {
+            Some(x) if x == op => {
+                self.ix += 1;
+                Ok(())
+            }
+            Some(x) => Err(format!("expected `{op}` but found `{x}`")),
+            None if self.peek().is_some() => Err(format!("expected `{op}`")),
+            None => Err(format!("expected `{op}` but the expression ended")),
+        }
+    }
+    /// Whether the next lexeme starts an operand, for implicit
+    /// multiplication, e.g. `2x` or `(x+1)(x-1)`.
+    fn starts_operand(&self) -> bool {
+        match self.peek() {
+            Some(Lexeme::Token(Token::Number(_))) => true,
+            Some(Lexeme::Variable) | Some(Lexeme::Func(..)) => true,
+            Some(Lexeme::Token(Token::Op('('))) => true,
+            Some(Lexeme::Token(Token::Op('|'))) => self.abs_depth == 0,
+            _ => false,
+        }
+    }
+    fn sum(&mut self) -> Result<Expr, String> {
+        let mut expr = self.product()?;
+        loop {
+            let op = match self.peek_op() {
+                Some('+') => BinOp::Add,
+                Some('-') => BinOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.ix += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+    }
+    fn product(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        loop {
+            let op = match self.peek_op() {
+                Some('*') => {
+                    self.ix += 1;
+                    BinOp::Mul
+                }
+                Some('/') => {
+                    self.ix += 1;
+                    BinOp::Div
+                }
+                _ if self.starts_operand() => BinOp::Mul,
+                _ => return Ok(expr),
+            };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.unary()?));
+        }
+    }
+    /// `-x^2` is `-(x^2)`.
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.peek_op() {
+            Some('-') => {
+                self.ix += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some('+') => {
+                self.ix += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+    /// Right associative, e.g. `2^3^2` is `2^9`.
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.operand()?;
+        if self.peek_op() == Some('^') {
+            self.ix += 1;
+            let exponent = self.unary()?;
+            return Ok(Expr::Binary(BinOp::Pow, Box::new(base), Box::new(exponent)))
+        }
+        Ok(base)
+    }
+    fn operand(&mut self) -> Result<Expr, String> {
+        let lexeme = match self.peek() {
+            Some(x) => x.clone(),
+            None => return Err(String::from("unexpected end of the expression")),
+        };
+        self.ix += 1;
+        match lexeme {
+            Lexeme::Token(Token::Number(x)) => Ok(Expr::Number(x)),
+            Lexeme::Variable => Ok(Expr::Variable),
+            Lexeme::Token(Token::Op('(')) => {
+                let abs_depth = std::mem::replace(&mut self.abs_depth, 0);
+                let expr = self.sum()?;
+                self.abs_depth = abs_depth;
+                self.expect_op(')')?;
+                Ok(expr)
+            }
+            Lexeme::Token(Token::Op('|')) if self.abs_depth == 0 => {
+                self.abs_depth += 1;
+                let expr = self.sum()?;
+                self.abs_depth -= 1;
+                self.expect_op('|')?;
+                Ok(Expr::Call(Func::Abs, vec![expr]))
+            }
+            Lexeme::Func(func, name) => {
+                if self.peek_op() != Some('(') {
+                    return Err(format!("expected `(` after `{name}`"))
+                }
+                self.ix += 1;
+                let abs_depth = std::mem::replace(&mut self.abs_depth, 0);
+                let mut args = vec![self.sum()?];
+                while self.peek_op() == Some(',') {
+                    self.ix += 1;
+                    args.push(self.sum()?);
+                }
+                self.abs_depth = abs_depth;
+                self.expect_op(')')?;
+                if args.len() != func.arity() {
+                    return Err(format!("`{name}` takes {} argument(s), not {}", func.arity(), args.len()))
+                }
+                Ok(Expr::Call(func, args))
+            }
+            Lexeme::Token(Token::Op(op)) => Err(format!("unexpected `{op}`")),
+        }
+    }
+}
+
+/// Parses comma separated expressions, e.g. `cos(t), sin(t)`, where each of
+/// the given variable names (e.g. `theta` and `θ`) refers to the variable.
+///
+/// Supports `+ - * / ^` (also `**`), implicit multiplication (e.g. `2x` or
+/// `2pi x`), `|x|`, the constants `pi`, `tau` and `e`, and the functions
+/// `sin`, `cos`, `tan`, `sec`, `csc`, `cot`, `asin`, `acos`, `atan`,
+/// `sinh`, `cosh`, `tanh`, `sqrt`, `cbrt`, `abs`, `exp`, `ln`, `log`
+/// (base 10), `log2`, `floor`, `ceil`, `round`, `sign`, `min` and `max`.
+pub fn parse_expressions(source: &str, variables: &[&str]) -> Result<Vec<Expression>, String> {
+    let lexemes = tokenize(source, variables)?;
+    if lexemes.is_empty() {
+        return Err(String::from("empty expression"))
+    }
+    let mut parser = Parser {lexemes, ix: 0, abs_depth: 0};
+    let mut expressions = vec![Expression(parser.sum()?)];
+    loop {
+        match parser.peek_op() {
+            Some(',') => {
+                parser.ix += 1;
+                expressions.push(Expression(parser.sum()?));
+            }
+            Some(op) => return Err(format!("unexpected `{op}`")),
+            None if parser.peek().is_some() => return Err(String::from("expected an operator")),
+            None => return Ok(expressions),
+        }
+    }
+}
+
+/// E.g. `domain="-pi, 2pi"`, the first value must be the smaller one.
+pub fn parse_interval(source: &str) -> Result<(f64, f64), String> {
+    let values = parse_expressions(source, &[])?
+        .into_iter()
+        .map(|x| x.eval(0.0))
+        .collect_vec();
+    match values[..] {
+        [a, b] if a.is_finite() && b.is_finite() && a < b => Ok((a, b)),
+        [_, _] => Err(String::from("expected an increasing interval")),
+        _ => Err(format!("expected two values but found {}", values.len())),
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// PLOTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlotMode {
+    /// `y = f(x)`.
+    #[default]
+    Function,
+    /// `x(t), y(t)`.
+    Parametric,
+    /// `r(θ)`.
+    Polar,
+}
+
+impl PlotMode {
+    pub const NAMES: &'static [&'static str] = &["function", "parametric", "polar"];
+    pub fn parse(value: &str) -> Option<PlotMode> {
+        match value.trim() {
+            "function" => Some(PlotMode::Function),
+            "parametric" => Some(PlotMode::Parametric),
+            "polar" => Some(PlotMode::Polar),
+            _ => None,
+        }
+    }
+    /// The names of the variable.
+    pub fn variables(self) -> &'static [&'static str] {
+        match self {
+            PlotMode::Function => &["x"],
+            PlotMode::Parametric => &["t"],
+            PlotMode::Polar => &["theta", "θ", "t"],
+        }
+    }
+    /// The number of comma separated expressions of each curve.
+    pub fn arity(self) -> usize {
+        match self {
+            PlotMode::Parametric => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Curve {
+    /// One expression, or two for parametric plots.
+    pub expressions: Vec<Expression>,
+    /// E.g. `y = x^2`, for the figure’s title and the legend (if labeled).
+    pub source: String,
+    pub label: Option<String>,
+    pub color: Option<String>,
+    pub style: LineStyle,
+    pub width: Option<f64>,
+}
+
+impl Curve {
+    /// Parses and checks the number of expressions of the given mode.
+    pub fn parse(source: &str, mode: PlotMode) -> Result<Curve, String> {
+        let expressions = parse_expressions(source, mode.variables())?;
+        if expressions.len() != mode.arity() {
+            return Err(match mode {
+                PlotMode::Parametric => String::from("expected `x(t), y(t)`"),
+                _ => String::from("expected one expression"),
+            })
+        }
+        Ok(Curve {
+            expressions,
+            source: source.trim().to_owned(),
+            label: None,
+            color: None,
+            style: LineStyle::Solid,
+            width: None,
+        })
+    }
+    fn point(&self, mode: PlotMode, value: f64) -> (f64, f64) {
+        match mode {
+            PlotMode::Function => (value, self.expressions[0].eval(value)),
+            PlotMode::Parametric => (self.expressions[0].eval(value), self.expressions[1].eval(value)),
+            PlotMode::Polar => {
+                let r = self.expressions[0].eval(value);
+                (r * value.cos(), r * value.sin())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Plot {
+    pub mode: PlotMode,
+    pub curves: Vec<Curve>,
+    /// The x interval, by default `-10…10` for functions, and otherwise
+    /// derived from the curves.
+    pub domain: Option<(f64, f64)>,
+    /// The y interval, by default derived from the curves.
+    pub range: Option<(f64, f64)>,
+    /// The interval of `t` (or `θ`), by default `0…2π`.
+    pub parameter: Option<(f64, f64)>,
+    pub grid: bool,
+    pub x_label: Option<String>,
+    pub y_label: Option<String>,
+    pub width: f64,
+    pub height: f64,
+    pub samples: usize,
+}
+
+impl Default for Plot {
+    fn default() -> Self {
+        Plot {
+            mode: PlotMode::Function,
+            curves: Vec::new(),
+            domain: None,
+            range: None,
+            parameter: None,
+            grid: false,
+            x_label: None,
+            y_label: None,
+            width: 600.0,
+            height: 400.0,
+            samples: 500,
+        }
+    }
+}
+
+impl Plot {
+    fn parameter_values(&self, interval: (f64, f64)) -> Vec<f64> {
+        let samples = self.samples.max(2);
+        (0..samples)
+            .map(|ix| interval.0 + (interval.1 - interval.0) * ix as f64 / (samples - 1) as f64)
+            .collect()
+    }
+    /// The sampled points of each curve.
+    fn sample(&self) -> Vec<Vec<(f64, f64)>> {
+        let interval = match self.mode {
+            PlotMode::Function => self.domain.unwrap_or((-10.0, 10.0)),
+            _ => self.parameter.unwrap_or((0.0, TAU)),
+        };
+        let values = self.parameter_values(interval);
+        self.curves
+            .iter()
+            .map(|curve| values.iter().map(|x| curve.point(self.mode, *x)).collect())
+            .collect()
+    }
+    /// Parametric and polar plots keep circles round, unless given a domain
+    /// or range.
+    fn bounds(&self, points: &[Vec<(f64, f64)>]) -> ((f64, f64), (f64, f64)) {
+        let all = points.iter().flatten();
+        let domain = match (self.domain, self.mode) {
+            (Some(domain), _) => domain,
+            (None, PlotMode::Function) => (-10.0, 10.0),
+            (None, _) => fit_interval(&mut all.clone().map(|x| x.0).collect(), false),
+        };
+        let range = match (self.range, self.mode) {
+            (Some(range), _) => range,
+            (None, PlotMode::Function) => fit_interval(&mut all.map(|x| x.1).collect(), true),
+            (None, _) => fit_interval(&mut all.map(|x| x.1).collect(), false),
+        };
+        if self.mode == PlotMode::Function || self.domain.is_some() || self.range.is_some() {
+            return (domain, range)
+        }
+        let ratio = (self.width - 60.0) / (self.height - 40.0);
+        let (w, h) = (domain.1 - domain.0, range.1 - range.0);
+        if w / h < ratio {
+            let pad = (h * ratio - w) / 2.0;
+            ((domain.0 - pad, domain.1 + pad), range)
+        } else {
+            let pad = (w / ratio - h) / 2.0;
+            (domain, (range.0 - pad, range.1 + pad))
+        }
+    }
+    pub fn to_figure(&self) -> Figure {
+        let points = self.sample();
+        let (domain, range) = self.bounds(&points);
+        let axes = Axes {
+            width: self.width,
+            height: self.height,
+            domain,
+            range,
+            grid: self.grid,
+            x_label: self.x_label.clone(),
+            y_label: self.y_label.clone(),
+            x_categories: None,
+        };
+        let frame = axes.frame();
+        let mut elements = axes.to_elements(&frame);
+        let strokes = curve_paints(&self.curves)
+            .into_iter()
+            .zip(self.curves.iter())
+            .map(|(paint, curve)| Stroke::new(paint, curve.width.unwrap_or(2.0)).with_style(curve.style))
+            .collect_vec();
+        let span = range.1 - range.0;
+        let curves = strokes
+            .iter()
+            .zip(points)
+            .map(|(stroke, points)| Element::Path {
+                segments: segments(&points, range)
+                    .into_iter()
+                    .map(|segment| segment
+                        .into_iter()
+                        // Far off points are clamped, they’re clipped anyway.
+                        .map(|(x, y)| (frame.x(x), frame.y(y.clamp(range.0 - 10.0 * span, range.1 + 10.0 * span))))
+                        .collect())
+                    .collect(),
+                stroke: stroke.clone(),
+            })
+            .collect_vec();
+        elements.push(frame.clip(curves));
+        let labeled = self.curves
+            .iter()
+            .zip(strokes)
+            .filter_map(|(curve, stroke)| Some((curve.label.clone()?, stroke)))
+            .collect_vec();
+        elements.extend(legend(&frame, &labeled));
+        let title = self.curves
+            .iter()
+            .map(|curve| match self.mode {
+                PlotMode::Function => format!("y = {}", curve.source),
+                PlotMode::Parametric => format!("(x, y) = ({})", curve.source),
+                PlotMode::Polar => format!("r = {}", curve.source),
+            })
+            .join(", ");
+        Figure {
+            width: self.width,
+            height: self.height,
+            title: if title.is_empty() {String::from("Empty plot")} else {format!("Plot of {title}")},
+            elements,
+        }
+    }
+}
+
+/// Curves without a custom color take the next color of the palette.
+fn curve_paints(curves: &[Curve]) -> Vec<Paint> {
+    let mut series = 0;
+    curves
+        .iter()
+        .map(|curve| match curve.color.as_ref() {
+            Some(color) => Paint::Custom(color.clone()),
+            None => {
+                series += 1;
+                Paint::Series(series - 1)
+            }
+        })
+        .collect()
+}
+
+/// Splits the points at undefined values (e.g. `sqrt(x)` for `x < 0`) and
+/// asymptotes (e.g. `tan(x)`), i.e. where consecutive points jump from
+/// above the range to below it (or vice versa).
+fn segments(points: &[(f64, f64)], range: (f64, f64)) -> Vec<Vec<(f64, f64)>> {
+    let mut segments: Vec<Vec<(f64, f64)>> = Vec::new();
+    let mut current: Vec<(f64, f64)> = Vec::new();
+    for point in points {
+        if !point.0.is_finite() || !point.1.is_finite() {
+            if !current.is_empty() {
+                segments.push(std::mem::take(&mut current));
+            }
+            continue
+        }
+        if let Some(last) = current.last() {
+            let jumps = (last.1 > range.1 && point.1 < range.0) || (last.1 < range.0 && point.1 > range.1);
+            if jumps {
+                segments.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(*point);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
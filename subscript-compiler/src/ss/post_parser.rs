@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::process::Command;
 use std::{collections::HashMap, path::PathBuf};
 use itertools::Itertools;
@@ -28,6 +29,8 @@ use crate::ss::ast_data::{Attribute, Attributes};
 use crate::ss::utils::{sep_by, partition};
 use crate::ss::RewriteRule;
 use crate::ss::ast_traits::SyntacticallyEq;
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::macro_decl::defined_macros;
 
 
 
@@ -149,6 +152,69 @@ impl CmdDeclaration {
         let match_scope = scope.match_cmd(&self.parent_env);
         match_ident && match_scope
     }
+    /// Only applies to commands with `CmdDeclaration::strict_attributes`,
+    /// since most (e.g. HTML tags) accept arbitrary attributes.
+    fn report_unknown_attributes(
+        &self,
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        ident: &Ann<Ident>,
+        attributes: &Attributes,
+    ) {
+        if !self.strict_attributes {
+            return
+        }
+        for key in attributes.get_str_keys().into_iter().sorted() {
+            if !self.attributes.keys().any(|x| x.key == key) {
+                env.report(
+                    Diagnostic::warning(format!(
+                        "unknown attribute `{key}` for `{}`, it will be ignored",
+                        ident.value.as_str(),
+                    ))
+                    .with_range(ident.range)
+                    .with_scope(scope)
+                );
+            }
+        }
+    }
+    /// E.g. `\expr[line-style="wavy"]{…}`, the value is still passed along.
+    /// Likewise only for `CmdDeclaration::strict_attributes`.
+    fn report_invalid_attribute_values(
+        &self,
+        env: &ResourceEnv,
+        scope: &SemanticScope,
+        ident: &Ann<Ident>,
+        attributes: &Attributes,
+    ) {
+        if !self.strict_attributes {
+            return
+        }
+        for attribute in attributes.clone().consume() {
+            let (key, value) = match attribute.to_key_value_str() {
+                Some((key, Some(value))) => (key, value),
+                _ => continue,
+            };
+            let value_ty = self.attributes
+                .iter()
+                .find(|(x, _)| x.key == key)
+                .and_then(|(_, value)| value.as_ref())
+                .map(|value| &value.value_ty);
+            match value_ty {
+                Some(value_ty) if !value_ty.is_valid(&value) => {
+                    env.report(
+                        Diagnostic::warning(format!(
+                            "invalid value `{value}` for `{key}` of `{}`, expected {}",
+                            ident.value.as_str(),
+                            value_ty.describe(),
+                        ))
+                        .with_range(ident.range)
+                        .with_scope(scope)
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
     pub fn match_nodes<'a>(
         &self,
         env: &ResourceEnv,
@@ -177,6 +243,8 @@ impl CmdDeclaration {
                         match nodes.get(index).and_then(Attributes::parse_as_attribute_node) {
                             Some(node_attrs) => {
                                 index = index + 1;
+                                self.report_unknown_attributes(env, scope, ident, &node_attrs);
+                                self.report_invalid_attribute_values(env, scope, ident, &node_attrs);
                                 parsed_attributes = Some(node_attrs);
                             }
                             None if are_any_attrs_required => {
@@ -225,11 +293,22 @@ impl CmdDeclaration {
                         attributes: parsed_attributes,
                         nodes: cmd_arguments.clone(),
                     };
-                    let mut cmd_call: Node = arg_match.apply.0(
+                    let result = arg_match.apply.0(
                         &mut intenral,
                         metadata,
                         cmd_payload,
-                    ).unwrap();
+                    );
+                    let mut cmd_call: Node = match result {
+                        Some(cmd_call) => cmd_call,
+                        None => {
+                            env.report(
+                                Diagnostic::error(format!("failed to process `{}`", ident.value.as_str()))
+                                    .with_range(ident.range)
+                                    .with_scope(scope)
+                            );
+                            Node::Fragment(cmd_arguments)
+                        }
+                    };
                     if let Some(rewrites) = intenral.rewrites {
                         if self.internal.automatically_apply_rewrites {
                             cmd_call = cmd_call.apply_rewrite_rules(&rewrites);
@@ -340,11 +419,38 @@ impl ArgumentsDeclInstance {
 // NODE COMMAND APPLYER - HELPERS
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 
+/// Expands a user-defined macro invocation at the start of the given nodes,
+/// see `crate::ss::macro_decl`.
+fn expand_macro(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    nodes: &[Node],
+) -> Option<(Node, usize)> {
+    let ident = nodes.first().and_then(Node::get_ident_ref)?;
+    let macro_decl = scope.macros.get(&ident.value)?;
+    let (expanded, skip_to_index) = macro_decl.expand(env, scope, nodes)?;
+    if scope.macros.is_expanding(&ident.value) {
+        env.report(
+            Diagnostic::error(format!("`{}` is defined in terms of itself", ident.value.as_str()))
+                .with_range(ident.range)
+                .with_scope(scope)
+        );
+        return Some((Node::Fragment(Vec::new()), skip_to_index))
+    }
+    // Only the template, since the arguments were processed by the caller.
+    let mut macro_scope = scope.clone();
+    macro_scope.macros.push_expanding(ident.value.clone());
+    Some((expanded.apply_commands(env, &macro_scope), skip_to_index))
+}
+
+/// Macros defined by a node (see `\define`) are available to the nodes that
+/// follow it.
 fn apply_commands_to_children<'a>(
     env: &ResourceEnv,
     scope: &SemanticScope,
     nodes: &'a [Node],
 ) -> (Vec<Node>, &'a [Node]) {
+    let mut scope = Cow::Borrowed(scope);
     let mut processed: Vec<Node> = Vec::with_capacity(nodes.len());
     let mut index_skip: Option<usize> = None;
     for (ix, next_node) in nodes.into_iter().enumerate() {
@@ -353,12 +459,21 @@ fn apply_commands_to_children<'a>(
                 continue;
             }
         }
-        if let Some((node, rest, skip_to_index)) = scope.to_matching_cmd_call(env, &nodes[ix..]) {
-            processed.push(node);
+        let node = if let Some((node, skip_to_index)) = expand_macro(env, &scope, &nodes[ix..]) {
             index_skip = Some(ix + skip_to_index);
+            node
+        } else if let Some((node, _, skip_to_index)) = scope.to_matching_cmd_call(env, &nodes[ix..]) {
+            index_skip = Some(ix + skip_to_index);
+            node.apply_commands(env, &scope)
+        } else if next_node.is_ident() {
+            next_node.clone()
         } else {
-            processed.push(next_node.clone());
+            next_node.clone().apply_commands(env, &scope)
+        };
+        for macro_decl in defined_macros(&node) {
+            scope.to_mut().macros.insert(macro_decl);
         }
+        processed.push(node);
     }
     (processed, &[])
 }
@@ -386,41 +501,27 @@ impl Node {
             xs
         }
         match self {
+            Node::Cmd(cmd_call) if scope
+                .get_cmd_decl(env, &cmd_call)
+                .map(|x| x.internal.verbatim_arguments)
+                .unwrap_or(false) => Node::Cmd(cmd_call),
             Node::Cmd(mut cmd_call) => {
                 let child_scope = scope.new_scope(env, &cmd_call);
                 cmd_call.arguments = {
                     process_children(env, &child_scope, cmd_call.arguments)
                 };
-                cmd_call.arguments = cmd_call.arguments
-                    .into_iter()
-                    .map(|x| {
-                        x.apply_commands(env, &child_scope)
-                    })
-                    .collect::<Vec<_>>();
                 Node::Cmd(cmd_call)
             }
             Node::Bracket(Ann{mut value, range}) => {
                 value.children = process_children(env, scope, value.children);
-                value.children = value.children
-                    .into_iter()
-                    .map(|x| x.apply_commands(env, scope))
-                    .collect::<Vec<_>>();
                 Node::Bracket(Ann{range, value})
             }
             Node::Quotation(Ann{mut value, range}) => {
                 value.children = process_children(env, scope, value.children);
-                value.children = value.children
-                    .into_iter()
-                    .map(|x| x.apply_commands(env, scope))
-                    .collect::<Vec<_>>();
                 Node::Quotation(Ann{range, value})
             }
             Node::Fragment(xs) => {
                 let xs = process_children(env, scope, xs);
-                let xs = xs
-                    .into_iter()
-                    .map(|x| x.apply_commands(env, scope))
-                    .collect::<Vec<_>>();
                 Node::Fragment(xs)
             }
             node @ Node::Ident(_) => {
@@ -433,7 +534,7 @@ impl Node {
             node @ Node::Drawing(_) => node,
         }
     }
-    fn apply_rewrite_rules(self, rewrites: &Vec<RewriteRule<Vec<Node>>>) -> Node {
+    pub(crate) fn apply_rewrite_rules(self, rewrites: &Vec<RewriteRule<Vec<Node>>>) -> Node {
         fn process_children(rewrites: &Vec<RewriteRule<Vec<Node>>>, xs: Vec<Node>) -> Vec<Node> {
             let (processed, unprocessed) = apply_rewrites_to_children(rewrites, &xs[..]);
             let mut xs = Vec::new();
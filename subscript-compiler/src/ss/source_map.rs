@@ -0,0 +1,77 @@
+//! Click-to-source mapping. With `--source-map`, the element generated by
+//! each block command (see `ss::BLOCK_COMMANDS`) points back at the command
+//! VIA a `data-ss-src="file:line:column"` attribute, e.g.
+//! `<p data-ss-src="pages/physics/index.ss:12:4">`, where the file is relative
+//! to the project directory and lines and columns are 1-based (as in
+//! diagnostics). Content of included files points into such.
+//!
+//! Editor integrations (or a preview) can then jump from an element to the
+//! exact command call.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use crate::data::Store;
+use crate::ss::{CharRange, SemanticScope};
+use crate::ss::env::canonical_path;
+
+pub const SOURCE_ATTRIBUTE: &str = "data-ss-src";
+
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    /// Paths are relative to this directory (if given), e.g. the project.
+    base_dir: Option<PathBuf>,
+    /// The source of each file seen so far, for computing lines and columns.
+    sources: Store<HashMap<PathBuf, Option<String>>>,
+}
+
+impl SourceMap {
+    pub fn new(base_dir: Option<impl AsRef<Path>>) -> Self {
+        // E.g. the parent of `index.ss`.
+        let base_dir = base_dir.map(|x| match x.as_ref().as_os_str().is_empty() {
+            true => canonical_path("."),
+            false => canonical_path(x),
+        });
+        SourceMap {
+            base_dir,
+            sources: Default::default(),
+        }
+    }
+    /// E.g. `pages/index.ss:3:1` for the start of the given range.
+    pub fn location(&self, file_path: &Path, range: CharRange) -> Option<String> {
+        let file_path = canonical_path(file_path);
+        let (line, column) = self.sources.map_mut(|sources| {
+            let source = sources
+                .entry(file_path.clone())
+                .or_insert_with(|| std::fs::read_to_string(&file_path).ok());
+            source.as_ref().map(|x| range.start.line_column(x))
+        })?;
+        let rel_path = self.base_dir
+            .as_ref()
+            .and_then(|base_dir| file_path.strip_prefix(base_dir).ok())
+            .unwrap_or(&file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        Some(format!("{rel_path}:{line}:{column}"))
+    }
+    /// Sets the `data-ss-src` attribute of the given element (unless it
+    /// already has one), other nodes are returned as is.
+    pub fn annotate(
+        &self,
+        scope: &SemanticScope,
+        range: Option<CharRange>,
+        node: crate::html::Node,
+    ) -> crate::html::Node {
+        let location = scope.file_path
+            .as_ref()
+            .zip(range)
+            .and_then(|(file_path, range)| self.location(file_path, range));
+        match (node, location) {
+            (crate::html::Node::Element(mut element), Some(location)) => {
+                element.attributes
+                    .entry(SOURCE_ATTRIBUTE.to_owned())
+                    .or_insert(location);
+                crate::html::Node::Element(element)
+            }
+            (node, _) => node,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Checks the processed AST (i.e. after `apply_commands`) for anything the
+//! compiler didn’t understand and would otherwise silently pass through to
+//! the output, namely unbalanced tokens and identifiers that didn’t match any
+//! command.
+use itertools::Itertools;
+use crate::ss::{Ann, ContentMode, Ident, Node, ResourceEnv, SemanticScope};
+use crate::ss::diagnostics::Diagnostic;
+
+/// Reports unbalanced tokens and unmatched identifiers (the latter as
+/// warnings, since such are still rendered as is).
+pub fn validate(env: &ResourceEnv, scope: &SemanticScope, node: &Node) {
+    Validator {env, check_idents: true}.visit(scope, node)
+}
+
+/// Only reports unbalanced tokens, this can be used on ASTs that haven’t been
+/// processed.
+pub fn validate_syntax(env: &ResourceEnv, scope: &SemanticScope, node: &Node) {
+    Validator {env, check_idents: false}.visit(scope, node)
+}
+
+struct Validator<'a> {
+    env: &'a ResourceEnv,
+    check_idents: bool,
+}
+
+impl<'a> Validator<'a> {
+    fn visit(&self, scope: &SemanticScope, node: &Node) {
+        match node {
+            // Included files are validated when processed, and their nodes
+            // point into a different source file.
+            Node::Cmd(cmd) if cmd.identifier.value == "\\include" => (),
+            Node::Cmd(cmd) => {
+                let child_scope = match scope.get_cmd_decl(self.env, cmd) {
+                    Some(cmd_decl) if cmd_decl.internal.verbatim_arguments => return,
+                    Some(_) => scope.new_scope(self.env, cmd),
+                    None => scope.clone(),
+                };
+                for argument in cmd.arguments.iter() {
+                    self.visit(&child_scope, argument);
+                }
+            }
+            Node::Ident(ident) if self.check_idents => {
+                // Symbolic modes pass unknown identifiers through to e.g. KaTeX.
+                if scope.content_mode == ContentMode::Text {
+                    self.unmatched_ident(scope, ident);
+                }
+            }
+            Node::Bracket(Ann{value, ..}) => {
+                for child in value.children.iter() {
+                    self.visit(scope, child);
+                }
+            }
+            Node::Quotation(Ann{value, ..}) => {
+                for child in value.children.iter() {
+                    self.visit(scope, child);
+                }
+            }
+            Node::Fragment(xs) => {
+                for child in xs.iter() {
+                    self.visit(scope, child);
+                }
+            }
+            Node::InvalidToken(token) => {
+                self.env.report(
+                    Diagnostic::error(format!("unmatched `{}`", token.value))
+                        .with_range(token.range)
+                        .with_scope(scope)
+                );
+            }
+            Node::Ident(_) | Node::Text(_) | Node::Symbol(_) | Node::Drawing(_) => (),
+        }
+    }
+    fn unmatched_ident(&self, scope: &SemanticScope, ident: &Ann<Ident>) {
+        let name = ident.value.as_str();
+        let message = match scope.cmd_decls.get(&ident.value) {
+            Some(cmd_decls) => {
+                let parents = cmd_decls
+                    .iter()
+                    .map(|x| x.parent_env.parent.as_ref())
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|parents| !parents.iter().any(|x| scope.has_parent(x.as_str())));
+                let required_attributes = cmd_decls
+                    .iter()
+                    .flat_map(|x| x.attributes.keys())
+                    .filter(|x| x.is_required())
+                    .map(|x| format!("`{}`", x.key))
+                    .unique()
+                    .collect_vec();
+                if let Some(parents) = parents {
+                    let parents = parents
+                        .into_iter()
+                        .map(|x| format!("`{}`", x.as_str()))
+                        .unique()
+                        .join(" or ");
+                    format!("`{name}` is only available within {parents}")
+                } else if !required_attributes.is_empty() {
+                    format!(
+                        "`{name}` is missing required attribute(s) {}",
+                        required_attributes.join(", "),
+                    )
+                } else {
+                    format!("`{name}` isn’t available here, or is missing arguments")
+                }
+            }
+            None => match suggest(scope, name) {
+                Some(suggestion) => {
+                    format!("unknown command `{name}`, did you mean `{suggestion}`?")
+                }
+                None => format!("unknown command `{name}`"),
+            },
+        };
+        self.env.report(
+            Diagnostic::warning(message)
+                .with_range(ident.range)
+                .with_scope(scope)
+        );
+    }
+}
+
+/// The closest command identifier by edit distance (if any is close enough).
+/// In the compiler `scope.cmd_decls` is `crate::ss_v1_std::all_commands_list`.
+fn suggest<'a>(scope: &'a SemanticScope, name: &str) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    scope.cmd_decls
+        .iter()
+        .map(|x| x.identifier.as_str())
+        .unique()
+        .map(|x| (levenshtein(name, x), x))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, x)| x)
+}
+
+fn levenshtein(left: &str, right: &str) -> usize {
+    let right = right.chars().collect_vec();
+    let mut row = (0..=right.len()).collect_vec();
+    for (i, l) in left.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, r) in right.iter().enumerate() {
+            let substitution = previous + if l == *r {0} else {1};
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[right.len()]
+}
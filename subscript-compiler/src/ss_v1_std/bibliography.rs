@@ -0,0 +1,180 @@
+//! Citations, see `crate::ss::bibliography`.
+use crate::html;
+use crate::html::utils::escape_html_text;
+use crate::ss::bibliography::{anchor_id, bibtex, CitationStyle, EntryPart, BIBLIOGRAPHY_IDENT, CITE_IDENT};
+use crate::ss::codegen::escape_latex_text;
+use super::*;
+
+pub fn all_bibliography_commands() -> Vec<cmd_decl::CmdDeclaration> {
+    // Also the HTML tag, e.g. `\cite{The Art of Computer Programming}`.
+    let cite = CmdDeclBuilder::new(Ident::from(CITE_IDENT).unwrap())
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+                () => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![]
+                    })
+                },
+            }
+        })
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                if !cmd.arguments.is_empty() {
+                    return crate::ss::codegen::default_cmd_html_cg(env, scope, cmd)
+                }
+                let citation = match env.bibliography.resolve(&env.resource_env, scope, &env.labels, &cmd) {
+                    Some(citation) => citation,
+                    None => return html::Node::Fragment(Vec::new()),
+                };
+                html::Node::Element(html::Element {
+                    name: String::from("a"),
+                    attributes: HashMap::from_iter([
+                        (String::from("href"), citation.href),
+                        (String::from("data-cmd"), String::from("cite")),
+                    ]),
+                    children: vec![html::Node::Text(escape_html_text(&citation.text))],
+                })
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                if !cmd.arguments.is_empty() {
+                    return crate::ss::codegen::default_cmd_latex_cg(env, scope, cmd)
+                }
+                let citation = match env.bibliography.resolve(&env.resource_env, scope, &env.labels, &cmd) {
+                    Some(citation) => citation,
+                    None => return String::new(),
+                };
+                let text = escape_latex_text(&citation.text);
+                // Other pages are separate documents.
+                if citation.is_local {
+                    format!("\\hyperref[{}]{{{text}}}", anchor_id(&citation.key))
+                } else {
+                    text
+                }
+            }
+        })
+        .documentation("Cites the given entry of the page’s `\\bibliography` (or a site-wide one), e.g. `\\cite[knuth98]` shows “[1]” or “(Knuth 1998)”, depending on the bibliography’s `style`.")
+        .parent_layout_mode(LayoutMode::Inline)
+        .finish();
+    let bibliography = CmdDeclBuilder::new(Ident::from(BIBLIOGRAPHY_IDENT).unwrap())
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                () => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(cmd_decl::AttributeKey::new_attr("src"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::FilePath)))
+        .attribute(cmd_decl::AttributeKey::new_attr("style"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .attribute(cmd_decl::AttributeKey::new_attr("site"), None)
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                let style = match env.bibliography.style {
+                    CitationStyle::Numeric => "numeric",
+                    CitationStyle::AuthorYear => "author-year",
+                };
+                let entries = env.bibliography.entries
+                    .iter()
+                    .map(|cited| {
+                        let mut children = Vec::new();
+                        if env.bibliography.style == CitationStyle::Numeric {
+                            children.push(html::Node::Element(html::Element {
+                                name: String::from("span"),
+                                attributes: HashMap::from_iter([
+                                    (String::from("data-label"), String::new()),
+                                ]),
+                                children: vec![html::Node::Text(escape_html_text(&cited.text))],
+                            }));
+                            children.push(html::Node::Text(String::from(" ")));
+                        }
+                        children.extend(cited.format().into_iter().map(entry_part_to_html));
+                        html::Node::Element(html::Element {
+                            name: String::from("li"),
+                            attributes: HashMap::from_iter([
+                                (String::from("id"), anchor_id(&cited.entry.key)),
+                            ]),
+                            children,
+                        })
+                    })
+                    .collect_vec();
+                html::Node::Element(html::Element {
+                    name: String::from("ol"),
+                    attributes: HashMap::from_iter([
+                        (String::from("data-cmd"), String::from("bibliography")),
+                        (String::from("data-style"), String::from(style)),
+                    ]),
+                    children: entries,
+                })
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                if env.bibliography.entries.is_empty() {
+                    return String::new()
+                }
+                let items = env.bibliography.entries
+                    .iter()
+                    .map(|cited| {
+                        let label = match env.bibliography.style {
+                            CitationStyle::Numeric => escape_latex_text(&cited.text),
+                            CitationStyle::AuthorYear => String::new(),
+                        };
+                        let text = cited.format()
+                            .into_iter()
+                            .map(entry_part_to_latex)
+                            .collect::<String>();
+                        format!(
+                            "\\item[{{{label}}}] \\phantomsection\\label{{{}}}{}",
+                            anchor_id(&cited.entry.key),
+                            text.trim_end(),
+                        )
+                    })
+                    .join("\n");
+                format!("\\begin{{itemize}}\n{items}\n\\end{{itemize}}\n")
+            }
+        })
+        .documentation("Lists the entries of the given BibTeX file that are cited on the page, e.g. `\\bibliography[src=\"refs.bib\"]`. Use `style=\"author-year\"` for e.g. “(Knuth 1998)” instead of numbered citations, and `site` to list every entry of the file, for the citations of every page that doesn’t have a bibliography of its own.")
+        .finish();
+    vec![cite, bibliography]
+}
+
+fn entry_part_to_html(part: EntryPart) -> html::Node {
+    let text = |value: &str| html::Node::Text(escape_html_text(&bibtex::to_plain_text(value)));
+    match part {
+        EntryPart::Text(value) => text(&value),
+        EntryPart::Emphasis(value) => html::Node::Element(html::Element {
+            name: String::from("em"),
+            attributes: HashMap::default(),
+            children: vec![text(&value)],
+        }),
+        EntryPart::Link {url, text: value} => html::Node::Element(html::Element {
+            name: String::from("a"),
+            attributes: HashMap::from_iter([(String::from("href"), url)]),
+            children: vec![html::Node::Text(escape_html_text(&value))],
+        }),
+    }
+}
+
+/// BibTeX values are already LaTeX.
+fn entry_part_to_latex(part: EntryPart) -> String {
+    match part {
+        EntryPart::Text(value) => value,
+        EntryPart::Emphasis(value) => format!("\\emph{{{value}}}"),
+        EntryPart::Link {url, ..} => format!("\\url{{{url}}}"),
+    }
+}
@@ -7,6 +7,7 @@ use crate::ss::ToNode;
 use crate::ss::ast_data::HeadingType;
 use crate::ss::SemanticScope;
 use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
 use crate::ss::SymbolicModeType;
 use crate::ss::ast_traits::*;
 use super::*;
@@ -271,10 +272,24 @@ fn process_no_toc(
 fn handle_include(
     env: &ResourceEnv,
     scope: &SemanticScope,
+    identifier: &Ann<Ident>,
     attributes: &Option<Attributes>,
     rewrite_rules: Option<Vec<RewriteRule<Vec<Node>>>>,
 ) -> Option<Node> {
-    let attributes = attributes.as_ref()?;
+    let report = |diagnostic: Diagnostic| {
+        env.report(diagnostic.with_range(identifier.range).with_scope(scope));
+    };
+    let src_path_str = attributes
+        .as_ref()
+        .and_then(|x| x.get("src"))
+        .and_then(|x| x.value.clone().as_stringified_attribute_value_str());
+    let (attributes, src_path_str) = match (attributes.as_ref(), src_path_str) {
+        (Some(attributes), Some(src_path_str)) => (attributes, src_path_str),
+        _ => {
+            report(Diagnostic::error("`\\include` is missing the `src` attribute"));
+            return None
+        }
+    };
     let toc_only = attributes.has_truthy_option("toc-only");
     let no_toc = attributes.has_truthy_option("no-toc");
     let baseline = attributes
@@ -287,24 +302,52 @@ fn handle_include(
             "h4" => Some(HeadingType::H4),
             "h5" => Some(HeadingType::H5),
             "h6" => Some(HeadingType::H6),
-            _ => None,
+            x => {
+                report(Diagnostic::warning(format!(
+                    "invalid `baseline` value `{x}`, expected one of `h1`…`h6`"
+                )));
+                None
+            }
         });
-    let src_path_str = attributes
-        .get("src")?
-        .value
-        .clone()
-        .as_stringified_attribute_value_str()?;
     let src_path = scope.normalize_file_path(&src_path_str)
         .unwrap_or_else(|()| PathBuf::from(&src_path_str));
+    env.add_dependency(scope, &src_path);
     if let Some(cached) = env.get_include_cache(&src_path) {
         return Some(cached.contents);
     }
-    let ext = src_path.extension()?.to_str();
+    if !src_path.exists() {
+        report(Diagnostic::error(format!("included file not found: {}", src_path.display())));
+        return None
+    }
+    // E.g. `\include[src="data.csv", sort="-Mass"]`, same as `\dataTable`.
+    if !attributes.has_attr("lang") && crate::ss::data::is_data_file(&src_path) {
+        let identifier = Ann::unannotated(Ident::from(super::data_tables::DATA_TABLE_IDENT).unwrap());
+        let mut table_attributes = Attributes::default();
+        for key in super::data_tables::DATA_TABLE_ATTRIBUTES {
+            if let Some(value) = attributes.get_str_value(*key) {
+                table_attributes.insert(*key, value);
+            }
+        }
+        return super::data_tables::data_table(identifier, table_attributes, &src_path, report)
+    }
+    let ext = src_path.extension().and_then(|x| x.to_str());
+    let is_document = matches!(ext, Some("ss") | Some(crate::ss::markdown::MARKDOWN_FILE_EXT));
+    let is_code = attributes.has_attr("lang")
+        || !is_document && crate::html::highlight::grammar_for_path(&src_path).is_some();
+    if is_code {
+        return include_code(attributes, &src_path, report)
+    }
     match ext {
-        Some("ss") => {
+        Some("ss") | Some(crate::ss::markdown::MARKDOWN_FILE_EXT) => {
             // println!("include for {:?}", scope.file_path);
             let sub_scope = scope.new_file(&src_path);
-            let nodes = crate::compiler::low_level_api::parse_process(env, &sub_scope).ok()?;
+            let nodes = match crate::compiler::low_level_api::parse_process(env, &sub_scope) {
+                Ok(nodes) => nodes,
+                Err(error) => {
+                    report(Diagnostic::error(error.to_string()));
+                    return None
+                }
+            };
             let nodes = normalize_ref_headings(&sub_scope, baseline, nodes);
             // if toc_only {
             //     nodes = Node::Fragment(process_toc_only(&sub_scope, nodes));
@@ -330,8 +373,71 @@ fn handle_include(
             env.cache_include(&src_path, &nodes);
             return Some(nodes);
         }
-        _ => None,
+        _ => {
+            report(Diagnostic::error(format!(
+                "unsupported file type for `\\include`: {}",
+                src_path.display(),
+            )));
+            None
+        }
+    }
+}
+
+/// E.g. `\include[src="main.rs", lines="10-40"]`, which becomes a `\pre`
+/// that’s highlighted as usual (see `crate::html::highlight`). Unlike other
+/// includes this isn’t cached, since the same file may be included with
+/// different lines.
+fn include_code(
+    attributes: &Attributes,
+    src_path: &PathBuf,
+    report: impl Fn(Diagnostic),
+) -> Option<Node> {
+    let code = match std::fs::read_to_string(src_path) {
+        Ok(code) => code,
+        Err(error) => {
+            report(Diagnostic::error(format!("failed to read {}: {error}", src_path.display())));
+            return None
+        }
+    };
+    let mut lines = code.lines().collect_vec();
+    let mut start = 1;
+    if let Some(value) = attributes.get_str_value("lines") {
+        match crate::html::highlight::parse_line_ranges(&value).as_deref() {
+            Some([range]) if *range.start() <= lines.len() => {
+                let end = (*range.end()).min(lines.len());
+                lines = lines[range.start() - 1..end].to_vec();
+                start = *range.start();
+            }
+            _ => report(Diagnostic::warning(format!(
+                "invalid `lines` value `{value}`, expected lines of the file such as `10-40`"
+            ))),
+        }
+    }
+    // Dropped by the `\pre` code-gen, so the numbering has to account for such.
+    let blank_lines = lines.iter().take_while(|x| x.trim().is_empty()).count();
+    start += blank_lines;
+    let lang = attributes
+        .get_str_value("lang")
+        .or_else(|| crate::html::highlight::grammar_for_path(src_path).map(|x| x.name().to_owned()));
+    let mut pre_attributes = Attributes::default();
+    if let Some(lang) = lang {
+        pre_attributes.insert("lang", lang);
+    }
+    if start != 1 {
+        pre_attributes.insert("start", start.to_string());
+    }
+    for key in ["line-numbers", "highlight"] {
+        if let Some(value) = attributes.get_str_value(key) {
+            pre_attributes.insert(key, value);
+        }
     }
+    // SS text is emitted as is, see `crate::html::utils::escape_html_text`.
+    let code = crate::html::utils::escape_html_text(&lines[blank_lines..].join("\n"));
+    Some(Node::Cmd(CmdCall {
+        identifier: Ann::unannotated(Ident::from("\\pre").unwrap()),
+        attributes: pre_attributes,
+        arguments: vec![Node::Text(Ann::unannotated(code))],
+    }))
 }
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
@@ -340,9 +446,9 @@ fn handle_include(
 
 pub fn core_subscript_commands() -> Vec<cmd_decl::CmdDeclaration> {
     let include = CmdDeclBuilder::new(Ident::from("\\include").unwrap())
-        .child_layout_mode(LayoutMode::Inline)
         .internal_cmd_options(cmd_decl::InternalCmdDeclOptions {
             automatically_apply_rewrites: false,
+            ..Default::default()
         })
         .arguments(arguments! {
             for (internal, metadata, cmd_payload) match {
@@ -350,16 +456,117 @@ pub fn core_subscript_commands() -> Vec<cmd_decl::CmdDeclaration> {
                     let result = handle_include(
                         metadata.resource_env,
                         &metadata.scope,
+                        &cmd_payload.identifier,
                         &cmd_payload.attributes,
                         internal.rewrites.clone()
                     );
+                    // The included nodes are kept under the `\include` cmd so
+                    // later passes (e.g. `crate::ss::validation`) know where
+                    // the included file starts.
                     match result {
-                        Some(result) => result,
+                        Some(result) => Node::Cmd(CmdCall {
+                            identifier: cmd_payload.identifier,
+                            attributes: cmd_payload.attributes.unwrap_or_default(),
+                            arguments: vec![result],
+                        }),
                         None => Node::Fragment(Vec::new())
                     }
                 },
             }
         })
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                // So e.g. links point into the included file.
+                let file_scope = crate::ss::labels::include_path(scope, &cmd)
+                    .map(|x| scope.new_file(x))
+                    .unwrap_or_else(|| scope.clone());
+                let children = cmd.arguments
+                    .into_iter()
+                    .map(|x| x.to_html(env, &file_scope))
+                    .collect::<Vec<_>>();
+                // Labeled drawings, see `crate::ss::labels`.
+                match (cmd.attributes.get_str_value("id"), cmd.attributes.get_str_value("data-number")) {
+                    (Some(id), Some(number)) => crate::html::Node::Element(crate::html::Element {
+                        name: String::from("figure"),
+                        attributes: HashMap::from_iter([
+                            (String::from("id"), id),
+                            (String::from("data-cmd"), String::from("figure")),
+                        ]),
+                        children: vec![
+                            crate::html::Node::Fragment(children),
+                            crate::html::Node::Element(crate::html::Element {
+                                name: String::from("figcaption"),
+                                attributes: HashMap::default(),
+                                children: vec![crate::html::Node::Text(format!("Figure {number}"))],
+                            }),
+                        ],
+                    }),
+                    _ => crate::html::Node::Fragment(children),
+                }
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                match cmd.attributes.get_str_value("data-number") {
+                    Some(number) => format!("{children}\\begin{{center}}\\emph{{Figure {number}}}\\end{{center}}\n"),
+                    None => children,
+                }
+            }
+        })
+        .strict_attributes(true)
+        .attribute(cmd_decl::AttributeKey::required_attr("src"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::FilePath)))
+        .attribute(cmd_decl::AttributeKey::new_attr("baseline"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .attribute(cmd_decl::AttributeKey::new_attr("toc-only"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("no-toc"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("lang"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .attribute(cmd_decl::AttributeKey::new_attr("lines"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .attribute(cmd_decl::AttributeKey::new_attr("line-numbers"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("highlight"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .documentation("Include the contents of another file. Supports `.ss` and Markdown (`.md`) files, drawings and compositions. Source code (e.g. `main.rs`, or any file given a `lang`) is shown as a highlighted code block, and CSV (or `.tsv`) files as a table (see `\\dataTable`).\n\nAttributes: `src` (required), `baseline` (`h1`…`h6`), `toc-only`, `no-toc`, for source code `lang`, `lines` (e.g. `10-40`), `line-numbers` and `highlight` (e.g. `2,4-6`), and for CSV files `header`, `columns`, `sort`, `decimals`, `thousands` and `delimiter`.");
+    let include = super::data_tables::data_table_attributes()
+        .into_iter()
+        .fold(include, |include, (key, value)| include.attribute(key, value))
+        .finish();
+    let define = CmdDeclBuilder::new(Ident::from(crate::ss::macro_decl::DEFINE_IDENT).unwrap())
+        .internal_cmd_options(cmd_decl::InternalCmdDeclOptions {
+            verbatim_arguments: true,
+            ..Default::default()
+        })
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({template}) => {
+                    let attributes = cmd_payload.attributes.unwrap_or_default();
+                    // The definition itself is kept in the AST, so it’s also
+                    // picked up from cached includes.
+                    match crate::ss::macro_decl::MacroDeclaration::parse(&attributes, &template) {
+                        Ok(_) => Node::Cmd(CmdCall {
+                            identifier: cmd_payload.identifier,
+                            attributes,
+                            arguments: vec![template],
+                        }),
+                        Err(message) => {
+                            metadata.resource_env.report(
+                                Diagnostic::error(message)
+                                    .with_range(cmd_payload.identifier.range)
+                                    .with_scope(metadata.scope)
+                            );
+                            Node::Fragment(Vec::new())
+                        }
+                    }
+                },
+            }
+        })
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                crate::html::Node::Fragment(Vec::new())
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                String::new()
+            }
+        })
+        .documentation("Define a new command (i.e. a macro) for the rest of the enclosing block, or for the including file when defined at the top level of e.g. a prelude.\n\nAttributes: `name` (required), `params` (the names of the curly brace arguments, separated by commas), anything else is an optional attribute with the given default value. Each is referenced in the template by name, e.g.\n```\n\\define[name=\"card\", params=\"title, body\", cols=\"3\"]{\n    \\note[boxed]{\\h2{\\title}\\grid[col=\"\\cols\"]{\\body}}\n}\n\\card[cols=\"2\"]{…}{…}\n```")
         .finish();
     // fn process_topics(node: Node) -> Node {
     //     match node {
@@ -391,5 +598,6 @@ pub fn core_subscript_commands() -> Vec<cmd_decl::CmdDeclaration> {
     //     .finish();
     vec![
         include,
+        define,
     ]
 }
@@ -0,0 +1,483 @@
+//! Tables and charts of CSV files, e.g. `\dataTable[src="trials.csv"]` (or
+//! `\include[src="trials.csv"]`) and `\chart[src="trials.csv", type="bar"]`.
+//!
+//! Files are read while rewriting, relative to the file that refers to them
+//! (see `handle_include`), and the selected rows are kept as the arguments of
+//! the command, i.e. `{{header rows…}{rows…}}` where each row is a curly
+//! brace of cells, which the code-gens turn back into a `Table`.
+use std::path::{Path, PathBuf};
+use crate::html;
+use crate::ss::ResourceEnv;
+use crate::ss::codegen::escape_latex_text;
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::chart::{Chart, ChartKind, Series};
+use crate::ss::data::{default_delimiter, format_number, parse_csv, parse_number, Table};
+use crate::ss::plot::parse_interval;
+use crate::html::utils::escape_html_text;
+use ss_freeform_format::ColorScheme;
+use super::*;
+
+pub const DATA_TABLE_IDENT: &str = "\\dataTable";
+const CHART_IDENT: &str = "\\chart";
+/// The `\include` attributes that are forwarded to `\dataTable`.
+pub const DATA_TABLE_ATTRIBUTES: &[&str] = &["header", "columns", "sort", "decimals", "thousands", "delimiter"];
+
+fn typed(value_ty: cmd_decl::AttributeValueType) -> Option<cmd_decl::AttributeValue> {
+    Some(cmd_decl::AttributeValue::new(value_ty))
+}
+
+/// E.g. `thousands` or `thousands=true`, but not `thousands=false`.
+fn flag_value(attributes: &Attributes, key: &str) -> bool {
+    attributes.has_attr(key) && !matches!(
+        attributes.get_str_value(key).as_deref().map(str::trim),
+        Some("false") | Some("0"),
+    )
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LOADING
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The `src` of the given command, relative to the file it’s written in.
+fn src_path(env: &ResourceEnv, scope: &SemanticScope, attributes: &Attributes) -> Option<PathBuf> {
+    let src = attributes.get_str_value("src")?;
+    let src_path = scope.normalize_file_path(&src).unwrap_or_else(|()| PathBuf::from(&src));
+    env.add_dependency(scope, &src_path);
+    Some(src_path)
+}
+
+fn load_table(
+    name: &str,
+    src_path: &Path,
+    attributes: &Attributes,
+    report: &impl Fn(Diagnostic),
+) -> Option<Table> {
+    let source = match std::fs::read_to_string(src_path) {
+        Ok(source) => source,
+        Err(error) => {
+            report(Diagnostic::error(format!("failed to read {}: {error}", src_path.display())));
+            return None
+        }
+    };
+    let delimiter = match attributes.get_str_value("delimiter") {
+        Some(value) if value == "tab" => '\t',
+        Some(value) if value.chars().count() == 1 => value.chars().next().unwrap(),
+        Some(value) => {
+            report(Diagnostic::warning(format!(
+                "invalid `delimiter` value `{value}` for `{name}`, expected a single character or `tab`"
+            )));
+            default_delimiter(src_path)
+        }
+        None => default_delimiter(src_path),
+    };
+    let records = match parse_csv(&source, delimiter) {
+        Ok(records) => records,
+        Err(error) => {
+            report(Diagnostic::error(format!("invalid CSV in {}, {error}", src_path.display())));
+            return None
+        }
+    };
+    // Invalid values are already reported (see `cmd_decl::AttributeValueType`).
+    let header_rows = attributes
+        .get_str_value("header")
+        .and_then(|x| x.trim().parse::<usize>().ok())
+        .unwrap_or(1);
+    Some(Table::new(records, header_rows))
+}
+
+fn table_to_nodes(table: &Table) -> Vec<Node> {
+    let rows = |rows: &[Vec<String>]| Node::new_curly_brace(
+        rows.iter()
+            .map(|row| Node::new_curly_brace(
+                row.iter()
+                    .map(|cell| Node::new_curly_brace(vec![Node::Text(Ann::unannotated(cell.clone()))]))
+                    .collect()
+            ))
+            .collect()
+    );
+    vec![rows(&table.header), rows(&table.rows)]
+}
+
+/// See `table_to_nodes`.
+fn nodes_to_table(nodes: &[Node]) -> Table {
+    fn text(node: &Node) -> String {
+        match node {
+            Node::Text(x) => x.value.clone(),
+            Node::Bracket(x) => x.value.children.iter().map(text).collect(),
+            Node::Fragment(xs) => xs.iter().map(text).collect(),
+            _ => String::new(),
+        }
+    }
+    let rows = |node: Option<&Node>| -> Vec<Vec<String>> {
+        node.and_then(Node::get_curly_brace_children)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(Node::get_curly_brace_children)
+            .map(|row| row.iter().filter(|x| x.is_curly_brace()).map(text).collect())
+            .collect()
+    };
+    Table {header: rows(nodes.first()), rows: rows(nodes.get(1))}
+}
+
+/// The given columns (e.g. `Time, Mass`), sorted by the given column (e.g.
+/// `-Mass` for descending), where invalid values are reported and ignored.
+fn select_rows(mut table: Table, attributes: &Attributes, report: &impl Fn(Diagnostic)) -> Table {
+    if let Some(value) = attributes.get_str_value("sort") {
+        let (column, descending) = match value.trim().strip_prefix('-') {
+            Some(column) => (column, true),
+            None => (value.trim().trim_start_matches('+'), false),
+        };
+        match table.find_column(column) {
+            Some(column) => table.sort_by(column, descending),
+            None => report(Diagnostic::warning(format!(
+                "invalid `sort` value `{value}` for `{DATA_TABLE_IDENT}`: unknown column `{column}`"
+            ))),
+        }
+    }
+    if let Some(value) = attributes.get_str_value("columns") {
+        match table.find_columns(&value) {
+            Ok(columns) => table = table.select(&columns),
+            Err(error) => report(Diagnostic::warning(format!(
+                "invalid `columns` value `{value}` for `{DATA_TABLE_IDENT}`: {error}"
+            ))),
+        }
+    }
+    table
+}
+
+/// The `\dataTable` of the given file, e.g. for `\include[src="data.csv"]`.
+pub fn data_table(
+    identifier: Ann<Ident>,
+    attributes: Attributes,
+    src_path: &Path,
+    report: impl Fn(Diagnostic),
+) -> Option<Node> {
+    let table = load_table(DATA_TABLE_IDENT, src_path, &attributes, &report)?;
+    let table = select_rows(table, &attributes, &report);
+    Some(Node::Cmd(CmdCall {
+        identifier,
+        attributes,
+        arguments: table_to_nodes(&table),
+    }))
+}
+
+/// Just the x and y columns, i.e. `{x}{y…}`.
+fn chart_table(
+    src_path: &Path,
+    attributes: &Attributes,
+    report: &impl Fn(Diagnostic),
+) -> Option<Table> {
+    let table = load_table(CHART_IDENT, src_path, attributes, report)?;
+    let names = table.column_names();
+    let x = match attributes.get_str_value("x") {
+        Some(value) => table.find_column(&value).or_else(|| {
+            report(Diagnostic::warning(format!(
+                "invalid `x` value `{value}` for `{CHART_IDENT}`: unknown column `{value}`"
+            )));
+            None
+        }),
+        None => None,
+    };
+    let x = x.unwrap_or(0);
+    let ys = match attributes.get_str_value("y").map(|x| (table.find_columns(&x), x)) {
+        Some((Ok(ys), _)) => ys,
+        Some((Err(error), value)) => {
+            report(Diagnostic::warning(format!("invalid `y` value `{value}` for `{CHART_IDENT}`: {error}")));
+            Vec::new()
+        }
+        None => {
+            let ys = (0..table.width()).filter(|ix| *ix != x && table.is_numeric(*ix)).collect_vec();
+            if ys.is_empty() {
+                report(Diagnostic::warning(format!("`{CHART_IDENT}` has no numeric columns to chart")));
+            }
+            ys
+        }
+    };
+    for y in ys.iter().copied() {
+        let skipped = table.rows
+            .iter()
+            .filter(|row| !row[y].trim().is_empty() && parse_number(&row[y]).is_none())
+            .count();
+        if skipped > 0 {
+            report(Diagnostic::warning(format!(
+                "`{CHART_IDENT}` skips {skipped} non-numeric value(s) of column `{}`",
+                names[y],
+            )));
+        }
+    }
+    let columns = std::iter::once(x).chain(ys).collect_vec();
+    Some(table.select(&columns))
+}
+
+/// Rewrites `\dataTable` and `\chart`, see `table_to_nodes`.
+fn load_cmd(
+    env: &ResourceEnv,
+    scope: &SemanticScope,
+    identifier: Ann<Ident>,
+    attributes: Option<Attributes>,
+) -> Node {
+    let report = |diagnostic: Diagnostic| {
+        env.report(diagnostic.with_range(identifier.range).with_scope(scope));
+    };
+    let attributes = attributes.unwrap_or_default();
+    let src_path = match src_path(env, scope, &attributes) {
+        Some(src_path) => src_path,
+        None => {
+            report(Diagnostic::error(format!("`{}` is missing the `src` attribute", identifier.value.as_str())));
+            return Node::Fragment(Vec::new())
+        }
+    };
+    if !src_path.exists() {
+        report(Diagnostic::error(format!("data file not found: {}", src_path.display())));
+        return Node::Fragment(Vec::new())
+    }
+    if identifier.value == DATA_TABLE_IDENT {
+        return data_table(identifier.clone(), attributes, &src_path, report)
+            .unwrap_or_else(|| Node::Fragment(Vec::new()))
+    }
+    match chart_table(&src_path, &attributes, &report) {
+        Some(table) => Node::Cmd(CmdCall {
+            identifier: identifier.clone(),
+            attributes,
+            arguments: table_to_nodes(&table),
+        }),
+        None => Node::Fragment(Vec::new()),
+    }
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// TABLES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The formatted cells, and whether each column is numeric (i.e. right
+/// aligned).
+fn formatted_table(cmd: &CmdCall) -> (Table, Vec<bool>) {
+    let mut table = nodes_to_table(&cmd.arguments);
+    let numeric = (0..table.width()).map(|ix| table.is_numeric(ix)).collect_vec();
+    let decimals = cmd.attributes
+        .get_str_value("decimals")
+        .and_then(|x| x.trim().parse::<usize>().ok());
+    let thousands = flag_value(&cmd.attributes, "thousands");
+    for row in table.rows.iter_mut() {
+        for (cell, _) in row.iter_mut().zip(numeric.iter()).filter(|(_, numeric)| **numeric) {
+            *cell = format_number(cell, decimals, thousands);
+        }
+    }
+    (table, numeric)
+}
+
+fn data_table_to_html(cmd: CmdCall) -> html::Node {
+    let (table, numeric) = formatted_table(&cmd);
+    let row = |tag: &str, cells: &[String]| {
+        html::TagBuilder::new("tr")
+            .with_children(cells.iter().zip(numeric.iter()).map(|(cell, numeric)| {
+                let cell = html::TagBuilder::new(tag).push_child(html::Node::Text(escape_html_text(cell)));
+                if *numeric {cell.with_attr_key("data-numeric")} else {cell}.finalize()
+            }))
+            .finalize()
+    };
+    let mut children = Vec::new();
+    if !table.header.is_empty() {
+        children.push(
+            html::TagBuilder::new("thead")
+                .with_children(table.header.iter().map(|x| row("th", x)))
+                .finalize()
+        );
+    }
+    children.push(
+        html::TagBuilder::new("tbody")
+            .with_children(table.rows.iter().map(|x| row("td", x)))
+            .finalize()
+    );
+    html::TagBuilder::new("div")
+        .with_attr_key("data-table-wrapper")
+        .push_child(
+            html::TagBuilder::new("table")
+                .with_attr_key("data-wrapped-table")
+                .with_attr("data-cmd", "dataTable")
+                .with_children(children)
+                .finalize()
+        )
+        .finalize()
+}
+
+fn data_table_to_latex(cmd: CmdCall) -> String {
+    let (table, numeric) = formatted_table(&cmd);
+    if table.width() == 0 {
+        return String::new()
+    }
+    let spec = numeric.iter().map(|x| if *x {'r'} else {'l'}).collect::<String>();
+    let row = |cells: &[String], bold: bool| {
+        let cells = cells.iter().map(|x| escape_latex_text(x)).map(|x| {
+            if bold && !x.is_empty() {format!("\\textbf{{{x}}}")} else {x}
+        });
+        format!("{} \\\\\n", cells.collect_vec().join(" & "))
+    };
+    let mut rows = String::new();
+    if !table.header.is_empty() {
+        rows.push_str("\\hline\n");
+        table.header.iter().for_each(|x| rows.push_str(&row(x, true)));
+        rows.push_str("\\hline\n");
+    }
+    table.rows.iter().for_each(|x| rows.push_str(&row(x, false)));
+    if !table.header.is_empty() {
+        rows.push_str("\\hline\n");
+    }
+    format!("\n\\begin{{center}}\\begin{{tabular}}{{{spec}}}\n{rows}\\end{{tabular}}\\end{{center}}\n")
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// CHARTS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+fn to_chart(resource_env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall) -> Chart {
+    let attributes = &cmd.attributes;
+    let table = nodes_to_table(&cmd.arguments);
+    let names = table.column_names();
+    let column = |ix: usize| table.rows.iter().map(|x| x[ix].clone()).collect_vec();
+    let interval = |key: &str| -> Option<(f64, f64)> {
+        let value = attributes.get_str_value(key)?;
+        parse_interval(&value)
+            .map_err(|error| resource_env.report(
+                Diagnostic::warning(format!("invalid `{key}` value `{value}` for `{CHART_IDENT}`: {error}"))
+                    .with_range(cmd.identifier.range)
+                    .with_scope(scope)
+            ))
+            .ok()
+    };
+    let x_labels = if table.width() > 0 {column(0)} else {Vec::new()};
+    let x_values = x_labels.iter().map(|x| parse_number(x)).collect_vec();
+    // Other than missing values, every x has to be a number.
+    let is_numeric = table.width() > 0 && table.is_numeric(0);
+    let series = (1..table.width())
+        .map(|ix| Series {
+            name: names[ix].clone(),
+            values: column(ix).iter().map(|x| parse_number(x)).collect(),
+        })
+        .collect_vec();
+    let has_header = !table.header.is_empty();
+    let mut chart = Chart {
+        kind: attributes
+            .get_str_value("type")
+            .and_then(|x| ChartKind::parse(&x))
+            .unwrap_or_default(),
+        x_values: if is_numeric {Some(x_values)} else {None},
+        domain: interval("domain"),
+        range: interval("range"),
+        grid: flag_value(attributes, "grid"),
+        x_label: attributes
+            .get_str_value("x-label")
+            .or_else(|| names.first().filter(|_| has_header).cloned()),
+        y_label: attributes
+            .get_str_value("y-label")
+            .or_else(|| series.iter().map(|x| x.name.clone()).exactly_one().ok().filter(|_| has_header)),
+        x_labels,
+        series,
+        ..Chart::default()
+    };
+    let size = |key: &str| attributes
+        .get_str_value(key)
+        .and_then(|x| x.trim().parse::<usize>().ok())
+        .filter(|x| *x > 0);
+    if let Some(width) = size("width") {
+        chart.width = width as f64;
+    }
+    if let Some(height) = size("height") {
+        chart.height = height as f64;
+    }
+    chart
+}
+
+fn chart_to_html(env: &crate::ss::HtmlCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> html::Node {
+    let figure = to_chart(&env.resource_env, scope, &cmd).to_figure();
+    html::TagBuilder::new("div")
+        .with_attr("data-cmd", "chart")
+        .push_child(figure.to_html(&ColorScheme::Dark))
+        .push_child(figure.to_html(&ColorScheme::Light))
+        .finalize()
+}
+
+fn chart_to_latex(env: &crate::ss::LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    let figure = to_chart(&env.resource_env, scope, &cmd).to_figure();
+    format!("\n\\begin{{center}}\n{}\n\\end{{center}}\n", figure.to_tikz())
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// COMMANDS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Shared by `\dataTable` and `\include` (see `handle_include`).
+pub fn data_table_attributes() -> Vec<(cmd_decl::AttributeKey, Option<cmd_decl::AttributeValue>)> {
+    use cmd_decl::{AttributeKey, AttributeValueType};
+    vec![
+        (AttributeKey::new_attr("header"), typed(AttributeValueType::Int)),
+        (AttributeKey::new_attr("columns"), typed(AttributeValueType::String)),
+        (AttributeKey::new_attr("sort"), typed(AttributeValueType::String)),
+        (AttributeKey::new_attr("decimals"), typed(AttributeValueType::Int)),
+        (AttributeKey::new_attr("thousands"), typed(AttributeValueType::Bool)),
+        (AttributeKey::new_attr("delimiter"), typed(AttributeValueType::String)),
+    ]
+}
+
+pub fn all_data_table_commands() -> Vec<cmd_decl::CmdDeclaration> {
+    use cmd_decl::{AttributeKey, AttributeValueType};
+    let mut data_table = CmdDeclBuilder::new(Ident::from(DATA_TABLE_IDENT).unwrap())
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                () => {
+                    load_cmd(metadata.resource_env, metadata.scope, cmd_payload.identifier, cmd_payload.attributes)
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::required_attr("src"), typed(AttributeValueType::FilePath))
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                data_table_to_html(cmd)
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                data_table_to_latex(cmd)
+            }
+        })
+        .documentation("A table of a CSV (or `.tsv`) file, e.g. `\\dataTable[src=\"trials.csv\", columns=\"Time, Mass\", sort=\"-Mass\", decimals=2]`, where numeric columns are right aligned. Same as `\\include` of such files.\n\nAttributes: `src` (required), `header` (the number of header rows, 1 by default), `columns` (names or 1-based indices, e.g. `1, 3-5`), `sort` (a column, prefixed with `-` for descending order), `decimals`, `thousands` (separators) and `delimiter` (a character or `tab`).");
+    for (key, value) in data_table_attributes() {
+        data_table = data_table.attribute(key, value);
+    }
+    let chart = CmdDeclBuilder::new(Ident::from(CHART_IDENT).unwrap())
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                () => {
+                    load_cmd(metadata.resource_env, metadata.scope, cmd_payload.identifier, cmd_payload.attributes)
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::required_attr("src"), typed(AttributeValueType::FilePath))
+        .attribute(AttributeKey::new_attr("type"), typed(AttributeValueType::OneOf(ChartKind::NAMES)))
+        .attribute(AttributeKey::new_attr("x"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("y"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("header"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("delimiter"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("domain"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("range"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("grid"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("x-label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("y-label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("width"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("height"), typed(AttributeValueType::Int))
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                chart_to_html(env, scope, cmd)
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                chart_to_latex(env, scope, cmd)
+            }
+        })
+        .documentation("A static chart of a CSV (or `.tsv`) file, drawn at compile time, e.g. `\\chart[src=\"trials.csv\", type=\"bar\", x=\"Trial\", y=\"Mass, Volume\"]`.\n\nAttributes: `src` (required), `type` (`line`, `bar` or `scatter`), `x` (a column, the first by default), `y` (columns, the other numeric ones by default), `header`, `delimiter`, `domain` and `range` (e.g. `0, 100`), `grid`, `x-label`, `y-label`, `width` and `height` (in pixels, 600×400 by default).")
+        .finish();
+    vec![data_table.finish(), chart]
+}
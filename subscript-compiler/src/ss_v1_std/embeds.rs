@@ -0,0 +1,553 @@
+//! Interactive Desmos and GeoGebra graphs, e.g.
+//! `\desmos{\expr[line-style="dashed"]{y=x^2}}`.
+//!
+//! The calculator options and expressions are serialized to a JSON config
+//! that `ss-runtime.js` (see “DESMOS & GEOGEBRA EMBEDS”) hands to the
+//! respective library, and the generated HTML is a static notice listing the
+//! expressions until then (or if the library can’t be loaded).
+use serde_json::{json, Map, Value};
+use crate::html;
+use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::codegen::escape_latex_text;
+use crate::ss::figure::parse_hex_color;
+use super::*;
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// ATTRIBUTES
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Boolean `\desmos` attributes and the corresponding calculator options.
+const DESMOS_OPTIONS: &[(&str, &str)] = &[
+    ("expressions", "expressions"),
+    ("keypad", "keypad"),
+    ("settings-menu", "settingsMenu"),
+    ("zoom-buttons", "zoomButtons"),
+    ("lock-viewport", "lockViewport"),
+    ("grid", "showGrid"),
+    ("x-axis", "showXAxis"),
+    ("y-axis", "showYAxis"),
+    ("degree-mode", "degreeMode"),
+    ("projector-mode", "projectorMode"),
+];
+
+/// Boolean `\geogebra` attributes and the corresponding applet parameters.
+const GEOGEBRA_PARAMS: &[(&str, &str)] = &[
+    ("toolbar", "showToolBar"),
+    ("algebra-input", "showAlgebraInput"),
+    ("menu-bar", "showMenuBar"),
+    ("reset-icon", "showResetIcon"),
+];
+
+const DESMOS_LINE_STYLES: &[&str] = &["solid", "dashed", "dotted"];
+const DESMOS_POINT_STYLES: &[&str] = &["point", "open", "cross"];
+/// In the order of GeoGebra’s line style numbers, except for “dashed short”.
+const GEOGEBRA_LINE_STYLES: &[&str] = &["solid", "dashed", "dotted", "dash-dot"];
+const GEOGEBRA_APPS: &[&str] = &["graphing", "geometry", "3d", "classic", "suite", "scientific"];
+
+const DEFAULT_HEIGHT: i64 = 400;
+
+fn typed(value_ty: cmd_decl::AttributeValueType) -> Option<cmd_decl::AttributeValue> {
+    Some(cmd_decl::AttributeValue::new(value_ty))
+}
+
+/// Bare flags are true, invalid values are already reported (see
+/// `cmd_decl::AttributeValueType::Bool`).
+fn bool_value(attributes: &Attributes, key: &str) -> Option<bool> {
+    if !attributes.has_attr(key) {
+        return None
+    }
+    match attributes.get_str_value(key).as_deref().map(str::trim) {
+        None | Some("") | Some("true") | Some("1") => Some(true),
+        Some("false") | Some("0") => Some(false),
+        Some(_) => None,
+    }
+}
+
+fn number_value(attributes: &Attributes, key: &str) -> Option<f64> {
+    attributes.get_str_value(key)?.trim().parse::<f64>().ok().filter(|x| x.is_finite())
+}
+
+fn height_value(attributes: &Attributes) -> i64 {
+    attributes.get_str_value("height")
+        .and_then(|x| x.trim().parse::<i64>().ok())
+        .filter(|x| *x > 0)
+        .unwrap_or(DEFAULT_HEIGHT)
+}
+
+/// E.g. `bounds="-10, 10, -5, 5"`, where the first value of each pair must be
+/// the smaller one.
+fn parse_bounds(value: &str) -> Option<[f64; 4]> {
+    let values = value
+        .split(',')
+        .map(|x| x.trim().parse::<f64>().ok().filter(|x| x.is_finite()))
+        .collect::<Option<Vec<_>>>()?;
+    match values[..] {
+        [a, b, c, d] if a < b && c < d => Some([a, b, c, d]),
+        _ => None,
+    }
+}
+
+fn report_warning(resource_env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall, message: String) {
+    resource_env.report(
+        Diagnostic::warning(message)
+            .with_range(cmd.identifier.range)
+            .with_scope(scope)
+    );
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// EXPRESSIONS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+const EXPR_IDENT: &str = "\\expr";
+
+struct Expression {
+    /// Of the `\expr`, bare lines have none.
+    attributes: Attributes,
+    /// LaTeX for Desmos, commands for GeoGebra.
+    code: String,
+}
+
+/// The `\expr` children, and otherwise each non-empty line, e.g.
+/// `\desmos{y=x^2}`. The given callback converts the nodes of the given
+/// scope to code, where `embed_scope` is the scope within the embed command.
+fn expressions(
+    resource_env: &ResourceEnv,
+    embed_scope: &SemanticScope,
+    cmd: &CmdCall,
+    mut to_code: impl FnMut(&SemanticScope, Vec<Node>) -> String,
+) -> Vec<Expression> {
+    let mut results = Vec::new();
+    let mut pending = Vec::new();
+    let flush = |pending: &mut Vec<Node>, results: &mut Vec<Expression>, to_code: &mut dyn FnMut(&SemanticScope, Vec<Node>) -> String| {
+        if pending.is_empty() {
+            return
+        }
+        let code = to_code(embed_scope, std::mem::take(pending));
+        results.extend(code.lines().map(str::trim).filter(|x| !x.is_empty()).map(|line| Expression {
+            attributes: Attributes::default(),
+            code: line.to_owned(),
+        }));
+    };
+    for node in cmd.arguments.iter().cloned().flat_map(Node::unblock_root_curly_brace) {
+        match node {
+            Node::Cmd(expr) if expr.identifier.value == EXPR_IDENT => {
+                flush(&mut pending, &mut results, &mut to_code);
+                let expr_scope = embed_scope.new_scope(resource_env, &expr);
+                let nodes = expr.arguments
+                    .into_iter()
+                    .flat_map(Node::unblock_root_curly_brace)
+                    .collect_vec();
+                results.push(Expression {
+                    attributes: expr.attributes,
+                    code: to_code(&expr_scope, nodes).trim().to_owned(),
+                });
+            }
+            node => pending.push(node),
+        }
+    }
+    flush(&mut pending, &mut results, &mut to_code);
+    results
+}
+
+fn desmos_expression_config(expression: &Expression) -> Value {
+    let attributes = &expression.attributes;
+    let mut config = Map::new();
+    config.insert(String::from("latex"), json!(expression.code));
+    if let Some(color) = attributes.get_str_value("color") {
+        config.insert(String::from("color"), json!(color.trim()));
+    }
+    for (key, option, values) in [("line-style", "lineStyle", DESMOS_LINE_STYLES), ("point-style", "pointStyle", DESMOS_POINT_STYLES)] {
+        if let Some(value) = attributes.get_str_value(key).filter(|x| values.contains(&x.trim())) {
+            config.insert(String::from(option), json!(value.trim().to_uppercase()));
+        }
+    }
+    for (key, option) in [("line-width", "lineWidth"), ("line-opacity", "lineOpacity"), ("fill-opacity", "fillOpacity")] {
+        if let Some(value) = number_value(attributes, key) {
+            config.insert(String::from(option), json!(value));
+        }
+    }
+    for key in ["hidden", "secret"] {
+        if let Some(value) = bool_value(attributes, key) {
+            config.insert(String::from(key), json!(value));
+        }
+    }
+    if let Some(label) = attributes.get_str_value("label") {
+        config.insert(String::from("label"), json!(label));
+        config.insert(String::from("showLabel"), json!(true));
+    }
+    let slider_bounds = [("slider-min", "min"), ("slider-max", "max"), ("slider-step", "step")]
+        .into_iter()
+        .filter_map(|(key, bound)| Some((String::from(bound), json!(number_value(attributes, key)?.to_string()))))
+        .collect::<Map<_, _>>();
+    if !slider_bounds.is_empty() {
+        config.insert(String::from("sliderBounds"), Value::Object(slider_bounds));
+    }
+    Value::Object(config)
+}
+
+fn geogebra_expression_config(
+    resource_env: &ResourceEnv,
+    scope: &SemanticScope,
+    cmd: &CmdCall,
+    expression: &Expression,
+) -> Value {
+    let attributes = &expression.attributes;
+    let mut config = Map::new();
+    config.insert(String::from("command"), json!(expression.code));
+    if let Some(color) = attributes.get_str_value("color") {
+        // GeoGebra only takes RGB values.
+        match parse_hex_color(&color) {
+            Some(rgb) => {
+                config.insert(String::from("color"), json!(rgb));
+            }
+            None => report_warning(resource_env, scope, cmd, format!(
+                "invalid `color` value `{color}` for `\\expr` of `\\geogebra`, expected a hex color (e.g. `#c74440`)"
+            )),
+        }
+    }
+    if let Some(style) = attributes.get_str_value("line-style") {
+        let style = match style.trim() {
+            "solid" => Some(0),
+            "dashed" => Some(1),
+            "dotted" => Some(3),
+            "dash-dot" => Some(4),
+            _ => None,
+        };
+        if let Some(style) = style {
+            config.insert(String::from("lineStyle"), json!(style));
+        }
+    }
+    if let Some(width) = attributes.get_str_value("line-width").and_then(|x| x.trim().parse::<i64>().ok()) {
+        config.insert(String::from("lineThickness"), json!(width));
+    }
+    if let Some(hidden) = bool_value(attributes, "hidden") {
+        config.insert(String::from("hidden"), json!(hidden));
+    }
+    if let Some(label) = attributes.get_str_value("label") {
+        config.insert(String::from("label"), json!(label));
+    }
+    Value::Object(config)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// HTML
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// The config is read by `ss-runtime.js`, `</` is escaped so it can’t close
+/// the script element.
+fn embed_to_html(kind: &str, config: Value, notice: &str, fallback: Vec<html::Node>) -> html::Node {
+    let config = config.to_string().replace("</", "<\\/");
+    let notice = html::TagBuilder::new("p").push_child(html::Node::Text(String::from(notice)));
+    let mut fallback_children = vec![notice.finalize()];
+    if !fallback.is_empty() {
+        let list = html::TagBuilder::new("ul")
+            .with_children(fallback.into_iter().map(|x| html::TagBuilder::new("li").push_child(x)));
+        fallback_children.push(list.finalize());
+    }
+    let fallback = html::TagBuilder::new("div")
+        .with_attr("data-embed-fallback", "")
+        .with_children(fallback_children);
+    html::TagBuilder::new("div")
+        .with_attr("data-cmd", kind)
+        .with_attr("data-embed", kind)
+        .push_child(
+            html::TagBuilder::new("script")
+                .with_attr("type", "application/json")
+                .with_attr("data-embed-config", "")
+                .push_child(html::Node::Text(config))
+        )
+        .push_child(fallback)
+        .finalize()
+}
+
+fn desmos_to_html(env: &crate::ss::HtmlCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> html::Node {
+    let resource_env = &env.resource_env;
+    let embed_scope = scope.new_scope(resource_env, &cmd);
+    let expressions = expressions(resource_env, &embed_scope, &cmd, |scope, nodes| {
+        let latex_env = crate::ss::env::LatexCodegenEnv::from_scope(scope)
+            .with_resource_env(resource_env);
+        nodes.into_iter().map(|x| x.to_latex(&latex_env, scope)).collect::<String>()
+    });
+    let mut options = Map::new();
+    for (key, option) in DESMOS_OPTIONS {
+        if let Some(value) = bool_value(&cmd.attributes, key) {
+            options.insert(String::from(*option), json!(value));
+        }
+    }
+    for (key, option) in [("x-axis-label", "xAxisLabel"), ("y-axis-label", "yAxisLabel")] {
+        if let Some(value) = cmd.attributes.get_str_value(key) {
+            options.insert(String::from(option), json!(value));
+        }
+    }
+    let mut config = Map::new();
+    config.insert(String::from("height"), json!(height_value(&cmd.attributes)));
+    config.insert(String::from("options"), Value::Object(options));
+    if let Some(bounds) = cmd.attributes.get_str_value("bounds") {
+        match parse_bounds(&bounds) {
+            Some([left, right, bottom, top]) => {
+                config.insert(String::from("bounds"), json!({"left": left, "right": right, "bottom": bottom, "top": top}));
+            }
+            None => report_warning(resource_env, scope, &cmd, format!(
+                "invalid `bounds` value `{bounds}` for `\\desmos`, expected `left, right, bottom, top`"
+            )),
+        }
+    }
+    config.insert(
+        String::from("expressions"),
+        Value::Array(expressions.iter().map(desmos_expression_config).collect()),
+    );
+    let is_unique = !scope.in_heading_scope();
+    let fallback = expressions
+        .into_iter()
+        .map(|expression| {
+            let element = env.add_inline_math_entry(scope, cmd.identifier.range, expression.code, is_unique);
+            html::Node::Element(element)
+        })
+        .collect_vec();
+    embed_to_html("desmos", Value::Object(config), "Interactive Desmos graph, requires JavaScript and desmos.com.", fallback)
+}
+
+fn geogebra_to_html(env: &crate::ss::HtmlCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> html::Node {
+    let resource_env = &env.resource_env;
+    let embed_scope = scope.new_scope(resource_env, &cmd);
+    let expressions = expressions(resource_env, &embed_scope, &cmd, |scope, nodes| {
+        let children = nodes.into_iter().map(|x| x.to_html(env, scope)).collect_vec();
+        crate::html::utils::unescape_html_text(&html::Node::Fragment(children).to_text_content())
+    });
+    let material = cmd.attributes.get_str_value("material").map(|x| x.trim().to_owned());
+    let app = cmd.attributes
+        .get_str_value("app")
+        .map(|x| x.trim().to_owned())
+        .filter(|x| GEOGEBRA_APPS.contains(&x.as_str()));
+    let mut params = Map::new();
+    match (app, material.as_ref()) {
+        (Some(app), _) => {
+            params.insert(String::from("appName"), json!(app));
+        }
+        // The material determines the app.
+        (None, Some(_)) => {}
+        (None, None) => {
+            params.insert(String::from("appName"), json!("graphing"));
+        }
+    }
+    if let Some(material) = material {
+        params.insert(String::from("material_id"), json!(material));
+    }
+    for (key, param) in GEOGEBRA_PARAMS {
+        if let Some(value) = bool_value(&cmd.attributes, key) {
+            params.insert(String::from(*param), json!(value));
+        }
+    }
+    let mut config = Map::new();
+    config.insert(String::from("height"), json!(height_value(&cmd.attributes)));
+    config.insert(String::from("params"), Value::Object(params));
+    for key in ["grid", "axes"] {
+        if let Some(value) = bool_value(&cmd.attributes, key) {
+            config.insert(String::from(key), json!(value));
+        }
+    }
+    if let Some(coords) = cmd.attributes.get_str_value("coords") {
+        match parse_bounds(&coords) {
+            Some(coords) => {
+                config.insert(String::from("coords"), json!(coords));
+            }
+            None => report_warning(resource_env, scope, &cmd, format!(
+                "invalid `coords` value `{coords}` for `\\geogebra`, expected `xmin, xmax, ymin, ymax`"
+            )),
+        }
+    }
+    config.insert(
+        String::from("expressions"),
+        Value::Array(expressions
+            .iter()
+            .map(|expression| geogebra_expression_config(resource_env, scope, &cmd, expression))
+            .collect()),
+    );
+    let fallback = expressions
+        .into_iter()
+        .map(|expression| {
+            html::TagBuilder::new("code")
+                .push_child(html::Node::Text(crate::html::utils::escape_html_text(&expression.code)))
+                .finalize()
+        })
+        .collect_vec();
+    embed_to_html("geogebra", Value::Object(config), "Interactive GeoGebra applet, requires JavaScript and geogebra.org.", fallback)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// LATEX
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+/// Interactive graphs can’t be exported, so this is just the notice.
+fn embed_to_latex(notice: &str, expressions: Vec<String>) -> String {
+    let lines = std::iter::once(format!("\\emph{{{notice}}}"))
+        .chain(expressions)
+        .join("\\\\\n");
+    format!("\n\\begin{{center}}\n{lines}\n\\end{{center}}\n")
+}
+
+fn desmos_to_latex(env: &crate::ss::LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    // Unlike the HTML codegen, the given scope is already within the command.
+    let expressions = expressions(&env.resource_env, scope, &cmd, |scope, nodes| {
+        nodes.into_iter().map(|x| x.to_latex(env, scope)).collect::<String>()
+    });
+    let expressions = expressions.into_iter().map(|x| format!("\\({}\\)", x.code)).collect_vec();
+    embed_to_latex("Interactive Desmos graph", expressions)
+}
+
+fn geogebra_to_latex(env: &crate::ss::LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    let expressions = expressions(&env.resource_env, scope, &cmd, |scope, nodes| {
+        // Text is escaped by the LaTeX codegen.
+        nodes.into_iter().map(|x| x.to_latex(env, scope)).collect::<String>()
+    });
+    let expressions = expressions.into_iter().map(|x| format!("\\texttt{{{}}}", x.code)).collect_vec();
+    embed_to_latex(&escape_latex_text("Interactive GeoGebra applet"), expressions)
+}
+
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+// COMMANDS
+//―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
+
+pub fn all_embed_commands() -> Vec<cmd_decl::CmdDeclaration> {
+    use cmd_decl::{AttributeKey, AttributeValueType};
+    let mut desmos = CmdDeclBuilder::new(Ident::from("\\desmos").unwrap())
+        .parent_content_mode(ContentMode::Text)
+        .child_content_mode(ContentMode::Symbolic(SymbolicModeType::Math))
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+                () => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("height"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("bounds"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("x-axis-label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("y-axis-label"), typed(AttributeValueType::String));
+    for (key, _) in DESMOS_OPTIONS {
+        desmos = desmos.attribute(AttributeKey::new_attr(key), typed(AttributeValueType::Bool));
+    }
+    let desmos = desmos
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                desmos_to_html(env, scope, cmd)
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                desmos_to_latex(env, scope, cmd)
+            }
+        })
+        .documentation("An interactive Desmos graph of the given `\\expr`s (or of each line), e.g. `\\desmos[bounds=\"-5, 5, -5, 5\"]{\\expr[line-style=\"dashed\"]{y=x^2}}`.\n\nAttributes: `height` (in pixels), `bounds` (`left, right, bottom, top`), `x-axis-label`, `y-axis-label`, and the flags `expressions`, `keypad`, `settings-menu`, `zoom-buttons`, `lock-viewport`, `grid`, `x-axis`, `y-axis`, `degree-mode` and `projector-mode` (e.g. `expressions=false`).")
+        .finish();
+    let desmos_expr = CmdDeclBuilder::new(Ident::from(EXPR_IDENT).unwrap())
+        .parent(Ident::from("\\desmos").unwrap())
+        .parent_content_mode(ContentMode::Symbolic(SymbolicModeType::Math))
+        .child_content_mode(ContentMode::Symbolic(SymbolicModeType::Math))
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("color"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("line-style"), typed(AttributeValueType::OneOf(DESMOS_LINE_STYLES)))
+        .attribute(AttributeKey::new_attr("line-width"), typed(AttributeValueType::Number))
+        .attribute(AttributeKey::new_attr("line-opacity"), typed(AttributeValueType::Number))
+        .attribute(AttributeKey::new_attr("fill-opacity"), typed(AttributeValueType::Number))
+        .attribute(AttributeKey::new_attr("point-style"), typed(AttributeValueType::OneOf(DESMOS_POINT_STYLES)))
+        .attribute(AttributeKey::new_attr("hidden"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("secret"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("slider-min"), typed(AttributeValueType::Number))
+        .attribute(AttributeKey::new_attr("slider-max"), typed(AttributeValueType::Number))
+        .attribute(AttributeKey::new_attr("slider-step"), typed(AttributeValueType::Number))
+        .documentation("A Desmos expression, e.g. `\\expr[color=\"#c74440\", line-style=\"dashed\"]{y=x^2}`.\n\nAttributes: `color`, `line-style` (`solid`, `dashed` or `dotted`), `line-width`, `line-opacity`, `fill-opacity`, `point-style` (`point`, `open` or `cross`), `hidden`, `secret`, `label`, and `slider-min`, `slider-max` and `slider-step` for variables.")
+        .finish();
+    let mut geogebra = CmdDeclBuilder::new(Ident::from("\\geogebra").unwrap())
+        .parent_content_mode(ContentMode::Text)
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+                () => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("app"), typed(AttributeValueType::OneOf(GEOGEBRA_APPS)))
+        .attribute(AttributeKey::new_attr("material"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("height"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("coords"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("grid"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("axes"), typed(AttributeValueType::Bool));
+    for (key, _) in GEOGEBRA_PARAMS {
+        geogebra = geogebra.attribute(AttributeKey::new_attr(key), typed(AttributeValueType::Bool));
+    }
+    let geogebra = geogebra
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                geogebra_to_html(env, scope, cmd)
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                geogebra_to_latex(env, scope, cmd)
+            }
+        })
+        .documentation("An interactive GeoGebra applet running the given `\\expr` commands (or each line), e.g. `\\geogebra{\\expr[color=\"#2d70b3\"]{Circle((0, 0), 2)}}`, or showing a published `material` (its ID).\n\nAttributes: `app` (`graphing`, `geometry`, `3d`, `classic`, `suite` or `scientific`), `material`, `height` (in pixels), `coords` (`xmin, xmax, ymin, ymax`), `grid`, `axes`, and the flags `toolbar`, `algebra-input`, `menu-bar` and `reset-icon`.")
+        .finish();
+    let geogebra_expr = CmdDeclBuilder::new(Ident::from(EXPR_IDENT).unwrap())
+        .parent(Ident::from("\\geogebra").unwrap())
+        .parent_content_mode(ContentMode::Text)
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("color"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("line-style"), typed(AttributeValueType::OneOf(GEOGEBRA_LINE_STYLES)))
+        .attribute(AttributeKey::new_attr("line-width"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("hidden"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("label"), typed(AttributeValueType::String))
+        .documentation("A GeoGebra command, e.g. `\\expr[line-style=\"dashed\"]{f(x) = x^2}`.\n\nAttributes: `color` (hex, e.g. `#2d70b3`), `line-style` (`solid`, `dashed`, `dotted` or `dash-dot`), `line-width` (1–13), `hidden` and `label` (the caption).")
+        .finish();
+    vec![desmos, desmos_expr, geogebra, geogebra_expr]
+}
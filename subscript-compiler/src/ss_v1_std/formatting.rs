@@ -65,6 +65,24 @@ pub fn all_block_formatting_commands() -> Vec<cmd_decl::CmdDeclaration> {
                 })
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                let columns = cmd.attributes
+                    .get_str_value("col")
+                    .and_then(|x| x.trim().parse::<usize>().ok())
+                    .unwrap_or(2);
+                if columns > 1 {
+                    format!("\n\\begin{{grid}}{{{columns}}}\n{children}\n\\end{{grid}}\n")
+                } else {
+                    children
+                }
+            }
+        })
+        .attribute(cmd_decl::AttributeKey::new_attr("col"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::Int)))
+        .attribute(cmd_decl::AttributeKey::new_attr("vr"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("show-rule"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("section"), None)
+        .documentation("Multi-column layout, set the number of columns with `col`. Use `vr` or `show-rule` to show column rules.")
         .finish();
     let grid = CmdDeclBuilder::new(Ident::from("\\grid").unwrap())
         .arguments(
@@ -132,6 +150,23 @@ pub fn all_block_formatting_commands() -> Vec<cmd_decl::CmdDeclaration> {
                 })
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                let columns = cmd.attributes
+                    .get_str_value("col")
+                    .and_then(|x| x.trim().parse::<usize>().ok())
+                    .unwrap_or(2);
+                if columns > 1 {
+                    format!("\n\\begin{{grid}}{{{columns}}}\n{children}\n\\end{{grid}}\n")
+                } else {
+                    children
+                }
+            }
+        })
+        .attribute(cmd_decl::AttributeKey::new_attr("col"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::Int)))
+        .attribute(cmd_decl::AttributeKey::new_attr("boxed"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("section"), None)
+        .documentation("Grid layout, set the number of columns with `col`. Use `boxed` for a bordered grid.")
         .finish();
     let note = CmdDeclBuilder::new(Ident::from("\\note").unwrap())
         .arguments(
@@ -161,6 +196,12 @@ pub fn all_block_formatting_commands() -> Vec<cmd_decl::CmdDeclaration> {
                 if cmd.attributes.has_truthy_option("boxed") {
                     attributes.insert(String::from("boxed"), String::new());
                 }
+                // Labeled, see `crate::ss::labels`.
+                for key in ["id", "data-number"] {
+                    if let Some(value) = cmd.attributes.get_str_value(key) {
+                        attributes.insert(String::from(key), value);
+                    }
+                }
                 let children = cmd.arguments
                     .into_iter()
                     .flat_map(Node::unblock_root_curly_brace)
@@ -173,6 +214,18 @@ pub fn all_block_formatting_commands() -> Vec<cmd_decl::CmdDeclaration> {
                 })
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                let name = if cmd.attributes.has_truthy_option("boxed") {"boxednote"} else {"note"};
+                let caption = cmd.attributes
+                    .get_str_value("data-number")
+                    .map(|number| format!("\\textbf{{Note {number}.}} "))
+                    .unwrap_or_default();
+                format!("\n\\begin{{{name}}}\n{caption}{children}\n\\end{{{name}}}\n")
+            }
+        })
+        .attribute(cmd_decl::AttributeKey::new_attr("boxed"), None)
+        .documentation("A note section. Use `boxed` for a bordered note.")
         .finish();
     vec![
         layout,
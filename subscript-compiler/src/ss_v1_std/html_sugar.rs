@@ -54,6 +54,23 @@ pub fn html_syntactic_sugar_extras() -> Vec<cmd_decl::CmdDeclaration> {
                 })
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                let cells = cmd.arguments
+                    .into_iter()
+                    .filter_map(|x| x.into_curly_brace_children())
+                    .map(|cell| {
+                        cell.into_iter()
+                            .map(|x| x.to_latex(env, scope))
+                            .collect::<String>()
+                            .trim()
+                            .to_owned()
+                    })
+                    .join(" & ");
+                format!("{cells} \\\\\n")
+            }
+        })
+        .documentation("A table row, each curly brace argument becomes a `td` cell. Only available within `\\table`.")
         .finish();
     // let table_row = CmdDeclBuilder::new(Ident::from("\\liX").unwrap())
     //     .parent_layout_mode(LayoutMode::Block)
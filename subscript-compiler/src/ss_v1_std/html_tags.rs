@@ -1,4 +1,5 @@
 use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
 use crate::ss::ast_data::HeadingType;
 use crate::ss::SemanticScope;
 use crate::ss::SymbolicModeType;
@@ -16,12 +17,15 @@ fn process_image(
         .map(|src| PathBuf::from(src))
         .and_then(|src| scope.normalize_file_path(src).ok())
         .and_then(|src| {
-            let original = src.clone();
-            let result = env.add_image(scope, src);
-            if result.is_none() {
-                eprintln!("[Warning] failed to find src path: {original:?}");
+            if !src.exists() {
+                env.report(
+                    Diagnostic::warning(format!("image not found: {}", src.display()))
+                        .with_range(img_cmd.identifier.range)
+                        .with_scope(scope)
+                );
+                return None
             }
-            result
+            env.add_image(scope, src)
         });
     if let Some(src_str) = file_path {
         let mut new_attrs = Attributes::default();
@@ -42,6 +46,170 @@ fn process_image(
     Node::Cmd(img_cmd)
 }
 
+/// Converts the `max-width` set by `process_image` to a LaTeX length.
+fn latex_image_width(style: &str) -> Option<String> {
+    let value = style
+        .split(';')
+        .filter_map(|x| x.split_once(':'))
+        .find(|(key, _)| key.trim() == "max-width")
+        .map(|(_, value)| value.trim())?;
+    if let Some(percent) = value.strip_suffix('%') {
+        let percent = percent.trim().parse::<f64>().ok()?;
+        return Some(format!("{}\\linewidth", (percent / 100.0).min(1.0)))
+    }
+    ["pt", "px", "em", "cm", "mm", "in"]
+        .iter()
+        .any(|unit| value.ends_with(unit))
+        .then(|| value.to_owned())
+}
+
+/// Attributes of `\pre` and `\code` used for highlighting, as opposed to
+/// HTML attributes.
+const CODE_ATTRIBUTES: &[&str] = &["lang", "line-numbers", "highlight", "start"];
+
+fn code_grammar(
+    env: &crate::ss::HtmlCodegenEnv,
+    scope: &SemanticScope,
+    cmd: &CmdCall,
+    lang: &str,
+) -> Option<&'static crate::html::highlight::Grammar> {
+    let grammar = crate::html::highlight::find_grammar(lang);
+    if grammar.is_none() {
+        env.resource_env.report(
+            Diagnostic::warning(format!(
+                "unknown `lang` value `{lang}`, so the code isn’t highlighted (supported: {})",
+                crate::html::highlight::grammar_names().join(", "),
+            ))
+            .with_range(cmd.identifier.range)
+            .with_scope(scope)
+        );
+    }
+    grammar
+}
+
+/// The code as shown without highlighting, i.e. as written.
+fn code_text(
+    env: &crate::ss::HtmlCodegenEnv,
+    scope: &SemanticScope,
+    cmd: &CmdCall,
+    arguments: Vec<Node>,
+) -> String {
+    let child_scope = scope.new_scope(&env.resource_env, cmd);
+    let children = arguments
+        .into_iter()
+        .flat_map(Node::unblock_root_curly_brace)
+        .map(|x| x.to_html(env, &child_scope))
+        .collect_vec();
+    crate::html::utils::unescape_html_text(&crate::html::Node::Fragment(children).to_text_content())
+}
+
+fn code_html_attributes(attributes: Attributes) -> HashMap<String, String> {
+    attributes
+        .consume()
+        .into_iter()
+        .filter_map(crate::ss::ast_data::Attribute::to_key_value_str)
+        .filter(|(key, _)| !CODE_ATTRIBUTES.contains(&key.as_str()))
+        .map(|(key, value)| (key, value.unwrap_or_default()))
+        .collect()
+}
+
+/// With a `lang`, `line-numbers` or `highlight` attribute, code blocks are
+/// rendered VIA `crate::html::highlight`, otherwise as is. The `lang` may
+/// also be given on a sole `\code` child, as generated for Markdown and
+/// imported HTML.
+fn pre_to_html(
+    env: &crate::ss::HtmlCodegenEnv,
+    scope: &SemanticScope,
+    cmd: CmdCall,
+) -> crate::html::Node {
+    let report = |message: String| {
+        env.resource_env.report(
+            Diagnostic::warning(message)
+                .with_range(cmd.identifier.range)
+                .with_scope(scope)
+        );
+    };
+    let sole_code = cmd.arguments
+        .iter()
+        .cloned()
+        .flat_map(Node::unblock_root_curly_brace)
+        .filter(|x| !matches!(x, Node::Text(text) if text.value.trim().is_empty()))
+        .exactly_one()
+        .ok()
+        .and_then(|x| match x {
+            Node::Cmd(code) if code.identifier.value == "\\code" => Some(code),
+            _ => None,
+        });
+    let lang = cmd.attributes
+        .get_str_value("lang")
+        .or_else(|| sole_code.as_ref()?.attributes.get_str_value("lang"));
+    let line_numbers = cmd.attributes.has_truthy_option("line-numbers");
+    let highlight = cmd.attributes.get_str_value("highlight");
+    if lang.is_none() && !line_numbers && highlight.is_none() {
+        return crate::ss::codegen::default_cmd_html_cg(env, scope, cmd)
+    }
+    let start = match cmd.attributes.get_str_value("start") {
+        Some(value) => value.trim().parse::<usize>().unwrap_or_else(|_| {
+            report(format!("invalid `start` value `{value}`, expected a line number"));
+            1
+        }),
+        None => 1,
+    };
+    let highlighted = match highlight {
+        Some(value) => crate::html::highlight::parse_line_ranges(&value).unwrap_or_else(|| {
+            report(format!("invalid `highlight` value `{value}`, expected lines such as `2,4-6`"));
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+    let grammar = lang.as_ref().and_then(|x| code_grammar(env, scope, &cmd, x));
+    let arguments = match sole_code {
+        Some(code) => code.arguments,
+        None => cmd.arguments.clone(),
+    };
+    let code = code_text(env, scope, &cmd, arguments);
+    let code = crate::html::highlight::normalize_code(&code);
+    let children = crate::html::highlight::code_block_to_html(grammar, &code, start, &highlighted);
+    let mut attributes = code_html_attributes(cmd.attributes);
+    if let Some(lang) = grammar.map(|x| x.name().to_owned()).or(lang) {
+        attributes.insert(String::from("data-lang"), lang);
+    }
+    if line_numbers {
+        attributes.insert(String::from("data-line-numbers"), String::new());
+    }
+    let code = crate::html::TagBuilder::new("code")
+        .with_class_if(grammar.is_some(), format!("language-{}", grammar.map(|x| x.name()).unwrap_or_default()))
+        .with_children(children)
+        .finalize();
+    crate::html::Node::Element(crate::html::Element {
+        name: String::from("pre"),
+        attributes,
+        children: vec![code],
+    })
+}
+
+/// Inline code with a `lang` attribute is highlighted, see `pre_to_html`.
+fn code_to_html(
+    env: &crate::ss::HtmlCodegenEnv,
+    scope: &SemanticScope,
+    cmd: CmdCall,
+) -> crate::html::Node {
+    let lang = match cmd.attributes.get_str_value("lang") {
+        Some(lang) => lang,
+        None => return crate::ss::codegen::default_cmd_html_cg(env, scope, cmd),
+    };
+    let grammar = code_grammar(env, scope, &cmd, &lang);
+    let code = code_text(env, scope, &cmd, cmd.arguments.clone());
+    let mut attributes = code_html_attributes(cmd.attributes);
+    attributes.insert(String::from("data-lang"), grammar.map(|x| x.name().to_owned()).unwrap_or(lang));
+    let mut element = crate::html::TagBuilder::new("code");
+    element.attributes = attributes;
+    element
+        .with_class_if(grammar.is_some(), format!("language-{}", grammar.map(|x| x.name()).unwrap_or_default()))
+        .with_children(crate::html::highlight::inline_code_to_html(grammar, &code))
+        .finalize()
+}
+
 pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
     vec![
         CmdDeclBuilder::new(Ident::from("\\address").unwrap())
@@ -181,6 +349,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 1, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\h2").unwrap())
             .arguments(
@@ -204,6 +373,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 2, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\h3").unwrap())
             .arguments(
@@ -227,6 +397,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 3, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\h4").unwrap())
             .arguments(
@@ -250,6 +421,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 4, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\h5").unwrap())
             .arguments(
@@ -273,6 +445,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 5, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\h6").unwrap())
             .arguments(
@@ -296,6 +469,7 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .documentation("Heading level 6, added to the table of contents.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\section").unwrap())
             .arguments(
@@ -552,6 +726,11 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Block)
+            .to_html(to_html! {
+                fn (env, scope, cmd) {
+                    pre_to_html(env, scope, cmd)
+                }
+            })
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\ul").unwrap())
             .arguments(
@@ -714,29 +893,6 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
             )
             .parent_layout_mode(LayoutMode::Inline)
             .finish(),
-        CmdDeclBuilder::new(Ident::from("\\cite").unwrap())
-            .arguments(
-                arguments! {
-                    for (internal, metadata, cmd_payload) match {
-                        ({xs}) => {
-                            Node::Cmd(CmdCall {
-                                identifier: cmd_payload.identifier,
-                                attributes: cmd_payload.attributes.unwrap_or_default(),
-                                arguments: vec![xs]
-                            })
-                        },
-                        () => {
-                            Node::Cmd(CmdCall {
-                                identifier: cmd_payload.identifier,
-                                attributes: cmd_payload.attributes.unwrap_or_default(),
-                                arguments: vec![]
-                            })
-                        },
-                    }
-                }
-            )
-            .parent_layout_mode(LayoutMode::Inline)
-            .finish(),
         CmdDeclBuilder::new(Ident::from("\\code").unwrap())
             .arguments(
                 arguments! {
@@ -759,6 +915,11 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                 }
             )
             .parent_layout_mode(LayoutMode::Inline)
+            .to_html(to_html! {
+                fn (env, scope, cmd) {
+                    code_to_html(env, scope, cmd)
+                }
+            })
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\data").unwrap())
             .arguments(
@@ -1211,7 +1372,32 @@ pub fn all_supported_html_tags() -> Vec<cmd_decl::CmdDeclaration> {
                     }
                 }
             )
+            .to_latex(to_latex! {
+                fn (env, scope, cmd) {
+                    // Only images that were found are rewritten to a path
+                    // relative to the output directory (see `process_image`).
+                    let path = match cmd.attributes.get_str_value("src") {
+                        Some(src) if src.starts_with('/') => src.trim_start_matches('/').to_owned(),
+                        _ => return String::new(),
+                    };
+                    let width = cmd.attributes
+                        .get_str_value("style")
+                        .and_then(|style| latex_image_width(&style))
+                        .unwrap_or_else(|| String::from("\\linewidth"));
+                    let graphic = format!("\\includegraphics[max width={width}]{{{path}}}");
+                    if cmd.attributes.has_attr("data-center") {
+                        format!("\n\\begin{{center}}{graphic}\\end{{center}}\n")
+                    } else {
+                        graphic
+                    }
+                }
+            })
             .parent_layout_mode(LayoutMode::Both)
+            .attribute(cmd_decl::AttributeKey::new_attr("src"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::FilePath)))
+            .attribute(cmd_decl::AttributeKey::new_attr("width"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+            .attribute(cmd_decl::AttributeKey::new_attr("max-width"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+            .attribute(cmd_decl::AttributeKey::new_attr("center"), None)
+            .documentation("An image. Attributes: `src` (relative to the current file), `width`, `max-width`, `center`.")
             .finish(),
         CmdDeclBuilder::new(Ident::from("\\map").unwrap())
             .arguments(
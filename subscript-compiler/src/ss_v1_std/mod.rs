@@ -1,6 +1,6 @@
 //! I’ve overhauled the parser (didn’t realize how bad the previous implementation was), and the core compiler data models, with a unified interface for command declarations, where commands can be implemented and made available in a very fined tuned manner.
 //! So you can have commands that are available based on parent command scope (for instance the `\row{…}` cmd is only available if it's nested under the `\table` cmd (doesn’t need to be a direct descendant)), block/inline mode, or content mode (i.e. text (the default) or the multitude “symbolic modes” (such as math, chemistry, both, etc.)). For instance, LaTeX technically has two different fraction macros, where one is for block display modes and the other for inline fractions (can’t remember what it’s called), with the interface I have: you can use the came command identifier for both, and the compiler will automatically select the appropriate version.
-//! **Although at the time of this writing, not all information is propagated during relevant AST traversals.** Also there needs to be support for defining documentation for a given command, which I haven’t yet got to. 
+//! **Although at the time of this writing, not all information is propagated during relevant AST traversals.** Documentation for a given command is defined via `CmdDeclBuilder::documentation`, although most commands don’t have any yet. 
 //! Defining/declaring SS commands in rust is somewhat awkward and very verbose, and perhaps could be better, but the real innovation here (as opposed to previous implementations) is that all commands are defined in a manner that (in theory) is easily fed to autocomplete engines. Furthermore, everything pertaining to a given command is defined in one place, from post-parser structure to target specific code-gens. Furthermore, for a given processing stage, all commands are essentially processed in a single traversal. 
 // use crate::ss::ast::{Ann, Bracket, Ident, IdentInitError, Node};
 use either::{Either, Either::Left, Either::Right};
@@ -21,6 +21,11 @@ pub mod html_tags;
 pub mod html_sugar;
 pub mod symbolic;
 pub mod formatting;
+pub mod references;
+pub mod bibliography;
+pub mod embeds;
+pub mod plots;
+pub mod data_tables;
 
 //―――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――――
 // DEV
@@ -44,6 +49,7 @@ pub struct CmdDeclBuilder {
     child_env_content_mode: Option<ContentMode>,
     child_env_layout_mode: Option<LayoutMode>,
     ignore_attributes: Option<bool>,
+    strict_attributes: Option<bool>,
     attributes: HashMap<cmd_decl::AttributeKey, Option<cmd_decl::AttributeValue>>,
     arguments: Option<cmd_decl::VariableArguments>,
     to_cmd: Option<
@@ -52,6 +58,7 @@ pub struct CmdDeclBuilder {
     to_html: Option<fn(&crate::ss::HtmlCodegenEnv, &SemanticScope, CmdCall) -> crate::html::ast::Node>,
     to_latex: Option<fn(&crate::ss::LatexCodegenEnv, &SemanticScope, CmdCall) -> String>,
     internal: Option<cmd_decl::InternalCmdDeclOptions>,
+    documentation: Option<String>,
 }
 
 impl CmdDeclBuilder {
@@ -64,12 +71,14 @@ impl CmdDeclBuilder {
             child_env_content_mode: None,
             child_env_layout_mode: None,
             ignore_attributes: None,
+            strict_attributes: None,
             attributes: HashMap::default(),
             arguments: None,
             to_cmd: None,
             to_html: None,
             to_latex: None,
             internal: None,
+            documentation: None,
         }
     }
     pub fn parent(mut self, ident: Ident) -> Self {
@@ -96,6 +105,20 @@ impl CmdDeclBuilder {
         self.ignore_attributes = Some(ignore_attributes);
         self
     }
+    /// See `cmd_decl::CmdDeclaration::strict_attributes`.
+    pub fn strict_attributes(mut self, strict_attributes: bool) -> Self {
+        self.strict_attributes = Some(strict_attributes);
+        self
+    }
+    /// A `None` value declares a flag attribute, e.g. `\note[boxed]{…}`.
+    pub fn attribute(
+        mut self,
+        key: cmd_decl::AttributeKey,
+        value: Option<cmd_decl::AttributeValue>,
+    ) -> Self {
+        self.attributes.insert(key, value);
+        self
+    }
     pub fn instance(mut self, arg_decl: cmd_decl::ArgumentsDeclInstance) -> Self {
         if self.arguments.is_none() {
             self.arguments = Some(cmd_decl::VariableArguments(vec![arg_decl]));
@@ -130,6 +153,10 @@ impl CmdDeclBuilder {
         self.internal = Some(internal);
         self
     }
+    pub fn documentation(mut self, docs: impl Into<String>) -> Self {
+        self.documentation = Some(docs.into());
+        self
+    }
     pub fn finish(self) -> cmd_decl::CmdDeclaration {
         let child_env = match (self.child_env_content_mode, self.child_env_layout_mode) {
             (None, None) => None,
@@ -155,6 +182,7 @@ impl CmdDeclBuilder {
             },
             child_env,
             ignore_attributes: self.ignore_attributes.unwrap_or(false),
+            strict_attributes: self.strict_attributes.unwrap_or(false),
             attributes: self.attributes,
             arguments: self.arguments.unwrap_or_default(),
             processors: cmd_decl::CmdCodegen {
@@ -163,6 +191,7 @@ impl CmdDeclBuilder {
                 to_latex: self.to_latex,
             },
             internal: self.internal.unwrap_or_default(),
+            documentation: self.documentation,
         }
     }
 }
@@ -217,6 +246,11 @@ pub fn all_commands_list() -> Vec<cmd_decl::CmdDeclaration> {
         symbolic::all_subscript_symbolic_mode_commands(),
         formatting::all_inline_formatting_commands(),
         formatting::all_block_formatting_commands(),
+        references::all_reference_commands(),
+        bibliography::all_bibliography_commands(),
+        embeds::all_embed_commands(),
+        plots::all_plot_commands(),
+        data_tables::all_data_table_commands(),
     ];
     commands.concat()
 }
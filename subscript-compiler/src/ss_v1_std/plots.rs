@@ -0,0 +1,235 @@
+//! Static function plots, e.g. `\plot[domain="-pi, pi"]{sin(x)}`, which are
+//! evaluated at compile time (see `crate::ss::plot`), so unlike `\desmos`
+//! they need no scripts and also work in print.
+use crate::html;
+use crate::ss::ResourceEnv;
+use crate::ss::diagnostics::Diagnostic;
+use crate::ss::figure::{parse_hex_color, Figure, LineStyle};
+use crate::ss::plot::{parse_interval, Curve, Plot, PlotMode};
+use ss_freeform_format::ColorScheme;
+use super::*;
+
+const EXPR_IDENT: &str = "\\expr";
+const LINE_STYLES: &[&str] = &["solid", "dashed", "dotted"];
+/// So a typo can’t stall the build.
+const MAX_SAMPLES: usize = 10_000;
+
+fn typed(value_ty: cmd_decl::AttributeValueType) -> Option<cmd_decl::AttributeValue> {
+    Some(cmd_decl::AttributeValue::new(value_ty))
+}
+
+fn report_warning(resource_env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall, message: String) {
+    resource_env.report(
+        Diagnostic::warning(message)
+            .with_range(cmd.identifier.range)
+            .with_scope(scope)
+    );
+}
+
+/// Invalid values are already reported (see `cmd_decl::AttributeValueType`).
+fn positive_int_value(attributes: &Attributes, key: &str) -> Option<usize> {
+    attributes.get_str_value(key)?.trim().parse::<usize>().ok().filter(|x| *x > 0)
+}
+
+/// The expressions as written, e.g. `sin(x)^2` rather than the HTML of the
+/// text (or `Node::to_string`, which puts enclosures on separate lines).
+fn source_text(nodes: &[Node]) -> String {
+    fn push(node: &Node, out: &mut String) {
+        match node {
+            Node::Text(x) | Node::Symbol(x) | Node::InvalidToken(x) => out.push_str(&x.value),
+            Node::Ident(x) => out.push_str(x.value.as_str()),
+            Node::Cmd(cmd) => {
+                out.push_str(cmd.identifier.value.as_str());
+                cmd.arguments.iter().for_each(|x| push(x, out));
+            }
+            Node::Bracket(x) => {
+                out.push_str(x.value.open.as_ref().map_or("", |x| x.value.as_str()));
+                x.value.children.iter().for_each(|x| push(x, out));
+                out.push_str(x.value.close.as_ref().map_or("", |x| x.value.as_str()));
+            }
+            Node::Quotation(x) => {
+                out.push_str(x.value.open.as_ref().map_or("", |x| x.value.as_str()));
+                x.value.children.iter().for_each(|x| push(x, out));
+                out.push_str(x.value.close.as_ref().map_or("", |x| x.value.as_str()));
+            }
+            Node::Fragment(xs) => xs.iter().for_each(|x| push(x, out)),
+            Node::Drawing(_) => (),
+        }
+    }
+    let mut out = String::new();
+    nodes.iter().for_each(|x| push(x, &mut out));
+    out
+}
+
+/// The `\expr` children, and otherwise each non-empty line, e.g.
+/// `\plot{x^2}`, where invalid expressions are reported and skipped.
+fn to_plot(resource_env: &ResourceEnv, scope: &SemanticScope, cmd: &CmdCall) -> Plot {
+    let attributes = &cmd.attributes;
+    let mode = attributes
+        .get_str_value("mode")
+        .and_then(|x| PlotMode::parse(&x))
+        .unwrap_or_default();
+    let interval = |key: &str| -> Option<(f64, f64)> {
+        let value = attributes.get_str_value(key)?;
+        parse_interval(&value)
+            .map_err(|error| report_warning(resource_env, scope, cmd, format!(
+                "invalid `{key}` value `{value}` for `\\plot`: {error}"
+            )))
+            .ok()
+    };
+    let mut plot = Plot {
+        mode,
+        domain: interval("domain"),
+        range: interval("range"),
+        parameter: interval("t-domain"),
+        grid: attributes.has_attr("grid") && !matches!(
+            attributes.get_str_value("grid").as_deref().map(str::trim),
+            Some("false") | Some("0"),
+        ),
+        x_label: attributes.get_str_value("x-label"),
+        y_label: attributes.get_str_value("y-label"),
+        ..Plot::default()
+    };
+    if let Some(width) = positive_int_value(attributes, "width") {
+        plot.width = width as f64;
+    }
+    if let Some(height) = positive_int_value(attributes, "height") {
+        plot.height = height as f64;
+    }
+    if let Some(samples) = positive_int_value(attributes, "samples") {
+        plot.samples = samples.clamp(2, MAX_SAMPLES);
+    }
+    let mut add_curve = |source: &str, expr_attributes: Option<&Attributes>| {
+        let mut curve = match Curve::parse(source, mode) {
+            Ok(curve) => curve,
+            Err(error) => {
+                report_warning(resource_env, scope, cmd, format!(
+                    "invalid expression `{}` for `\\plot`: {error}",
+                    source.trim(),
+                ));
+                return
+            }
+        };
+        if let Some(attributes) = expr_attributes {
+            curve.label = attributes.get_str_value("label");
+            curve.style = attributes
+                .get_str_value("line-style")
+                .and_then(|x| LineStyle::parse(&x))
+                .unwrap_or_default();
+            curve.width = attributes
+                .get_str_value("line-width")
+                .and_then(|x| x.trim().parse::<f64>().ok())
+                .filter(|x| x.is_finite() && *x > 0.0);
+            if let Some(color) = attributes.get_str_value("color") {
+                match parse_hex_color(&color) {
+                    Some(_) => curve.color = Some(color.trim().to_owned()),
+                    None => report_warning(resource_env, scope, cmd, format!(
+                        "invalid `color` value `{color}` for `\\expr` of `\\plot`, expected a hex color (e.g. `#c74440`)"
+                    )),
+                }
+            }
+        }
+        plot.curves.push(curve);
+    };
+    let mut pending = Vec::new();
+    let mut flush = |pending: &mut Vec<Node>, add_curve: &mut dyn FnMut(&str, Option<&Attributes>)| {
+        let source = source_text(&std::mem::take(pending));
+        for line in source.lines().map(str::trim).filter(|x| !x.is_empty()) {
+            add_curve(line, None);
+        }
+    };
+    for node in cmd.arguments.iter().cloned().flat_map(Node::unblock_root_curly_brace) {
+        match node {
+            Node::Cmd(expr) if expr.identifier.value == EXPR_IDENT => {
+                flush(&mut pending, &mut add_curve);
+                let nodes = expr.arguments
+                    .into_iter()
+                    .flat_map(Node::unblock_root_curly_brace)
+                    .collect_vec();
+                add_curve(&source_text(&nodes), Some(&expr.attributes));
+            }
+            node => pending.push(node),
+        }
+    }
+    flush(&mut pending, &mut add_curve);
+    if plot.curves.is_empty() {
+        report_warning(resource_env, scope, cmd, String::from("`\\plot` has nothing to plot"));
+    }
+    plot
+}
+
+fn plot_to_html(env: &crate::ss::HtmlCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> html::Node {
+    let figure: Figure = to_plot(&env.resource_env, scope, &cmd).to_figure();
+    html::TagBuilder::new("div")
+        .with_attr("data-cmd", "plot")
+        .push_child(figure.to_html(&ColorScheme::Dark))
+        .push_child(figure.to_html(&ColorScheme::Light))
+        .finalize()
+}
+
+fn plot_to_latex(env: &crate::ss::LatexCodegenEnv, scope: &SemanticScope, cmd: CmdCall) -> String {
+    let figure = to_plot(&env.resource_env, scope, &cmd).to_figure();
+    format!("\n\\begin{{center}}\n{}\n\\end{{center}}\n", figure.to_tikz())
+}
+
+pub fn all_plot_commands() -> Vec<cmd_decl::CmdDeclaration> {
+    use cmd_decl::{AttributeKey, AttributeValueType};
+    let plot = CmdDeclBuilder::new(Ident::from("\\plot").unwrap())
+        .parent_content_mode(ContentMode::Text)
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("mode"), typed(AttributeValueType::OneOf(PlotMode::NAMES)))
+        .attribute(AttributeKey::new_attr("domain"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("range"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("t-domain"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("grid"), typed(AttributeValueType::Bool))
+        .attribute(AttributeKey::new_attr("x-label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("y-label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("width"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("height"), typed(AttributeValueType::Int))
+        .attribute(AttributeKey::new_attr("samples"), typed(AttributeValueType::Int))
+        .to_html(to_html! {
+            fn (env, scope, cmd) {
+                plot_to_html(env, scope, cmd)
+            }
+        })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd) {
+                plot_to_latex(env, scope, cmd)
+            }
+        })
+        .documentation("A static plot of the given `\\expr`s (or of each line), evaluated at compile time, e.g. `\\plot[domain=\"-pi, pi\", grid]{\\expr[label=\"sine\"]{sin(x)}}`.\n\nAttributes: `mode` (`function` of `x`, `parametric` as in `cos(t), sin(t)`, or `polar` of `theta`), `domain` and `range` (e.g. `-2pi, 2pi`), `t-domain` (of `t` or `theta`, `0, 2pi` by default), `grid`, `x-label`, `y-label`, `width` and `height` (in pixels, 600×400 by default) and `samples` (500 by default).")
+        .finish();
+    let plot_expr = CmdDeclBuilder::new(Ident::from(EXPR_IDENT).unwrap())
+        .parent(Ident::from("\\plot").unwrap())
+        .parent_content_mode(ContentMode::Text)
+        .arguments(arguments! {
+            for (internal, metadata, cmd_payload) match {
+                ({xs}) => {
+                    Node::Cmd(CmdCall {
+                        identifier: cmd_payload.identifier,
+                        attributes: cmd_payload.attributes.unwrap_or_default(),
+                        arguments: vec![xs]
+                    })
+                },
+            }
+        })
+        .strict_attributes(true)
+        .attribute(AttributeKey::new_attr("color"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("label"), typed(AttributeValueType::String))
+        .attribute(AttributeKey::new_attr("line-style"), typed(AttributeValueType::OneOf(LINE_STYLES)))
+        .attribute(AttributeKey::new_attr("line-width"), typed(AttributeValueType::Number))
+        .documentation("A plotted expression, e.g. `\\expr[color=\"#2d70b3\", line-style=\"dashed\"]{x^2 - 1}`.\n\nAttributes: `color` (hex), `label` (shown in the legend), `line-style` (`solid`, `dashed` or `dotted`) and `line-width` (in pixels).")
+        .finish();
+    vec![plot, plot_expr]
+}
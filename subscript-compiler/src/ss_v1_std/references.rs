@@ -0,0 +1,98 @@
+//! Cross references, see `crate::ss::labels`.
+use crate::html;
+use crate::ss::SymbolicModeType;
+use crate::ss::labels::LABEL_IDENT;
+use super::*;
+
+pub fn all_reference_commands() -> Vec<cmd_decl::CmdDeclaration> {
+    // Labels are removed (and attached to their targets) before code-gen, so
+    // the declarations just keep them in the AST until then.
+    let label = |content_mode: ContentMode| {
+        CmdDeclBuilder::new(Ident::from(LABEL_IDENT).unwrap())
+            .parent_content_mode(content_mode)
+            .arguments(arguments! {
+                for (internal, metadata, cmd_payload) match {
+                    () => {
+                        Node::Cmd(CmdCall {
+                            identifier: cmd_payload.identifier,
+                            attributes: cmd_payload.attributes.unwrap_or_default(),
+                            arguments: vec![]
+                        })
+                    },
+                }
+            })
+            .to_html(to_html! {
+                fn (env, scope, cmd) {
+                    html::Node::Fragment(Vec::new())
+                }
+            })
+            .to_latex(to_latex! {
+                fn (env, scope, cmd) {
+                    String::new()
+                }
+            })
+            .documentation("Names the preceding heading, equation, drawing (i.e. `\\include`) or note, or the one it’s inside of, e.g. `\\h2{Energy}\\label[energy]` or `\\equation{E = mc^2 \\label[eq:energy]}`. Referenced VIA `\\ref[…]` or `\\eqref[…]` from any page in the project.")
+            .finish()
+    };
+    let reference = |ident: &str, documentation: &str| {
+        CmdDeclBuilder::new(Ident::from(ident).unwrap())
+            .arguments(arguments! {
+                for (internal, metadata, cmd_payload) match {
+                    () => {
+                        Node::Cmd(CmdCall {
+                            identifier: cmd_payload.identifier,
+                            attributes: cmd_payload.attributes.unwrap_or_default(),
+                            arguments: vec![]
+                        })
+                    },
+                }
+            })
+            .to_html(to_html! {
+                fn (env, scope, cmd) {
+                    let label = match env.labels.resolve(&env.resource_env, scope, &cmd) {
+                        Some(label) => label,
+                        None => return html::Node::Fragment(Vec::new()),
+                    };
+                    let text = match cmd.identifier.value.as_str() {
+                        "\\eqref" => format!("({})", label.text),
+                        _ => label.text.clone(),
+                    };
+                    html::Node::Element(html::Element {
+                        name: String::from("a"),
+                        attributes: HashMap::from_iter([
+                            (String::from("href"), label.href.clone()),
+                            (String::from("data-cmd"), String::from("ref")),
+                        ]),
+                        children: vec![html::Node::Text(text)],
+                    })
+                }
+            })
+            .to_latex(to_latex! {
+                fn (env, scope, cmd) {
+                    let label = match env.labels.resolve(&env.resource_env, scope, &cmd) {
+                        Some(label) => label,
+                        None => return String::new(),
+                    };
+                    let text = match cmd.identifier.value.as_str() {
+                        "\\eqref" => format!("({})", label.text),
+                        _ => label.text.clone(),
+                    };
+                    let text = crate::ss::codegen::escape_latex_text(&text);
+                    // Other pages are separate documents.
+                    if env.labels.is_local(label) {
+                        format!("\\hyperref[{}]{{{text}}}", label.id)
+                    } else {
+                        text
+                    }
+                }
+            })
+            .documentation(documentation)
+            .finish()
+    };
+    vec![
+        label(ContentMode::Text),
+        label(ContentMode::Symbolic(SymbolicModeType::All)),
+        reference("\\ref", "A link to the given label (see `\\label`), showing the number of equations, drawings and notes, or the title of headings. E.g. `see \\ref[energy]`."),
+        reference("\\eqref", "Like `\\ref`, but in parentheses, e.g. `by \\eqref[eq:energy]` shows “by (1)”."),
+    ]
+}
@@ -61,6 +61,99 @@ impl LabelMeta {
     }
 }
 
+/// The environment of a `\math` block, based on its `preset` attribute (if
+/// any).
+fn math_block_delimiters(attributes: &Attributes) -> Option<(String, String)> {
+    attributes
+        .get_str_value("preset")
+        .and_then(|val| {
+            match val.as_str() {
+                // "multiline" | "ml" => Some(EnvironmentPreset::Multiline),
+                "centered" | "c" => Some(EnvironmentPreset::Centered),
+                "equations" | "e" => Some(EnvironmentPreset::Equations),
+                _ => None
+            }
+        })
+        .map(|preset| {
+            let label = LabelMeta::from_attributes(attributes);
+            match preset {
+                // EnvironmentPreset::Multiline => label.for_each(LabelMetaApply {
+                //     default: &|| {
+                //         let start = "\\begin{multline*}";
+                //         let end = "\\end{multline*}\\end{split}";
+                //         (start.to_owned(), end.to_owned())
+                //     },
+                //     tag: &|tag: &str| {
+                //         let start = format!("\\begin{{multline}}\\tag{{{tag}}}");
+                //         let end = "\\end{multline}";
+                //         (start.to_owned(), end.to_owned())
+                //     },
+                //     numbered: &|| {
+                //         let start = "\\begin{multline}";
+                //         let end = "\\end{multline}";
+                //         (start.to_owned(), end.to_owned())
+                //     },
+                // }),
+                EnvironmentPreset::Centered => label.for_each(LabelMetaApply {
+                    default: &|| {
+                        let start = "\\begin{gather*}";
+                        let end = "\\end{gather*}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                    tag: &|tag: &str| {
+                        let start = format!("\\begin{{gather}}\\tag{{{tag}}}");
+                        let end = "\\end{gather}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                    numbered: &|| {
+                        let start = "\\begin{gather}";
+                        let end = "\\end{gather}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                }),
+                EnvironmentPreset::Equations => label.for_each(LabelMetaApply {
+                    default: &|| {
+                        let start = "\\begin{align*}";
+                        let end = "\\end{align*}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                    tag: &|tag: &str| {
+                        let start = format!("\\begin{{align}}\\tag{{{tag}}}");
+                        let end = "\\end{align}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                    numbered: &|| {
+                        let start = "\\begin{align}";
+                        let end = "\\end{align}";
+                        (start.to_owned(), end.to_owned())
+                    },
+                }),
+            }
+        })
+}
+
+/// Equations are always wrapped in a `split` environment.
+fn equation_delimiters(attributes: &Attributes) -> (String, String) {
+    let config = LabelMeta::from_attributes(attributes);
+    config.for_each(LabelMetaApply {
+        default: &|| {
+            let start = "\\begin{equation*}\\begin{split}";
+            let end = "\\end{split}\\end{equation*}";
+            (start.to_owned(), end.to_owned())
+        },
+        tag: &|tag: &str| {
+            let start = format!("\\begin{{equation}}\\tag{{{tag}}}\\begin{{split}}");
+            let end = "\\end{split}\\end{equation}";
+            (start.to_owned(), end.to_owned())
+        },
+        numbered: &|| {
+            let start = "\\begin{equation}\\begin{split}";
+            let end = "\\end{split}\\end{equation}";
+            (start.to_owned(), end.to_owned())
+        },
+    })
+}
+
 pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
     let inline_math = CmdDeclBuilder::new(Ident::from("\\").unwrap())
         .child_layout_mode(LayoutMode::Inline)
@@ -81,18 +174,25 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
         .to_html(to_html! {
             fn (env, scope, cmd) {
                 let child_scope = scope.new_scope(&env.resource_env, &cmd);
-                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope);
+                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope)
+                    .with_resource_env(&env.resource_env);
                 let latex_code = cmd.arguments
                     .into_iter()
                     .flat_map(Node::unblock_root_curly_brace)
                     .map(|x| x.to_latex(&mut latex_env, &child_scope))
                     .collect::<String>();
                 let is_unique = !scope.in_heading_scope();
-                let mut html_node = env.add_inline_math_entry(latex_code, is_unique);
+                let mut html_node = env.add_inline_math_entry(scope, cmd.identifier.range, latex_code, is_unique);
                 html_node.attributes.insert(String::from("data-cmd"), String::from("inline-math"));
                 html::Node::Element(html_node)
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                format!("\\({children}\\)")
+            }
+        })
+        .documentation("Inline math, e.g. `\\{x^2}`.")
         .finish();
     let math_block = CmdDeclBuilder::new(Ident::from("\\math").unwrap())
         .child_layout_mode(LayoutMode::Inline)
@@ -113,73 +213,9 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
         .to_html(to_html! {
             fn (env, scope, cmd) {
                 let child_scope = scope.new_scope(&env.resource_env, &cmd);
-                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope);
-                let preset = cmd.attributes
-                    .get_str_value("preset")
-                    .and_then(|val| {
-                        match val.as_str() {
-                            // "multiline" | "ml" => Some(EnvironmentPreset::Multiline),
-                            "centered" | "c" => Some(EnvironmentPreset::Centered),
-                            "equations" | "e" => Some(EnvironmentPreset::Equations),
-                            _ => None
-                        }
-                    })
-                    .map(|preset| {
-                        let label = LabelMeta::from_attributes(&cmd.attributes);
-                        match preset {
-                            // EnvironmentPreset::Multiline => label.for_each(LabelMetaApply {
-                            //     default: &|| {
-                            //         let start = "\\begin{multline*}";
-                            //         let end = "\\end{multline*}\\end{split}";
-                            //         (start.to_owned(), end.to_owned())
-                            //     },
-                            //     tag: &|tag: &str| {
-                            //         let start = format!("\\begin{{multline}}\\tag{{{tag}}}");
-                            //         let end = "\\end{multline}";
-                            //         (start.to_owned(), end.to_owned())
-                            //     },
-                            //     numbered: &|| {
-                            //         let start = "\\begin{multline}";
-                            //         let end = "\\end{multline}";
-                            //         (start.to_owned(), end.to_owned())
-                            //     },
-                            // }),
-                            EnvironmentPreset::Centered => label.for_each(LabelMetaApply {
-                                default: &|| {
-                                    let start = "\\begin{gather*}";
-                                    let end = "\\end{gather*}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                                tag: &|tag: &str| {
-                                    let start = format!("\\begin{{gather}}\\tag{{{tag}}}");
-                                    let end = "\\end{gather}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                                numbered: &|| {
-                                    let start = "\\begin{gather}";
-                                    let end = "\\end{gather}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                            }),
-                            EnvironmentPreset::Equations => label.for_each(LabelMetaApply {
-                                default: &|| {
-                                    let start = "\\begin{align*}";
-                                    let end = "\\end{align*}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                                tag: &|tag: &str| {
-                                    let start = format!("\\begin{{align}}\\tag{{{tag}}}");
-                                    let end = "\\end{align}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                                numbered: &|| {
-                                    let start = "\\begin{align}";
-                                    let end = "\\end{align}";
-                                    (start.to_owned(), end.to_owned())
-                                },
-                            }),
-                        }
-                    });
+                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope)
+                    .with_resource_env(&env.resource_env);
+                let preset = math_block_delimiters(&cmd.attributes);
                 let is_unique = !scope.in_heading_scope();
                 let latex_code = cmd.arguments
                     .into_iter()
@@ -190,11 +226,27 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
                     Some((open, close)) => format!("{open}{latex_code}{close}"),
                     _ => latex_code
                 };
-                let mut html_node = env.add_block_entry(latex_code, is_unique);
+                let mut html_node = env.add_block_entry(scope, cmd.identifier.range, latex_code, is_unique);
                 html_node.attributes.insert(String::from("data-cmd"), String::from("math"));
+                // Labeled, see `crate::ss::labels`.
+                if let Some(id) = cmd.attributes.get_str_value("id") {
+                    html_node.attributes.insert(String::from("id"), id);
+                }
                 html::Node::Element(html_node)
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                match math_block_delimiters(&cmd.attributes) {
+                    Some((open, close)) => format!("\n{open}{children}{close}\n"),
+                    None => format!("\n\\[{children}\\]\n"),
+                }
+            }
+        })
+        .attribute(cmd_decl::AttributeKey::new_attr("preset"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .attribute(cmd_decl::AttributeKey::new_attr("num"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("tag"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .documentation("Block math. Attributes: `preset` (`centered` or `equations`), `num`, `tag`.")
         .finish();
     let equation = CmdDeclBuilder::new(Ident::from("\\equation").unwrap())
         .child_layout_mode(LayoutMode::Inline)
@@ -214,39 +266,36 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
         )
         .to_html(to_html! {
             fn (env, scope, cmd) {
-                let config = LabelMeta::from_attributes(&cmd.attributes);
-                let (start, end) = config.for_each(LabelMetaApply {
-                    default: &|| {
-                        let start = "\\begin{equation*}\\begin{split}";
-                        let end = "\\end{split}\\end{equation*}";
-                        (start.to_owned(), end.to_owned())
-                    },
-                    tag: &|tag: &str| {
-                        let start = format!("\\begin{{equation}}\\tag{{{tag}}}\\begin{{split}}");
-                        let end = "\\end{split}\\end{equation}";
-                        (start.to_owned(), end.to_owned())
-                    },
-                    numbered: &|| {
-                        let start = "\\begin{equation}\\begin{split}";
-                        let end = "\\end{split}\\end{equation}";
-                        (start.to_owned(), end.to_owned())
-                    },
-                });
+                let (start, end) = equation_delimiters(&cmd.attributes);
                 let is_unique = !scope.in_heading_scope();
                 let child_scope = scope.new_scope(&env.resource_env, &cmd);
-                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope);
+                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope)
+                    .with_resource_env(&env.resource_env);
                 let latex_code = cmd.arguments
                     .into_iter()
                     .flat_map(Node::unblock_root_curly_brace)
                     .map(|x| x.to_latex(&mut latex_env, &child_scope))
                     .collect::<String>();
                 let latex_code = format!("{start}{latex_code}{end}");
-                let mut html_node = env.add_block_entry(latex_code, is_unique);
+                let mut html_node = env.add_block_entry(scope, cmd.identifier.range, latex_code, is_unique);
                 html_node.attributes.insert(String::from("data-cmd"), String::from("equation"));
+                // Labeled, see `crate::ss::labels`.
+                if let Some(id) = cmd.attributes.get_str_value("id") {
+                    html_node.attributes.insert(String::from("id"), id);
+                }
                 html::Node::Element(html_node)
 
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                let (start, end) = equation_delimiters(&cmd.attributes);
+                format!("\n{start}{children}{end}\n")
+            }
+        })
+        .attribute(cmd_decl::AttributeKey::new_attr("num"), None)
+        .attribute(cmd_decl::AttributeKey::new_attr("tag"), Some(cmd_decl::AttributeValue::new(cmd_decl::AttributeValueType::String)))
+        .documentation("A block equation, wrapped in a `split` environment. Attributes: `num`, `tag`.")
         .finish();
     let chem = CmdDeclBuilder::new(Ident::from("\\chem").unwrap())
         .child_content_mode(ContentMode::Symbolic(SymbolicModeType::All))
@@ -266,7 +315,8 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
         .to_html(to_html! {
             fn (env, scope, cmd) {
                 let child_scope = scope.new_scope(&env.resource_env, &cmd);
-                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope);
+                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope)
+                    .with_resource_env(&env.resource_env);
                 let latex_code = cmd.arguments
                     .into_iter()
                     .flat_map(Node::unblock_root_curly_brace)
@@ -275,14 +325,22 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
                 let latex_code = format!("\\ce{{{latex_code}}}");
                 let is_unique = !scope.in_heading_scope();
                 let mut html_node = if scope.in_inline_mode() {
-                    env.add_inline_math_entry(latex_code, is_unique)
+                    env.add_inline_math_entry(scope, cmd.identifier.range, latex_code, is_unique)
                 } else {
-                    env.add_block_entry(latex_code, is_unique)
+                    env.add_block_entry(scope, cmd.identifier.range, latex_code, is_unique)
                 };
                 html_node.attributes.insert(String::from("data-cmd"), String::from("chem"));
                 html::Node::Element(html_node)
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                // The given scope is the child scope, so the layout mode of
+                // the call site is unknown.
+                format!("\\(\\ce{{{children}}}\\)")
+            }
+        })
+        .documentation("Chemical formulas and equations via mhchem, e.g. `\\chem{H2O}`.")
         .finish();
     let unit = CmdDeclBuilder::new(Ident::from("\\unit").unwrap())
         .child_content_mode(ContentMode::Symbolic(SymbolicModeType::All))
@@ -302,7 +360,8 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
         .to_html(to_html! {
             fn (env, scope, cmd) {
                 let child_scope = scope.new_scope(&env.resource_env, &cmd);
-                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope);
+                let mut latex_env = crate::ss::env::LatexCodegenEnv::from_scope(&child_scope)
+                    .with_resource_env(&env.resource_env);
                 let latex_code = cmd.arguments
                     .into_iter()
                     .flat_map(Node::unblock_root_curly_brace)
@@ -311,14 +370,21 @@ pub fn all_subscript_symbolic_environments() -> Vec<cmd_decl::CmdDeclaration> {
                 let latex_code = format!("\\pu{{{latex_code}}}");
                 let is_unique = !scope.in_heading_scope();
                 let mut html_node = if scope.in_inline_mode() {
-                    env.add_inline_math_entry(latex_code, is_unique)
+                    env.add_inline_math_entry(scope, cmd.identifier.range, latex_code, is_unique)
                 } else {
-                    env.add_block_entry(latex_code, is_unique)
+                    env.add_block_entry(scope, cmd.identifier.range, latex_code, is_unique)
                 };
                 html_node.attributes.insert(String::from("data-cmd"), String::from("unit"));
                 html::Node::Element(html_node)
             }
         })
+        .to_latex(to_latex! {
+            fn (env, scope, cmd, all children) {
+                // The given scope is the child scope, so the layout mode of
+                // the call site is unknown.
+                format!("\\(\\pu{{{children}}}\\)")
+            }
+        })
         .finish();
     vec![
         inline_math,
@@ -2,13 +2,6 @@ use std::path::{PathBuf, Path};
 
 pub mod format;
 
-pub fn random_str_id() -> String {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    let number: usize = rng.gen();
-    return format!("ID{}", number)
-}
-
 pub fn file_path_union<T, U>(
     path1: T,
     path2: U,
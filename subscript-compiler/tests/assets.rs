@@ -0,0 +1,85 @@
+//! Content-addressed images, i.e. `compiler::assets`.
+mod common;
+
+use subscript_compiler::compiler::assets::{remove_stale_assets, write_assets, Asset, AssetOptions};
+use common::{run, temp_dir, write, write_project};
+
+const IMAGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/3x3-determinant.png");
+
+#[test]
+fn assets_are_named_after_their_contents() {
+    let dir = temp_dir("assets-hashes");
+    std::fs::copy(IMAGE, dir.join("a.png")).unwrap();
+    std::fs::copy(IMAGE, dir.join("copy.png")).unwrap();
+    write(&dir, "b.svg", "<svg></svg>");
+    let options = AssetOptions::default();
+    let a = Asset::new(dir.join("a.png"), &options).unwrap();
+    let copy = Asset::new(dir.join("copy.png"), &options).unwrap();
+    assert_eq!(a.hash, copy.hash);
+    assert_eq!(a.file_name, format!("a-{}.png", a.hash));
+    assert_eq!(a.rel_path(), format!("static-assets/a-{}.png", a.hash));
+    // Options are part of the hash, but only of the images they affect.
+    let downscaled = AssetOptions {max_image_width: Some(2), jpeg_quality: None};
+    assert_ne!(Asset::new(dir.join("a.png"), &downscaled).unwrap().hash, a.hash);
+    let b = Asset::new(dir.join("b.svg"), &options).unwrap();
+    assert_eq!(Asset::new(dir.join("b.svg"), &downscaled).unwrap(), b);
+    write(&dir, "b.svg", "<svg><rect/></svg>");
+    assert_ne!(Asset::new(dir.join("b.svg"), &options).unwrap().hash, b.hash);
+    assert!(Asset::new(dir.join("missing.png"), &options).is_err());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn assets_are_written_and_stale_ones_removed() {
+    let dir = temp_dir("assets-output");
+    std::fs::copy(IMAGE, dir.join("a.png")).unwrap();
+    std::fs::copy(IMAGE, dir.join("copy.png")).unwrap();
+    let options = AssetOptions::default();
+    let a = Asset::new(dir.join("a.png"), &options).unwrap();
+    let copy = Asset::new(dir.join("copy.png"), &options).unwrap();
+    let out = dir.join("out");
+    assert!(write_assets(&out, [a.clone(), copy.clone()], &options).is_empty());
+    let original = std::fs::read(IMAGE).unwrap();
+    assert_eq!(std::fs::read(out.join(a.rel_path())).unwrap(), original);
+    assert_eq!(std::fs::read(out.join(copy.rel_path())).unwrap(), original);
+    // Wider images are downscaled.
+    let downscaled_options = AssetOptions {max_image_width: Some(2), jpeg_quality: None};
+    let downscaled = Asset::new(dir.join("a.png"), &downscaled_options).unwrap();
+    assert!(write_assets(&out, [downscaled.clone()], &downscaled_options).is_empty());
+    let (width, _) = image::image_dimensions(out.join(downscaled.rel_path())).unwrap();
+    assert_eq!(width, 2);
+    // Everything else is removed, e.g. older versions or directories.
+    write(&out, "static-assets/old/a.png", "");
+    remove_stale_assets(&out, &[a.clone()]);
+    let names = std::fs::read_dir(out.join("static-assets"))
+        .unwrap()
+        .map(|x| x.unwrap().file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [a.file_name.clone()]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_sources_are_rewritten() {
+    let dir = temp_dir("assets-rewrites");
+    write_project(&dir);
+    std::fs::create_dir_all(dir.join("pages/img")).unwrap();
+    std::fs::copy(IMAGE, dir.join("pages/img/a.png")).unwrap();
+    write(&dir, "pages/index.ss", "\\h1{Home}\n\\img[src=\"img/a.png\"]");
+    let build = || {
+        let output = run(&dir, &["build", "--project-dir", "."]);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let asset = Asset::new(dir.join("pages/img/a.png"), &AssetOptions::default()).unwrap();
+        let html = std::fs::read_to_string(dir.join("output/index.html")).unwrap();
+        assert!(html.contains(&format!("<img src=\"/{}\"", asset.rel_path())), "{html}");
+        assert!(dir.join("output").join(asset.rel_path()).exists());
+        asset
+    };
+    let first = build();
+    // A new version of the image gets a new name, and the old one is removed.
+    write(&dir, "pages/img/a.png", "not really a PNG");
+    let second = build();
+    assert_ne!(first.file_name, second.file_name);
+    assert!(!dir.join("output").join(first.rel_path()).exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
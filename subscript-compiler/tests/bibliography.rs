@@ -0,0 +1,160 @@
+//! Citations, i.e. `ss::bibliography` and the `\cite` and `\bibliography`
+//! commands of `ss_v1_std::bibliography`.
+mod common;
+
+use std::path::Path;
+use common::{compile, compile_page, run, temp_dir, write, write_project};
+
+/// Compiles `index.ss` (along with `refs.bib`), returning the `<main>`
+/// element and the diagnostics.
+fn compile_file(dir: &Path, source: &str) -> (String, String) {
+    let page = compile_page(dir, source);
+    (page.contents, page.stderr)
+}
+
+fn compile_latex(dir: &Path, source: &str) -> String {
+    write(dir, "index.ss", source);
+    let result = compile(dir, "compile-latex", "index.ss", "out/index.tex", &[]);
+    assert!(result.status.success(), "{}", result.stderr);
+    result.contents
+}
+
+const REFS: &str = r#"
+@book{knuth98,
+    author = {Donald E. Knuth},
+    title = {The Art of Computer Programming},
+    publisher = {Addison-Wesley},
+    year = 1998,
+}
+@article{knuthplass81,
+    author = {Donald E. Knuth and Michael F. Plass},
+    title = {Breaking Paragraphs into Lines},
+    journal = {Software: Practice and Experience},
+    volume = 11, number = 11, pages = {1119--1184},
+    year = 1981,
+}
+@book{lamport94,
+    author = {Leslie Lamport},
+    title = {LaTeX: A Document Preparation System},
+    publisher = {Addison-Wesley},
+    year = 1994,
+}
+"#;
+
+const CITING: &str = "\\p{Lines \\cite[lamport94], sorting \\cite[knuth98] and again \\cite[lamport94].}\n";
+
+#[test]
+fn numeric_citations_are_numbered_in_order() {
+    let dir = temp_dir("bibliography-numeric");
+    write(&dir, "refs.bib", REFS);
+    let (html, stderr) = compile_file(&dir, &format!("{CITING}\\bibliography[src=\"refs.bib\"]"));
+    assert!(stderr.is_empty(), "{stderr}");
+    assert!(html.contains(concat!(
+        "Lines <a data-cmd=\"cite\" href=\"#cite-lamport94\">[1]</a>, ",
+        "sorting <a data-cmd=\"cite\" href=\"#cite-knuth98\">[2]</a> ",
+        "and again <a data-cmd=\"cite\" href=\"#cite-lamport94\">[1]</a>.",
+    )), "{html}");
+    // Only the cited entries are listed.
+    assert!(html.contains(concat!(
+        "<ol data-cmd=\"bibliography\" data-style=\"numeric\">",
+        "<li id=\"cite-lamport94\"><span data-label=\"\">[1]</span> Leslie Lamport (1994). ",
+        "<em>LaTeX: A Document Preparation System</em>. Addison-Wesley. </li>",
+        "<li id=\"cite-knuth98\"><span data-label=\"\">[2]</span> Donald E. Knuth (1998). ",
+        "<em>The Art of Computer Programming</em>. Addison-Wesley. </li></ol>",
+    )), "{html}");
+    assert!(!html.contains("knuthplass81"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn author_year_citations_are_sorted_by_author() {
+    let dir = temp_dir("bibliography-author-year");
+    write(&dir, "refs.bib", REFS);
+    let (html, stderr) = compile_file(&dir, &format!(
+        "{CITING}\\p{{Breaking \\cite[knuthplass81].}}\n\\bibliography[src=\"refs.bib\", style=\"author-year\"]"
+    ));
+    assert!(stderr.is_empty(), "{stderr}");
+    assert!(html.contains("<a data-cmd=\"cite\" href=\"#cite-lamport94\">(Lamport 1994)</a>"), "{html}");
+    assert!(html.contains("<a data-cmd=\"cite\" href=\"#cite-knuthplass81\">(Knuth and Plass 1981)</a>"), "{html}");
+    let list = &html[html.find("<ol").unwrap()..];
+    assert!(list.starts_with("<ol data-cmd=\"bibliography\" data-style=\"author-year\"><li id=\"cite-knuth98\">"), "{list}");
+    let ids = ["cite-knuth98", "cite-knuthplass81", "cite-lamport94"].map(|x| list.find(x).unwrap());
+    assert!(ids.windows(2).all(|x| x[0] < x[1]), "{list}");
+    assert!(list.contains(concat!(
+        "Donald E. Knuth and Michael F. Plass (1981). Breaking Paragraphs into Lines. ",
+        // TeX dashes and accents are converted to plain text.
+        "<em>Software: Practice and Experience</em>, 11(11), 1119–1184. </li>",
+    )), "{list}");
+    // No numbers in this style.
+    assert!(!list.contains("data-label"), "{list}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn latex_citations_link_to_their_entries() {
+    let dir = temp_dir("bibliography-latex");
+    write(&dir, "refs.bib", REFS);
+    let latex = compile_latex(&dir, &format!("{CITING}\\bibliography[src=\"refs.bib\"]"));
+    assert!(latex.contains("Lines \\hyperref[cite-lamport94]{[1]}, sorting \\hyperref[cite-knuth98]{[2]}"), "{latex}");
+    assert!(latex.contains(concat!(
+        "\\begin{itemize}\n",
+        "\\item[{[1]}] \\phantomsection\\label{cite-lamport94}Leslie Lamport (1994). ",
+        "\\emph{LaTeX: A Document Preparation System}. Addison-Wesley.\n",
+        "\\item[{[2]}] \\phantomsection\\label{cite-knuth98}Donald E. Knuth (1998). ",
+        "\\emph{The Art of Computer Programming}. Addison-Wesley.\n",
+        "\\end{itemize}",
+    )), "{latex}");
+    let latex = compile_latex(&dir, &format!("{CITING}\\bibliography[src=\"refs.bib\", style=\"author-year\"]"));
+    assert!(latex.contains("\\hyperref[cite-lamport94]{(Lamport 1994)}"), "{latex}");
+    assert!(latex.contains("\\item[{}] \\phantomsection\\label{cite-knuth98}Donald E. Knuth (1998)."), "{latex}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn site_wide_bibliographies_are_cited_from_other_pages() {
+    let dir = temp_dir("bibliography-site");
+    write_project(&dir);
+    write(&dir, "pages/refs.bib", REFS);
+    write(&dir, "pages/index.ss", "\\h1{References}\n\\bibliography[src=\"refs.bib\", site]");
+    write(&dir, "pages/a/index.ss", "\\h1{A}\n\\p{See \\cite[lamport94].}");
+    let output = run(&dir, &["build", "--project-dir", "."]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.is_empty(), "{stderr}");
+    // Every entry is listed, by author.
+    let index = std::fs::read_to_string(dir.join("output/index.html")).unwrap();
+    let ids = ["cite-knuth98", "cite-knuthplass81", "cite-lamport94"].map(|x| index.find(x).unwrap());
+    assert!(ids.windows(2).all(|x| x[0] < x[1]), "{index}");
+    let page = std::fs::read_to_string(dir.join("output/a/index.html")).unwrap();
+    assert!(page.contains("See <a data-cmd=\"cite\" href=\"/index.html#cite-lamport94\">[3]</a>."), "{page}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn undefined_citations_are_reported() {
+    let dir = temp_dir("bibliography-undefined");
+    write(&dir, "refs.bib", REFS);
+    let (html, stderr) = compile_file(&dir, "\\p{See \\cite[missing].}\n\\bibliography[src=\"refs.bib\"]");
+    assert!(stderr.contains(concat!(
+        "error: undefined citation `missing`, is it missing from the page’s (or a site-wide) `\\bibliography`?\n",
+        "  --> index.ss:1:8",
+    )), "{stderr}");
+    assert!(html.contains("<p>See .</p>"), "{html}");
+    // Likewise without any bibliography.
+    let (_, stderr) = compile_file(&dir, "\\p{See \\cite[knuth98].}");
+    assert!(stderr.contains("error: undefined citation `knuth98`"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn duplicate_entries_are_reported() {
+    let dir = temp_dir("bibliography-duplicate");
+    write(&dir, "refs.bib", &format!("{REFS}\n@book{{knuth98, title = {{Duplicate}}, year = 2000}}"));
+    let (html, stderr) = compile_file(&dir, "\\p{\\cite[knuth98]}\n\\bibliography[src=\"refs.bib\"]");
+    assert!(stderr.contains("warning: duplicate BibTeX entry `knuth98` in "), "{stderr}");
+    assert!(stderr.contains("refs.bib\n  --> index.ss:2:1"), "{stderr}");
+    // The first one wins.
+    assert!(html.contains("The Art of Computer Programming"), "{html}");
+    assert!(!html.contains("Duplicate"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
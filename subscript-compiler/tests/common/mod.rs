@@ -0,0 +1,71 @@
+//! Fixtures shared by the integration tests, which run the compiler on
+//! sources written to a temporary directory. Each test crate only uses some
+//! of them.
+#![allow(dead_code)]
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Output};
+
+/// An empty directory, unique to the given name and test process.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("subscript-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Writes the given file (relative to `dir`), along with its parent
+/// directories.
+pub fn write(dir: &Path, path: &str, contents: &str) {
+    let path = dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+/// The manifest and template of a project, with its pages in `pages` and
+/// its output in `output`.
+pub fn write_project(dir: &Path) {
+    write(dir, "subscript.toml", concat!(
+        "[project]\ntitle = \"Notes\"\n\n",
+        "[project.locations]\noutput = \"output\"\npages = \"pages\"\ntemplate = \"template/page.html\"\n",
+    ));
+    write(dir, "template/page.html", "<html><body><content></content></body></html>");
+}
+
+/// Runs the compiler with the given arguments, within `dir`.
+pub fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_subscript-compiler"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+pub struct Compiled {
+    pub status: ExitStatus,
+    /// The `<main>` element of HTML pages (i.e. without the stylesheet and
+    /// table of contents), and otherwise the whole file. Empty if nothing
+    /// was written.
+    pub contents: String,
+    pub stderr: String,
+}
+
+/// Compiles the given source file to the given output (both relative to
+/// `dir`) with the given subcommand, e.g. `compile-file` or `compile-latex`.
+pub fn compile(dir: &Path, command: &str, source: &str, output: &str, args: &[&str]) -> Compiled {
+    let result = run(dir, &[&[command, "--source", source, "--output", output], args].concat());
+    let mut contents = std::fs::read_to_string(dir.join(output)).unwrap_or_default();
+    if let (Some(start), Some(end)) = (contents.find("<main>"), contents.find("</main>")) {
+        contents = contents[start..end + "</main>".len()].to_owned();
+    }
+    Compiled {
+        status: result.status,
+        contents,
+        stderr: String::from_utf8_lossy(&result.stderr).into_owned(),
+    }
+}
+
+/// Compiles the given source as `index.ss` to `out/index.html`.
+pub fn compile_page(dir: &Path, source: &str) -> Compiled {
+    write(dir, "index.ss", source);
+    compile(dir, "compile-file", "index.ss", "out/index.html", &[])
+}
@@ -0,0 +1,79 @@
+//! Context aware completions, i.e. `ss::completion`.
+use subscript_compiler::ss::{ResourceEnv, SemanticScope};
+use subscript_compiler::ss::completion::{completions_for_source, CompletionKind};
+
+/// The labels of the completions at the `|` of the given source.
+fn labels(source: &str) -> Vec<String> {
+    let byte_index = source.find('|').unwrap();
+    let source = source.replacen('|', "", 1);
+    let env = ResourceEnv::default();
+    let scope = SemanticScope::test_mode_with_cmds(subscript_compiler::ss_v1_std::all_commands_list());
+    completions_for_source(&env, &scope, &source, byte_index)
+        .into_iter()
+        .map(|x| x.label)
+        .collect()
+}
+
+#[test]
+fn commands_depend_on_the_enclosing_scope() {
+    // `\row` is only available within `\table`.
+    assert!(labels("\\table{\\ro|}").contains(&String::from("\\row")));
+    assert!(labels("\\note{\\table{\\row{{a}}\n\\ro|}}").contains(&String::from("\\row")));
+    assert!(!labels("\\p{\\ro|}").contains(&String::from("\\row")));
+    assert!(!labels("\\table{}\n\\ro|").contains(&String::from("\\row")));
+    // Likewise `\expr` within embeds and plots.
+    assert!(labels("\\plot{\\ex|}").contains(&String::from("\\expr")));
+    assert!(!labels("\\ex|").contains(&String::from("\\expr")));
+    // Only the given prefix.
+    let labels = labels("\\h|");
+    assert!(labels.contains(&String::from("\\h1")), "{labels:?}");
+    assert!(labels.iter().all(|x| x.starts_with("\\h")), "{labels:?}");
+    assert!(labels.windows(2).all(|x| x[0] < x[1]), "sorted and unique: {labels:?}");
+}
+
+#[test]
+fn attributes_are_completed_within_brackets() {
+    let include = labels("\\include[|]");
+    assert!(include.contains(&String::from("src")), "{include:?}");
+    assert!(include.contains(&String::from("baseline")), "{include:?}");
+    // Given keys aren’t suggested again, and only those with the prefix.
+    let include = labels("\\include[src=\"a.ss\", b|]");
+    assert_eq!(include, vec![String::from("baseline")]);
+    // Not within values, or after the brackets.
+    assert!(labels("\\include[src=\"a|\"]").is_empty());
+    assert!(labels("\\include[baseline=|]").is_empty());
+    assert!(labels("\\include[src=\"a.ss\"]{|}").is_empty());
+    // Attributes of commands nested within others.
+    let expr = labels("\\plot{\\expr[co|]{x}}");
+    assert_eq!(expr, vec![String::from("color")]);
+}
+
+#[test]
+fn attribute_kinds_are_described() {
+    let source = "\\include[|]";
+    let env = ResourceEnv::default();
+    let scope = SemanticScope::test_mode_with_cmds(subscript_compiler::ss_v1_std::all_commands_list());
+    let items = completions_for_source(&env, &scope, &source.replace('|', ""), source.find('|').unwrap());
+    let src = items.iter().find(|x| x.label == "src").unwrap();
+    assert!(matches!(src.kind, CompletionKind::Attribute {required: true, value_ty: Some(_)}), "{src:?}");
+    let no_toc = items.iter().find(|x| x.label == "no-toc").unwrap();
+    assert!(matches!(no_toc.kind, CompletionKind::Attribute {required: false, value_ty: None}), "{no_toc:?}");
+}
+
+#[test]
+fn overloaded_commands_are_listed_once() {
+    let source = "\\h";
+    let env = ResourceEnv::default();
+    let commands = subscript_compiler::ss_v1_std::all_commands_list;
+    let once = SemanticScope::test_mode_with_cmds(commands());
+    let twice = SemanticScope::test_mode_with_cmds([commands(), commands()].concat());
+    let once = completions_for_source(&env, &once, source, source.len());
+    let twice = completions_for_source(&env, &twice, source, source.len());
+    let labels = twice.iter().map(|x| x.label.as_str()).collect::<Vec<_>>();
+    assert!(labels.windows(2).all(|x| x[0] < x[1]), "sorted and unique: {labels:?}");
+    assert_eq!(once.len(), twice.len());
+    // With the signatures of every overload.
+    for (once, twice) in once.iter().zip(twice.iter()) {
+        assert_eq!(once.signatures, twice.signatures, "{}", once.label);
+    }
+}
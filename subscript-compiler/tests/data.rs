@@ -0,0 +1,179 @@
+//! CSV tables and charts, i.e. `ss::data`, `ss::chart`, `\dataTable` and
+//! `\chart`.
+mod common;
+
+use std::path::Path;
+use subscript_compiler::ss::chart::{Chart, ChartKind, Series};
+use subscript_compiler::ss::data::{format_number, parse_csv, Table};
+use common::{temp_dir, write};
+
+/// Compiles the given source as `index.ss` with the given subcommand
+/// (`compile-file` or `compile-latex`) to the given file, returning its
+/// contents (just the page, without the stylesheet) and the diagnostics.
+fn compile(dir: &Path, command: &str, output: &str, source: &str) -> (String, String) {
+    write(dir, "index.ss", source);
+    let result = common::compile(dir, command, "index.ss", output, &[]);
+    (result.contents, result.stderr)
+}
+
+const TRIALS: &str = "\
+Trial,Time,Mass,Note
+A,1,1234.5,\"first, ok\"
+B,2,,R&D
+
+C,3,900.25,
+D,4,1500,last
+";
+
+fn trials() -> Table {
+    Table::new(parse_csv(TRIALS, ',').unwrap(), 1)
+}
+
+fn column(table: &Table, ix: usize) -> Vec<&str> {
+    table.rows.iter().map(|x| x[ix].as_str()).collect()
+}
+
+#[test]
+fn csv_records_are_parsed() {
+    let records = parse_csv("a,\"b \"\"c\"\"\",\"d,\ne\"\r\n\r\n1, 2 ,3\n4\n", ',').unwrap();
+    assert_eq!(records, vec![
+        vec!["a", "b \"c\"", "d,\ne"],
+        vec!["1", "2", "3"],
+        vec!["4"],
+    ]);
+    assert_eq!(parse_csv("a\tb\n", '\t').unwrap(), vec![vec!["a", "b"]]);
+    assert_eq!(parse_csv("a\n\"b,c\n", ',').unwrap_err(), "unterminated quote on line 2");
+    // Short rows are padded.
+    let table = Table::new(parse_csv("x,y\n1\n", ',').unwrap(), 1);
+    assert_eq!(table.rows, vec![vec!["1", ""]]);
+}
+
+#[test]
+fn columns_are_selected_and_sorted() {
+    let mut table = trials();
+    assert_eq!(table.find_columns("Trial, Mass").unwrap(), vec![0, 2]);
+    assert_eq!(table.find_columns("1, 3-4").unwrap(), vec![0, 2, 3]);
+    assert_eq!(table.find_columns("Time-Note").unwrap(), vec![1, 2, 3]);
+    assert_eq!(table.find_columns("Mass, Weight").unwrap_err(), "unknown column `Weight`");
+    assert!(table.is_numeric(2));
+    assert!(!table.is_numeric(3));
+    table.sort_by(2, true);
+    // Empty cells come last.
+    assert_eq!(column(&table, 0), vec!["D", "A", "C", "B"]);
+    table.sort_by(3, false);
+    assert_eq!(column(&table, 0), vec!["A", "D", "B", "C"]);
+    let table = table.select(&[3, 0]);
+    assert_eq!(table.header, vec![vec!["Note", "Trial"]]);
+    assert_eq!(table.rows[0], vec!["first, ok", "A"]);
+}
+
+#[test]
+fn numbers_are_formatted() {
+    assert_eq!(format_number("1234.5", Some(2), true), "1,234.50");
+    assert_eq!(format_number("-1234567", None, true), "-1,234,567");
+    assert_eq!(format_number("+0.125", None, false), "0.125");
+    assert_eq!(format_number("3", Some(1), false), "3.0");
+    assert_eq!(format_number("2e5", None, true), "2e5");
+    assert_eq!(format_number("n/a", Some(2), true), "n/a");
+}
+
+#[test]
+fn charts_are_drawn() {
+    let chart = Chart {
+        kind: ChartKind::Line,
+        x_labels: vec![String::from("1"), String::from("2"), String::from("3"), String::from("4")],
+        x_values: Some(vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)]),
+        series: vec![
+            Series {name: String::from("Mass"), values: vec![Some(1.0), None, Some(3.0), Some(2.0)]},
+            Series {name: String::from("Volume"), values: vec![Some(2.0), Some(2.5), Some(1.0), Some(0.5)]},
+        ],
+        x_label: Some(String::from("Time")),
+        ..Chart::default()
+    };
+    let html = chart.to_figure().to_html(&ss_freeform_format::ColorScheme::Light).to_html_fragment_str();
+    assert!(html.contains("<title>Line chart of Mass, Volume by Time</title>"), "{html}");
+    // The missing value splits the first line, leaving a lone point.
+    assert_eq!(html.matches("<polyline").count(), 2, "{html}");
+    assert_eq!(html.matches("<circle").count(), 1, "{html}");
+    for label in [">Mass</text>", ">Volume</text>", ">Time</text>"] {
+        assert!(html.contains(label), "{label}\n{html}");
+    }
+    let bars = Chart {kind: ChartKind::Bar, ..chart};
+    let html = bars.to_figure().to_html(&ss_freeform_format::ColorScheme::Light).to_html_fragment_str();
+    // Seven bars (but for the missing value), the legend and the clip path.
+    assert_eq!(html.matches("<rect").count(), 7 + 1 + 1, "{html}");
+}
+
+#[test]
+fn data_tables_are_rendered() {
+    let dir = temp_dir("data-html");
+    write(&dir, "trials.csv", TRIALS);
+    write(&dir, "chapters/volumes.tsv", "Trial\tVolume\nA\t2\nB\t3\n");
+    write(&dir, "chapters/one.ss", "\\include[src=\"volumes.tsv\"]");
+    let (html, stderr) = compile(&dir, "compile-file", "out/index.html", &[
+        "\\dataTable[src=\"trials.csv\", columns=\"Trial, Mass, Note\", sort=\"-Mass\", decimals=1, thousands]",
+        "\\include[src=\"chapters/one.ss\"]",
+        "\\chart[src=\"trials.csv\", type=\"bar\", x=\"Trial\", y=\"Mass\"]",
+    ].join("\n"));
+    assert!(!stderr.contains("warning") && !stderr.contains("error"), "{stderr}");
+    assert_eq!(html.matches("<table data-cmd=\"dataTable\" data-wrapped-table=\"\">").count(), 2, "{html}");
+    assert!(html.contains(concat!(
+        "<thead><tr><th>Trial</th><th data-numeric=\"\">Mass</th><th>Note</th></tr></thead>",
+        "<tbody><tr><td>D</td><td data-numeric=\"\">1,500.0</td><td>last</td></tr>",
+    )), "{html}");
+    assert!(html.contains("<td>R&amp;D</td></tr></tbody>"), "{html}");
+    // Relative to the including file.
+    assert!(html.contains("<tr><td>B</td><td data-numeric=\"\">3</td></tr>"), "{html}");
+    assert_eq!(html.matches("<div data-cmd=\"chart\">").count(), 1);
+    assert!(html.contains("<title>Bar chart of Mass by Trial</title>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn data_tables_are_printed() {
+    let dir = temp_dir("data-latex");
+    write(&dir, "trials.csv", TRIALS);
+    let (latex, stderr) = compile(&dir, "compile-latex", "out/index.tex", &[
+        "\\include[src=\"trials.csv\", columns=\"1, 3-4\"]",
+        "\\chart[src=\"trials.csv\", type=\"scatter\", x=\"Time\", y=\"Mass\"]",
+    ].join("\n"));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    assert!(latex.contains(concat!(
+        "\\begin{center}\\begin{tabular}{lrl}\n\\hline\n",
+        "\\textbf{Trial} & \\textbf{Mass} & \\textbf{Note} \\\\\n\\hline\n",
+        "A & 1234.5 & first, ok \\\\\nB &  & R\\&D \\\\\n",
+    )), "{latex}");
+    assert!(latex.contains("\\begin{tikzpicture}"), "{latex}");
+    assert!(latex.contains("\\fill["), "{latex}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn invalid_data_is_reported() {
+    let dir = temp_dir("data-invalid");
+    write(&dir, "trials.csv", TRIALS);
+    write(&dir, "broken.csv", "a,b\n\"1,2\n");
+    let (html, stderr) = compile(&dir, "compile-file", "out/index.html", &[
+        "\\dataTable[src=\"trials.csv\", columns=\"Trial, Weight\", sort=\"-Height\"]",
+        "\\dataTable[src=\"missing.csv\"]",
+        "\\include[src=\"broken.csv\"]",
+        "\\chart[src=\"trials.csv\", type=\"pie\", x=\"Time\", y=\"Note\"]",
+        "\\chart[src=\"trials.csv\", x=\"Time\", y=\"Weight\"]",
+    ].join("\n"));
+    for warning in [
+        "invalid `columns` value `Trial,Weight` for `\\dataTable`: unknown column `Weight`",
+        "invalid `sort` value `-Height` for `\\dataTable`: unknown column `Height`",
+        "data file not found: missing.csv",
+        "invalid CSV in broken.csv, unterminated quote on line 2",
+        "invalid value `pie` for `type` of `\\chart`, expected one of line, bar, scatter",
+        "`\\chart` skips 3 non-numeric value(s) of column `Note`",
+        "invalid `y` value `Weight` for `\\chart`: unknown column `Weight`",
+    ] {
+        assert!(stderr.contains(warning), "{warning}\n{stderr}");
+    }
+    // Invalid options are ignored.
+    assert!(html.contains("<th>Note</th>"), "{html}");
+    assert!(html.contains("<title>Line chart of Note by Time</title>"), "{html}");
+    assert!(html.contains("<title>Empty chart</title>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,126 @@
+//! `\desmos` and `\geogebra`, i.e. `ss_v1_std::embeds`.
+mod common;
+
+use std::path::Path;
+use common::{compile_page, temp_dir};
+
+/// Compiles the given source as `index.ss`, returning the `<main>` element
+/// and the diagnostics.
+fn compile_file(dir: &Path, source: &str) -> (String, String) {
+    let page = compile_page(dir, source);
+    assert!(page.status.success(), "{}", page.stderr);
+    (page.contents, page.stderr)
+}
+
+/// The JSON configs read by `ss-runtime.js`.
+fn configs(html: &str) -> Vec<serde_json::Value> {
+    html.split("<script data-embed-config=\"\" type=\"application/json\">")
+        .skip(1)
+        .map(|x| serde_json::from_str(&x[..x.find("</script>").unwrap()]).unwrap())
+        .collect()
+}
+
+#[test]
+fn desmos_graphs_are_configured() {
+    let dir = temp_dir("embeds-desmos");
+    let (html, stderr) = compile_file(&dir, &[
+        "\\desmos[bounds=\"-5, 5, -2, 8\", expressions=false, grid, height=300]{",
+        "    \\expr[line-style='dashed', color=\"#c74440\"]{y=x^2}",
+        "    \\expr[label=\"a\", slider-min=0, slider-max=10, hidden=false]{a=1}",
+        "    y=\\frac{1}{x}",
+        "}",
+    ].join("\n"));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    assert_eq!(configs(&html), [serde_json::json!({
+        "height": 300,
+        "options": {"expressions": false, "showGrid": true},
+        "bounds": {"left": -5.0, "right": 5.0, "bottom": -2.0, "top": 8.0},
+        "expressions": [
+            {"latex": "y=x^2", "color": "#c74440", "lineStyle": "DASHED"},
+            {"latex": "a=1", "label": "a", "showLabel": true, "hidden": false, "sliderBounds": {"min": "0", "max": "10"}},
+            {"latex": "y=\\frac{1}{x}"},
+        ],
+    })]);
+    // Shown until the calculator is loaded.
+    assert!(html.starts_with("<main><div data-cmd=\"desmos\" data-embed=\"desmos\">"), "{html}");
+    assert!(html.contains("<div data-embed-fallback=\"\"><p>Interactive Desmos graph"), "{html}");
+    assert_eq!(html.matches("<li><span data-math-node=\"inline\"").count(), 3, "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn geogebra_commands_are_kept_as_written() {
+    let dir = temp_dir("embeds-geogebra");
+    let (html, stderr) = compile_file(&dir, &[
+        "\\geogebra[app=\"geometry\", toolbar=false, coords=\"-5, 5, -5, 5\"]{",
+        "    \\expr[color=\"#2d70b3\", line-style=\"dotted\", line-width=4]{c = Circle((0, 0), 2)}",
+        "    \\expr{Text(\"a </script> b\")}",
+        "    A = (1, 2)",
+        "}",
+        "\\geogebra[material=\"RHYH3UQ8\"]",
+    ].join("\n"));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    // The config can’t close its script element.
+    assert!(html.contains("a <\\/script> b"), "{html}");
+    assert_eq!(configs(&html), [
+        serde_json::json!({
+            "height": 400,
+            "params": {"appName": "geometry", "showToolBar": false},
+            "coords": [-5.0, 5.0, -5.0, 5.0],
+            "expressions": [
+                {"command": "c = Circle((0, 0), 2)", "color": [45, 112, 179], "lineStyle": 3, "lineThickness": 4},
+                {"command": "Text(\"a </script> b\")"},
+                {"command": "A = (1, 2)"},
+            ],
+        }),
+        serde_json::json!({
+            "height": 400,
+            "params": {"material_id": "RHYH3UQ8"},
+            "expressions": [],
+        }),
+    ]);
+    assert!(html.contains("<li><code>Text(\"a &lt;/script&gt; b\")</code></li>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn only_embed_attributes_are_checked() {
+    let dir = temp_dir("embeds-strict");
+    let (_, stderr) = compile_file(&dir, &[
+        "\\desmos[size=large]{\\expr{y=x}}",
+        // Most commands (e.g. HTML tags) pass other attributes along.
+        "\\note[compact, style=\"color: red\"]{\\p{Text}}",
+        "\\grid[style=\"gap: 1em\"]{\\p{Text}}",
+    ].join("\n"));
+    assert!(stderr.contains("unknown attribute `size` for `\\desmos`, it will be ignored"), "{stderr}");
+    assert_eq!(stderr.matches("warning:").count(), 1, "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn invalid_embed_attributes_are_reported() {
+    let dir = temp_dir("embeds-invalid");
+    let (html, stderr) = compile_file(&dir, &[
+        "\\desmos[height=tall, bounds=\"5, -5, 0, 1\"]{\\expr[line-style=wavy, line-width=thick]{y=x}}",
+        "\\geogebra[app=\"cad\"]{\\expr[color=red]{f(x) = x}}",
+        "\\p{\\expr{y=x}}",
+    ].join("\n"));
+    for warning in [
+        "invalid value `tall` for `height` of `\\desmos`, expected an integer",
+        "invalid `bounds` value `5,-5,0,1` for `\\desmos`",
+        "invalid value `wavy` for `line-style` of `\\expr`, expected one of solid, dashed, dotted",
+        "invalid value `thick` for `line-width` of `\\expr`, expected a number",
+        "invalid value `cad` for `app` of `\\geogebra`, expected one of graphing, geometry, 3d, classic, suite, scientific",
+        "invalid `color` value `red` for `\\expr` of `\\geogebra`",
+        // `\expr` is restricted to its parents.
+        "`\\expr` is only available within `\\desmos` or `\\geogebra` or `\\plot`",
+    ] {
+        assert!(stderr.contains(warning), "{warning}\n{stderr}");
+    }
+    // Invalid values fall back to the defaults.
+    let configs = configs(&html);
+    assert_eq!(configs[0]["height"], 400);
+    assert_eq!(configs[0]["expressions"], serde_json::json!([{"latex": "y=x"}]));
+    assert_eq!(configs[1]["params"], serde_json::json!({"appName": "graphing"}));
+    let _ = std::fs::remove_dir_all(&dir);
+}
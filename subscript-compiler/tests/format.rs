@@ -0,0 +1,125 @@
+//! The `.ss` source formatter, i.e. `ss::format`.
+use std::path::{Path, PathBuf};
+use subscript_compiler::ss::format::{format_source, is_equivalent, FormatError};
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn ss_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            ss_files(&path, files);
+        } else if path.extension().map(|x| x == "ss").unwrap_or(false) {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn messy_source_is_formatted() {
+    let source = [
+        "",
+        "\\h1{  Title }   ",
+        "\\note[ boxed ,  title = \"A b\" ]{",
+        "\t\\p{Some \\b{bold} text,   /// a comment",
+        "    and more.}\\p{Second}",
+        "        \\ul{\\li{One}\\li{Two}}",
+        "}",
+        "",
+        "",
+        "",
+        "\\equation[num]{",
+        "        a &= b \\\\",
+        "          &= c",
+        "   }",
+        "\\pre{",
+        "  fn main() {   }",
+        "}",
+        "\\img[",
+        "  src=\"a.png\",",
+        "    width=20px",
+        "]",
+        "\\p{\\mark[font]{Cat}ions}",
+    ];
+    let expected = [
+        "\\h1{Title}",
+        "\\note[boxed, title=\"A b\"]{",
+        "   \\p{",
+        "      Some \\b{bold} text,   /// a comment",
+        "      and more.",
+        "   }",
+        "   \\p{Second}",
+        "   \\ul{",
+        "      \\li{One}",
+        "      \\li{Two}",
+        "   }",
+        "}",
+        "",
+        "\\equation[num]{",
+        "   a &= b \\\\",
+        "     &= c",
+        "}",
+        "\\pre{",
+        "  fn main() {   }",
+        "}",
+        "\\img[",
+        "   src=\"a.png\",",
+        "   width=20px",
+        "]",
+        "\\p{\\mark[font]{Cat}ions}",
+        "",
+    ];
+    let formatted = format_source(&source.join("\n")).unwrap();
+    assert_eq!(formatted, expected.join("\n"));
+}
+
+#[test]
+fn formatting_is_idempotent() {
+    let source = "\\p{a}\\p{b \\{x}}\n\\ul{\n\\li{\\p{c}\n}}\n";
+    let formatted = format_source(source).unwrap();
+    assert_eq!(format_source(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn comments_are_kept() {
+    let source = "/// Header\n\\p{x} /// after a block\n\\note{ /// after a bracket\n\\p{y}}\n";
+    let expected = "/// Header\n\\p{x} /// after a block\n\\note{ /// after a bracket\n   \\p{y}\n}\n";
+    assert_eq!(format_source(source).unwrap(), expected);
+}
+
+#[test]
+fn syntax_errors_are_reported() {
+    match format_source("\\p{unclosed") {
+        Err(FormatError::Syntax(diagnostics)) => assert!(!diagnostics.is_empty()),
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn equivalence_ignores_only_insignificant_whitespace() {
+    assert!(is_equivalent("\\p{ a   b }\\p{c}", "\\p{a b}\n\\p{c}\n"));
+    assert!(is_equivalent("\\img[ src = \"x\" ,a]", "\\img[src=\"x\", a]"));
+    assert!(!is_equivalent("\\b{a b}", "\\b{ab}"));
+    assert!(!is_equivalent("\\b{a}b", "\\b{a} b"));
+    assert!(!is_equivalent("\\code{a  b}", "\\code{a b}"));
+    assert!(!is_equivalent("\\p{a} /// x", "\\p{a} /// y"));
+}
+
+/// Every `.ss` file of the repository is formatted without changing its
+/// meaning, and formatting it again changes nothing.
+#[test]
+fn repository_sources_are_formatted() {
+    let mut files = Vec::new();
+    ss_files(&manifest_dir().join("../example-project"), &mut files);
+    ss_files(&manifest_dir().join("tests"), &mut files);
+    assert!(!files.is_empty());
+    for file in files {
+        let source = std::fs::read_to_string(&file).unwrap();
+        let formatted = format_source(&source)
+            .unwrap_or_else(|error| panic!("{}: {error}", file.display()));
+        assert!(is_equivalent(&source, &formatted), "{}", file.display());
+        assert_eq!(format_source(&formatted).unwrap(), formatted, "{}", file.display());
+    }
+}
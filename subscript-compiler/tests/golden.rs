@@ -0,0 +1,121 @@
+//! Golden-file snapshots of the example project. Builds must be reproducible
+//! (e.g. no random IDs), and changes to the generated HTML show up in review
+//! as changes to `tests/golden`. To accept such changes, run
+//!
+//! ```shell
+//! UPDATE_GOLDEN=1 cargo test -p subscript-compiler --test golden
+//! ```
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Skipped, since the build cache has absolute paths.
+const BUILD_CACHE_FILE_NAME: &str = ".subscript-build-cache.json";
+
+/// Images are only listed, since their names are content hashes.
+const ASSETS_DIR_NAME: &str = "static-assets";
+const ASSETS_LIST_FILE_NAME: &str = "static-assets.txt";
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Every file of the given directory, keyed by its path relative to it (with
+/// forward slashes).
+fn read_files(dir: &Path) -> BTreeMap<String, Vec<u8>> {
+    fn go(root: &Path, dir: &Path, files: &mut BTreeMap<String, Vec<u8>>) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for entry in entries.map(Result::unwrap) {
+            let path = entry.path();
+            if path.is_dir() {
+                go(root, &path, files);
+                continue
+            }
+            let rel_path = path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.insert(rel_path, std::fs::read(&path).unwrap());
+        }
+    }
+    let mut files = BTreeMap::new();
+    go(dir, dir, &mut files);
+    files
+}
+
+/// The snapshot of the given output directory.
+fn snapshot(output_dir: &Path) -> BTreeMap<String, Vec<u8>> {
+    let mut files = read_files(output_dir);
+    files.remove(BUILD_CACHE_FILE_NAME);
+    let assets = files
+        .keys()
+        .filter_map(|x| x.strip_prefix(&format!("{ASSETS_DIR_NAME}/")))
+        .map(|x| format!("{x}\n"))
+        .collect::<String>();
+    files.retain(|path, _| !path.starts_with(&format!("{ASSETS_DIR_NAME}/")));
+    files.insert(ASSETS_LIST_FILE_NAME.to_owned(), assets.into_bytes());
+    files
+}
+
+#[test]
+fn example_project_builds_without_warnings() {
+    let project_dir = manifest_dir().join("../example-project");
+    let output_dir = std::env::temp_dir().join(format!("subscript-example-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&output_dir);
+    let output = Command::new(env!("CARGO_BIN_EXE_subscript-compiler"))
+        .arg("build")
+        .arg("--project-dir").arg(&project_dir)
+        .arg("--output-dir").arg(&output_dir)
+        .arg("--force")
+        .output()
+        .unwrap();
+    let _ = std::fs::remove_dir_all(&output_dir);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.trim().is_empty(), "{stderr}");
+}
+
+#[test]
+fn example_project_matches_golden_files() {
+    let project_dir = manifest_dir().join("../example-project");
+    let golden_dir = manifest_dir().join("tests/golden/example-project");
+    let output_dir = std::env::temp_dir().join(format!("subscript-golden-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&output_dir);
+    // Diagnostics are checked by `example_project_builds_without_warnings`,
+    // so the exit status is only checked for panics.
+    let status = Command::new(env!("CARGO_BIN_EXE_subscript-compiler"))
+        .arg("build")
+        .arg("--project-dir").arg(&project_dir)
+        .arg("--output-dir").arg(&output_dir)
+        .arg("--force")
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.code().map(|x| x == 0 || x == 1).unwrap_or(false), "the build failed with {status}");
+    let actual = snapshot(&output_dir);
+    let _ = std::fs::remove_dir_all(&output_dir);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let _ = std::fs::remove_dir_all(&golden_dir);
+        for (rel_path, contents) in actual.iter() {
+            let path = golden_dir.join(rel_path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        return
+    }
+    let expected = read_files(&golden_dir);
+    let mismatches = actual
+        .keys()
+        .chain(expected.keys())
+        .filter(|x| actual.get(*x) != expected.get(*x))
+        .collect::<std::collections::BTreeSet<_>>();
+    assert!(
+        mismatches.is_empty(),
+        "the output differs from tests/golden/example-project in {mismatches:#?}, \
+        rerun with `UPDATE_GOLDEN=1` to accept the changes",
+    );
+}
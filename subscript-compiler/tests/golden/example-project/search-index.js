@@ -0,0 +1 @@
+window.SUBSCRIPT_SEARCH_INDEX = {"pages":[{"href":"/index.html","title":"Colbyn’s School Notes","sections":[{"id":"chemistry","heading":"Chemistry","text":""},{"id":"basics","heading":"Basics","text":"Given some element Where Cations Positively （＋） Charged Anions Negatively （−） Charged Mnemonic Cations are Pawsitive"},{"id":"conventionsonhomework","heading":"Conventions on homework","text":"When a question says, determine the energy of 1 ㏖ of photons, the unit will be ᴶ/㏖."},{"id":"units","heading":"Units","text":""},{"id":"siprefixes","heading":"SI Prefixes","text":"Value Prefix Symbol deca hecto kilo mega giga tera Value Prefix Symbol deci centi mili micro nano pico"},{"id":"classificationofmatter","heading":"Classification of Matter","text":""},{"id":"overview","heading":"Overview","text":""},{"id":"mixtures","heading":"Mixtures","text":""},{"id":"heterogeneousmixture","heading":"Heterogeneous mixture","text":"Where the prefix Hetero- means different"},{"id":"homogeneousmixture","heading":"Homogeneous mixture","text":"Where the prefix Homo- means same"},{"id":"theatomandnuclearchemistry","heading":"The Atom and Nuclear Chemistry","text":""},{"id":"isotopesandsubatomicparticles","heading":"Isotopes and Subatomic Particles","text":""},{"id":"electronsandquantummechanics","heading":"Electrons and Quantum Mechanics","text":""},{"id":"averageatomicmass","heading":"Average Atomic Mass","text":"If the average atomic mass of boron is , what is the percent abundance of boron-11 （mass of ） if the only other isotope is boron-10 （mass of ）? Given the formula for average atomic mass: Therefore in summary, we are given the following known quantities With the following unknown quantities And asked to find the percent abundance of boron-11 （）. Therefore our equation is We have two unknowns, but luckily we can use the following fact/relation and therefore express the percent abundance of boron-10 in terms of the percent abundance of boron-11 Therefore But we aren't done, we have to compute significant figures. Therefore, we round our answer to 2 sig figs, yielding"},{"id":"moleculesandcompounds","heading":"Molecules and Compounds","text":""},{"id":"terms","heading":"Terms","text":"Electronegativity Can be used to to determine type of bond based on electronegativity difference. Ionic Bond Covalent Bond Polar Covalent Bond Sharing of electrons. Hydrogen Bond Hydrogen is bonded with an electronegative element."},{"id":"prefixes","heading":"Prefixes","text":"Prefix Value mono- di- tri- tetra- penta- Prefix Value hexa- hepta- octa- nona- deca-"},{"id":"formulasummary","heading":"Formula Summary","text":""},{"id":"formalchargeoption1","heading":"Formal Charge （Option 1）","text":"Given some element which has number of valance electrons, and a lewis diagram of bonded （） electrons and non-bonded （） electrons, the formal charge for the given element in the lewis structure is For the given quantities"},{"id":"formalchargeoption2","heading":"Formal Charge （Option 2）","text":"Given some element which has number of valance electrons, and a lewis diagram of some number of bonds （） and dots （）, the formal charge for the given element in the lewis structure is For the given quantities Note: each bond is 2 electrons, but counts as one bond."},{"id":"polyatomicions","heading":"Polyatomic Ions","text":""},{"id":"rememberingthenumberofoxygens","heading":"Remembering the number of oxygens","text":""},{"id":"general","heading":"General","text":"Given a set of oxygens in increasing order Where For example Given some element or compound ionically bonded with such For example The following table denotes the naming conventions therein Prefix Prefix Meaning （relative to suffix） Suffix Oxygen Order Example Formula Name Per- More than -ate Highest # Perchlorate -ate Higher # Chlorate -ite Lower # Chlorite Hypo- less than -ite Lowest # Hypochlorite"},{"id":"oxygenvsnooxygencomparison","heading":"Oxygen vs No-Oxygen Comparison","text":"Prefix Suffix Meaning Example Name Per- -ate or Persulfate -ate Sulfate -ite Sulfite Hypo- -ite Hyposulfite -ide No oxygennon-metal Sulfide In summary Per*ate Greater than *ate *ite Hypo*ite *ate Less than Per*ate Greater than *ite Hypo*ite *ite Less than Per*ate *ate Greater than Hypo*ite Hypo*ite Less than Per*ate *ate *ite"},{"id":"generally","heading":"Generally","text":"per-ate Used in the ion with the largest number of oxygen atoms -ate-ite Polyatomic ions of oxygen hypo-ite Used in the ion with the lowest number of oxygen atoms -ide Non-metal, no oxygens"},{"id":"determiningthecharge","heading":"Determining The Charge†","text":""},{"id":"warning","heading":"Warning","text":"Phosphate （） is the only one that violates this rule!"},{"id":"examplefornitrate","heading":"Example for Nitrate","text":""},{"id":"exampleforcyanide","heading":"Example for Cyanide","text":""},{"id":"exampleforoxalate","heading":"Example for Oxalate","text":""},{"id":"exampleforhydrogencarbonate","heading":"Example for Hydrogen Carbonate","text":"† Source"},{"id":"ioniclewisstructures","heading":"Ionic Lewis Structures","text":""},{"id":"ioniclewisstructures1","heading":"Ionic Lewis Structures","text":""},{"id":"examples","heading":"Examples","text":""},{"id":"lewisstructureforsulfateion","heading":"Lewis structure for sulfate ion","text":""},{"id":"notes","heading":"Notes","text":"When naming an ionic compound, the name of the cation is followed by the name of the anion. Monatomic anions are named with the ending -ide."},{"id":"periodicpropertiesoftheelements","heading":"Periodic Properties of the Elements","text":"Note, electron affinity is not the same as electronegativity!"},{"id":"terms1","heading":"Terms","text":"Isoelectronic Atoms with the same number of electrons. Ionization energy Predict Metallic Character Based on Periodic Trends"},{"id":"expandedoctetexceptionstotheoctetrule","heading":"Expanded Octet （Exceptions to the Octet Rule）","text":"All non-metals from period 3 to period 8 of the Periodic Table, can have expanded octets."},{"id":"quantummechanicalmodelsoftheatom","heading":"Quantum Mechanical Models of the Atom","text":""},{"id":"theelectromagneticspectrum","heading":"The Electromagnetic Spectrum","text":""},{"id":"terms2","heading":"Terms","text":"Pauli Exclusion Principle No two electrons in an atom can have the same four quantum numbers. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Aufbau Principle This pattern of orbital filling is known as the aufbau principle （the German word aufbau means “build up”）. Hund’s rule When filling degenerate orbitals, electrons fill them singly first, then with parallel spins. I.e. start by filling boxes with single 'upward' arrows, and then once all of such boxes are maxed out, then you add double arrows pointing in opposite directions. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Coulomb’s Law"},{"id":"aufbauprinciple","heading":"Aufbau Principle","text":""},{"id":"hundsrule","heading":"Hund’s rule","text":""},{"id":"paulisexclusionprinciple","heading":"Pauli's Exclusion Principle","text":"Each election has a unique set of four quantum numbers （i.e. see quantum numbers）. They are"},{"id":"overview1","heading":"Overview","text":""},{"id":"formulas","heading":"Formulas","text":""},{"id":"values","heading":"Values","text":"Name Symbol Unit Description Range Wavelength Any unit for distance Distance between two analogous points Always Positive Frequency or （nu） Number of cycles Always Positive Energy （joule） Amount of energy （） in a light packet"},{"id":"constants","heading":"Constants","text":"Name Symbol Unit Value Speed of Light Planck's constant Energy multiplied by time joule-seconds"},{"id":"otherformulas","heading":"Other Formulas","text":""},{"id":"debroglierelation","heading":"de Broglie Relation","text":""},{"id":"heisenbergsuncertaintyprinciple","heading":"Heisenberg's Uncertainty Principle","text":"Where is the uncertainty in position. is the uncertainty in velocity. is the mass of the particle. is the plank's constant. In general it states that the more you know about an electrons position, the less you know about it's velocity."},{"id":"energyofanelectroninanorbitalwithquantumnumberinahydrogenatom","heading":"Energy of an Electron in an Orbital with Quantum Number in a Hydrogen Atom","text":""},{"id":"energyofanelectroninanorbitalwithquantumnumberforanyatom","heading":"Energy of an Electron in an Orbital with Quantum Number for any atom","text":"Where is the atomic number of the given element."},{"id":"changeinenergythatoccursinanatomwhenitundergoesatransitionbetweenlevelsfurtherdetails","heading":"Change in Energy That Occurs in an Atom When It Undergoes a Transition between Levels （Further Details）","text":"and If is negative, energy is being released. If is positive, energy is being absorbed."},{"id":"ionizationenergy","heading":"Ionization Energy","text":"Where is the electron number the point on the table where you see the highest difference/delta."},{"id":"atomicspectroscopy","heading":"Atomic Spectroscopy","text":""},{"id":"theprincipalquantumnumbernhydrogenatom","heading":"The Principal Quantum Number （n） （Hydrogen Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by Therefore the difference in energy is given by the following"},{"id":"theprincipalquantumnumbernanyatom","heading":"The Principal Quantum Number （n） （Any Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by TODO Where is the atomic number of the given element."},{"id":"electronconfiguration","heading":"Electron Configuration","text":""},{"id":"traditionalchart","heading":"Traditional Chart","text":""},{"id":"bettermethod","heading":"Better Method","text":""},{"id":"examples1","heading":"Examples","text":""},{"id":"electronconfigurationfor","heading":"Electron configuration for","text":"Since the electron configuration for Argon is"},{"id":"electronconfigurationfor1","heading":"Electron configuration for","text":"Beginning with the electron configuration for Remove the electrons from the term with the higher electron state. Warning! Do not just remove the electrons from the rightmost term since the rightmost term may be a lower electron state. For instance given is in a higher electron state is in a lower electron state Alternatively, to compute the lowest energy orbital, add the principle quantum number （） to the The angular momentum quantum number （） to get the orbital with the lowest energy. Therefore Given and NVM this is an exception to the rule... TODO move this somewhere else... As shown Therefore the electron configuration for is:"},{"id":"electronconfigurationfor2","heading":"Electron configuration for","text":"It would appear that the electron configuration for would be But this is wrong! It's actually"},{"id":"howtos","heading":"How-tos","text":""},{"id":"whatarethevalenceelectrons","heading":"What are the valence electrons?","text":"Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons. Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons."},{"id":"quantumnumbers","heading":"Quantum Numbers","text":""},{"id":"overview2","heading":"Overview","text":"Symbol Description The principle quantum number The angular momentum quantum number The magnetic quantum number The spin quantum number"},{"id":"theprinciplequantumnumber","heading":"The Principle Quantum Number （）","text":"Value of Value of Orbital Sublevel"},{"id":"angularmomentumquantumnumber","heading":"Angular Momentum Quantum Number","text":"Value Result Value of Value of"},{"id":"summary","heading":"Summary","text":""},{"id":"usefulformulas","heading":"Useful Formulas","text":"The equation for a maximum number of electrons a given energy level can hold given some value for How many orbitals are possible given some value for"},{"id":"examples2","heading":"Examples","text":""},{"id":"light","heading":"Light","text":""},{"id":"interferenceanddiffraction","heading":"Interference and Diffraction","text":""},{"id":"constructiveinterference","heading":"Constructive Interference","text":"If two waves of equal amplitude are in phase when they interact—that is, they align with overlapping crests—a wave with twice the amplitude results. This is called constructive interference."},{"id":"destructiveinterference","heading":"Destructive Interference","text":"If two waves are completely out of phase when they interact—that is, they align so that the crest from one overlaps with the trough from the other—the waves cancel by destructive interference."},{"id":"mathematics","heading":"Mathematics","text":""},{"id":"algebra","heading":"Algebra","text":""},{"id":"miscellaneous","heading":"Miscellaneous","text":""},{"id":"functionalutilitiesnotationconveniences","heading":"Functional Utilities & Notation Conveniences","text":""},{"id":"righttoleftevaluation","heading":"Right to Left Evaluation","text":""},{"id":"lefttorightevaluation","heading":"Left to Right Evaluation","text":""},{"id":"derivativeshorthand","heading":"Derivative Shorthand","text":"For this notation, the derivative with respect to a given variable, is implicit."},{"id":"radiansradianconversion","heading":"Radians & Radian Conversion","text":""},{"id":"constants1","heading":"Constants","text":""},{"id":"conversion","heading":"Conversion","text":""},{"id":"constants2","heading":"Constants","text":""},{"id":"ℯeulersnumber","heading":"ℯ （Euler's number）","text":""},{"id":"algebra1","heading":"Algebra","text":""},{"id":"properties","heading":"Properties","text":""},{"id":"trigonometry","heading":"Trigonometry","text":""},{"id":"theunitcirclespecialanglesintrig","heading":"The Unit Circle & Special Angles In Trig","text":""},{"id":"warning1","heading":"Warning","text":"Never use Pi （）! It makes （thinking in terms of） radians confusing, Tao （） is what the enlightened trigonometer uses, and won't screw you over. To easily memorize the special angles in trig, notice the repeating patterns on the above angles. For values on the x-axis, anything over and under will be negative For values on the y-axis, anything over will be negative Diagonals will be , For ratios of on the sides, i.e. , , , , , , , and . Draw a circle and dot the point where it occurs （which is pretty easy since the above are simple ratios of a circle when expressed in terms of ）. Then with regards to the and axis values: The longer size will be The shorter side will be See the above examples."},{"id":"trigonometricidentities","heading":"Trigonometric Identities","text":""},{"id":"pythagoreanidentities","heading":"Pythagorean Identities","text":""},{"id":"sumanddifferenceidentities","heading":"Sum and Difference Identities","text":""},{"id":"cofunctionidentities","heading":"Cofunction Identities","text":""},{"id":"ratioidentities","heading":"Ratio Identities","text":""},{"id":"doubleangleidentities","heading":"Double-Angle Identities","text":""},{"id":"halfangleidentities","heading":"Half-Angle Identities","text":""},{"id":"powerreducingidentities","heading":"Power-Reducing Identities","text":""},{"id":"producttosumidentities","heading":"Product-to-Sum Identities","text":""},{"id":"sumtoproductidentities","heading":"Sum-to-Product-Identities","text":""},{"id":"trigonometricequations","heading":"Trigonometric Equations","text":""},{"id":"eulersformula","heading":"Euler's Formula","text":""},{"id":"coordinatenumbersystems","heading":"Coordinate & Number Systems","text":""},{"id":"polarcoordinatesystem","heading":"Polar Coordinate System","text":"Given Then"},{"id":"properties1","heading":"Properties","text":"Given Then De Moivre’s Theorem De Moivre’s Theorem For Finding Roots"},{"id":"trigonometricformofacomplexnumber","heading":"Trigonometric form of a complex number","text":""},{"id":"vectors","heading":"Vectors","text":""},{"id":"quickfacts","heading":"Quick Facts","text":"Two vectors are equal if they share the same magnitude and direction. Initial points don't matter. You can define a vector with two points. Do not divide vectors!"},{"id":"vectoroperations","heading":"Vector Operations","text":""},{"id":"dotproduct","heading":"Dot Product","text":""},{"id":"crossproduct","heading":"Cross Product","text":""},{"id":"lengthofavector","heading":"Length of a Vector","text":""},{"id":"definitionofvectoraddition","heading":"Definition of Vector Addition","text":"If and are positioned so the initial point of is at the terminal point of , then the sum is the vector from the initial point of to the terminal point of . Given some vectors and , the vector is the vector that points from the head of to the head of"},{"id":"standardbasisvectors","heading":"Standard Basis Vectors","text":""},{"id":"orthogonal","heading":"Orthogonal","text":"Two vectors are orthogonal if and only if"},{"id":"theunitvector","heading":"The Unit Vector","text":""},{"id":"ifistheanglebetweenthevectorsandthen","heading":"If is the angle between the vectors and , then","text":""},{"id":"ifistheanglebetweenthenonzerovectorsandthen","heading":"If is the angle between the nonzero vectors and , then","text":""},{"id":"twononzerovectorsandareparallelifandonlyif","heading":"Two nonzero vectors and are parallel if and only if","text":""},{"id":"propertiesofthedotproduct","heading":"Properties of the Dot Product","text":""},{"id":"directioncosinesdirectionanglesofavector","heading":"Direction Cosines & Direction Angles of a Vector","text":"Where"},{"id":"directioncosines","heading":"Direction Cosines","text":""},{"id":"directionangles","heading":"Direction Angles","text":""},{"id":"theorem","heading":"Theorem","text":""},{"id":"proof","heading":"Proof","text":"Given Therefore"},{"id":"vectorrelations","heading":"Vector Relations","text":""},{"id":"parallelvectors","heading":"Parallel Vectors","text":"When two vectors are parallel; they never intersect （duh）. Given some vectors The vectors and are parallel if and only if they are scalar multiples of one another."},{"id":"alternatively","heading":"Alternatively","text":""},{"id":"orthogonalvectors","heading":"Orthogonal Vectors","text":"When two vectors are orthogonal; they meet at right angles. Given some vectors Two vectors are orthogonal if and only if"},{"id":"reparameterizationofthepositionvectorintermsoflength","heading":"Reparameterization of the position vector in terms of length","text":"We can parametrize a curve with respect to arc length; because arc length arises naturally from the shape of the curve and does not depend on any coordinate system."},{"id":"thearclengthfunction","heading":"The Arc Length Function","text":"Given We can redefine in terms of arc length between two endpoints That is, is the length of the curve （） between and . Furthermore from the adjacent definition; we can simply the above to"},{"id":"thearclengthfunction1","heading":"The Arc Length Function","text":"That is"},{"id":"vectorsderivedfromsomecurvedefinedby","heading":"Vectors Derived From Some Curve Defined by","text":""},{"id":"theunitvector1","heading":"The Unit Vector","text":""},{"id":"theunittangentvector","heading":"The Unit Tangent Vector","text":""},{"id":"theunitnormalvector","heading":"The Unit Normal Vector","text":""},{"id":"thebinormalvector","heading":"The Binormal Vector","text":"Therefore, the binormal vector is orthogonal to both the tangent vector and the normal vector. The plane determined by the normal and binormal vectors N and B at a point P on a curve C is called the normal plane of C at P. The plane determined by the vectors T and N is called the osculating plane of C at P. The name comes from the Latin osculum, meaning “kiss.” It is the plane that comes closest to containing the part of the curve near P. （For a plane curve, the osculating plane is simply the plane that contains the curve.）"},{"id":"kappacurvatureofavector","heading":"Kappa - Curvature of a Vector","text":""},{"id":"tangentialnormalcomponentsoftheaccelerationvectorofthecurve","heading":"Tangential & Normal Components of the Acceleration Vector of the Curve","text":"When we study the motion of a particle, it is often useful to resolve the acceleration into two components, one in the direction of the tangent and the other in the direction of the normal. Specifically"},{"id":"vectorcalculus","heading":"Vector Calculus","text":""},{"id":"thepositionvector","heading":"The Position Vector","text":"（Original Function）"},{"id":"thevelocityvector","heading":"The Velocity Vector","text":"（First Derivative） The velocity vector is also the tangent vector and points in the direction of the tangent line. The speed of the particle at time t is the magnitude of the velocity vector, that is,"},{"id":"theaccelerationvector","heading":"The Acceleration Vector","text":"（Second Derivative）"},{"id":"matrices","heading":"Matrices","text":""},{"id":"reference","heading":"Reference","text":""},{"id":"thedeterminantofamatrix","heading":"The Determinant of A Matrix","text":"Only works for square matrices."},{"id":"thecrossproduct","heading":"The Cross Product","text":""},{"id":"geometry","heading":"Geometry","text":""},{"id":"thecircle","heading":"The Circle","text":""},{"id":"definitionofaline","heading":"Definition of a Line","text":""},{"id":"vectorequationofaline","heading":"Vector Equation of a Line","text":"Given We can define a vector between and"},{"id":"therefore","heading":"Therefore","text":"The equation of a line in 3D space or can be defined VIA the following options That is"},{"id":"parametricequationofaline","heading":"Parametric Equation of a Line","text":""},{"id":"essentially","heading":"Essentially","text":"That is, is the scaling factor. In a way, it's like it's a function of , but also similar to the slope （） in , except （i.e. ） is parameterized. Sometimes this will be （confusingly） denoted as"},{"id":"symmetricequationofaline","heading":"Symmetric Equation of a Line","text":"Therefore"},{"id":"rationale","heading":"Rationale","text":"We rewrite in terms of . That is"},{"id":"parameterizationsofacurve","heading":"Parameterizations of a curve","text":"Parametrized curve A curve in the plane is said to be parameterized if the set of coordinates on the curve, （x,y）, are represented as functions of a variable t. A parametrized Curve is a path in the xy-plane traced out by the point as the parameter ranges over an interval . A parametrized Curve is a path in the xyz-plane traced out by the point as the parameter ranges over an interval ."},{"id":"curvatureproperties","heading":"Curvature Properties","text":""},{"id":"lengthofacurve","heading":"Length of a Curve","text":""},{"id":"thearclengthfunction2","heading":"The Arc Length Function","text":"Suppose Given some curve defined by some vector in where is continuous and is traversed exactly once as increases from to We can define it's arc length function VIA *"},{"id":"calculus","heading":"Calculus","text":""},{"id":"derivativetables","heading":"Derivative Tables","text":""},{"id":"integrationtables","heading":"Integration Tables","text":""},{"id":"riemannsums","heading":"Riemann Sums","text":"Given"},{"id":"leftriemannsum","heading":"Left Riemann Sum","text":""},{"id":"rightriemannsum","heading":"Right Riemann Sum","text":""},{"id":"midpointriemannsum","heading":"Midpoint Riemann Sum","text":"We can also do away with the index notation and simplify things."},{"id":"trapezoidalriemannsum","heading":"Trapezoidal Riemann Sum","text":""},{"id":"simpsonsrule","heading":"Simpson's Rule","text":""},{"id":"improperintegrals","heading":"Improper Integrals","text":""},{"id":"infinitesequences","heading":"Infinite Sequences","text":""},{"id":"infinitesequence","heading":"Infinite Sequence","text":""},{"id":"helpfultheorem","heading":"Helpful Theorem","text":""},{"id":"example","heading":"Example","text":"Given Therefore"},{"id":"example1","heading":"Example","text":"Given Therefore"},{"id":"infiniteseries","heading":"Infinite Series","text":""},{"id":"infiniteseries1","heading":"Infinite Series","text":"Note that the limit of every convergent series is equal to zero. But the inverse isn't always true. If the limit is equal to zero, it may not be convergent. For example, does diverge; but it's limit is equal to zero. If the limit is equal to zero; the test is inconclusive."},{"id":"geometricseries","heading":"Geometric Series","text":"Given Alternatively Tests Furthermore"},{"id":"theintegraltest","heading":"The Integral Test","text":""},{"id":"constraintson","heading":"Constraints on","text":"Continuous Positive Decreasing （i.e. use derivative test）"},{"id":"pseriesorharmonicseries","heading":"P-Series -or- Harmonic Series","text":"Note: the Harmonic series is the special case where"},{"id":"comparisontest","heading":"Comparison Test","text":""},{"id":"limitcomparisontest","heading":"Limit Comparison Test","text":""},{"id":"warning2","heading":"Warning","text":"If , this only means that the limit comparison test can be used. You still need to determine if either or converges or diverges. Therefore, this does not apply to any arbitrary rational function."},{"id":"notes1","heading":"Notes","text":"For many series, we find a suitable comparison, , by keeping only the highest powers in the numerator and denominator of ."},{"id":"estimatinginfiniteseries","heading":"Estimating Infinite Series","text":""},{"id":"differentialequations","heading":"Differential Equations","text":""},{"id":"separabledifferentialequations","heading":"Separable Differential Equations","text":""},{"id":"growthanddecaymodels","heading":"Growth and Decay Models","text":"The above states that all solutions for are of the form . Where Exponential growth occurs when , and exponential decay occurs when . The Law of Natural Growth: The Logistic Model of Population Growth: Where"},{"id":"solvingthelogisticequation","heading":"Solving the Logistic Equation","text":"Via partial fraction decomposition Rewriting the differential equation"},{"id":"secondorderhomogeneouslineardifferentialequationswithconstantcoefficients","heading":"Second Order Homogeneous Linear Differential Equations with Constant Coefficients","text":""},{"id":"properties2","heading":"Properties","text":"If and are solutions; then is also a solution. Therefore, the most general solution to some second order homogeneous linear differential equations with constant coefficients would be . This is why, when you find two solutions to the characteristic equation and respectively, we write it like so."},{"id":"","heading":"","text":"Given some: We can presume that is of the form , and therefore: Substituting this back into the original equation, we have: Where: So therefore: Where the general solution is of the form:"},{"id":"parametricequations","heading":"Parametric Equations","text":""},{"id":"firstderivativeformula","heading":"First Derivative Formula","text":"To find the derivative of a given function defined parametrically by the equations and ."},{"id":"secondderivativeformula","heading":"Second Derivative Formula","text":"To find the second derivative of a given function defined parametrically by the equations and . Given Therefore The above shows different ways of representing . （I.e. it doesn't correspond to some final solution.）"},{"id":"arclength","heading":"Arc Length","text":"Formula for the arc length of a parametric curve over the interval ."},{"id":"physics","heading":"Physics","text":""},{"id":"prelude","heading":"Prelude","text":""},{"id":"constants3","heading":"Constants","text":""},{"id":"tables","heading":"Tables","text":""},{"id":"linearkinematicequations1d","heading":"Linear Kinematic Equations （1D）","text":"Formula Missing Quantities Present"},{"id":"rotationalkinematicequations","heading":"Rotational Kinematic Equations","text":"Formula Missing Quantities Present"},{"id":"scalarorvector","heading":"Scalar or Vector","text":"Name Type Speed Scalar Distance Scalar Velocity Vector Acceleration Vector Average Velocity Vector Displacement Vector Position Vector"},{"id":"kinematicequationsin1d","heading":"Kinematic Equations in 1D","text":""},{"id":"conventions","heading":"Conventions","text":""},{"id":"graphicalrepresentation","heading":"Graphical Representation","text":""},{"id":"standardequations","heading":"Standard Equations","text":""},{"id":"summary1","heading":"Summary","text":"Formula Missing Quantities Present"},{"id":"basics1","heading":"Basics","text":""},{"id":"constantvelocity","heading":"Constant Velocity","text":""},{"id":"uniformacceleration","heading":"Uniform acceleration","text":""},{"id":"miscellaneous1","heading":"Miscellaneous","text":""},{"id":"derivingdisplacementformulas","heading":"Deriving Displacement Formulas","text":""},{"id":"displacementwhenobjectmoveswithconstantvelocity","heading":"Displacement when object moves with constant velocity","text":""},{"id":"deriving","heading":"Deriving","text":""},{"id":"displacementwhenobjectacceleratesfromrest","heading":"Displacement when object accelerates from rest","text":""},{"id":"deriving1","heading":"Deriving","text":""},{"id":"displacementwhenobjectaccelerateswithinitialvelocity","heading":"Displacement when object accelerates with initial velocity","text":""},{"id":"deriving2","heading":"Deriving","text":""},{"id":"derivingtheotherkinematicformulas","heading":"Deriving The Other Kinematic Formulas","text":""},{"id":"deriving3","heading":"Deriving","text":"Given We can rearrange from equation （1） like so Therefore"},{"id":"deriving4","heading":"Deriving","text":"Given from equation （1） can be rearranged as from equation （2） can be rearranged like so Using the following equations from above from equation （3） from equation （4） Rearranging equation （5） Rearrange again to obtain the more common form TODO"},{"id":"twodimensionalprojectilemotion","heading":"Two-dimensional Projectile Motion","text":""},{"id":"conventions1","heading":"Conventions","text":""},{"id":"summary2","heading":"Summary","text":"It's easy to see in the above visualization that and increase linearly, while is non-linear."},{"id":"formulas1","heading":"Formulas","text":""},{"id":"displacementprojectileposition","heading":"Displacement & Projectile Position","text":""},{"id":"generalized","heading":"Generalized","text":"In general （without respect to any or axis values） Where the distance traveled or displaced is"},{"id":"intermsofandaxisvalues","heading":"In terms of and axis values","text":""},{"id":"withrespecttotheaxis","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Since Which can be read as （in terms of the axis）"},{"id":"withrespecttotheaxis1","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Note that （because there is no force acting on the projectile in the horizontal direction）, and therefore the initial and final velocities are the same. I.e. it's constant throughout. Therefore in summary and therefore we will simple refer to the velocity vector as as . Therefore we can simplify equation （1） considerably"},{"id":"solvingprojectilemotionproblems","heading":"Solving Projectile Motion Problems","text":""},{"id":"projectilemotion","heading":"Projectile Motion","text":""},{"id":"intermsoftheaxis","heading":"In terms of the axis","text":"TODO TODO"},{"id":"intermsoftheaxis1","heading":"In terms of the axis","text":"TODO TODO"},{"id":"insummary","heading":"In Summary","text":"Initial Quantities Derived expressions Solutions"},{"id":"projectilemotionfromaninitialheightwithgiveninitialvelocityandangle","heading":"Projectile Motion from an initial height, with given initial velocity and angle","text":"Given A projectile angle The initial height The initial velocity We can therefore derive the the initial velocities for and in terms of the given angle and initial velocity. Given the general formulas for displacement and velocity Which this information, we will derive specific equations in terms of the and axes governing the projectile."},{"id":"intermsoftheaxis2","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts From the given depiction of the problem, we know that . There is no acceleration along the axis, so . as shown above. Therefore"},{"id":"derivingvelocity","heading":"Deriving velocity","text":""},{"id":"intermsoftheaxis3","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime1","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts Initial height is given to us which we will represent as , for the sake of generality. Acceleration along the axis is the constant for gravity, so . as shown above. Therefore"},{"id":"derivingvelocity1","heading":"Deriving velocity","text":""},{"id":"insummary1","heading":"In summary","text":""},{"id":"tofindtherange","heading":"To find the range","text":"We know that at the moment of impact , therefore we can use equation Rearranging a bit and setting , we can see that solving for will yield the time at which . Therefore Plugging the solution for （and ignoring the negative or non-real solutions for ） into will yield the horizontal displacement （range） at the time . Therefore:"},{"id":"tofindthemaximumverticaldisplacementiepeakheight","heading":"To find the maximum vertical displacement （i.e. peak height）","text":"We begin with equation We know that at the moment our projectile crests its trajectory, the vertical component of our projectile will be zero. Therefore . To find the time, we simply solve for . Therefore, knowing the time at which our projectile crests its trajectory, we simply plugin our solution for into the function given in equation . I.e:"},{"id":"tofindthevelocityatagivenmomentoftime","heading":"To find the velocity at a given moment of time","text":"Given some time which we will denote as , to find the velocity we simply plug in our given values for and into equations and . I.e. With the given value for , yielding the vector at time , which we will denote as To define the vector in terms of engineering notation, （i.e. ） To define the vector in terms of magnitude （which we will denote as ） and direction （which we will denote as ）"},{"id":"range","heading":"Range","text":"The distance a projectile travels is called its range. Only applies in situations where the projectile lands at the same elevation from which it was fired."},{"id":"reasoningaboutprojectilemotion","heading":"Reasoning About Projectile Motion","text":""},{"id":"notes2","heading":"Notes","text":"An object is in free fall when the only force acting on it is the force of gravity."},{"id":"question","heading":"Question","text":"Based on the figure, for which trajectory was the object in the air for the greatest amount of time?"},{"id":"answer","heading":"Answer","text":"Trajectory A"},{"id":"explanation","heading":"Explanation","text":"All that matters is the vertical height of the trajectory, which is based on the component of the initial velocity in the vertical direction （）. The higher the trajectory, the more time the object will be in the air, regardless of the object's range or horizontal velocity."},{"id":"problems","heading":"Problems","text":"The function in this graph represents an object that is speeding up, or accelerating at a constant rate. When you throw a ball directly upward, what is true about its acceleration after the ball has left your hand? Answer: The ball’s acceleration is always directed downward. Wrong: The ball’s acceleration is always directed downward, except at the top of the motion, where the acceleration is zero. Question As an object moves in the x-y plane, which statement is true about the object’s instantaneous velocity at a given moment? Answer The instantaneous velocity is tangent to the object’s path Wrong The instantaneous velocity is perpendicular to the object’s path. The instantaneous velocity can point in any direction, independent of the object’s path. Explanation As an object moves in the x-y plane the instantaneous velocity is tangent to the object‘s path at a given moment. This is because the displacement vector during an infinitesimally small time interval is always directed along the object’s path and the velocity vector always has the same direction as the displacement vector."},{"id":"relativemotion","heading":"Relative Motion","text":""},{"id":"galileantransformationofvelocity","heading":"Galilean transformation of velocity","text":"The velocity of some object P as seen from a stationary frame must be the sum of and Where Symbol Description Velocity as measured in a stationary frame Velocity of an object measured in the moving frame relative to the moving frame velocity of the moving frame - with respect to the stationary frame"},{"id":"galileantransformationofvelocityalternatenotation","heading":"Galilean transformation of velocity （alternate notation）","text":"Given two reference frames A and and some object . The velocity of the object can be defined in terms of or as shown Symbol Description The velocity of relative to The velocity of relative to The velocity of relative to The velocity of relative to . It locates the origin of relative to the origin of . Therefore"},{"id":"rotationalmotionkinematics","heading":"Rotational Motion & Kinematics","text":""},{"id":"basics2","heading":"Basics","text":""},{"id":"auxiliaryformulareference","heading":"Auxiliary Formula Reference","text":"Formula Missing Quantities Present A particle moves with uniform circular motion if and only if its angular velocity V is constant and unchanging."},{"id":"uniformcircularmotion","heading":"Uniform Circular Motion","text":"Uniform means content speed"},{"id":"forcesandnewtonslawsofmotion","heading":"Forces and Newton's laws of motion","text":""},{"id":"newtonslawsofmotion","heading":"Newton's laws of motion","text":""},{"id":"normalforceandcontactforce","heading":"Normal force and contact force","text":""},{"id":"balancedandunbalancedforces","heading":"Balanced and unbalanced forces","text":""},{"id":"inclinedplanesandfriction","heading":"Inclined planes and friction","text":""},{"id":"tension","heading":"Tension","text":""},{"id":"testpage","heading":"Test Page","text":""},{"id":"helloworld","heading":"Hello world","text":""},{"id":"helloworld1","heading":"Hello world","text":""},{"id":"helloworld2","heading":"Hello world","text":""},{"id":"helloworld3","heading":"Hello world","text":""},{"id":"helloworld4","heading":"Hello world","text":""},{"id":"helloworld5","heading":"Hello world","text":""},{"id":"math","heading":"Math","text":""},{"id":"drawings","heading":"Drawings","text":""},{"id":"togglevisivility","heading":"Toggle Visivility","text":""},{"id":"compositions","heading":"Compositions","text":""},{"id":"helloworld6","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld7","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor."},{"id":"helloworld8","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld9","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld10","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld11","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld12","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld13","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld14","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld15","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld16","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld17","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld18","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld19","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld20","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld21","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld22","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld23","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld24","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld25","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld26","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld27","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld28","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld29","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld30","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld31","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld32","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."}]},{"href":"/chem/index.html","title":"Chemistry","sections":[{"id":"chemistry","heading":"Chemistry","text":""},{"id":"basics","heading":"Basics","text":"Given some element Where Cations Positively （＋） Charged Anions Negatively （−） Charged Mnemonic Cations are Pawsitive"},{"id":"conventionsonhomework","heading":"Conventions on homework","text":"When a question says, determine the energy of 1 ㏖ of photons, the unit will be ᴶ/㏖."},{"id":"units","heading":"Units","text":""},{"id":"siprefixes","heading":"SI Prefixes","text":"Value Prefix Symbol deca hecto kilo mega giga tera Value Prefix Symbol deci centi mili micro nano pico"},{"id":"classificationofmatter","heading":"Classification of Matter","text":""},{"id":"overview","heading":"Overview","text":""},{"id":"mixtures","heading":"Mixtures","text":""},{"id":"heterogeneousmixture","heading":"Heterogeneous mixture","text":"Where the prefix Hetero- means different"},{"id":"homogeneousmixture","heading":"Homogeneous mixture","text":"Where the prefix Homo- means same"},{"id":"theatomandnuclearchemistry","heading":"The Atom and Nuclear Chemistry","text":""},{"id":"isotopesandsubatomicparticles","heading":"Isotopes and Subatomic Particles","text":""},{"id":"electronsandquantummechanics","heading":"Electrons and Quantum Mechanics","text":""},{"id":"averageatomicmass","heading":"Average Atomic Mass","text":"If the average atomic mass of boron is , what is the percent abundance of boron-11 （mass of ） if the only other isotope is boron-10 （mass of ）? Given the formula for average atomic mass: Therefore in summary, we are given the following known quantities With the following unknown quantities And asked to find the percent abundance of boron-11 （）. Therefore our equation is We have two unknowns, but luckily we can use the following fact/relation and therefore express the percent abundance of boron-10 in terms of the percent abundance of boron-11 Therefore But we aren't done, we have to compute significant figures. Therefore, we round our answer to 2 sig figs, yielding"},{"id":"moleculesandcompounds","heading":"Molecules and Compounds","text":""},{"id":"terms","heading":"Terms","text":"Electronegativity Can be used to to determine type of bond based on electronegativity difference. Ionic Bond Covalent Bond Polar Covalent Bond Sharing of electrons. Hydrogen Bond Hydrogen is bonded with an electronegative element."},{"id":"prefixes","heading":"Prefixes","text":"Prefix Value mono- di- tri- tetra- penta- Prefix Value hexa- hepta- octa- nona- deca-"},{"id":"formulasummary","heading":"Formula Summary","text":""},{"id":"formalchargeoption1","heading":"Formal Charge （Option 1）","text":"Given some element which has number of valance electrons, and a lewis diagram of bonded （） electrons and non-bonded （） electrons, the formal charge for the given element in the lewis structure is For the given quantities"},{"id":"formalchargeoption2","heading":"Formal Charge （Option 2）","text":"Given some element which has number of valance electrons, and a lewis diagram of some number of bonds （） and dots （）, the formal charge for the given element in the lewis structure is For the given quantities Note: each bond is 2 electrons, but counts as one bond."},{"id":"polyatomicions","heading":"Polyatomic Ions","text":""},{"id":"rememberingthenumberofoxygens","heading":"Remembering the number of oxygens","text":""},{"id":"general","heading":"General","text":"Given a set of oxygens in increasing order Where For example Given some element or compound ionically bonded with such For example The following table denotes the naming conventions therein Prefix Prefix Meaning （relative to suffix） Suffix Oxygen Order Example Formula Name Per- More than -ate Highest # Perchlorate -ate Higher # Chlorate -ite Lower # Chlorite Hypo- less than -ite Lowest # Hypochlorite"},{"id":"oxygenvsnooxygencomparison","heading":"Oxygen vs No-Oxygen Comparison","text":"Prefix Suffix Meaning Example Name Per- -ate or Persulfate -ate Sulfate -ite Sulfite Hypo- -ite Hyposulfite -ide No oxygennon-metal Sulfide In summary Per*ate Greater than *ate *ite Hypo*ite *ate Less than Per*ate Greater than *ite Hypo*ite *ite Less than Per*ate *ate Greater than Hypo*ite Hypo*ite Less than Per*ate *ate *ite"},{"id":"generally","heading":"Generally","text":"per-ate Used in the ion with the largest number of oxygen atoms -ate-ite Polyatomic ions of oxygen hypo-ite Used in the ion with the lowest number of oxygen atoms -ide Non-metal, no oxygens"},{"id":"determiningthecharge","heading":"Determining The Charge†","text":""},{"id":"warning","heading":"Warning","text":"Phosphate （） is the only one that violates this rule!"},{"id":"examplefornitrate","heading":"Example for Nitrate","text":""},{"id":"exampleforcyanide","heading":"Example for Cyanide","text":""},{"id":"exampleforoxalate","heading":"Example for Oxalate","text":""},{"id":"exampleforhydrogencarbonate","heading":"Example for Hydrogen Carbonate","text":"† Source"},{"id":"ioniclewisstructures","heading":"Ionic Lewis Structures","text":""},{"id":"ioniclewisstructures1","heading":"Ionic Lewis Structures","text":""},{"id":"examples","heading":"Examples","text":""},{"id":"lewisstructureforsulfateion","heading":"Lewis structure for sulfate ion","text":""},{"id":"notes","heading":"Notes","text":"When naming an ionic compound, the name of the cation is followed by the name of the anion. Monatomic anions are named with the ending -ide."},{"id":"periodicpropertiesoftheelements","heading":"Periodic Properties of the Elements","text":"Note, electron affinity is not the same as electronegativity!"},{"id":"terms1","heading":"Terms","text":"Isoelectronic Atoms with the same number of electrons. Ionization energy Predict Metallic Character Based on Periodic Trends"},{"id":"expandedoctetexceptionstotheoctetrule","heading":"Expanded Octet （Exceptions to the Octet Rule）","text":"All non-metals from period 3 to period 8 of the Periodic Table, can have expanded octets."},{"id":"quantummechanicalmodelsoftheatom","heading":"Quantum Mechanical Models of the Atom","text":""},{"id":"theelectromagneticspectrum","heading":"The Electromagnetic Spectrum","text":""},{"id":"terms2","heading":"Terms","text":"Pauli Exclusion Principle No two electrons in an atom can have the same four quantum numbers. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Aufbau Principle This pattern of orbital filling is known as the aufbau principle （the German word aufbau means “build up”）. Hund’s rule When filling degenerate orbitals, electrons fill them singly first, then with parallel spins. I.e. start by filling boxes with single 'upward' arrows, and then once all of such boxes are maxed out, then you add double arrows pointing in opposite directions. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Coulomb’s Law"},{"id":"aufbauprinciple","heading":"Aufbau Principle","text":""},{"id":"hundsrule","heading":"Hund’s rule","text":""},{"id":"paulisexclusionprinciple","heading":"Pauli's Exclusion Principle","text":"Each election has a unique set of four quantum numbers （i.e. see quantum numbers）. They are"},{"id":"overview1","heading":"Overview","text":""},{"id":"formulas","heading":"Formulas","text":""},{"id":"values","heading":"Values","text":"Name Symbol Unit Description Range Wavelength Any unit for distance Distance between two analogous points Always Positive Frequency or （nu） Number of cycles Always Positive Energy （joule） Amount of energy （） in a light packet"},{"id":"constants","heading":"Constants","text":"Name Symbol Unit Value Speed of Light Planck's constant Energy multiplied by time joule-seconds"},{"id":"otherformulas","heading":"Other Formulas","text":""},{"id":"debroglierelation","heading":"de Broglie Relation","text":""},{"id":"heisenbergsuncertaintyprinciple","heading":"Heisenberg's Uncertainty Principle","text":"Where is the uncertainty in position. is the uncertainty in velocity. is the mass of the particle. is the plank's constant. In general it states that the more you know about an electrons position, the less you know about it's velocity."},{"id":"energyofanelectroninanorbitalwithquantumnumberinahydrogenatom","heading":"Energy of an Electron in an Orbital with Quantum Number in a Hydrogen Atom","text":""},{"id":"energyofanelectroninanorbitalwithquantumnumberforanyatom","heading":"Energy of an Electron in an Orbital with Quantum Number for any atom","text":"Where is the atomic number of the given element."},{"id":"changeinenergythatoccursinanatomwhenitundergoesatransitionbetweenlevelsfurtherdetails","heading":"Change in Energy That Occurs in an Atom When It Undergoes a Transition between Levels （Further Details）","text":"and If is negative, energy is being released. If is positive, energy is being absorbed."},{"id":"ionizationenergy","heading":"Ionization Energy","text":"Where is the electron number the point on the table where you see the highest difference/delta."},{"id":"atomicspectroscopy","heading":"Atomic Spectroscopy","text":""},{"id":"theprincipalquantumnumbernhydrogenatom","heading":"The Principal Quantum Number （n） （Hydrogen Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by Therefore the difference in energy is given by the following"},{"id":"theprincipalquantumnumbernanyatom","heading":"The Principal Quantum Number （n） （Any Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by TODO Where is the atomic number of the given element."},{"id":"electronconfiguration","heading":"Electron Configuration","text":""},{"id":"traditionalchart","heading":"Traditional Chart","text":""},{"id":"bettermethod","heading":"Better Method","text":""},{"id":"examples1","heading":"Examples","text":""},{"id":"electronconfigurationfor","heading":"Electron configuration for","text":"Since the electron configuration for Argon is"},{"id":"electronconfigurationfor1","heading":"Electron configuration for","text":"Beginning with the electron configuration for Remove the electrons from the term with the higher electron state. Warning! Do not just remove the electrons from the rightmost term since the rightmost term may be a lower electron state. For instance given is in a higher electron state is in a lower electron state Alternatively, to compute the lowest energy orbital, add the principle quantum number （） to the The angular momentum quantum number （） to get the orbital with the lowest energy. Therefore Given and NVM this is an exception to the rule... TODO move this somewhere else... As shown Therefore the electron configuration for is:"},{"id":"electronconfigurationfor2","heading":"Electron configuration for","text":"It would appear that the electron configuration for would be But this is wrong! It's actually"},{"id":"howtos","heading":"How-tos","text":""},{"id":"whatarethevalenceelectrons","heading":"What are the valence electrons?","text":"Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons. Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons."},{"id":"quantumnumbers","heading":"Quantum Numbers","text":""},{"id":"overview2","heading":"Overview","text":"Symbol Description The principle quantum number The angular momentum quantum number The magnetic quantum number The spin quantum number"},{"id":"theprinciplequantumnumber","heading":"The Principle Quantum Number （）","text":"Value of Value of Orbital Sublevel"},{"id":"angularmomentumquantumnumber","heading":"Angular Momentum Quantum Number","text":"Value Result Value of Value of"},{"id":"summary","heading":"Summary","text":""},{"id":"usefulformulas","heading":"Useful Formulas","text":"The equation for a maximum number of electrons a given energy level can hold given some value for How many orbitals are possible given some value for"},{"id":"examples2","heading":"Examples","text":""},{"id":"light","heading":"Light","text":""},{"id":"interferenceanddiffraction","heading":"Interference and Diffraction","text":""},{"id":"constructiveinterference","heading":"Constructive Interference","text":"If two waves of equal amplitude are in phase when they interact—that is, they align with overlapping crests—a wave with twice the amplitude results. This is called constructive interference."},{"id":"destructiveinterference","heading":"Destructive Interference","text":"If two waves are completely out of phase when they interact—that is, they align so that the crest from one overlaps with the trough from the other—the waves cancel by destructive interference."}]},{"href":"/chem/atom-nuclear-chem/index.html","title":"The Atom and Nuclear Chemistry","sections":[{"id":"theatomandnuclearchemistry","heading":"The Atom and Nuclear Chemistry","text":""},{"id":"isotopesandsubatomicparticles","heading":"Isotopes and Subatomic Particles","text":""},{"id":"electronsandquantummechanics","heading":"Electrons and Quantum Mechanics","text":""},{"id":"averageatomicmass","heading":"Average Atomic Mass","text":"If the average atomic mass of boron is , what is the percent abundance of boron-11 （mass of ） if the only other isotope is boron-10 （mass of ）? Given the formula for average atomic mass: Therefore in summary, we are given the following known quantities With the following unknown quantities And asked to find the percent abundance of boron-11 （）. Therefore our equation is We have two unknowns, but luckily we can use the following fact/relation and therefore express the percent abundance of boron-10 in terms of the percent abundance of boron-11 Therefore But we aren't done, we have to compute significant figures. Therefore, we round our answer to 2 sig figs, yielding"}]},{"href":"/chem/basics/index.html","title":"Basics","sections":[{"id":"basics","heading":"Basics","text":"Given some element Where Cations Positively （＋） Charged Anions Negatively （−） Charged Mnemonic Cations are Pawsitive"},{"id":"conventionsonhomework","heading":"Conventions on homework","text":"When a question says, determine the energy of 1 ㏖ of photons, the unit will be ᴶ/㏖."},{"id":"units","heading":"Units","text":""},{"id":"siprefixes","heading":"SI Prefixes","text":"Value Prefix Symbol deca hecto kilo mega giga tera Value Prefix Symbol deci centi mili micro nano pico"},{"id":"classificationofmatter","heading":"Classification of Matter","text":""},{"id":"overview","heading":"Overview","text":""},{"id":"mixtures","heading":"Mixtures","text":""},{"id":"heterogeneousmixture","heading":"Heterogeneous mixture","text":"Where the prefix Hetero- means different"},{"id":"homogeneousmixture","heading":"Homogeneous mixture","text":"Where the prefix Homo- means same"}]},{"href":"/chem/molecules-compounds/index.html","title":"Molecules and Compounds","sections":[{"id":"moleculesandcompounds","heading":"Molecules and Compounds","text":""},{"id":"terms","heading":"Terms","text":"Electronegativity Can be used to to determine type of bond based on electronegativity difference. Ionic Bond Covalent Bond Polar Covalent Bond Sharing of electrons. Hydrogen Bond Hydrogen is bonded with an electronegative element."},{"id":"prefixes","heading":"Prefixes","text":"Prefix Value mono- di- tri- tetra- penta- Prefix Value hexa- hepta- octa- nona- deca-"},{"id":"formulasummary","heading":"Formula Summary","text":""},{"id":"formalchargeoption1","heading":"Formal Charge （Option 1）","text":"Given some element which has number of valance electrons, and a lewis diagram of bonded （） electrons and non-bonded （） electrons, the formal charge for the given element in the lewis structure is For the given quantities"},{"id":"formalchargeoption2","heading":"Formal Charge （Option 2）","text":"Given some element which has number of valance electrons, and a lewis diagram of some number of bonds （） and dots （）, the formal charge for the given element in the lewis structure is For the given quantities Note: each bond is 2 electrons, but counts as one bond."},{"id":"polyatomicions","heading":"Polyatomic Ions","text":""},{"id":"rememberingthenumberofoxygens","heading":"Remembering the number of oxygens","text":""},{"id":"general","heading":"General","text":"Given a set of oxygens in increasing order Where For example Given some element or compound ionically bonded with such For example The following table denotes the naming conventions therein Prefix Prefix Meaning （relative to suffix） Suffix Oxygen Order Example Formula Name Per- More than -ate Highest # Perchlorate -ate Higher # Chlorate -ite Lower # Chlorite Hypo- less than -ite Lowest # Hypochlorite"},{"id":"oxygenvsnooxygencomparison","heading":"Oxygen vs No-Oxygen Comparison","text":"Prefix Suffix Meaning Example Name Per- -ate or Persulfate -ate Sulfate -ite Sulfite Hypo- -ite Hyposulfite -ide No oxygennon-metal Sulfide In summary Per*ate Greater than *ate *ite Hypo*ite *ate Less than Per*ate Greater than *ite Hypo*ite *ite Less than Per*ate *ate Greater than Hypo*ite Hypo*ite Less than Per*ate *ate *ite"},{"id":"generally","heading":"Generally","text":"per-ate Used in the ion with the largest number of oxygen atoms -ate-ite Polyatomic ions of oxygen hypo-ite Used in the ion with the lowest number of oxygen atoms -ide Non-metal, no oxygens"},{"id":"determiningthecharge","heading":"Determining The Charge†","text":""},{"id":"warning","heading":"Warning","text":"Phosphate （） is the only one that violates this rule!"},{"id":"examplefornitrate","heading":"Example for Nitrate","text":""},{"id":"exampleforcyanide","heading":"Example for Cyanide","text":""},{"id":"exampleforoxalate","heading":"Example for Oxalate","text":""},{"id":"exampleforhydrogencarbonate","heading":"Example for Hydrogen Carbonate","text":"† Source"},{"id":"ioniclewisstructures","heading":"Ionic Lewis Structures","text":""},{"id":"ioniclewisstructures1","heading":"Ionic Lewis Structures","text":""},{"id":"examples","heading":"Examples","text":""},{"id":"lewisstructureforsulfateion","heading":"Lewis structure for sulfate ion","text":""},{"id":"notes","heading":"Notes","text":"When naming an ionic compound, the name of the cation is followed by the name of the anion. Monatomic anions are named with the ending -ide."}]},{"href":"/chem/periodic-properties/index.html","title":"Periodic Properties of the Elements","sections":[{"id":"periodicpropertiesoftheelements","heading":"Periodic Properties of the Elements","text":"Note, electron affinity is not the same as electronegativity!"},{"id":"terms","heading":"Terms","text":"Isoelectronic Atoms with the same number of electrons. Ionization energy Predict Metallic Character Based on Periodic Trends"},{"id":"expandedoctetexceptionstotheoctetrule","heading":"Expanded Octet （Exceptions to the Octet Rule）","text":"All non-metals from period 3 to period 8 of the Periodic Table, can have expanded octets."}]},{"href":"/chem/qa-model/index.html","title":"Quantum Mechanical Models of the Atom","sections":[{"id":"quantummechanicalmodelsoftheatom","heading":"Quantum Mechanical Models of the Atom","text":""},{"id":"theelectromagneticspectrum","heading":"The Electromagnetic Spectrum","text":""},{"id":"terms","heading":"Terms","text":"Pauli Exclusion Principle No two electrons in an atom can have the same four quantum numbers. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Aufbau Principle This pattern of orbital filling is known as the aufbau principle （the German word aufbau means “build up”）. Hund’s rule When filling degenerate orbitals, electrons fill them singly first, then with parallel spins. I.e. start by filling boxes with single 'upward' arrows, and then once all of such boxes are maxed out, then you add double arrows pointing in opposite directions. Pauli’s Principle prevents two electrons with the same spin from existing in the same subshell, each subshell will be filled with one spin direction before they are filled with the opposite spin. This is the second of Hund’s Rules. Coulomb’s Law"},{"id":"aufbauprinciple","heading":"Aufbau Principle","text":""},{"id":"hundsrule","heading":"Hund’s rule","text":""},{"id":"paulisexclusionprinciple","heading":"Pauli's Exclusion Principle","text":"Each election has a unique set of four quantum numbers （i.e. see quantum numbers）. They are"},{"id":"overview","heading":"Overview","text":""},{"id":"formulas","heading":"Formulas","text":""},{"id":"values","heading":"Values","text":"Name Symbol Unit Description Range Wavelength Any unit for distance Distance between two analogous points Always Positive Frequency or （nu） Number of cycles Always Positive Energy （joule） Amount of energy （） in a light packet"},{"id":"constants","heading":"Constants","text":"Name Symbol Unit Value Speed of Light Planck's constant Energy multiplied by time joule-seconds"},{"id":"otherformulas","heading":"Other Formulas","text":""},{"id":"debroglierelation","heading":"de Broglie Relation","text":""},{"id":"heisenbergsuncertaintyprinciple","heading":"Heisenberg's Uncertainty Principle","text":"Where is the uncertainty in position. is the uncertainty in velocity. is the mass of the particle. is the plank's constant. In general it states that the more you know about an electrons position, the less you know about it's velocity."},{"id":"energyofanelectroninanorbitalwithquantumnumberinahydrogenatom","heading":"Energy of an Electron in an Orbital with Quantum Number in a Hydrogen Atom","text":""},{"id":"energyofanelectroninanorbitalwithquantumnumberforanyatom","heading":"Energy of an Electron in an Orbital with Quantum Number for any atom","text":"Where is the atomic number of the given element."},{"id":"changeinenergythatoccursinanatomwhenitundergoesatransitionbetweenlevelsfurtherdetails","heading":"Change in Energy That Occurs in an Atom When It Undergoes a Transition between Levels （Further Details）","text":"and If is negative, energy is being released. If is positive, energy is being absorbed."},{"id":"ionizationenergy","heading":"Ionization Energy","text":"Where is the electron number the point on the table where you see the highest difference/delta."},{"id":"atomicspectroscopy","heading":"Atomic Spectroscopy","text":""},{"id":"theprincipalquantumnumbernhydrogenatom","heading":"The Principal Quantum Number （n） （Hydrogen Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by Therefore the difference in energy is given by the following"},{"id":"theprincipalquantumnumbernanyatom","heading":"The Principal Quantum Number （n） （Any Atom）","text":"For the hydrogen atom, the energy of an electron in an orbital with quantum number is given by TODO Where is the atomic number of the given element."},{"id":"electronconfiguration","heading":"Electron Configuration","text":""},{"id":"traditionalchart","heading":"Traditional Chart","text":""},{"id":"bettermethod","heading":"Better Method","text":""},{"id":"examples","heading":"Examples","text":""},{"id":"electronconfigurationfor","heading":"Electron configuration for","text":"Since the electron configuration for Argon is"},{"id":"electronconfigurationfor1","heading":"Electron configuration for","text":"Beginning with the electron configuration for Remove the electrons from the term with the higher electron state. Warning! Do not just remove the electrons from the rightmost term since the rightmost term may be a lower electron state. For instance given is in a higher electron state is in a lower electron state Alternatively, to compute the lowest energy orbital, add the principle quantum number （） to the The angular momentum quantum number （） to get the orbital with the lowest energy. Therefore Given and NVM this is an exception to the rule... TODO move this somewhere else... As shown Therefore the electron configuration for is:"},{"id":"electronconfigurationfor2","heading":"Electron configuration for","text":"It would appear that the electron configuration for would be But this is wrong! It's actually"},{"id":"howtos","heading":"How-tos","text":""},{"id":"whatarethevalenceelectrons","heading":"What are the valence electrons?","text":"Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons. Given The valance electrons will be the ones in the highest energy state. Therefore Therefore there are valence electrons."},{"id":"quantumnumbers","heading":"Quantum Numbers","text":""},{"id":"overview1","heading":"Overview","text":"Symbol Description The principle quantum number The angular momentum quantum number The magnetic quantum number The spin quantum number"},{"id":"theprinciplequantumnumber","heading":"The Principle Quantum Number （）","text":"Value of Value of Orbital Sublevel"},{"id":"angularmomentumquantumnumber","heading":"Angular Momentum Quantum Number","text":"Value Result Value of Value of"},{"id":"summary","heading":"Summary","text":""},{"id":"usefulformulas","heading":"Useful Formulas","text":"The equation for a maximum number of electrons a given energy level can hold given some value for How many orbitals are possible given some value for"},{"id":"examples1","heading":"Examples","text":""},{"id":"light","heading":"Light","text":""},{"id":"interferenceanddiffraction","heading":"Interference and Diffraction","text":""},{"id":"constructiveinterference","heading":"Constructive Interference","text":"If two waves of equal amplitude are in phase when they interact—that is, they align with overlapping crests—a wave with twice the amplitude results. This is called constructive interference."},{"id":"destructiveinterference","heading":"Destructive Interference","text":"If two waves are completely out of phase when they interact—that is, they align so that the crest from one overlaps with the trough from the other—the waves cancel by destructive interference."}]},{"href":"/math/index.html","title":"Mathematics","sections":[{"id":"mathematics","heading":"Mathematics","text":""},{"id":"algebra","heading":"Algebra","text":""},{"id":"miscellaneous","heading":"Miscellaneous","text":""},{"id":"functionalutilitiesnotationconveniences","heading":"Functional Utilities & Notation Conveniences","text":""},{"id":"righttoleftevaluation","heading":"Right to Left Evaluation","text":""},{"id":"lefttorightevaluation","heading":"Left to Right Evaluation","text":""},{"id":"derivativeshorthand","heading":"Derivative Shorthand","text":"For this notation, the derivative with respect to a given variable, is implicit."},{"id":"radiansradianconversion","heading":"Radians & Radian Conversion","text":""},{"id":"constants","heading":"Constants","text":""},{"id":"conversion","heading":"Conversion","text":""},{"id":"constants1","heading":"Constants","text":""},{"id":"ℯeulersnumber","heading":"ℯ （Euler's number）","text":""},{"id":"algebra1","heading":"Algebra","text":""},{"id":"properties","heading":"Properties","text":""},{"id":"trigonometry","heading":"Trigonometry","text":""},{"id":"theunitcirclespecialanglesintrig","heading":"The Unit Circle & Special Angles In Trig","text":""},{"id":"warning","heading":"Warning","text":"Never use Pi （）! It makes （thinking in terms of） radians confusing, Tao （） is what the enlightened trigonometer uses, and won't screw you over. To easily memorize the special angles in trig, notice the repeating patterns on the above angles. For values on the x-axis, anything over and under will be negative For values on the y-axis, anything over will be negative Diagonals will be , For ratios of on the sides, i.e. , , , , , , , and . Draw a circle and dot the point where it occurs （which is pretty easy since the above are simple ratios of a circle when expressed in terms of ）. Then with regards to the and axis values: The longer size will be The shorter side will be See the above examples."},{"id":"trigonometricidentities","heading":"Trigonometric Identities","text":""},{"id":"pythagoreanidentities","heading":"Pythagorean Identities","text":""},{"id":"sumanddifferenceidentities","heading":"Sum and Difference Identities","text":""},{"id":"cofunctionidentities","heading":"Cofunction Identities","text":""},{"id":"ratioidentities","heading":"Ratio Identities","text":""},{"id":"doubleangleidentities","heading":"Double-Angle Identities","text":""},{"id":"halfangleidentities","heading":"Half-Angle Identities","text":""},{"id":"powerreducingidentities","heading":"Power-Reducing Identities","text":""},{"id":"producttosumidentities","heading":"Product-to-Sum Identities","text":""},{"id":"sumtoproductidentities","heading":"Sum-to-Product-Identities","text":""},{"id":"trigonometricequations","heading":"Trigonometric Equations","text":""},{"id":"eulersformula","heading":"Euler's Formula","text":""},{"id":"coordinatenumbersystems","heading":"Coordinate & Number Systems","text":""},{"id":"polarcoordinatesystem","heading":"Polar Coordinate System","text":"Given Then"},{"id":"properties1","heading":"Properties","text":"Given Then De Moivre’s Theorem De Moivre’s Theorem For Finding Roots"},{"id":"trigonometricformofacomplexnumber","heading":"Trigonometric form of a complex number","text":""},{"id":"vectors","heading":"Vectors","text":""},{"id":"quickfacts","heading":"Quick Facts","text":"Two vectors are equal if they share the same magnitude and direction. Initial points don't matter. You can define a vector with two points. Do not divide vectors!"},{"id":"vectoroperations","heading":"Vector Operations","text":""},{"id":"dotproduct","heading":"Dot Product","text":""},{"id":"crossproduct","heading":"Cross Product","text":""},{"id":"lengthofavector","heading":"Length of a Vector","text":""},{"id":"definitionofvectoraddition","heading":"Definition of Vector Addition","text":"If and are positioned so the initial point of is at the terminal point of , then the sum is the vector from the initial point of to the terminal point of . Given some vectors and , the vector is the vector that points from the head of to the head of"},{"id":"standardbasisvectors","heading":"Standard Basis Vectors","text":""},{"id":"orthogonal","heading":"Orthogonal","text":"Two vectors are orthogonal if and only if"},{"id":"theunitvector","heading":"The Unit Vector","text":""},{"id":"ifistheanglebetweenthevectorsandthen","heading":"If is the angle between the vectors and , then","text":""},{"id":"ifistheanglebetweenthenonzerovectorsandthen","heading":"If is the angle between the nonzero vectors and , then","text":""},{"id":"twononzerovectorsandareparallelifandonlyif","heading":"Two nonzero vectors and are parallel if and only if","text":""},{"id":"propertiesofthedotproduct","heading":"Properties of the Dot Product","text":""},{"id":"directioncosinesdirectionanglesofavector","heading":"Direction Cosines & Direction Angles of a Vector","text":"Where"},{"id":"directioncosines","heading":"Direction Cosines","text":""},{"id":"directionangles","heading":"Direction Angles","text":""},{"id":"theorem","heading":"Theorem","text":""},{"id":"proof","heading":"Proof","text":"Given Therefore"},{"id":"vectorrelations","heading":"Vector Relations","text":""},{"id":"parallelvectors","heading":"Parallel Vectors","text":"When two vectors are parallel; they never intersect （duh）. Given some vectors The vectors and are parallel if and only if they are scalar multiples of one another."},{"id":"alternatively","heading":"Alternatively","text":""},{"id":"orthogonalvectors","heading":"Orthogonal Vectors","text":"When two vectors are orthogonal; they meet at right angles. Given some vectors Two vectors are orthogonal if and only if"},{"id":"reparameterizationofthepositionvectorintermsoflength","heading":"Reparameterization of the position vector in terms of length","text":"We can parametrize a curve with respect to arc length; because arc length arises naturally from the shape of the curve and does not depend on any coordinate system."},{"id":"thearclengthfunction","heading":"The Arc Length Function","text":"Given We can redefine in terms of arc length between two endpoints That is, is the length of the curve （） between and . Furthermore from the adjacent definition; we can simply the above to"},{"id":"thearclengthfunction1","heading":"The Arc Length Function","text":"That is"},{"id":"vectorsderivedfromsomecurvedefinedby","heading":"Vectors Derived From Some Curve Defined by","text":""},{"id":"theunitvector1","heading":"The Unit Vector","text":""},{"id":"theunittangentvector","heading":"The Unit Tangent Vector","text":""},{"id":"theunitnormalvector","heading":"The Unit Normal Vector","text":""},{"id":"thebinormalvector","heading":"The Binormal Vector","text":"Therefore, the binormal vector is orthogonal to both the tangent vector and the normal vector. The plane determined by the normal and binormal vectors N and B at a point P on a curve C is called the normal plane of C at P. The plane determined by the vectors T and N is called the osculating plane of C at P. The name comes from the Latin osculum, meaning “kiss.” It is the plane that comes closest to containing the part of the curve near P. （For a plane curve, the osculating plane is simply the plane that contains the curve.）"},{"id":"kappacurvatureofavector","heading":"Kappa - Curvature of a Vector","text":""},{"id":"tangentialnormalcomponentsoftheaccelerationvectorofthecurve","heading":"Tangential & Normal Components of the Acceleration Vector of the Curve","text":"When we study the motion of a particle, it is often useful to resolve the acceleration into two components, one in the direction of the tangent and the other in the direction of the normal. Specifically"},{"id":"vectorcalculus","heading":"Vector Calculus","text":""},{"id":"thepositionvector","heading":"The Position Vector","text":"（Original Function）"},{"id":"thevelocityvector","heading":"The Velocity Vector","text":"（First Derivative） The velocity vector is also the tangent vector and points in the direction of the tangent line. The speed of the particle at time t is the magnitude of the velocity vector, that is,"},{"id":"theaccelerationvector","heading":"The Acceleration Vector","text":"（Second Derivative）"},{"id":"matrices","heading":"Matrices","text":""},{"id":"reference","heading":"Reference","text":""},{"id":"thedeterminantofamatrix","heading":"The Determinant of A Matrix","text":"Only works for square matrices."},{"id":"thecrossproduct","heading":"The Cross Product","text":""},{"id":"geometry","heading":"Geometry","text":""},{"id":"thecircle","heading":"The Circle","text":""},{"id":"definitionofaline","heading":"Definition of a Line","text":""},{"id":"vectorequationofaline","heading":"Vector Equation of a Line","text":"Given We can define a vector between and"},{"id":"therefore","heading":"Therefore","text":"The equation of a line in 3D space or can be defined VIA the following options That is"},{"id":"parametricequationofaline","heading":"Parametric Equation of a Line","text":""},{"id":"essentially","heading":"Essentially","text":"That is, is the scaling factor. In a way, it's like it's a function of , but also similar to the slope （） in , except （i.e. ） is parameterized. Sometimes this will be （confusingly） denoted as"},{"id":"symmetricequationofaline","heading":"Symmetric Equation of a Line","text":"Therefore"},{"id":"rationale","heading":"Rationale","text":"We rewrite in terms of . That is"},{"id":"parameterizationsofacurve","heading":"Parameterizations of a curve","text":"Parametrized curve A curve in the plane is said to be parameterized if the set of coordinates on the curve, （x,y）, are represented as functions of a variable t. A parametrized Curve is a path in the xy-plane traced out by the point as the parameter ranges over an interval . A parametrized Curve is a path in the xyz-plane traced out by the point as the parameter ranges over an interval ."},{"id":"curvatureproperties","heading":"Curvature Properties","text":""},{"id":"lengthofacurve","heading":"Length of a Curve","text":""},{"id":"thearclengthfunction2","heading":"The Arc Length Function","text":"Suppose Given some curve defined by some vector in where is continuous and is traversed exactly once as increases from to We can define it's arc length function VIA *"},{"id":"calculus","heading":"Calculus","text":""},{"id":"derivativetables","heading":"Derivative Tables","text":""},{"id":"integrationtables","heading":"Integration Tables","text":""},{"id":"riemannsums","heading":"Riemann Sums","text":"Given"},{"id":"leftriemannsum","heading":"Left Riemann Sum","text":""},{"id":"rightriemannsum","heading":"Right Riemann Sum","text":""},{"id":"midpointriemannsum","heading":"Midpoint Riemann Sum","text":"We can also do away with the index notation and simplify things."},{"id":"trapezoidalriemannsum","heading":"Trapezoidal Riemann Sum","text":""},{"id":"simpsonsrule","heading":"Simpson's Rule","text":""},{"id":"improperintegrals","heading":"Improper Integrals","text":""},{"id":"infinitesequences","heading":"Infinite Sequences","text":""},{"id":"infinitesequence","heading":"Infinite Sequence","text":""},{"id":"helpfultheorem","heading":"Helpful Theorem","text":""},{"id":"example","heading":"Example","text":"Given Therefore"},{"id":"example1","heading":"Example","text":"Given Therefore"},{"id":"infiniteseries","heading":"Infinite Series","text":""},{"id":"infiniteseries1","heading":"Infinite Series","text":"Note that the limit of every convergent series is equal to zero. But the inverse isn't always true. If the limit is equal to zero, it may not be convergent. For example, does diverge; but it's limit is equal to zero. If the limit is equal to zero; the test is inconclusive."},{"id":"geometricseries","heading":"Geometric Series","text":"Given Alternatively Tests Furthermore"},{"id":"theintegraltest","heading":"The Integral Test","text":""},{"id":"constraintson","heading":"Constraints on","text":"Continuous Positive Decreasing （i.e. use derivative test）"},{"id":"pseriesorharmonicseries","heading":"P-Series -or- Harmonic Series","text":"Note: the Harmonic series is the special case where"},{"id":"comparisontest","heading":"Comparison Test","text":""},{"id":"limitcomparisontest","heading":"Limit Comparison Test","text":""},{"id":"warning1","heading":"Warning","text":"If , this only means that the limit comparison test can be used. You still need to determine if either or converges or diverges. Therefore, this does not apply to any arbitrary rational function."},{"id":"notes","heading":"Notes","text":"For many series, we find a suitable comparison, , by keeping only the highest powers in the numerator and denominator of ."},{"id":"estimatinginfiniteseries","heading":"Estimating Infinite Series","text":""},{"id":"differentialequations","heading":"Differential Equations","text":""},{"id":"separabledifferentialequations","heading":"Separable Differential Equations","text":""},{"id":"growthanddecaymodels","heading":"Growth and Decay Models","text":"The above states that all solutions for are of the form . Where Exponential growth occurs when , and exponential decay occurs when . The Law of Natural Growth: The Logistic Model of Population Growth: Where"},{"id":"solvingthelogisticequation","heading":"Solving the Logistic Equation","text":"Via partial fraction decomposition Rewriting the differential equation"},{"id":"secondorderhomogeneouslineardifferentialequationswithconstantcoefficients","heading":"Second Order Homogeneous Linear Differential Equations with Constant Coefficients","text":""},{"id":"properties2","heading":"Properties","text":"If and are solutions; then is also a solution. Therefore, the most general solution to some second order homogeneous linear differential equations with constant coefficients would be . This is why, when you find two solutions to the characteristic equation and respectively, we write it like so."},{"id":"","heading":"","text":"Given some: We can presume that is of the form , and therefore: Substituting this back into the original equation, we have: Where: So therefore: Where the general solution is of the form:"},{"id":"parametricequations","heading":"Parametric Equations","text":""},{"id":"firstderivativeformula","heading":"First Derivative Formula","text":"To find the derivative of a given function defined parametrically by the equations and ."},{"id":"secondderivativeformula","heading":"Second Derivative Formula","text":"To find the second derivative of a given function defined parametrically by the equations and . Given Therefore The above shows different ways of representing . （I.e. it doesn't correspond to some final solution.）"},{"id":"arclength","heading":"Arc Length","text":"Formula for the arc length of a parametric curve over the interval ."}]},{"href":"/math/algebra/index.html","title":"Algebra","sections":[{"id":"algebra","heading":"Algebra","text":""},{"id":"miscellaneous","heading":"Miscellaneous","text":""},{"id":"functionalutilitiesnotationconveniences","heading":"Functional Utilities & Notation Conveniences","text":""},{"id":"righttoleftevaluation","heading":"Right to Left Evaluation","text":""},{"id":"lefttorightevaluation","heading":"Left to Right Evaluation","text":""},{"id":"derivativeshorthand","heading":"Derivative Shorthand","text":"For this notation, the derivative with respect to a given variable, is implicit."},{"id":"radiansradianconversion","heading":"Radians & Radian Conversion","text":""},{"id":"constants","heading":"Constants","text":""},{"id":"conversion","heading":"Conversion","text":""},{"id":"constants1","heading":"Constants","text":""},{"id":"ℯeulersnumber","heading":"ℯ （Euler's number）","text":""},{"id":"algebra1","heading":"Algebra","text":""},{"id":"properties","heading":"Properties","text":""}]},{"href":"/math/calc/index.html","title":"Calculus","sections":[{"id":"calculus","heading":"Calculus","text":""},{"id":"derivativetables","heading":"Derivative Tables","text":""},{"id":"integrationtables","heading":"Integration Tables","text":""},{"id":"riemannsums","heading":"Riemann Sums","text":"Given"},{"id":"leftriemannsum","heading":"Left Riemann Sum","text":""},{"id":"rightriemannsum","heading":"Right Riemann Sum","text":""},{"id":"midpointriemannsum","heading":"Midpoint Riemann Sum","text":"We can also do away with the index notation and simplify things."},{"id":"trapezoidalriemannsum","heading":"Trapezoidal Riemann Sum","text":""},{"id":"simpsonsrule","heading":"Simpson's Rule","text":""},{"id":"improperintegrals","heading":"Improper Integrals","text":""},{"id":"infinitesequences","heading":"Infinite Sequences","text":""},{"id":"infinitesequence","heading":"Infinite Sequence","text":""},{"id":"helpfultheorem","heading":"Helpful Theorem","text":""},{"id":"example","heading":"Example","text":"Given Therefore"},{"id":"example1","heading":"Example","text":"Given Therefore"},{"id":"infiniteseries","heading":"Infinite Series","text":""},{"id":"infiniteseries1","heading":"Infinite Series","text":"Note that the limit of every convergent series is equal to zero. But the inverse isn't always true. If the limit is equal to zero, it may not be convergent. For example, does diverge; but it's limit is equal to zero. If the limit is equal to zero; the test is inconclusive."},{"id":"geometricseries","heading":"Geometric Series","text":"Given Alternatively Tests Furthermore"},{"id":"theintegraltest","heading":"The Integral Test","text":""},{"id":"constraintson","heading":"Constraints on","text":"Continuous Positive Decreasing （i.e. use derivative test）"},{"id":"pseriesorharmonicseries","heading":"P-Series -or- Harmonic Series","text":"Note: the Harmonic series is the special case where"},{"id":"comparisontest","heading":"Comparison Test","text":""},{"id":"limitcomparisontest","heading":"Limit Comparison Test","text":""},{"id":"warning","heading":"Warning","text":"If , this only means that the limit comparison test can be used. You still need to determine if either or converges or diverges. Therefore, this does not apply to any arbitrary rational function."},{"id":"notes","heading":"Notes","text":"For many series, we find a suitable comparison, , by keeping only the highest powers in the numerator and denominator of ."},{"id":"estimatinginfiniteseries","heading":"Estimating Infinite Series","text":""},{"id":"differentialequations","heading":"Differential Equations","text":""},{"id":"separabledifferentialequations","heading":"Separable Differential Equations","text":""},{"id":"growthanddecaymodels","heading":"Growth and Decay Models","text":"The above states that all solutions for are of the form . Where Exponential growth occurs when , and exponential decay occurs when . The Law of Natural Growth: The Logistic Model of Population Growth: Where"},{"id":"solvingthelogisticequation","heading":"Solving the Logistic Equation","text":"Via partial fraction decomposition Rewriting the differential equation"},{"id":"secondorderhomogeneouslineardifferentialequationswithconstantcoefficients","heading":"Second Order Homogeneous Linear Differential Equations with Constant Coefficients","text":""},{"id":"properties","heading":"Properties","text":"If and are solutions; then is also a solution. Therefore, the most general solution to some second order homogeneous linear differential equations with constant coefficients would be . This is why, when you find two solutions to the characteristic equation and respectively, we write it like so."},{"id":"","heading":"","text":"Given some: We can presume that is of the form , and therefore: Substituting this back into the original equation, we have: Where: So therefore: Where the general solution is of the form:"},{"id":"parametricequations","heading":"Parametric Equations","text":""},{"id":"firstderivativeformula","heading":"First Derivative Formula","text":"To find the derivative of a given function defined parametrically by the equations and ."},{"id":"secondderivativeformula","heading":"Second Derivative Formula","text":"To find the second derivative of a given function defined parametrically by the equations and . Given Therefore The above shows different ways of representing . （I.e. it doesn't correspond to some final solution.）"},{"id":"arclength","heading":"Arc Length","text":"Formula for the arc length of a parametric curve over the interval ."}]},{"href":"/math/pre-calc/index.html","title":"Pre-Calculus","sections":[{"id":"precalculus","heading":"Pre-Calculus","text":""},{"id":"limits","heading":"Limits","text":""},{"id":"lhospitalsrule","heading":"L’Hospital’s Rule","text":"In other words, if L’Hospital’s Rule applies to indeterminate forms."},{"id":"limitlaws","heading":"Limit Laws","text":""},{"id":"limitformulas","heading":"Limit Formulas","text":""},{"id":"functiongraphs","heading":"Function Graphs","text":""},{"id":"example","heading":"Example","text":""},{"id":"example1","heading":"Example","text":""},{"id":"example2","heading":"Example","text":""},{"id":"growthrates","heading":"Growth Rates","text":"Factorial functions grow faster than exponential functions. Exponential functions grow faster than polynomials."}]},{"href":"/math/trig/index.html","title":"Trigonometry","sections":[{"id":"trigonometry","heading":"Trigonometry","text":""},{"id":"theunitcirclespecialanglesintrig","heading":"The Unit Circle & Special Angles In Trig","text":""},{"id":"warning","heading":"Warning","text":"Never use Pi （）! It makes （thinking in terms of） radians confusing, Tao （） is what the enlightened trigonometer uses, and won't screw you over. To easily memorize the special angles in trig, notice the repeating patterns on the above angles. For values on the x-axis, anything over and under will be negative For values on the y-axis, anything over will be negative Diagonals will be , For ratios of on the sides, i.e. , , , , , , , and . Draw a circle and dot the point where it occurs （which is pretty easy since the above are simple ratios of a circle when expressed in terms of ）. Then with regards to the and axis values: The longer size will be The shorter side will be See the above examples."},{"id":"trigonometricidentities","heading":"Trigonometric Identities","text":""},{"id":"pythagoreanidentities","heading":"Pythagorean Identities","text":""},{"id":"sumanddifferenceidentities","heading":"Sum and Difference Identities","text":""},{"id":"cofunctionidentities","heading":"Cofunction Identities","text":""},{"id":"ratioidentities","heading":"Ratio Identities","text":""},{"id":"doubleangleidentities","heading":"Double-Angle Identities","text":""},{"id":"halfangleidentities","heading":"Half-Angle Identities","text":""},{"id":"powerreducingidentities","heading":"Power-Reducing Identities","text":""},{"id":"producttosumidentities","heading":"Product-to-Sum Identities","text":""},{"id":"sumtoproductidentities","heading":"Sum-to-Product-Identities","text":""},{"id":"trigonometricequations","heading":"Trigonometric Equations","text":""},{"id":"eulersformula","heading":"Euler's Formula","text":""},{"id":"coordinatenumbersystems","heading":"Coordinate & Number Systems","text":""},{"id":"polarcoordinatesystem","heading":"Polar Coordinate System","text":"Given Then"},{"id":"properties","heading":"Properties","text":"Given Then De Moivre’s Theorem De Moivre’s Theorem For Finding Roots"},{"id":"trigonometricformofacomplexnumber","heading":"Trigonometric form of a complex number","text":""},{"id":"vectors","heading":"Vectors","text":""},{"id":"quickfacts","heading":"Quick Facts","text":"Two vectors are equal if they share the same magnitude and direction. Initial points don't matter. You can define a vector with two points. Do not divide vectors!"},{"id":"vectoroperations","heading":"Vector Operations","text":""},{"id":"dotproduct","heading":"Dot Product","text":""},{"id":"crossproduct","heading":"Cross Product","text":""},{"id":"lengthofavector","heading":"Length of a Vector","text":""},{"id":"definitionofvectoraddition","heading":"Definition of Vector Addition","text":"If and are positioned so the initial point of is at the terminal point of , then the sum is the vector from the initial point of to the terminal point of . Given some vectors and , the vector is the vector that points from the head of to the head of"},{"id":"standardbasisvectors","heading":"Standard Basis Vectors","text":""},{"id":"orthogonal","heading":"Orthogonal","text":"Two vectors are orthogonal if and only if"},{"id":"theunitvector","heading":"The Unit Vector","text":""},{"id":"ifistheanglebetweenthevectorsandthen","heading":"If is the angle between the vectors and , then","text":""},{"id":"ifistheanglebetweenthenonzerovectorsandthen","heading":"If is the angle between the nonzero vectors and , then","text":""},{"id":"twononzerovectorsandareparallelifandonlyif","heading":"Two nonzero vectors and are parallel if and only if","text":""},{"id":"propertiesofthedotproduct","heading":"Properties of the Dot Product","text":""},{"id":"directioncosinesdirectionanglesofavector","heading":"Direction Cosines & Direction Angles of a Vector","text":"Where"},{"id":"directioncosines","heading":"Direction Cosines","text":""},{"id":"directionangles","heading":"Direction Angles","text":""},{"id":"theorem","heading":"Theorem","text":""},{"id":"proof","heading":"Proof","text":"Given Therefore"},{"id":"vectorrelations","heading":"Vector Relations","text":""},{"id":"parallelvectors","heading":"Parallel Vectors","text":"When two vectors are parallel; they never intersect （duh）. Given some vectors The vectors and are parallel if and only if they are scalar multiples of one another."},{"id":"alternatively","heading":"Alternatively","text":""},{"id":"orthogonalvectors","heading":"Orthogonal Vectors","text":"When two vectors are orthogonal; they meet at right angles. Given some vectors Two vectors are orthogonal if and only if"},{"id":"reparameterizationofthepositionvectorintermsoflength","heading":"Reparameterization of the position vector in terms of length","text":"We can parametrize a curve with respect to arc length; because arc length arises naturally from the shape of the curve and does not depend on any coordinate system."},{"id":"thearclengthfunction","heading":"The Arc Length Function","text":"Given We can redefine in terms of arc length between two endpoints That is, is the length of the curve （） between and . Furthermore from the adjacent definition; we can simply the above to"},{"id":"thearclengthfunction1","heading":"The Arc Length Function","text":"That is"},{"id":"vectorsderivedfromsomecurvedefinedby","heading":"Vectors Derived From Some Curve Defined by","text":""},{"id":"theunitvector1","heading":"The Unit Vector","text":""},{"id":"theunittangentvector","heading":"The Unit Tangent Vector","text":""},{"id":"theunitnormalvector","heading":"The Unit Normal Vector","text":""},{"id":"thebinormalvector","heading":"The Binormal Vector","text":"Therefore, the binormal vector is orthogonal to both the tangent vector and the normal vector. The plane determined by the normal and binormal vectors N and B at a point P on a curve C is called the normal plane of C at P. The plane determined by the vectors T and N is called the osculating plane of C at P. The name comes from the Latin osculum, meaning “kiss.” It is the plane that comes closest to containing the part of the curve near P. （For a plane curve, the osculating plane is simply the plane that contains the curve.）"},{"id":"kappacurvatureofavector","heading":"Kappa - Curvature of a Vector","text":""},{"id":"tangentialnormalcomponentsoftheaccelerationvectorofthecurve","heading":"Tangential & Normal Components of the Acceleration Vector of the Curve","text":"When we study the motion of a particle, it is often useful to resolve the acceleration into two components, one in the direction of the tangent and the other in the direction of the normal. Specifically"},{"id":"vectorcalculus","heading":"Vector Calculus","text":""},{"id":"thepositionvector","heading":"The Position Vector","text":"（Original Function）"},{"id":"thevelocityvector","heading":"The Velocity Vector","text":"（First Derivative） The velocity vector is also the tangent vector and points in the direction of the tangent line. The speed of the particle at time t is the magnitude of the velocity vector, that is,"},{"id":"theaccelerationvector","heading":"The Acceleration Vector","text":"（Second Derivative）"},{"id":"matrices","heading":"Matrices","text":""},{"id":"reference","heading":"Reference","text":""},{"id":"thedeterminantofamatrix","heading":"The Determinant of A Matrix","text":"Only works for square matrices."},{"id":"thecrossproduct","heading":"The Cross Product","text":""},{"id":"geometry","heading":"Geometry","text":""},{"id":"thecircle","heading":"The Circle","text":""},{"id":"definitionofaline","heading":"Definition of a Line","text":""},{"id":"vectorequationofaline","heading":"Vector Equation of a Line","text":"Given We can define a vector between and"},{"id":"therefore","heading":"Therefore","text":"The equation of a line in 3D space or can be defined VIA the following options That is"},{"id":"parametricequationofaline","heading":"Parametric Equation of a Line","text":""},{"id":"essentially","heading":"Essentially","text":"That is, is the scaling factor. In a way, it's like it's a function of , but also similar to the slope （） in , except （i.e. ） is parameterized. Sometimes this will be （confusingly） denoted as"},{"id":"symmetricequationofaline","heading":"Symmetric Equation of a Line","text":"Therefore"},{"id":"rationale","heading":"Rationale","text":"We rewrite in terms of . That is"},{"id":"parameterizationsofacurve","heading":"Parameterizations of a curve","text":"Parametrized curve A curve in the plane is said to be parameterized if the set of coordinates on the curve, （x,y）, are represented as functions of a variable t. A parametrized Curve is a path in the xy-plane traced out by the point as the parameter ranges over an interval . A parametrized Curve is a path in the xyz-plane traced out by the point as the parameter ranges over an interval ."},{"id":"curvatureproperties","heading":"Curvature Properties","text":""},{"id":"lengthofacurve","heading":"Length of a Curve","text":""},{"id":"thearclengthfunction2","heading":"The Arc Length Function","text":"Suppose Given some curve defined by some vector in where is continuous and is traversed exactly once as increases from to We can define it's arc length function VIA *"}]},{"href":"/physics/index.html","title":"Physics","sections":[{"id":"physics","heading":"Physics","text":""},{"id":"prelude","heading":"Prelude","text":""},{"id":"constants","heading":"Constants","text":""},{"id":"tables","heading":"Tables","text":""},{"id":"linearkinematicequations1d","heading":"Linear Kinematic Equations （1D）","text":"Formula Missing Quantities Present"},{"id":"rotationalkinematicequations","heading":"Rotational Kinematic Equations","text":"Formula Missing Quantities Present"},{"id":"scalarorvector","heading":"Scalar or Vector","text":"Name Type Speed Scalar Distance Scalar Velocity Vector Acceleration Vector Average Velocity Vector Displacement Vector Position Vector"},{"id":"kinematicequationsin1d","heading":"Kinematic Equations in 1D","text":""},{"id":"conventions","heading":"Conventions","text":""},{"id":"graphicalrepresentation","heading":"Graphical Representation","text":""},{"id":"standardequations","heading":"Standard Equations","text":""},{"id":"summary","heading":"Summary","text":"Formula Missing Quantities Present"},{"id":"basics","heading":"Basics","text":""},{"id":"constantvelocity","heading":"Constant Velocity","text":""},{"id":"uniformacceleration","heading":"Uniform acceleration","text":""},{"id":"miscellaneous","heading":"Miscellaneous","text":""},{"id":"derivingdisplacementformulas","heading":"Deriving Displacement Formulas","text":""},{"id":"displacementwhenobjectmoveswithconstantvelocity","heading":"Displacement when object moves with constant velocity","text":""},{"id":"deriving","heading":"Deriving","text":""},{"id":"displacementwhenobjectacceleratesfromrest","heading":"Displacement when object accelerates from rest","text":""},{"id":"deriving1","heading":"Deriving","text":""},{"id":"displacementwhenobjectaccelerateswithinitialvelocity","heading":"Displacement when object accelerates with initial velocity","text":""},{"id":"deriving2","heading":"Deriving","text":""},{"id":"derivingtheotherkinematicformulas","heading":"Deriving The Other Kinematic Formulas","text":""},{"id":"deriving3","heading":"Deriving","text":"Given We can rearrange from equation （1） like so Therefore"},{"id":"deriving4","heading":"Deriving","text":"Given from equation （1） can be rearranged as from equation （2） can be rearranged like so Using the following equations from above from equation （3） from equation （4） Rearranging equation （5） Rearrange again to obtain the more common form TODO"},{"id":"twodimensionalprojectilemotion","heading":"Two-dimensional Projectile Motion","text":""},{"id":"conventions1","heading":"Conventions","text":""},{"id":"summary1","heading":"Summary","text":"It's easy to see in the above visualization that and increase linearly, while is non-linear."},{"id":"formulas","heading":"Formulas","text":""},{"id":"displacementprojectileposition","heading":"Displacement & Projectile Position","text":""},{"id":"generalized","heading":"Generalized","text":"In general （without respect to any or axis values） Where the distance traveled or displaced is"},{"id":"intermsofandaxisvalues","heading":"In terms of and axis values","text":""},{"id":"withrespecttotheaxis","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Since Which can be read as （in terms of the axis）"},{"id":"withrespecttotheaxis1","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Note that （because there is no force acting on the projectile in the horizontal direction）, and therefore the initial and final velocities are the same. I.e. it's constant throughout. Therefore in summary and therefore we will simple refer to the velocity vector as as . Therefore we can simplify equation （1） considerably"},{"id":"solvingprojectilemotionproblems","heading":"Solving Projectile Motion Problems","text":""},{"id":"projectilemotion","heading":"Projectile Motion","text":""},{"id":"intermsoftheaxis","heading":"In terms of the axis","text":"TODO TODO"},{"id":"intermsoftheaxis1","heading":"In terms of the axis","text":"TODO TODO"},{"id":"insummary","heading":"In Summary","text":"Initial Quantities Derived expressions Solutions"},{"id":"projectilemotionfromaninitialheightwithgiveninitialvelocityandangle","heading":"Projectile Motion from an initial height, with given initial velocity and angle","text":"Given A projectile angle The initial height The initial velocity We can therefore derive the the initial velocities for and in terms of the given angle and initial velocity. Given the general formulas for displacement and velocity Which this information, we will derive specific equations in terms of the and axes governing the projectile."},{"id":"intermsoftheaxis2","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts From the given depiction of the problem, we know that . There is no acceleration along the axis, so . as shown above. Therefore"},{"id":"derivingvelocity","heading":"Deriving velocity","text":""},{"id":"intermsoftheaxis3","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime1","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts Initial height is given to us which we will represent as , for the sake of generality. Acceleration along the axis is the constant for gravity, so . as shown above. Therefore"},{"id":"derivingvelocity1","heading":"Deriving velocity","text":""},{"id":"insummary1","heading":"In summary","text":""},{"id":"tofindtherange","heading":"To find the range","text":"We know that at the moment of impact , therefore we can use equation Rearranging a bit and setting , we can see that solving for will yield the time at which . Therefore Plugging the solution for （and ignoring the negative or non-real solutions for ） into will yield the horizontal displacement （range） at the time . Therefore:"},{"id":"tofindthemaximumverticaldisplacementiepeakheight","heading":"To find the maximum vertical displacement （i.e. peak height）","text":"We begin with equation We know that at the moment our projectile crests its trajectory, the vertical component of our projectile will be zero. Therefore . To find the time, we simply solve for . Therefore, knowing the time at which our projectile crests its trajectory, we simply plugin our solution for into the function given in equation . I.e:"},{"id":"tofindthevelocityatagivenmomentoftime","heading":"To find the velocity at a given moment of time","text":"Given some time which we will denote as , to find the velocity we simply plug in our given values for and into equations and . I.e. With the given value for , yielding the vector at time , which we will denote as To define the vector in terms of engineering notation, （i.e. ） To define the vector in terms of magnitude （which we will denote as ） and direction （which we will denote as ）"},{"id":"range","heading":"Range","text":"The distance a projectile travels is called its range. Only applies in situations where the projectile lands at the same elevation from which it was fired."},{"id":"reasoningaboutprojectilemotion","heading":"Reasoning About Projectile Motion","text":""},{"id":"notes","heading":"Notes","text":"An object is in free fall when the only force acting on it is the force of gravity."},{"id":"question","heading":"Question","text":"Based on the figure, for which trajectory was the object in the air for the greatest amount of time?"},{"id":"answer","heading":"Answer","text":"Trajectory A"},{"id":"explanation","heading":"Explanation","text":"All that matters is the vertical height of the trajectory, which is based on the component of the initial velocity in the vertical direction （）. The higher the trajectory, the more time the object will be in the air, regardless of the object's range or horizontal velocity."},{"id":"problems","heading":"Problems","text":"The function in this graph represents an object that is speeding up, or accelerating at a constant rate. When you throw a ball directly upward, what is true about its acceleration after the ball has left your hand? Answer: The ball’s acceleration is always directed downward. Wrong: The ball’s acceleration is always directed downward, except at the top of the motion, where the acceleration is zero. Question As an object moves in the x-y plane, which statement is true about the object’s instantaneous velocity at a given moment? Answer The instantaneous velocity is tangent to the object’s path Wrong The instantaneous velocity is perpendicular to the object’s path. The instantaneous velocity can point in any direction, independent of the object’s path. Explanation As an object moves in the x-y plane the instantaneous velocity is tangent to the object‘s path at a given moment. This is because the displacement vector during an infinitesimally small time interval is always directed along the object’s path and the velocity vector always has the same direction as the displacement vector."},{"id":"relativemotion","heading":"Relative Motion","text":""},{"id":"galileantransformationofvelocity","heading":"Galilean transformation of velocity","text":"The velocity of some object P as seen from a stationary frame must be the sum of and Where Symbol Description Velocity as measured in a stationary frame Velocity of an object measured in the moving frame relative to the moving frame velocity of the moving frame - with respect to the stationary frame"},{"id":"galileantransformationofvelocityalternatenotation","heading":"Galilean transformation of velocity （alternate notation）","text":"Given two reference frames A and and some object . The velocity of the object can be defined in terms of or as shown Symbol Description The velocity of relative to The velocity of relative to The velocity of relative to The velocity of relative to . It locates the origin of relative to the origin of . Therefore"},{"id":"rotationalmotionkinematics","heading":"Rotational Motion & Kinematics","text":""},{"id":"basics1","heading":"Basics","text":""},{"id":"auxiliaryformulareference","heading":"Auxiliary Formula Reference","text":"Formula Missing Quantities Present A particle moves with uniform circular motion if and only if its angular velocity V is constant and unchanging."},{"id":"uniformcircularmotion","heading":"Uniform Circular Motion","text":"Uniform means content speed"},{"id":"forcesandnewtonslawsofmotion","heading":"Forces and Newton's laws of motion","text":""},{"id":"newtonslawsofmotion","heading":"Newton's laws of motion","text":""},{"id":"normalforceandcontactforce","heading":"Normal force and contact force","text":""},{"id":"balancedandunbalancedforces","heading":"Balanced and unbalanced forces","text":""},{"id":"inclinedplanesandfriction","heading":"Inclined planes and friction","text":""},{"id":"tension","heading":"Tension","text":""}]},{"href":"/physics/1d-motion/index.html","title":"Kinematic Equations in 1D","sections":[{"id":"kinematicequationsin1d","heading":"Kinematic Equations in 1D","text":""},{"id":"conventions","heading":"Conventions","text":""},{"id":"graphicalrepresentation","heading":"Graphical Representation","text":""},{"id":"standardequations","heading":"Standard Equations","text":""},{"id":"summary","heading":"Summary","text":"Formula Missing Quantities Present"},{"id":"basics","heading":"Basics","text":""},{"id":"constantvelocity","heading":"Constant Velocity","text":""},{"id":"uniformacceleration","heading":"Uniform acceleration","text":""},{"id":"miscellaneous","heading":"Miscellaneous","text":""},{"id":"derivingdisplacementformulas","heading":"Deriving Displacement Formulas","text":""},{"id":"displacementwhenobjectmoveswithconstantvelocity","heading":"Displacement when object moves with constant velocity","text":""},{"id":"deriving","heading":"Deriving","text":""},{"id":"displacementwhenobjectacceleratesfromrest","heading":"Displacement when object accelerates from rest","text":""},{"id":"deriving1","heading":"Deriving","text":""},{"id":"displacementwhenobjectaccelerateswithinitialvelocity","heading":"Displacement when object accelerates with initial velocity","text":""},{"id":"deriving2","heading":"Deriving","text":""},{"id":"derivingtheotherkinematicformulas","heading":"Deriving The Other Kinematic Formulas","text":""},{"id":"deriving3","heading":"Deriving","text":"Given We can rearrange from equation （1） like so Therefore"},{"id":"deriving4","heading":"Deriving","text":"Given from equation （1） can be rearranged as from equation （2） can be rearranged like so Using the following equations from above from equation （3） from equation （4） Rearranging equation （5） Rearrange again to obtain the more common form TODO"}]},{"href":"/physics/2d-motion/index.html","title":"Two-dimensional Projectile Motion","sections":[{"id":"twodimensionalprojectilemotion","heading":"Two-dimensional Projectile Motion","text":""},{"id":"conventions","heading":"Conventions","text":""},{"id":"summary","heading":"Summary","text":"It's easy to see in the above visualization that and increase linearly, while is non-linear."},{"id":"formulas","heading":"Formulas","text":""},{"id":"displacementprojectileposition","heading":"Displacement & Projectile Position","text":""},{"id":"generalized","heading":"Generalized","text":"In general （without respect to any or axis values） Where the distance traveled or displaced is"},{"id":"intermsofandaxisvalues","heading":"In terms of and axis values","text":""},{"id":"withrespecttotheaxis","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Since Which can be read as （in terms of the axis）"},{"id":"withrespecttotheaxis1","heading":"With respect to the axis","text":"The displacement of a given projectile in terms of the axis is Note that （because there is no force acting on the projectile in the horizontal direction）, and therefore the initial and final velocities are the same. I.e. it's constant throughout. Therefore in summary and therefore we will simple refer to the velocity vector as as . Therefore we can simplify equation （1） considerably"},{"id":"solvingprojectilemotionproblems","heading":"Solving Projectile Motion Problems","text":""},{"id":"projectilemotion","heading":"Projectile Motion","text":""},{"id":"intermsoftheaxis","heading":"In terms of the axis","text":"TODO TODO"},{"id":"intermsoftheaxis1","heading":"In terms of the axis","text":"TODO TODO"},{"id":"insummary","heading":"In Summary","text":"Initial Quantities Derived expressions Solutions"},{"id":"projectilemotionfromaninitialheightwithgiveninitialvelocityandangle","heading":"Projectile Motion from an initial height, with given initial velocity and angle","text":"Given A projectile angle The initial height The initial velocity We can therefore derive the the initial velocities for and in terms of the given angle and initial velocity. Given the general formulas for displacement and velocity Which this information, we will derive specific equations in terms of the and axes governing the projectile."},{"id":"intermsoftheaxis2","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts From the given depiction of the problem, we know that . There is no acceleration along the axis, so . as shown above. Therefore"},{"id":"derivingvelocity","heading":"Deriving velocity","text":""},{"id":"intermsoftheaxis3","heading":"In terms of the axis","text":""},{"id":"derivingdisplacementasafunctionoftime1","heading":"Deriving displacement as a function of time","text":"Using the general formula from above in terms of as a function of time. Which we can simplify using the following facts Initial height is given to us which we will represent as , for the sake of generality. Acceleration along the axis is the constant for gravity, so . as shown above. Therefore"},{"id":"derivingvelocity1","heading":"Deriving velocity","text":""},{"id":"insummary1","heading":"In summary","text":""},{"id":"tofindtherange","heading":"To find the range","text":"We know that at the moment of impact , therefore we can use equation Rearranging a bit and setting , we can see that solving for will yield the time at which . Therefore Plugging the solution for （and ignoring the negative or non-real solutions for ） into will yield the horizontal displacement （range） at the time . Therefore:"},{"id":"tofindthemaximumverticaldisplacementiepeakheight","heading":"To find the maximum vertical displacement （i.e. peak height）","text":"We begin with equation We know that at the moment our projectile crests its trajectory, the vertical component of our projectile will be zero. Therefore . To find the time, we simply solve for . Therefore, knowing the time at which our projectile crests its trajectory, we simply plugin our solution for into the function given in equation . I.e:"},{"id":"tofindthevelocityatagivenmomentoftime","heading":"To find the velocity at a given moment of time","text":"Given some time which we will denote as , to find the velocity we simply plug in our given values for and into equations and . I.e. With the given value for , yielding the vector at time , which we will denote as To define the vector in terms of engineering notation, （i.e. ） To define the vector in terms of magnitude （which we will denote as ） and direction （which we will denote as ）"},{"id":"range","heading":"Range","text":"The distance a projectile travels is called its range. Only applies in situations where the projectile lands at the same elevation from which it was fired."},{"id":"reasoningaboutprojectilemotion","heading":"Reasoning About Projectile Motion","text":""},{"id":"notes","heading":"Notes","text":"An object is in free fall when the only force acting on it is the force of gravity."},{"id":"question","heading":"Question","text":"Based on the figure, for which trajectory was the object in the air for the greatest amount of time?"},{"id":"answer","heading":"Answer","text":"Trajectory A"},{"id":"explanation","heading":"Explanation","text":"All that matters is the vertical height of the trajectory, which is based on the component of the initial velocity in the vertical direction （）. The higher the trajectory, the more time the object will be in the air, regardless of the object's range or horizontal velocity."},{"id":"problems","heading":"Problems","text":"The function in this graph represents an object that is speeding up, or accelerating at a constant rate. When you throw a ball directly upward, what is true about its acceleration after the ball has left your hand? Answer: The ball’s acceleration is always directed downward. Wrong: The ball’s acceleration is always directed downward, except at the top of the motion, where the acceleration is zero. Question As an object moves in the x-y plane, which statement is true about the object’s instantaneous velocity at a given moment? Answer The instantaneous velocity is tangent to the object’s path Wrong The instantaneous velocity is perpendicular to the object’s path. The instantaneous velocity can point in any direction, independent of the object’s path. Explanation As an object moves in the x-y plane the instantaneous velocity is tangent to the object‘s path at a given moment. This is because the displacement vector during an infinitesimally small time interval is always directed along the object’s path and the velocity vector always has the same direction as the displacement vector."},{"id":"relativemotion","heading":"Relative Motion","text":""},{"id":"galileantransformationofvelocity","heading":"Galilean transformation of velocity","text":"The velocity of some object P as seen from a stationary frame must be the sum of and Where Symbol Description Velocity as measured in a stationary frame Velocity of an object measured in the moving frame relative to the moving frame velocity of the moving frame - with respect to the stationary frame"},{"id":"galileantransformationofvelocityalternatenotation","heading":"Galilean transformation of velocity （alternate notation）","text":"Given two reference frames A and and some object . The velocity of the object can be defined in terms of or as shown Symbol Description The velocity of relative to The velocity of relative to The velocity of relative to The velocity of relative to . It locates the origin of relative to the origin of . Therefore"},{"id":"rotationalmotionkinematics","heading":"Rotational Motion & Kinematics","text":""},{"id":"basics","heading":"Basics","text":""},{"id":"auxiliaryformulareference","heading":"Auxiliary Formula Reference","text":"Formula Missing Quantities Present A particle moves with uniform circular motion if and only if its angular velocity V is constant and unchanging."},{"id":"uniformcircularmotion","heading":"Uniform Circular Motion","text":"Uniform means content speed"}]},{"href":"/physics/forces-newtons-laws/index.html","title":"Forces and Newton's laws of motion","sections":[{"id":"forcesandnewtonslawsofmotion","heading":"Forces and Newton's laws of motion","text":""},{"id":"newtonslawsofmotion","heading":"Newton's laws of motion","text":""},{"id":"normalforceandcontactforce","heading":"Normal force and contact force","text":""},{"id":"balancedandunbalancedforces","heading":"Balanced and unbalanced forces","text":""},{"id":"inclinedplanesandfriction","heading":"Inclined planes and friction","text":""},{"id":"tension","heading":"Tension","text":""}]},{"href":"/physics/forces-newtons-laws/balanced-unbalanced-forces/index.html","title":"Balanced and unbalanced forces","sections":[{"id":"balancedandunbalancedforces","heading":"Balanced and unbalanced forces","text":""}]},{"href":"/physics/forces-newtons-laws/inclined-planes-friction/index.html","title":"Inclined planes and friction","sections":[{"id":"inclinedplanesandfriction","heading":"Inclined planes and friction","text":""}]},{"href":"/physics/forces-newtons-laws/newton-laws-motion/index.html","title":"Newton's laws of motion","sections":[{"id":"newtonslawsofmotion","heading":"Newton's laws of motion","text":""}]},{"href":"/physics/forces-newtons-laws/normal-contact-force/index.html","title":"Normal force and contact force","sections":[{"id":"normalforceandcontactforce","heading":"Normal force and contact force","text":""}]},{"href":"/physics/forces-newtons-laws/tension/index.html","title":"Tension","sections":[{"id":"tension","heading":"Tension","text":""}]},{"href":"/physics/prelude/index.html","title":"Prelude","sections":[{"id":"prelude","heading":"Prelude","text":""},{"id":"constants","heading":"Constants","text":""},{"id":"tables","heading":"Tables","text":""},{"id":"linearkinematicequations1d","heading":"Linear Kinematic Equations （1D）","text":"Formula Missing Quantities Present"},{"id":"rotationalkinematicequations","heading":"Rotational Kinematic Equations","text":"Formula Missing Quantities Present"},{"id":"scalarorvector","heading":"Scalar or Vector","text":"Name Type Speed Scalar Distance Scalar Velocity Vector Acceleration Vector Average Velocity Vector Displacement Vector Position Vector"}]},{"href":"/test/index.html","title":"Test Page","sections":[{"id":"testpage","heading":"Test Page","text":""},{"id":"helloworld","heading":"Hello world","text":""},{"id":"helloworld1","heading":"Hello world","text":""},{"id":"helloworld2","heading":"Hello world","text":""},{"id":"helloworld3","heading":"Hello world","text":""},{"id":"helloworld4","heading":"Hello world","text":""},{"id":"helloworld5","heading":"Hello world","text":""},{"id":"math","heading":"Math","text":""},{"id":"drawings","heading":"Drawings","text":""},{"id":"togglevisivility","heading":"Toggle Visivility","text":""},{"id":"compositions","heading":"Compositions","text":""},{"id":"helloworld6","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld7","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor."},{"id":"helloworld8","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld9","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld10","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld11","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld12","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld13","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld14","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld15","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld16","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld17","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld18","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld19","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld20","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld21","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld22","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld23","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld24","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld25","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld26","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld27","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld28","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld29","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld30","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld31","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."},{"id":"helloworld32","heading":"Hello world","text":"Lorem ipsum dolor sit amet, consectetur adipiscing elit. Etiam a nisi eu sapien bibendum scelerisque. Vestibulum ante ipsum primis in faucibus orci luctus et ultrices posuere cubilia curae; Fusce id blandit lacus, at interdum massa. Aenean a purus aliquam justo varius feugiat. Quisque eget posuere odio, mollis consectetur purus. Morbi iaculis porta diam, quis rhoncus orci dictum tempor. Pellentesque viverra quam erat, sagittis luctus nulla condimentum in. Morbi dui ligula, laoreet eget urna sed, accumsan semper metus. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Mauris ex enim, dapibus vitae sollicitudin vitae, placerat et lorem. Class aptent taciti sociosqu ad litora torquent per conubia nostra, per inceptos himenaeos. Etiam vel viverra ante. Ut ac lacus erat. Nunc eleifend elementum ex a posuere."}]}]};
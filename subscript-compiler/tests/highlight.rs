@@ -0,0 +1,142 @@
+//! Build-time syntax highlighting, i.e. `html::highlight`, for `\pre`,
+//! `\code` and `\include`d source files.
+mod common;
+
+use std::path::Path;
+use subscript_compiler::html::highlight::{find_grammar, parse_line_ranges, Class};
+use subscript_compiler::html::import::import_html_str;
+use common::{compile, temp_dir, write};
+
+/// Compiles `index.ss` of the given directory, returning the `<main>` element
+/// and the diagnostics.
+fn compile_file(dir: &Path) -> (String, String) {
+    let page = compile(dir, "compile-file", "index.ss", "out/index.html", &[]);
+    assert!(page.status.success(), "{}", page.stderr);
+    (page.contents, page.stderr)
+}
+
+/// The classed tokens, e.g. `keyword fn`.
+fn classed_tokens(lang: &str, code: &str) -> Vec<String> {
+    find_grammar(lang)
+        .unwrap()
+        .tokens(code)
+        .into_iter()
+        .filter_map(|token| Some(format!("{} {}", token.class?.css_class(), token.text)))
+        .collect()
+}
+
+#[test]
+fn tokens_are_classified() {
+    assert_eq!(
+        classed_tokens("rust", "#[derive(Debug)]\nfn main() { let s: String = format!(\"{}\", 1); } // done"),
+        [
+            "hl-attribute #[derive(Debug)]",
+            "hl-keyword fn",
+            "hl-function main",
+            "hl-keyword let",
+            "hl-type String",
+            "hl-macro format!",
+            "hl-string \"{}\"",
+            "hl-number 1",
+            "hl-comment // done",
+        ],
+    );
+    assert_eq!(
+        classed_tokens("py", "def f(x=None):\n    return 'a' # b"),
+        ["hl-keyword def", "hl-function f", "hl-constant None", "hl-keyword return", "hl-string 'a'", "hl-comment # b"],
+    );
+    assert_eq!(
+        classed_tokens("json", "{\"key\": [1.5, true]}"),
+        ["hl-property \"key\"", "hl-number 1.5", "hl-constant true"],
+    );
+    assert_eq!(
+        classed_tokens("SQL", "Select name FROM t"),
+        ["hl-keyword Select", "hl-keyword FROM"],
+    );
+    // Tokens spanning lines are split.
+    let lines = find_grammar("c").unwrap().lines("/* a\nb */ int x;");
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1][0].class, Some(Class::Comment));
+    assert_eq!(lines[1][0].text, "b */");
+    assert!(find_grammar("klingon").is_none());
+}
+
+#[test]
+fn line_ranges_are_parsed() {
+    assert_eq!(parse_line_ranges("2, 4-6"), Some(vec![2..=2, 4..=6]));
+    assert_eq!(parse_line_ranges("10-"), Some(vec![10..=usize::MAX]));
+    assert_eq!(parse_line_ranges("6-4"), None);
+    assert_eq!(parse_line_ranges("0"), None);
+    assert_eq!(parse_line_ranges("x"), None);
+}
+
+#[test]
+fn code_blocks_are_highlighted() {
+    let dir = temp_dir("highlight-pre");
+    write(
+        &dir,
+        "index.ss",
+        &[
+            "\\pre[lang=rust, line-numbers, highlight=\"2\", class=\"wide\"]{",
+            "fn main() {",
+            "    x < 1",
+            "}",
+            "}",
+            "\\p{Run \\code[lang=sh]{echo $HOME} or \\code{plain}.}",
+            "\\pre{plain}",
+            "\\pre[lang=klingon]{x}",
+        ].join("\n"),
+    );
+    let (html, stderr) = compile_file(&dir);
+    let expected = [
+        "<pre class=\"wide\" data-lang=\"rust\" data-line-numbers=\"\"><code class=\"language-rust\">",
+        "<span class=\"line\" data-line=\"1\"><span class=\"hl-keyword\">fn</span> <span class=\"hl-function\">main</span>() {</span>\n",
+        "<span class=\"line\" data-highlighted=\"\" data-line=\"2\">    x &lt; <span class=\"hl-number\">1</span></span>\n",
+        "<span class=\"line\" data-line=\"3\">}</span>",
+        "</code></pre>",
+        "<p>Run <code class=\"language-shell\" data-lang=\"shell\">echo <span class=\"hl-variable\">$HOME</span></code> or <code>plain</code>.</p>",
+        "<pre>plain</pre>",
+        "<pre data-lang=\"klingon\"><code><span class=\"line\" data-line=\"1\">x</span></code></pre>",
+    ];
+    assert_eq!(html, format!("<main>{}</main>", expected.concat()));
+    assert!(stderr.contains("unknown `lang` value `klingon`"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn source_files_are_included() {
+    let dir = temp_dir("highlight-include");
+    write(&dir, "main.rs", "use std::io;\n\n\nfn main() {\n    // <main>\n}\n");
+    write(
+        &dir,
+        "index.ss",
+        "\\include[src=\"main.rs\", lines=\"2-5\", highlight=\"5\"]\n\\include[src=\"main.rs\", lang=\"text\", lines=\"1\"]\n",
+    );
+    let (html, stderr) = compile_file(&dir);
+    let expected = [
+        // The blank lines are dropped, but the numbering is kept.
+        "<pre data-lang=\"rust\"><code class=\"language-rust\">",
+        "<span class=\"line\" data-line=\"4\"><span class=\"hl-keyword\">fn</span> <span class=\"hl-function\">main</span>() {</span>\n",
+        "<span class=\"line\" data-highlighted=\"\" data-line=\"5\">    <span class=\"hl-comment\">// &lt;main&gt;</span></span>",
+        "</code></pre>",
+        "<pre data-lang=\"text\"><code class=\"language-text\"><span class=\"line\" data-line=\"1\">use std::io;</span></code></pre>",
+    ];
+    assert_eq!(html, format!("<main>{}</main>", expected.concat()));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn markdown_and_imported_code_is_highlighted() {
+    let dir = temp_dir("highlight-markdown");
+    write(&dir, "notes.md", "```python\nprint(1)\n```\n");
+    write(&dir, "index.ss", "\\include[src=\"notes.md\"]\n");
+    let (html, _) = compile_file(&dir);
+    assert!(
+        html.contains("<code class=\"language-python\"><span class=\"line\" data-line=\"1\"><span class=\"hl-function\">print</span>"),
+        "{html}",
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+    let source = import_html_str("<pre><code class=\"language-rust hljs\">fn main() {}</code></pre>").source;
+    assert!(source.contains("\\code[lang=\"rust\"]"), "{source}");
+}
@@ -0,0 +1,234 @@
+//! Round trips of `import-html`, i.e. HTML is imported VIA
+//! `html::import::import_html_str`, and the generated source is compiled back
+//! to HTML, which should have the same text, structure and math.
+mod common;
+
+use subscript_compiler::compiler::low_level_api;
+use subscript_compiler::html;
+use subscript_compiler::html::import::{check_syntax, import_html_str};
+use subscript_compiler::ss::bibliography::Bibliography;
+use subscript_compiler::ss::diagnostics::Diagnostic;
+use subscript_compiler::ss::labels::LabelIndex;
+use subscript_compiler::ss::{ResourceEnv, SemanticScope};
+use common::{run, temp_dir, write};
+
+const NOTES: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head><title>Notes</title><style>p {color: red}</style></head>
+<body>
+<div class="content">
+  <h1>Kinematics</h1>
+  <p>The <b>velocity</b> <i>of a particle</i> is
+    <span class="katex"><span class="katex-mathml"><math><semantics><mrow><mi>v</mi></mrow><annotation encoding="application/x-tex">v = \frac{dx}{dt}</annotation></semantics></math></span><span class="katex-html" aria-hidden="true"><span class="mord">v</span></span></span>,
+    see <a href="other.html" class="btn">the other page</a>.</p>
+  <span class="katex-display"><span class="katex"><span class="katex-mathml"><math display="block"><semantics><mrow></mrow><annotation encoding="application/x-tex">
+      a = \frac{dv}{dt}
+  </annotation></semantics></math></span></span></span>
+  <p>With MathJax <span class="MathJax_Preview">x^2</span><span class="MathJax"><nobr>x2</nobr></span><script type="math/tex">x^2</script>, and \(y^2\) before typesetting.</p>
+  <p>A [note] (unbalanced and a "quote, C:\path, a &lt;tag&gt; &amp; 1 /// 2.</p>
+  <ul>
+    <li>First <em>item</em></li>
+    <li>Second
+      <ol start="3"><li>Nested</li><li>Another</li></ol>
+    </li>
+  </ul>
+  <table>
+    <thead><tr><th>Name</th><th>Value</th></tr></thead>
+    <tbody><tr><td colspan="2">Both</td></tr><tr><td>a</td><td><code>f(x)</code></td></tr></tbody>
+  </table>
+  <pre><code class="language-rust hljs"><span class="hljs-keyword">fn</span> main() {
+    println!("{}", x[0]);
+}</code></pre>
+  <div>A div paragraph</div>
+</div>
+</body>
+</html>
+"#;
+
+/// Compiles the given source, along with the TeX of every math node.
+fn compile(source: &str) -> (html::Node, Vec<String>, Vec<Diagnostic>) {
+    let env = ResourceEnv::default();
+    let scope = SemanticScope::new(
+        ".",
+        "import.ss",
+        subscript_compiler::ss_v1_std::all_commands_list(),
+    );
+    let node = low_level_api::parse_source(&env, &scope, source).unwrap();
+    let node = low_level_api::process_commands(&env, &scope, node);
+    subscript_compiler::ss::validation::validate(&env, &scope, &node);
+    let (cg_env, html) = low_level_api::ast_to_html(
+        &env,
+        &scope,
+        &LabelIndex::default(),
+        &Bibliography::default(),
+        node,
+    );
+    let math = cg_env.math_env.map(|x| {
+        x.entries.iter().map(|x| x.code.clone()).collect::<Vec<_>>()
+    });
+    (html, math, env.diagnostics())
+}
+
+fn elements(node: &html::Node, f: &mut impl FnMut(&html::Element)) {
+    match node {
+        html::Node::Element(element) => {
+            f(element);
+            element.children.iter().for_each(|x| elements(x, f));
+        }
+        html::Node::Fragment(xs) => xs.iter().for_each(|x| elements(x, f)),
+        _ => (),
+    }
+}
+
+/// The given tags in document order.
+fn structure(node: &html::Node, tags: &[&str]) -> Vec<String> {
+    let mut result = Vec::new();
+    elements(node, &mut |x| {
+        if tags.contains(&x.name.as_str()) {
+            result.push(x.name.clone());
+        }
+    });
+    result
+}
+
+/// The text, without math and whitespace, and where Subscript’s full-width
+/// brackets and curly quotes are mapped back to ASCII.
+fn normalized_text(node: &html::Node) -> String {
+    fn go(node: &html::Node, result: &mut String) {
+        match node {
+            html::Node::Text(text) => result.push_str(text),
+            html::Node::Element(element) => {
+                let is_math = element.attributes.contains_key("data-math-node")
+                    || element.attributes.get("class").map(|x| x.contains("katex")).unwrap_or(false)
+                    || element.attributes.get("class").map(|x| x.starts_with("MathJax")).unwrap_or(false)
+                    || matches!(element.name.as_str(), "script" | "style" | "head" | "title");
+                if !is_math {
+                    element.children.iter().for_each(|x| go(x, result));
+                }
+            }
+            html::Node::Fragment(xs) => xs.iter().for_each(|x| go(x, result)),
+            html::Node::Drawing(_) => (),
+        }
+    }
+    let mut text = String::new();
+    go(node, &mut text);
+    text.chars()
+        .filter(|x| !x.is_whitespace() && *x != '\u{2060}')
+        .map(|x| match x {
+            '（' => '(',
+            '）' => ')',
+            '［' => '[',
+            '］' => ']',
+            '｛' => '{',
+            '｝' => '}',
+            '“' | '”' | '＂' => '"',
+            '＼' => '\\',
+            x => x,
+        })
+        .collect::<String>()
+        .replace("&lt;", "<")
+        .replace("&amp;", "&")
+}
+
+fn parse_html(html_str: &str) -> html::Node {
+    html::Node::Fragment(html::parser::parse_html_str_keep_whitespace(html_str).payload)
+}
+
+#[test]
+fn round_trip_keeps_text() {
+    let source = import_html_str(NOTES).source;
+    let (output, _, diagnostics) = compile(&source);
+    assert!(diagnostics.iter().all(|x| !x.is_error()), "{diagnostics:#?}");
+    // `\(y^2\)` is math.
+    let original = normalized_text(&parse_html(NOTES)).replace("\\(y^2\\)", "");
+    assert_eq!(normalized_text(&output), original, "{source}");
+}
+
+#[test]
+fn round_trip_keeps_structure() {
+    let tags = [
+        "h1", "p", "b", "i", "a", "ul", "ol", "li", "table", "thead", "tbody", "tr", "th", "td",
+        "pre", "code",
+    ];
+    let source = import_html_str(NOTES).source;
+    let (output, _, _) = compile(&source);
+    let mut expected = structure(&parse_html(NOTES), &tags);
+    // The `<div>` used as a paragraph.
+    expected.push(String::from("p"));
+    assert_eq!(structure(&output, &tags), expected, "{source}");
+}
+
+#[test]
+fn round_trip_keeps_math() {
+    let source = import_html_str(NOTES).source;
+    let (_, math, _) = compile(&source);
+    let math = math
+        .iter()
+        .map(|x| x.split_whitespace().collect::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(math, ["v=\\frac{dx}{dt}", "a=\\frac{dv}{dt}", "x^2", "y^2"]);
+}
+
+#[test]
+fn generated_source_is_clean() {
+    let html = r#"
+        <h2>Title</h2>
+        <p>Some <b>bold</b> <i>text</i> and <span class="katex"><math><semantics><mrow></mrow><annotation encoding="application/x-tex">\left\{ x \right.</annotation></semantics></math></span>.</p>
+        <ul><li>One</li><li>Two<br>lines</li></ul>
+        <script type="math/tex; mode=display">\int_0^1 f(x)\,dx</script>
+    "#;
+    let expected = [
+        "\\h2{Title}",
+        "\\p{Some \\b{bold} \\i{text} and \\{\\left\\lbrace x \\right.}.}",
+        "\\ul{",
+        "   \\li{One}",
+        "   \\li{Two\\br lines}",
+        "}",
+        "\\math{",
+        "   \\int_0^1 f(x)\\,dx",
+        "}",
+        "",
+    ];
+    let source = import_html_str(html).source;
+    assert_eq!(source, expected.join("\n"));
+    assert!(check_syntax("import.ss", &source).is_empty());
+}
+
+#[test]
+fn text_that_looks_like_syntax_is_escaped() {
+    let html = "<p><b>x</b>[1] and {a} but a ( and \\cmd /// not a comment</p>";
+    let source = import_html_str(html).source;
+    assert!(check_syntax("import.ss", &source).is_empty(), "{source}");
+    assert_eq!(source, "\\p{\\b{x}［1］ and {a} but a （ and ＼cmd //\u{2060}/ not a comment}\n");
+}
+
+#[test]
+fn unmapped_tags_are_reported() {
+    let html = "<p><font color=\"red\">Red</font> text</p><svg><circle r=\"1\"/></svg><marquee>Hi</marquee>";
+    let result = import_html_str(html);
+    let unmapped = result.unmapped_tags
+        .iter()
+        .map(|(tag, count)| (tag.as_str(), *count))
+        .collect::<Vec<_>>();
+    assert_eq!(unmapped, [("font", 1), ("marquee", 1), ("svg", 1)]);
+    assert_eq!(result.source, "\\p{Red text}\nHi\n");
+}
+
+#[test]
+fn unwritable_outputs_are_reported() {
+    let dir = temp_dir("import-unwritable");
+    write(&dir, "notes.html", NOTES);
+    // A file where the output directory should be.
+    write(&dir, "out", "");
+    let output = run(&dir, &["import-html", "--source", "notes.html", "--output", "out/notes.ss"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: failed to write out/notes.ss"), "{stderr}");
+    assert!(!stderr.contains("panicked"), "{stderr}");
+    // Likewise for projects without a manifest.
+    let output = run(&dir, &["build", "--project-dir", "."]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: .: No manifest file"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,67 @@
+//! Incremental builds, i.e. `compiler::incremental`.
+mod common;
+
+use std::path::Path;
+use std::time::SystemTime;
+use common::{run, temp_dir, write, write_project};
+
+fn build(dir: &Path) {
+    let result = run(dir, &["build", "--project-dir", "."]);
+    assert!(result.status.success(), "{}", String::from_utf8_lossy(&result.stderr));
+}
+
+fn modified(dir: &Path, out_file: &str) -> SystemTime {
+    std::fs::metadata(dir.join("output").join(out_file)).unwrap().modified().unwrap()
+}
+
+/// The output files rewritten by a build, since the given build.
+fn rewritten(dir: &Path, before: &[(&'static str, SystemTime)]) -> Vec<&'static str> {
+    before
+        .iter()
+        .filter(|(out_file, time)| modified(dir, out_file) != *time)
+        .map(|(out_file, _)| *out_file)
+        .collect()
+}
+
+const PAGES: [&str; 3] = ["index.html", "a/index.html", "b/index.html"];
+
+fn snapshot(dir: &Path) -> Vec<(&'static str, SystemTime)> {
+    PAGES.iter().map(|x| (*x, modified(dir, x))).collect()
+}
+
+/// Ensures later writes get a different modification time, even on
+/// filesystems with coarse timestamps.
+fn tick() {
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+}
+
+#[test]
+fn only_changed_pages_are_recompiled() {
+    let dir = temp_dir("incremental");
+    write_project(&dir);
+    write(&dir, "pages/index.ss", "\\h1{Home}\n\\p{Hi}");
+    write(&dir, "pages/a/index.ss", "\\h1{A}\n\\include[src=\"../shared/note.ss\"]");
+    write(&dir, "pages/b/index.ss", "\\h1{B}\n\\p{Unrelated}");
+    write(&dir, "pages/shared/note.ss", "\\p{First draft}");
+    build(&dir);
+    assert!(dir.join("output").join(".subscript-build-cache.json").exists());
+    // Unchanged pages are skipped.
+    let first = snapshot(&dir);
+    tick();
+    build(&dir);
+    assert_eq!(rewritten(&dir, &first), Vec::<&str>::new());
+    // Editing an include recompiles only its dependents.
+    write(&dir, "pages/shared/note.ss", "\\p{Second draft}");
+    tick();
+    build(&dir);
+    assert_eq!(rewritten(&dir, &first), vec!["a/index.html"]);
+    let html = std::fs::read_to_string(dir.join("output/a/index.html")).unwrap();
+    assert!(html.contains("Second draft"), "{html}");
+    // As does editing the page itself.
+    let second = snapshot(&dir);
+    write(&dir, "pages/b/index.ss", "\\h1{B}\n\\p{Edited}");
+    tick();
+    build(&dir);
+    assert_eq!(rewritten(&dir, &second), vec!["b/index.html"]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
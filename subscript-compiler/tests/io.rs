@@ -0,0 +1,58 @@
+//! I/O failures, which are reported as diagnostics rather than panics.
+mod common;
+
+use std::path::Path;
+use common::{compile, temp_dir, write};
+
+#[test]
+fn unwritable_outputs_are_reported() {
+    let dir = temp_dir("io-unwritable");
+    write(&dir, "index.ss", "\\h1{Hi}");
+    // A file where the output directory should be.
+    write(&dir, "out", "");
+    let result = compile(&dir, "compile-file", "index.ss", "out/index.html", &[]);
+    let stderr = &result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: failed to create out"), "{stderr}");
+    assert!(!stderr.contains("panicked"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn unreadable_sources_are_reported() {
+    let dir = temp_dir("io-unreadable");
+    // Not valid UTF-8.
+    std::fs::write(dir.join("index.ss"), [b'\\', b'p', b'{', 0xff, 0xfe, b'}']).unwrap();
+    let result = compile(&dir, "compile-file", "index.ss", "out/index.html", &[]);
+    let stderr = &result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: Failed to read \"index.ss\""), "{stderr}");
+    assert!(!stderr.contains("panicked"), "{stderr}");
+    assert!(!dir.join("out/index.html").exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn non_html_outputs_are_reported() {
+    let dir = temp_dir("io-extension");
+    write(&dir, "index.ss", "\\h1{Hi}");
+    let result = compile(&dir, "compile-file", "index.ss", "out/index.txt", &[]);
+    let stderr = &result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: the HTML output of index.ss should be a `.html` file, not out/index.txt"), "{stderr}");
+    assert!(!dir.join("out/index.txt").exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn images_of_sources_in_the_current_directory_are_found() {
+    let dir = temp_dir("io-bare-source");
+    let image = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/3x3-determinant.png");
+    std::fs::copy(image, dir.join("image.png")).unwrap();
+    write(&dir, "index.ss", "\\img[src=\"image.png\"]");
+    let result = compile(&dir, "compile-file", "index.ss", "out/index.html", &[]);
+    let stderr = &result.stderr;
+    assert!(result.status.success(), "{stderr}");
+    assert!(result.contents.contains("<img"), "{}", result.contents);
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,32 @@
+//! The LaTeX backend, i.e. `compile-latex`.
+mod common;
+
+use common::{compile, temp_dir, write, Compiled};
+
+fn compile_latex(dir: &std::path::Path, output: &str) -> Compiled {
+    compile(dir, "compile-latex", "index.ss", output, &[])
+}
+
+#[test]
+fn documents_load_the_packages_they_use() {
+    let dir = temp_dir("latex-preamble");
+    write(&dir, "index.ss", "\\h1{Hi}\n\\p{Text}");
+    let result = compile_latex(&dir, "out/index.tex");
+    assert!(result.status.success(), "{}", result.stderr);
+    let latex = result.contents;
+    // `\textcolor` is emitted for colored text and highlighted code.
+    assert!(latex.contains("\\usepackage{xcolor}"), "{latex}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn non_tex_outputs_are_reported() {
+    let dir = temp_dir("latex-extension");
+    write(&dir, "index.ss", "\\h1{Hi}");
+    let result = compile_latex(&dir, "out/index.txt");
+    let stderr = result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: the LaTeX output of index.ss should be a `.tex` file, not out/index.txt"), "{stderr}");
+    assert!(!dir.join("out/index.txt").exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,38 @@
+//! Link checking, i.e. `compiler::links`.
+mod common;
+
+use std::path::Path;
+use common::{compile, temp_dir, write};
+
+/// Compiles the given file with `--deny-warnings`, returning whether it
+/// succeeded and the diagnostics.
+fn compile_file(dir: &Path, source: &str, output: &str) -> (bool, String) {
+    let result = compile(dir, "compile-file", source, output, &["--deny-warnings"]);
+    (result.status.success(), result.stderr)
+}
+
+#[test]
+fn single_files_have_no_broken_links() {
+    let dir = temp_dir("links-single-file");
+    write(&dir, "notes.ss", "\\h1{Hi}\nText");
+    write(&dir, "chapters/one.ss", "\\h1{One}\n\\h2{Details}");
+    // Neither the template’s home link, nor the table of contents.
+    let (success, stderr) = compile_file(&dir, "notes.ss", "out/notes.html");
+    assert!(success, "{stderr}");
+    let (success, stderr) = compile_file(&dir, "chapters/one.ss", "out/first.html");
+    assert!(success, "{stderr}");
+    let html = std::fs::read_to_string(dir.join("out/first.html")).unwrap();
+    assert!(html.contains("href=\"/first.html#details\""), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn broken_links_are_reported() {
+    let dir = temp_dir("links-broken");
+    write(&dir, "notes.ss", "\\h1{Hi}\nSee \\a[href=\"/missing.html\"]{this}.");
+    let (success, stderr) = compile_file(&dir, "notes.ss", "out/notes.html");
+    assert!(!success, "{stderr}");
+    assert!(stderr.contains("warning: broken link `/missing.html`: missing.html doesn’t exist"), "{stderr}");
+    assert!(stderr.contains("--> notes.ss:2:5"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
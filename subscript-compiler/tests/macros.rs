@@ -0,0 +1,73 @@
+//! User-defined macros, i.e. `ss::macro_decl` and `\define`.
+mod common;
+
+use std::path::Path;
+use common::{compile_page, temp_dir};
+
+/// Compiles the given source as `index.ss`, returning the `<main>` element
+/// and the diagnostics.
+fn compile_file(dir: &Path, source: &str) -> (String, String) {
+    let page = compile_page(dir, source);
+    (page.contents, page.stderr)
+}
+
+/// The example from the README.
+const CARD: &str = "\
+\\define[name=\"card\", params=\"title, body\", cols=\"3\"]{
+    \\note[boxed]{\\h2{\\title}\\grid[col=\"\\cols\"]{\\body}}
+}
+";
+
+#[test]
+fn macros_are_expanded() {
+    let dir = temp_dir("macros-expanded");
+    let (html, stderr) = compile_file(&dir, &format!(
+        "{CARD}\n\\card[cols=\"2\"]{{Some Title}}{{\\p{{One}}\\p{{Two}}}}\n\\card{{Default}}{{\\p{{Three}}}}"
+    ));
+    assert!(!stderr.contains("warning") && !stderr.contains("error"), "{stderr}");
+    assert!(html.contains(concat!(
+        "<section boxed=\"\" data-cmd=\"note\"><h2 id=\"sometitle\">",
+        "<a href=\"/index.html#sometitle\">Some Title</a></h2>",
+        "<div data-cmd=\"grid\" data-col=\"2\"><p>One</p><p>Two</p></div></section>",
+    )), "{html}");
+    // The default of the attribute.
+    assert!(html.contains("<div data-cmd=\"grid\" data-col=\"3\"><p>Three</p></div>"), "{html}");
+    assert!(!html.contains("\\card") && !html.contains("\\define"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn invalid_invocations_are_reported() {
+    let dir = temp_dir("macros-invalid");
+    let (html, stderr) = compile_file(&dir, &format!(
+        "{CARD}\n\\card{{Missing}}\n\\card[rows=\"2\"]{{Extra}}{{\\p{{Four}}}}"
+    ));
+    assert!(stderr.contains("error: `\\card` expects 2 argument(s), but was given 1"), "{stderr}");
+    assert!(stderr.contains("--> index.ss:5:1"), "{stderr}");
+    assert!(stderr.contains("warning: unknown attribute `rows` for `\\card`, it will be ignored"), "{stderr}");
+    // The invalid invocation is dropped, the unknown attribute ignored.
+    assert!(!html.contains("Missing"), "{html}");
+    assert!(html.contains("<div data-cmd=\"grid\" data-col=\"3\"><p>Four</p></div>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn recursive_macros_are_reported() {
+    let dir = temp_dir("macros-recursive");
+    let (html, stderr) = compile_file(&dir, "\\define[name=\"loop\", params=\"x\"]{\\p{\\loop{\\x}}}\n\\loop{Once}");
+    assert!(stderr.contains("error: `\\loop` is defined in terms of itself"), "{stderr}");
+    assert_eq!(stderr.matches("error:").count(), 1, "{stderr}");
+    // Expanded once, without the recursive invocation.
+    assert!(html.contains("<p></p>"), "{html}");
+    assert!(!html.contains("Once"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn nested_invocations_are_expanded() {
+    let dir = temp_dir("macros-nested");
+    let (html, stderr) = compile_file(&dir, "\\define[name=\"box\", params=\"body\"]{\\note{\\body}}\n\\box{\\box{\\p{Inner}}}");
+    assert!(!stderr.contains("warning") && !stderr.contains("error"), "{stderr}");
+    assert!(html.contains("<section data-cmd=\"note\"><section data-cmd=\"note\"><p>Inner</p></section></section>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
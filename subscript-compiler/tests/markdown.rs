@@ -0,0 +1,116 @@
+//! The Markdown front end, i.e. `ss::markdown`.
+mod common;
+
+use std::path::Path;
+use common::{compile, temp_dir, write};
+
+/// Compiles the given file (within `site`), returning the `<main>` element
+/// and the diagnostics.
+fn compile_file(dir: &Path, source: &str) -> (String, String) {
+    let page = compile(dir, "compile-file", &format!("site/{source}"), "out/index.html", &[]);
+    (page.contents, page.stderr)
+}
+
+const NOTES: &str = r#"---
+title: Skipped
+---
+# Title
+
+Setext
+------
+
+Some *emphasis*, **strong**, ~~gone~~, `a * b` and $x^2$ math.
+"#;
+
+#[test]
+fn blocks_are_translated() {
+    let dir = temp_dir("markdown-blocks");
+    write(&dir, "site/index.md", &format!("{NOTES}{}", r#"
+- One
+- Two
+  1. Nested
+  2. Ordered
+
+> Quoted
+
+```rust
+fn main() {}
+```
+
+    indented *code*
+
+$$
+\frac{1}{2}
+$$
+
+***
+"#));
+    let (html, stderr) = compile_file(&dir, "index.md");
+    assert!(!stderr.contains("warning") && !stderr.contains("error"), "{stderr}");
+    assert!(!html.contains("Skipped"), "{html}");
+    // ATX and setext headings.
+    assert!(html.contains("<h1 id=\"title\"><a href=\"/index.html#title\">Title</a></h1>"), "{html}");
+    assert!(html.contains("<h2 id=\"setext\"><a href=\"/index.html#setext\">Setext</a></h2>"), "{html}");
+    assert!(html.contains(concat!(
+        "<ul><li>One</li><li>Two<ol><li>Nested</li><li>Ordered</li></ol></li></ul>",
+        "<blockquote><p>Quoted</p></blockquote>",
+    )), "{html}");
+    // Fenced code is highlighted, indented code is kept as is.
+    assert!(html.contains("<pre data-lang=\"rust\"><code class=\"language-rust\">"), "{html}");
+    assert!(html.contains("<span class=\"hl-keyword\">fn</span> <span class=\"hl-function\">main</span>() {}"), "{html}");
+    assert!(html.contains("<pre><code>indented *code*</code></pre>"), "{html}");
+    assert!(html.contains("<div data-cmd=\"math\" data-math-node=\"block\""), "{html}");
+    assert!(html.contains("<mfrac><mn>1</mn><mn>2</mn></mfrac>"), "{html}");
+    assert!(html.contains("<hr></hr></main>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn inlines_are_translated() {
+    let dir = temp_dir("markdown-inlines");
+    std::fs::create_dir_all(dir.join("site/img")).unwrap();
+    std::fs::copy("tests/3x3-determinant.png", dir.join("site/img/a.png")).unwrap();
+    write(&dir, "site/index.md", &format!("{NOTES}{}", r#"
+A [link](https://example.com "Example"), a [reference][ref], <https://auto.example>
+and \*escaped\* text.
+
+![Alt text](img/a.png)
+
+[ref]: https://reference.example
+"#));
+    let (html, stderr) = compile_file(&dir, "index.md");
+    assert!(!stderr.contains("warning") && !stderr.contains("error"), "{stderr}");
+    assert!(html.contains(concat!(
+        "Some <em>emphasis</em>, <strong>strong</strong>, <del>gone</del>, ",
+        "<code>a * b</code> and <span data-cmd=\"inline-math\" data-math-node=\"inline\"",
+    )), "{html}");
+    assert!(html.contains("<msup><mi>x</mi><mn>2</mn></msup>"), "{html}");
+    assert!(html.contains(concat!(
+        "A <a href=\"https://example.com\" title=\"Example\">link</a>, ",
+        "a <a href=\"https://reference.example\">reference</a>, ",
+        "<a href=\"https://auto.example\">https://auto.example</a>\nand *escaped* text.",
+    )), "{html}");
+    // Images are written like those of `\img`.
+    assert!(html.contains("<p><img src=\"/static-assets/a-"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn markdown_files_are_included() {
+    let dir = temp_dir("markdown-include");
+    write(&dir, "site/chapters/notes.md", NOTES);
+    write(&dir, "site/index.ss", concat!(
+        "\\h1{Page}\n",
+        "\\include[src=\"chapters/notes.md\", baseline=\"h2\"]\n",
+        "\\include[src=\"chapters/missing.md\"]",
+    ));
+    let (html, stderr) = compile_file(&dir, "index.ss");
+    assert!(stderr.contains("included file not found"), "{stderr}");
+    assert!(stderr.contains("missing.md"), "{stderr}");
+    // Headings are shifted by the baseline, like those of `.ss` includes.
+    assert!(html.contains("<h2 id=\"title\">"), "{html}");
+    assert!(html.contains("<h3 id=\"setext\">"), "{html}");
+    assert!(html.contains("<strong>strong</strong>"), "{html}");
+    assert!(html.contains("<msup><mi>x</mi><mn>2</mn></msup>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,17 @@
+//! Prerendered math, i.e. KaTeX errors reported by `HtmlCodegenEnv`.
+mod common;
+
+use common::{compile_page, temp_dir};
+
+#[test]
+fn invalid_math_points_at_its_source() {
+    let dir = temp_dir("math-invalid");
+    let result = compile_page(&dir, "\\h1{Math}\n\\p{Some \\{x^} text}");
+    let stderr = result.stderr;
+    assert!(stderr.contains("warning: invalid math `x^`"), "{stderr}");
+    assert!(stderr.contains("--> index.ss:2:9"), "{stderr}");
+    assert!(stderr.contains("2 | \\p{Some \\{x^} text}"), "{stderr}");
+    let html = result.contents;
+    assert!(html.contains("data-math-error"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
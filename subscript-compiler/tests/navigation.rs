@@ -0,0 +1,97 @@
+//! Site-wide navigation, i.e. `html::navigation`.
+use subscript_compiler::html::navigation::{NavPageEntry, SiteNavigation};
+
+fn entry(rel_path: &str, title: Option<&str>) -> NavPageEntry {
+    NavPageEntry {
+        rel_path: rel_path.to_owned(),
+        href: format!("/{rel_path}"),
+        title: title.map(String::from),
+    }
+}
+
+/// Given out of order, with a directory (`c`) without a page of its own.
+fn site() -> SiteNavigation {
+    SiteNavigation::new([
+        entry("c/deep/x.html", Some("X")),
+        entry("b/index.html", Some("B")),
+        entry("a/two.html", None),
+        entry("index.html", None),
+        entry("a/one/index.html", Some("One")),
+        entry("a/index.html", Some("A")),
+    ], Some("Notes"))
+}
+
+#[test]
+fn pages_are_in_reading_order() {
+    let site = site();
+    let pages = site.pages()
+        .into_iter()
+        .map(|x| format!("{} {}", x.href, x.title))
+        .collect::<Vec<_>>();
+    assert_eq!(pages, [
+        // Named after the site, or their file otherwise.
+        "/index.html Notes",
+        "/a/index.html A",
+        "/a/one/index.html One",
+        "/a/two.html two",
+        "/b/index.html B",
+        "/c/deep/x.html X",
+    ]);
+}
+
+#[test]
+fn pages_link_to_their_neighbours() {
+    let site = site();
+    let page_nav = |href: &str| site.to_page_nav(href).to_html_fragment_str();
+    let link = |rel: &str, href: &str, label: &str, title: &str| format!(
+        "<a href=\"{href}\" rel=\"{rel}\"><span data-label=\"\">{label}</span><span data-title=\"\">{title}</span></a>"
+    );
+    assert_eq!(
+        page_nav("/index.html"),
+        format!("<nav id=\"page-nav\">{}</nav>", link("next", "/a/index.html", "Next", "A")),
+    );
+    assert_eq!(
+        page_nav("/a/two.html"),
+        format!(
+            "<nav id=\"page-nav\">{}{}</nav>",
+            link("prev", "/a/one/index.html", "Previous", "One"),
+            link("next", "/b/index.html", "Next", "B"),
+        ),
+    );
+    assert_eq!(
+        page_nav("/c/deep/x.html"),
+        format!("<nav id=\"page-nav\">{}</nav>", link("prev", "/b/index.html", "Previous", "B")),
+    );
+    assert_eq!(page_nav("/missing.html"), "");
+}
+
+#[test]
+fn breadcrumbs_lead_from_the_root() {
+    let site = site();
+    let breadcrumbs = |href: &str| site.to_breadcrumbs(href).to_html_fragment_str();
+    assert_eq!(breadcrumbs("/a/one/index.html"), concat!(
+        "<nav id=\"breadcrumbs\"><ol>",
+        "<li><a href=\"/index.html\">Notes</a></li>",
+        "<li><a href=\"/a/index.html\">A</a></li>",
+        "<li><a aria-current=\"page\" href=\"/a/one/index.html\">One</a></li>",
+        "</ol></nav>",
+    ));
+    // Directories without a page aren’t links.
+    assert_eq!(breadcrumbs("/c/deep/x.html"), concat!(
+        "<nav id=\"breadcrumbs\"><ol>",
+        "<li><a href=\"/index.html\">Notes</a></li>",
+        "<li><span>c</span></li>",
+        "<li><span>deep</span></li>",
+        "<li><a aria-current=\"page\" href=\"/c/deep/x.html\">X</a></li>",
+        "</ol></nav>",
+    ));
+    assert_eq!(breadcrumbs("/index.html"), "");
+}
+
+#[test]
+fn single_pages_have_no_navigation() {
+    let site = SiteNavigation::new([entry("index.html", Some("Home"))], None);
+    for (_, slot) in site.to_template_slots("/index.html") {
+        assert_eq!(slot.to_html_fragment_str(), "");
+    }
+}
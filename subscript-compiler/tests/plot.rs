@@ -0,0 +1,166 @@
+//! Compile-time plots, i.e. `ss::plot` and `\plot`.
+mod common;
+
+use std::path::Path;
+use subscript_compiler::ss::figure::format_tick;
+use subscript_compiler::ss::plot::{parse_expressions, parse_interval, Curve, Plot, PlotMode};
+use common::{temp_dir, write};
+
+/// Compiles the given source as `index.ss` with the given subcommand
+/// (`compile-file` or `compile-latex`) to the given file, returning its
+/// contents (just the page, without the stylesheet) and the diagnostics.
+fn compile(dir: &Path, command: &str, output: &str, source: &str) -> (String, String) {
+    write(dir, "index.ss", source);
+    let result = common::compile(dir, command, "index.ss", output, &[]);
+    assert!(result.status.success(), "{}", result.stderr);
+    (result.contents, result.stderr)
+}
+
+fn eval(source: &str, x: f64) -> f64 {
+    let expressions = parse_expressions(source, &["x"]).unwrap();
+    assert_eq!(expressions.len(), 1, "{source}");
+    expressions[0].eval(x)
+}
+
+#[test]
+fn expressions_are_evaluated() {
+    for (source, x, expected) in [
+        ("2x^2 + 1", 3.0, 19.0),
+        ("-x^2", 3.0, -9.0),
+        ("2^3^2", 0.0, 512.0),
+        ("x**2", 4.0, 16.0),
+        ("(x + 1)(x - 1)", 3.0, 8.0),
+        ("2pi x", 0.5, std::f64::consts::PI),
+        ("sin(x)^2 + cos(x)^2", 1.3, 1.0),
+        ("|x - 5| + abs(-1)", 2.0, 4.0),
+        ("max(x, 2) / min(x, 2)", 4.0, 2.0),
+        ("ln(e) + log(100) + 1e-1", 0.0, 3.1),
+    ] {
+        let value = eval(source, x);
+        assert!((value - expected).abs() < 1e-9, "{source} = {value}, expected {expected}");
+    }
+    assert!(eval("sqrt(x)", -1.0).is_nan());
+    assert_eq!(eval("1/x", 0.0), f64::INFINITY);
+    let pair = parse_expressions("cos(t), sin(t)", &["t"]).unwrap();
+    assert_eq!(pair.len(), 2);
+    assert_eq!(parse_interval("-pi, 2pi").unwrap(), (-std::f64::consts::PI, 2.0 * std::f64::consts::PI));
+}
+
+#[test]
+fn invalid_expressions_are_explained() {
+    for (source, error) in [
+        ("foo(x)", "unknown name `foo`, expected `x` or a function"),
+        ("sin x", "expected `(` after `sin`"),
+        ("(x + 1", "expected `)` but the expression ended"),
+        ("x + ", "unexpected end of the expression"),
+        ("x)", "unexpected `)`"),
+        ("max(x)", "`max` takes 2 argument(s), not 1"),
+        ("x $ 2", "unexpected `$`"),
+        ("", "empty expression"),
+    ] {
+        assert_eq!(parse_expressions(source, &["x"]).unwrap_err(), error, "{source}");
+    }
+    assert_eq!(parse_interval("1, -1").unwrap_err(), "expected an increasing interval");
+    assert_eq!(parse_interval("1").unwrap_err(), "expected two values but found 1");
+    assert_eq!(Curve::parse("cos(t)", PlotMode::Parametric).unwrap_err(), "expected `x(t), y(t)`");
+}
+
+#[test]
+fn ticks_are_formatted() {
+    assert_eq!(format_tick(-2.0, 1.0), "−2");
+    assert_eq!(format_tick(0.5, 0.5), "0.5");
+    assert_eq!(format_tick(-0.0000001, 0.1), "0");
+    assert_eq!(format_tick(1.0, 0.25), "1.0");
+}
+
+#[test]
+fn asymptotes_split_curves() {
+    let plot = Plot {
+        curves: vec![Curve::parse("tan(x)", PlotMode::Function).unwrap()],
+        domain: Some((-3.0, 3.0)),
+        range: Some((-5.0, 5.0)),
+        ..Plot::default()
+    };
+    let figure = plot.to_figure();
+    let html = figure.to_html(&ss_freeform_format::ColorScheme::Light).to_html_fragment_str();
+    // Three branches of `tan(x)` within `-3…3`.
+    assert_eq!(html.matches("<polyline").count(), 3, "{html}");
+    assert!(html.contains("<title>Plot of y = tan(x)</title>"), "{html}");
+}
+
+#[test]
+fn plots_adapt_to_color_schemes() {
+    let dir = temp_dir("plot-html");
+    let (html, stderr) = compile(&dir, "compile-file", "out/index.html", &[
+        "\\plot[domain=\"-pi, pi\", range=\"-2, 2\", grid, x-label=\"time\"]{",
+        "    \\expr[label=\"sine\", color=\"#2d70b3\", line-style=\"dashed\"]{sin(x)}",
+        "    x^2/4",
+        "}",
+        "\\plot[mode=\"polar\"]{1 + cos(theta)}",
+        "\\plot[mode=\"parametric\", t-domain=\"0, 2pi\"]{cos(3t), sin(2t)}",
+    ].join("\n"));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    assert_eq!(html.matches("<div data-cmd=\"plot\">").count(), 3);
+    assert_eq!(html.matches("data-svg-color-scheme=\"dark-mode\"").count(), 3);
+    assert_eq!(html.matches("data-svg-color-scheme=\"light-mode\"").count(), 3);
+    assert!(html.contains("<title>Plot of y = sin(x), y = x^2/4</title>"), "{html}");
+    assert!(html.contains("<title>Plot of r = 1 + cos(theta)</title>"), "{html}");
+    assert!(html.contains("<title>Plot of (x, y) = (cos(3t), sin(2t))</title>"), "{html}");
+    // Custom colors are kept, others follow the scheme.
+    assert!(html.contains("stroke=\"#2d70b3\" stroke-width=\"2\" stroke-dasharray=\"8 6\""), "{html}");
+    assert!(html.contains("stroke=\"#c74440\""), "{html}");
+    assert!(html.contains("stroke=\"#ff7b72\""), "{html}");
+    for label in [">sine</text>", ">time</text>", ">−3</text>", ">3</text>"] {
+        assert!(html.contains(label), "{label}\n{html}");
+    }
+    // Each figure clips its curves to a distinct path.
+    let ids = html
+        .split("<clipPath id=\"")
+        .skip(1)
+        .map(|x| &x[..x.find('"').unwrap()])
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(ids.len(), 6, "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn invalid_plots_are_reported() {
+    let dir = temp_dir("plot-invalid");
+    let (html, stderr) = compile(&dir, "compile-file", "out/index.html", &[
+        "\\plot[domain=\"3, 1\", mode=\"spiral\"]{",
+        "    \\expr[color=blue]{sin x}",
+        "    x^2",
+        "}",
+        "\\plot[mode=\"parametric\"]{cos(t)}",
+    ].join("\n"));
+    for warning in [
+        "invalid `domain` value `3,1` for `\\plot`: expected an increasing interval",
+        "invalid value `spiral` for `mode` of `\\plot`, expected one of function, parametric, polar",
+        "invalid expression `sin x` for `\\plot`: expected `(` after `sin`",
+        "invalid expression `cos(t)` for `\\plot`: expected `x(t), y(t)`",
+        "`\\plot` has nothing to plot",
+    ] {
+        assert!(stderr.contains(warning), "{warning}\n{stderr}");
+    }
+    // Valid expressions are still plotted.
+    assert!(html.contains("<title>Plot of y = x^2</title>"), "{html}");
+    assert!(html.contains("<title>Empty plot</title>"), "{html}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn plots_are_drawn_with_tikz() {
+    let dir = temp_dir("plot-latex");
+    let (latex, stderr) = compile(&dir, "compile-latex", "out/index.tex", &[
+        "\\plot[domain=\"-2, 2\", y-label=\"f\"]{\\expr[label=\"R&D\", color=\"#c74440\"]{x^3}}",
+    ].join("\n"));
+    assert!(!stderr.contains("warning"), "{stderr}");
+    assert!(latex.contains("\\usepackage{tikz}"), "{latex}");
+    assert!(latex.contains("\\begin{center}\n\\begin{tikzpicture}[x=0.02cm, y=-0.02cm]\n"), "{latex}");
+    assert!(latex.contains("\\clip (64,12) rectangle (584,374);"), "{latex}");
+    assert!(latex.contains("draw={rgb,255:red,199;green,68;blue,64}"), "{latex}");
+    assert!(latex.contains("{\\(-\\)1.5}"), "{latex}");
+    assert!(latex.contains("rotate=90] at (14,193) {f};"), "{latex}");
+    assert!(latex.contains("{R\\&D};"), "{latex}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,38 @@
+//! The full-text search index, i.e. `html::search`.
+use subscript_compiler::html::ast::Node;
+use subscript_compiler::html::search::{page_sections, SearchSection};
+
+fn section(id: &str, heading: &str, text: &str) -> SearchSection {
+    SearchSection {
+        id: id.to_owned(),
+        heading: heading.to_owned(),
+        text: text.to_owned(),
+    }
+}
+
+#[test]
+fn pages_are_split_at_their_headings() {
+    let page = Node::parse_str(concat!(
+        "<main>",
+        "<p>Intro  text,\n   across lines.</p>",
+        "<h2 id=\"first\">First <em>heading</em></h2>",
+        "<p>Some</p><p>text with <span data-math-node=\"inline\"><math><mi>x</mi></math></span> math</p>",
+        "<ul><li>one</li><li>two</li></ul>",
+        "<h3 id=\"second\">Second &amp; last</h3>",
+        "<svg><text>drawn</text></svg><script>let x = 1;</script>",
+        // Only headings with an anchor start a section.
+        "<h4>Not anchored</h4>",
+        "</main>",
+    ));
+    assert_eq!(page_sections(&page), [
+        section("", "", "Intro text, across lines."),
+        section("first", "First heading", "Some text with math one two"),
+        section("second", "Second & last", "Not anchored"),
+    ]);
+}
+
+#[test]
+fn empty_sections_are_dropped() {
+    let page = Node::parse_str("<h1 id=\"title\">Title</h1><p> </p>");
+    assert_eq!(page_sections(&page), [section("title", "Title", "")]);
+}
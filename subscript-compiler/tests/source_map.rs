@@ -0,0 +1,48 @@
+//! `--source-map`, i.e. `ss::source_map`.
+mod common;
+
+use std::path::Path;
+use common::{compile, temp_dir, write};
+
+/// Compiles `index.ss` of the given directory, returning the page.
+fn compile_file(dir: &Path, source_map: bool) -> String {
+    let args: &[&str] = if source_map {&["--source-map"]} else {&[]};
+    let page = compile(dir, "compile-file", "index.ss", "out/index.html", args);
+    assert!(page.status.success(), "{}", page.stderr);
+    page.contents
+}
+
+/// The start tags with a `data-ss-src` attribute, e.g. `p index.ss:2:1`.
+fn mapped_elements(html: &str) -> Vec<String> {
+    html.split('<')
+        .filter_map(|tag| {
+            let tag = tag.split('>').next()?;
+            let name = tag.split_whitespace().next()?;
+            let (_, rest) = tag.split_once("data-ss-src=\"")?;
+            let location = rest.split('"').next()?;
+            Some(format!("{name} {location}"))
+        })
+        .collect()
+}
+
+#[test]
+fn block_elements_point_at_their_source() {
+    let dir = temp_dir("source-map");
+    write(
+        &dir,
+        "index.ss",
+        "\\h1{Title}\n\\p{Hello \\b{world}}\n\\ul{\n   \\li{One}\n}\n\\include[src=\"sub/part.ss\"]\n",
+    );
+    write(&dir, "sub/part.ss", "\n  \\p{Included}\n");
+    let html = compile_file(&dir, true);
+    assert_eq!(mapped_elements(&html), [
+        "h1 index.ss:1:1",
+        "p index.ss:2:1",
+        "ul index.ss:3:1",
+        "li index.ss:4:4",
+        "p sub/part.ss:2:3",
+    ]);
+    let html = compile_file(&dir, false);
+    assert!(mapped_elements(&html).is_empty());
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,70 @@
+//! Diagnostics for input the compiler didn’t understand, i.e.
+//! `ss::validation` and the attribute checks of `ss::post_parser`.
+mod common;
+
+use std::path::Path;
+use common::{compile, temp_dir, write, Compiled};
+
+fn compile_file(dir: &Path, source: &str, args: &[&str]) -> Compiled {
+    write(dir, "index.ss", source);
+    compile(dir, "compile-file", "index.ss", "out/index.html", args)
+}
+
+#[test]
+fn unknown_commands_get_suggestions() {
+    let dir = temp_dir("validation-suggest");
+    let result = compile_file(&dir, "\\nte{\\p{Text}}\n\\p{\\strng{Bold}}\n\\p{\\zzzzzzzz}", &[]);
+    let stderr = &result.stderr;
+    for warning in [
+        "warning: unknown command `\\nte`, did you mean `\\note`?\n  --> index.ss:1:1",
+        "warning: unknown command `\\strng`, did you mean `\\strong`?\n  --> index.ss:2:4",
+        // Nothing is close enough.
+        "warning: unknown command `\\zzzzzzzz`\n  --> index.ss:3:4",
+    ] {
+        assert!(stderr.contains(warning), "{warning}\n{stderr}");
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn attributes_are_checked() {
+    let dir = temp_dir("validation-attributes");
+    let result = compile_file(&dir, &[
+        "\\include",
+        "\\plot[size=large, samples=many]{x^2}",
+        "\\p{\\expr{x^2}}",
+        // Most commands pass other attributes along.
+        "\\note[compact]{\\p{Text}}",
+    ].join("\n"), &[]);
+    let stderr = &result.stderr;
+    for diagnostic in [
+        "warning: `\\include` is missing required attribute(s) `src`\n  --> index.ss:1:1",
+        "warning: unknown attribute `size` for `\\plot`, it will be ignored\n  --> index.ss:2:1",
+        "warning: invalid value `many` for `samples` of `\\plot`, expected an integer\n  --> index.ss:2:1",
+        "warning: `\\expr` is only available within `\\desmos` or `\\geogebra` or `\\plot`\n  --> index.ss:3:4",
+    ] {
+        assert!(stderr.contains(diagnostic), "{diagnostic}\n{stderr}");
+    }
+    assert_eq!(stderr.matches("warning:").count(), 4, "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn deny_warnings_fails_the_build() {
+    let dir = temp_dir("validation-deny-warnings");
+    let source = "\\p{\\unknownCmd}";
+    let result = compile_file(&dir, source, &[]);
+    let stderr = &result.stderr;
+    assert!(result.status.success(), "{stderr}");
+    assert!(stderr.contains("warning: unknown command `\\unknownCmd`"), "{stderr}");
+    let result = compile_file(&dir, source, &["--deny-warnings"]);
+    let stderr = &result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("warning: unknown command `\\unknownCmd`"), "{stderr}");
+    // Errors fail the build either way.
+    let result = compile_file(&dir, "\\p{Text]}", &[]);
+    let stderr = &result.stderr;
+    assert_eq!(result.status.code(), Some(1), "{stderr}");
+    assert!(stderr.contains("error: unmatched `]`"), "{stderr}");
+    let _ = std::fs::remove_dir_all(&dir);
+}